@@ -1,32 +1,47 @@
 use crate::{
     component::{
-        CommandBarComponent, ConnectionListComponent, DataListComponentMySQL, DataListComponentPG,
-        HomeComponent, QueryDetailComponentMySQL, QueryDetailComponentPG, QueryListComponent,
-        RoleDetailComponentPG, RoleListComponentPG, TableDetailComponentMySQL,
-        TableDetailComponentPG, TableListComponentMySQL, TableListComponentPG,
-        UserDetailComponentMySQL, UserListComponentMySQL, ViewDetailComponentMySQL,
-        ViewDetailComponentPG, ViewListComponentMySQL, ViewListComponentPG,
+        ActivityListComponentPG, CommandBarComponent, ConnectionListComponent,
+        DataListComponentMySQL, DataListComponentPG, EventListComponentMySQL,
+        FunctionDetailComponentPG, FunctionListComponentPG, HomeComponent,
+        ProcessListComponentMySQL, QueryDetailComponentMySQL, QueryDetailComponentPG,
+        QueryListComponent, RoleDetailComponentPG, RoleListComponentPG,
+        RoutineDetailComponentMySQL, RoutineListComponentMySQL, SequenceListComponentPG,
+        StatusLineComponent, TableDetailComponentMySQL, TableDetailComponentPG,
+        TableListComponentMySQL, TableListComponentPG, UserDetailComponentMySQL,
+        UserListComponentMySQL, ViewDetailComponentMySQL, ViewDetailComponentPG,
+        ViewListComponentMySQL, ViewListComponentPG,
     },
     config::Config,
-    dialog::confirm::{ConfirmDialog, Kind as ConfirmKind},
-    event::{self, Key, KeyCode, KeyModifier},
+    dialog::{
+        confirm::{ConfirmDialog, Kind as ConfirmKind},
+        CommandPaletteDialog, ConnectionSwitchDialog, FavoriteDialog,
+    },
+    event::{self, config::*, Key},
     model::{
-        mysql::Connections as MySQLConnections, pg::Connections as PGConnections, query::Queries,
+        favorite::Favorites,
+        get_all_connections, get_connection,
+        mysql::Connections as MySQLConnections,
+        pg::Connections as PGConnections,
+        query::Queries,
+        session::{Session, SessionPanel},
+        table_view::TableViews,
         DatabaseKind,
     },
     pool::{MySQLPools, PGPools},
+    widget::ConnectionAccent,
 };
-use anyhow::Result;
+use anyhow::{Error, Result};
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use std::{cell::RefCell, io, rc::Rc};
-use tui::{
+use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame, Terminal,
 };
+use std::{cell::RefCell, io, rc::Rc};
 use uuid::Uuid;
 
 pub const APP_DIR: &str = ".sqltui";
@@ -68,11 +83,13 @@ pub enum Goto {
         conn_id: Uuid,
         db_name: String,
         query_name: Option<String>,
+        sql: Option<String>,
     },
     QueryDetailPG {
         conn_id: Uuid,
         db_name: String,
         query_name: Option<String>,
+        sql: Option<String>,
     },
     ViewListMySQL {
         conn_id: Uuid,
@@ -94,9 +111,42 @@ pub enum Goto {
         schema_name: String,
         view_name: Option<String>,
     },
+    RoutineListMySQL {
+        conn_id: Uuid,
+        db_name: String,
+    },
+    RoutineDetailMySQL {
+        conn_id: Uuid,
+        db_name: String,
+        routine_kind: Option<String>,
+        routine_name: Option<String>,
+    },
+    EventListMySQL {
+        conn_id: Uuid,
+        db_name: String,
+    },
+    FunctionListPG {
+        conn_id: Uuid,
+        db_name: String,
+        schema_name: String,
+    },
+    FunctionDetailPG {
+        conn_id: Uuid,
+        db_name: String,
+        schema_name: String,
+        function_oid: Option<i64>,
+    },
+    SequenceListPG {
+        conn_id: Uuid,
+        db_name: String,
+        schema_name: String,
+    },
     UserListMySQL {
         conn_id: Uuid,
     },
+    ProcessListMySQL {
+        conn_id: Uuid,
+    },
     UserDetailMySQL {
         conn_id: Uuid,
         user_host: Option<String>,
@@ -105,6 +155,9 @@ pub enum Goto {
     RoleListPG {
         conn_id: Uuid,
     },
+    ActivityListPG {
+        conn_id: Uuid,
+    },
     RoleDetailPG {
         conn_id: Uuid,
         role_name: Option<String>,
@@ -122,7 +175,39 @@ pub enum Goto {
     },
 }
 
-#[derive(PartialEq)]
+impl Goto {
+    pub fn get_conn_id(&self) -> Uuid {
+        match self {
+            Goto::TableListMySQL { conn_id, .. }
+            | Goto::TableDetailMySQL { conn_id, .. }
+            | Goto::TableListPG { conn_id, .. }
+            | Goto::TableDetailPG { conn_id, .. }
+            | Goto::QueryList { conn_id, .. }
+            | Goto::QueryDetailMySQL { conn_id, .. }
+            | Goto::QueryDetailPG { conn_id, .. }
+            | Goto::ViewListMySQL { conn_id, .. }
+            | Goto::ViewDetailMySQL { conn_id, .. }
+            | Goto::ViewListPG { conn_id, .. }
+            | Goto::ViewDetailPG { conn_id, .. }
+            | Goto::RoutineListMySQL { conn_id, .. }
+            | Goto::RoutineDetailMySQL { conn_id, .. }
+            | Goto::EventListMySQL { conn_id, .. }
+            | Goto::FunctionListPG { conn_id, .. }
+            | Goto::FunctionDetailPG { conn_id, .. }
+            | Goto::SequenceListPG { conn_id, .. }
+            | Goto::UserListMySQL { conn_id }
+            | Goto::UserDetailMySQL { conn_id, .. }
+            | Goto::ProcessListMySQL { conn_id }
+            | Goto::RoleListPG { conn_id }
+            | Goto::ActivityListPG { conn_id }
+            | Goto::RoleDetailPG { conn_id, .. }
+            | Goto::DataListMySQL { conn_id, .. }
+            | Goto::DataListPG { conn_id, .. } => *conn_id,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
 pub enum Focus {
     LeftPanel,
     MainPanel,
@@ -152,10 +237,18 @@ pub enum MainPanel {
     ViewDetailMySQL,
     ViewListPG,
     ViewDetailPG,
+    RoutineListMySQL,
+    RoutineDetailMySQL,
+    EventListMySQL,
+    FunctionListPG,
+    FunctionDetailPG,
+    SequenceListPG,
     UserListMySQL,
     UserDetailMySQL,
+    ProcessListMySQL,
     RoleListPG,
     RoleDetailPG,
+    ActivityListPG,
 }
 
 pub struct App<'a> {
@@ -164,8 +257,9 @@ pub struct App<'a> {
     pub home: HomeComponent,
     pub connection_list: ConnectionListComponent<'a>,
     pub command_bar: Rc<RefCell<CommandBarComponent>>,
-    pub table_list_mysql: TableListComponentMySQL,
-    pub table_list_pg: TableListComponentPG,
+    pub status_line: Rc<RefCell<StatusLineComponent>>,
+    pub table_list_mysql: TableListComponentMySQL<'a>,
+    pub table_list_pg: TableListComponentPG<'a>,
     pub data_list_mysql: DataListComponentMySQL<'a>,
     pub data_list_pg: DataListComponentPG<'a>,
     pub table_detail_mysql: TableDetailComponentMySQL<'a>,
@@ -177,11 +271,31 @@ pub struct App<'a> {
     pub view_detail_mysql: ViewDetailComponentMySQL<'a>,
     pub view_list_pg: ViewListComponentPG,
     pub view_detail_pg: ViewDetailComponentPG<'a>,
+    pub routine_list_mysql: RoutineListComponentMySQL,
+    pub routine_detail_mysql: RoutineDetailComponentMySQL<'a>,
+    pub event_list_mysql: EventListComponentMySQL<'a>,
+    pub function_list_pg: FunctionListComponentPG,
+    pub function_detail_pg: FunctionDetailComponentPG<'a>,
+    pub sequence_list_pg: SequenceListComponentPG<'a>,
     pub user_list_mysql: UserListComponentMySQL,
     pub user_detail_mysql: UserDetailComponentMySQL<'a>,
+    pub process_list_mysql: ProcessListComponentMySQL,
+    pub activity_list_pg: ActivityListComponentPG,
     pub role_list_pg: RoleListComponentPG,
     pub role_detail_pg: RoleDetailComponentPG<'a>,
     pub error_dlg: Option<ConfirmDialog>,
+    pub quit_dlg: Option<ConfirmDialog>,
+    pub command_palette_dlg: Option<CommandPaletteDialog>,
+    pub connection_switch_dlg: Option<ConnectionSwitchDialog>,
+    pub favorite_dlg: Option<FavoriteDialog>,
+    pub main_panel_rect: Rect,
+    pub session: Option<Session>,
+    pub startup: Option<Session>,
+    pub table_views: Rc<RefCell<TableViews>>,
+    pub favorites: Rc<RefCell<Favorites>>,
+    pub active_conn_id: Option<Uuid>,
+    pub mysql_conns: Rc<RefCell<MySQLConnections>>,
+    pub pg_conns: Rc<RefCell<PGConnections>>,
 }
 
 impl<'a> App<'a> {
@@ -192,40 +306,73 @@ impl<'a> App<'a> {
         pg_pools: Rc<RefCell<PGPools>>,
         config: Rc<RefCell<Config>>,
         queries: Rc<RefCell<Queries>>,
+        startup: Option<Session>,
     ) -> Self {
+        let session = if config.borrow().restore_session {
+            Some(Session::load().unwrap_or_default())
+        } else {
+            None
+        };
         let command_bar = Rc::new(RefCell::new(CommandBarComponent::new()));
+        let status_line = Rc::new(RefCell::new(StatusLineComponent::new()));
+        let table_views = Rc::new(RefCell::new(TableViews::load().unwrap_or_default()));
+        let favorites = Rc::new(RefCell::new(Favorites::load().unwrap_or_default()));
         let data_list_mysql = DataListComponentMySQL::new(
             mysql_conns.clone(),
             mysql_pools.clone(),
             command_bar.clone(),
+            status_line.clone(),
+            table_views.clone(),
+            config.clone(),
+        );
+        let data_list_pg = DataListComponentPG::new(
+            pg_conns.clone(),
+            pg_pools.clone(),
+            command_bar.clone(),
+            status_line.clone(),
+            table_views.clone(),
+            config.clone(),
         );
-        let data_list_pg =
-            DataListComponentPG::new(pg_conns.clone(), pg_pools.clone(), command_bar.clone());
         let table_detail_mysql = TableDetailComponentMySQL::new(
             command_bar.clone(),
             mysql_conns.clone(),
             mysql_pools.clone(),
+            config.clone(),
+        );
+        let table_detail_pg = TableDetailComponentPG::new(
+            command_bar.clone(),
+            pg_conns.clone(),
+            pg_pools.clone(),
+            config.clone(),
         );
-        let table_detail_pg =
-            TableDetailComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
         let table_list_mysql = TableListComponentMySQL::new(
             command_bar.clone(),
             mysql_conns.clone(),
             mysql_pools.clone(),
+            favorites.clone(),
+        );
+        let table_list_pg = TableListComponentPG::new(
+            command_bar.clone(),
+            pg_conns.clone(),
+            pg_pools.clone(),
+            favorites.clone(),
+            config.clone(),
         );
-        let table_list_pg =
-            TableListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
         let query_detail_mysql = QueryDetailComponentMySQL::new(
             mysql_conns.clone(),
             mysql_pools.clone(),
             queries.clone(),
             command_bar.clone(),
+            status_line.clone(),
+            config.clone(),
         );
         let query_detail_pg = QueryDetailComponentPG::new(
             pg_conns.clone(),
             pg_pools.clone(),
             queries.clone(),
             command_bar.clone(),
+            status_line.clone(),
+            config.clone(),
         );
         let query_list = QueryListComponent::new(queries, command_bar.clone());
         let view_detail_mysql = ViewDetailComponentMySQL::new(
@@ -242,6 +389,27 @@ impl<'a> App<'a> {
             ViewDetailComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
         let view_list_pg =
             ViewListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
+        let routine_list_mysql = RoutineListComponentMySQL::new(
+            command_bar.clone(),
+            mysql_conns.clone(),
+            mysql_pools.clone(),
+        );
+        let routine_detail_mysql = RoutineDetailComponentMySQL::new(
+            command_bar.clone(),
+            mysql_conns.clone(),
+            mysql_pools.clone(),
+        );
+        let event_list_mysql = EventListComponentMySQL::new(
+            command_bar.clone(),
+            mysql_conns.clone(),
+            mysql_pools.clone(),
+        );
+        let function_list_pg =
+            FunctionListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
+        let function_detail_pg =
+            FunctionDetailComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
+        let sequence_list_pg =
+            SequenceListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
 
         let user_list_mysql = UserListComponentMySQL::new(
             command_bar.clone(),
@@ -252,15 +420,28 @@ impl<'a> App<'a> {
             command_bar.clone(),
             mysql_conns.clone(),
             mysql_pools.clone(),
+            config.clone(),
         );
+        let process_list_mysql = ProcessListComponentMySQL::new(
+            command_bar.clone(),
+            mysql_conns.clone(),
+            mysql_pools.clone(),
+        );
+        let activity_list_pg =
+            ActivityListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
         let role_list_pg =
             RoleListComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
-        let role_detail_pg =
-            RoleDetailComponentPG::new(command_bar.clone(), pg_conns.clone(), pg_pools.clone());
+        let role_detail_pg = RoleDetailComponentPG::new(
+            command_bar.clone(),
+            pg_conns.clone(),
+            pg_pools.clone(),
+            config.clone(),
+        );
         let connection_list = ConnectionListComponent::new(
             command_bar.clone(),
-            mysql_conns,
-            pg_conns,
+            status_line.clone(),
+            mysql_conns.clone(),
+            pg_conns.clone(),
             mysql_pools,
             pg_pools,
             config,
@@ -272,6 +453,7 @@ impl<'a> App<'a> {
             home: HomeComponent::new(),
             connection_list,
             command_bar,
+            status_line,
             table_list_mysql,
             data_list_mysql,
             table_detail_mysql,
@@ -285,17 +467,37 @@ impl<'a> App<'a> {
             view_detail_mysql,
             view_list_pg,
             view_detail_pg,
+            routine_list_mysql,
+            routine_detail_mysql,
+            event_list_mysql,
+            function_list_pg,
+            function_detail_pg,
+            sequence_list_pg,
             user_list_mysql,
             user_detail_mysql,
+            process_list_mysql,
+            activity_list_pg,
             role_list_pg,
             role_detail_pg,
             error_dlg: None,
+            quit_dlg: None,
+            command_palette_dlg: None,
+            connection_switch_dlg: None,
+            favorite_dlg: None,
+            main_panel_rect: Rect::default(),
+            session,
+            startup,
+            table_views,
+            favorites,
+            active_conn_id: None,
+            mysql_conns,
+            pg_conns,
         }
     }
     pub async fn start(&mut self) -> Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
 
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
@@ -303,6 +505,18 @@ impl<'a> App<'a> {
         terminal.clear()?;
 
         let events = event::Events::new(250);
+        let result = if let Some(startup) = self.startup.take() {
+            self.open_startup(startup).await
+        } else {
+            self.restore_session().await.map(|_| ())
+        };
+        if let Err(e) = result {
+            self.error_dlg = Some(ConfirmDialog::new(
+                ConfirmKind::Error,
+                "Error",
+                e.root_cause().to_string().as_str(),
+            ));
+        }
 
         loop {
             terminal.draw(|f| {
@@ -311,12 +525,25 @@ impl<'a> App<'a> {
             })?;
             match events.next()? {
                 event::Event::Input(key) => {
-                    if key
-                        == (Key {
-                            code: KeyCode::Char('c'),
-                            modifier: KeyModifier::Ctrl,
-                        })
-                    {
+                    if let Some(dlg) = self.quit_dlg.as_mut() {
+                        match dlg.handle_event(&key) {
+                            DialogResult::Cancel => self.quit_dlg = None,
+                            DialogResult::Confirm(_) => break,
+                            _ => (),
+                        }
+                        continue;
+                    }
+                    if key == QUIT_APP_KEY {
+                        if self.table_detail_mysql.has_unsaved_changes()
+                            || self.table_detail_pg.has_unsaved_changes()
+                        {
+                            self.quit_dlg = Some(ConfirmDialog::new(
+                                ConfirmKind::Warning,
+                                "Quit",
+                                "You have unsaved changes, quit anyway?",
+                            ));
+                            continue;
+                        }
                         break;
                     }
                     if let Some(dlg) = self.error_dlg.as_mut() {
@@ -328,79 +555,427 @@ impl<'a> App<'a> {
                         }
                         continue;
                     }
-                    if let Err(e) = self.handle_input_event(&key).await {
+                    if let Some(dlg) = self.command_palette_dlg.as_mut() {
+                        match dlg.handle_event(&key) {
+                            DialogResult::Cancel => self.command_palette_dlg = None,
+                            DialogResult::Confirm(cmd_key) => {
+                                self.command_palette_dlg = None;
+                                self.dispatch_input_event(&cmd_key).await;
+                            }
+                            _ => (),
+                        }
+                        continue;
+                    }
+                    if let Some(dlg) = self.connection_switch_dlg.as_mut() {
+                        match dlg.handle_event(&key) {
+                            DialogResult::Cancel => self.connection_switch_dlg = None,
+                            DialogResult::Confirm(conn_id) => {
+                                self.connection_switch_dlg = None;
+                                self.focus = Focus::LeftPanel;
+                                if let Err(e) = self.connection_list.goto_connection(&conn_id).await
+                                {
+                                    self.error_dlg = Some(ConfirmDialog::new(
+                                        ConfirmKind::Error,
+                                        "Error",
+                                        &e.to_string(),
+                                    ));
+                                }
+                            }
+                            _ => (),
+                        }
+                        continue;
+                    }
+                    if let Some(dlg) = self.favorite_dlg.as_mut() {
+                        match dlg.handle_event(&key) {
+                            Ok(DialogResult::Cancel) => self.favorite_dlg = None,
+                            Ok(DialogResult::Confirm(favorite)) => {
+                                self.favorite_dlg = None;
+                                if let Err(e) = self.open_startup(favorite.to_session()).await {
+                                    self.error_dlg = Some(ConfirmDialog::new(
+                                        ConfirmKind::Error,
+                                        "Error",
+                                        e.root_cause().to_string().as_str(),
+                                    ));
+                                }
+                            }
+                            Ok(_) => (),
+                            Err(e) => {
+                                self.error_dlg = Some(ConfirmDialog::new(
+                                    ConfirmKind::Error,
+                                    "Error",
+                                    e.root_cause().to_string().as_str(),
+                                ));
+                            }
+                        }
+                        continue;
+                    }
+                    if key == FAVORITE_KEY {
+                        self.favorite_dlg = Some(FavoriteDialog::new(
+                            self.favorites.clone(),
+                            &get_all_connections(self.mysql_conns.clone(), self.pg_conns.clone()),
+                        ));
+                        continue;
+                    }
+                    if key == CONNECTION_SWITCH_KEY {
+                        self.connection_switch_dlg = Some(ConnectionSwitchDialog::new(
+                            &get_all_connections(self.mysql_conns.clone(), self.pg_conns.clone()),
+                        ));
+                        continue;
+                    }
+                    if key == COMMAND_PALETTE_KEY {
+                        self.command_palette_dlg = Some(CommandPaletteDialog::new(
+                            self.command_bar.borrow().get_commands(),
+                        ));
+                        continue;
+                    }
+                    self.dispatch_input_event(&key).await;
+                }
+                event::Event::Mouse(mouse) => {
+                    if self.error_dlg.is_some()
+                        || self.command_palette_dlg.is_some()
+                        || self.connection_switch_dlg.is_some()
+                        || self.favorite_dlg.is_some()
+                    {
+                        continue;
+                    }
+                    if let Err(e) = self.handle_mouse_event(&mouse).await {
+                        self.error_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Error,
+                            "Error",
+                            e.root_cause().to_string().as_str(),
+                        ));
+                    }
+                }
+                event::Event::Tick => {
+                    self.status_line.borrow_mut().handle_tick();
+                    if let Err(e) = self.query_detail_mysql.handle_tick().await {
+                        self.error_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Error,
+                            "Error",
+                            e.root_cause().to_string().as_str(),
+                        ));
+                    }
+                    if let Err(e) = self.query_detail_pg.handle_tick().await {
                         self.error_dlg = Some(ConfirmDialog::new(
                             ConfirmKind::Error,
                             "Error",
                             e.root_cause().to_string().as_str(),
                         ));
-                    };
+                    }
+                    if let Err(e) = self.connection_list.handle_tick().await {
+                        self.error_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Error,
+                            "Error",
+                            e.root_cause().to_string().as_str(),
+                        ));
+                    }
                 }
-                event::Event::Tick => {}
             }
         }
-        execute!(io::stdout(), LeaveAlternateScreen)?;
+        execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
         disable_raw_mode()?;
+        if let Some(session) = self.session.as_ref() {
+            session.save()?;
+        }
+        self.table_views.borrow().save()?;
         Ok(())
     }
-    pub async fn handle_input_event(&mut self, key: &Key) -> Result<()> {
-        match self.focus {
-            Focus::LeftPanel => {
-                match self.connection_list.handle_event(key).await? {
-                    ComponentResult::Goto(goto) => match goto {
-                        Goto::TableListMySQL { conn_id, db_name } => {
-                            self.table_list_mysql.set_data(&conn_id, &db_name).await?;
-                            self.main_panel = MainPanel::TableListMySQL;
-                            self.focus = Focus::MainPanel;
-                        }
-                        Goto::TableListPG {
-                            conn_id,
-                            db_name,
-                            schema_name,
-                        } => {
-                            self.table_list_pg
-                                .set_data(&conn_id, &db_name, &schema_name)
+    async fn dispatch_input_event(&mut self, key: &Key) {
+        if let Err(e) = self.handle_input_event(key).await {
+            self.error_dlg = Some(ConfirmDialog::new(
+                ConfirmKind::Error,
+                "Error",
+                e.root_cause().to_string().as_str(),
+            ));
+        }
+        if let MainPanel::TableListMySQL | MainPanel::TableListPG = self.main_panel {
+            self.set_session_table(None, SessionPanel::TableList);
+        }
+    }
+    fn set_session(
+        &mut self,
+        conn_id: &Uuid,
+        kind: DatabaseKind,
+        db_name: &str,
+        schema_name: Option<&str>,
+    ) {
+        if let Some(session) = self.session.as_mut() {
+            *session = Session {
+                conn_id: Some(*conn_id),
+                kind: Some(kind),
+                db_name: Some(db_name.to_string()),
+                schema_name: schema_name.map(|s| s.to_string()),
+                table_name: None,
+                panel: Some(SessionPanel::TableList),
+            };
+        }
+    }
+    fn set_session_table(&mut self, table_name: Option<&str>, panel: SessionPanel) {
+        if let Some(session) = self.session.as_mut() {
+            if let Some(name) = table_name {
+                session.table_name = Some(name.to_string());
+            }
+            session.panel = Some(panel);
+        }
+    }
+    async fn open_startup(&mut self, startup: Session) -> Result<()> {
+        if !self.open_session(startup.clone()).await? {
+            return Err(Error::msg(format!(
+                "cannot open {}",
+                [startup.db_name, startup.schema_name, startup.table_name]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<String>>()
+                    .join(".")
+            )));
+        }
+        if let Some(session) = self.session.as_mut() {
+            *session = startup;
+        }
+        Ok(())
+    }
+    async fn restore_session(&mut self) -> Result<bool> {
+        match self.session.clone() {
+            Some(session) => self.open_session(session).await,
+            None => Ok(false),
+        }
+    }
+    async fn open_session(&mut self, session: Session) -> Result<bool> {
+        let (conn_id, kind, db_name) = match (session.conn_id, session.kind, session.db_name) {
+            (Some(conn_id), Some(kind), Some(db_name)) => (conn_id, kind, db_name),
+            _ => return Ok(false),
+        };
+        if !self
+            .connection_list
+            .restore_session(&conn_id, &db_name, session.schema_name.as_deref())
+            .await?
+        {
+            return Ok(false);
+        }
+        self.active_conn_id = Some(conn_id);
+        let table_name = session.table_name.as_deref();
+        let panel = session.panel.unwrap_or(SessionPanel::TableList);
+        match kind {
+            DatabaseKind::MySQL => {
+                self.table_list_mysql.set_data(&conn_id, &db_name).await?;
+                self.main_panel = MainPanel::TableListMySQL;
+                if let Some(table_name) = table_name {
+                    self.table_list_mysql.select_table(table_name);
+                    match panel {
+                        SessionPanel::DataList => {
+                            self.data_list_mysql
+                                .set_data(&conn_id, &db_name, table_name, MainPanel::TableListMySQL)
                                 .await?;
-                            self.main_panel = MainPanel::TableListPG;
-                            self.focus = Focus::MainPanel;
-                        }
-                        Goto::QueryList {
-                            conn_id,
-                            db_name,
-                            kind,
-                        } => {
-                            self.query_list.set_data(&conn_id, &db_name, kind)?;
-                            self.main_panel = MainPanel::QueryList;
-                            self.focus = Focus::MainPanel;
+                            self.main_panel = MainPanel::DataListMySQL;
                         }
-                        Goto::ViewListMySQL { conn_id, db_name } => {
-                            self.view_list_mysql.set_data(&conn_id, &db_name).await?;
-                            self.main_panel = MainPanel::ViewListMySQL;
-                            self.focus = Focus::MainPanel;
-                        }
-                        Goto::ViewListPG {
-                            conn_id,
-                            db_name,
-                            schema_name,
-                        } => {
-                            self.view_list_pg
-                                .set_data(&conn_id, &db_name, &schema_name)
+                        SessionPanel::TableDetail => {
+                            self.table_detail_mysql
+                                .set_data(&conn_id, &db_name, Some(table_name))
                                 .await?;
-                            self.main_panel = MainPanel::ViewListPG;
-                            self.focus = Focus::MainPanel;
+                            self.main_panel = MainPanel::TableDetailMySQL;
                         }
-                        Goto::UserListMySQL { conn_id } => {
-                            self.user_list_mysql.set_data(&conn_id).await?;
-                            self.main_panel = MainPanel::UserListMySQL;
-                            self.focus = Focus::MainPanel;
+                        SessionPanel::TableList => (),
+                    }
+                }
+            }
+            DatabaseKind::PostgreSQL => {
+                let schema_name = match session.schema_name.as_deref() {
+                    Some(s) => s,
+                    None => return Ok(false),
+                };
+                self.table_list_pg
+                    .set_data(&conn_id, &db_name, schema_name)
+                    .await?;
+                self.main_panel = MainPanel::TableListPG;
+                if let Some(table_name) = table_name {
+                    self.table_list_pg.select_table(table_name);
+                    match panel {
+                        SessionPanel::DataList => {
+                            self.data_list_pg
+                                .set_data(
+                                    &conn_id,
+                                    &db_name,
+                                    schema_name,
+                                    table_name,
+                                    MainPanel::TableListPG,
+                                )
+                                .await?;
+                            self.main_panel = MainPanel::DataListPG;
                         }
-                        Goto::RoleListPG { conn_id } => {
-                            self.role_list_pg.set_data(&conn_id).await?;
-                            self.main_panel = MainPanel::RoleListPG;
-                            self.focus = Focus::MainPanel;
+                        SessionPanel::TableDetail => {
+                            self.table_detail_pg
+                                .set_data(&conn_id, &db_name, schema_name, Some(table_name))
+                                .await?;
+                            self.main_panel = MainPanel::TableDetailPG;
                         }
+                        SessionPanel::TableList => (),
+                    }
+                }
+            }
+        }
+        self.focus = Focus::MainPanel;
+        Ok(true)
+    }
+    fn open_query_mysql(&mut self, conn_id: &Uuid, db_name: &str, sql: Option<&str>) -> Result<()> {
+        self.query_list
+            .set_data(conn_id, db_name, DatabaseKind::MySQL)?;
+        self.query_detail_mysql
+            .set_data(conn_id, db_name, None, sql)?;
+        self.main_panel = MainPanel::QueryDetailMySQL;
+        Ok(())
+    }
+    fn open_query_pg(&mut self, conn_id: &Uuid, db_name: &str, sql: Option<&str>) -> Result<()> {
+        self.query_list
+            .set_data(conn_id, db_name, DatabaseKind::PostgreSQL)?;
+        self.query_detail_pg.set_data(conn_id, db_name, None, sql)?;
+        self.main_panel = MainPanel::QueryDetailPG;
+        Ok(())
+    }
+    pub async fn handle_mouse_event(&mut self, mouse: &MouseEvent) -> Result<()> {
+        let focus = if mouse.column < self.main_panel_rect.x {
+            Focus::LeftPanel
+        } else {
+            Focus::MainPanel
+        };
+        match mouse.kind {
+            MouseEventKind::ScrollUp => {
+                self.focus = focus;
+                self.handle_input_event(&UP_KEY).await?;
+            }
+            MouseEventKind::ScrollDown => {
+                self.focus = focus;
+                self.handle_input_event(&DOWN_KEY).await?;
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.focus = focus;
+                let (x, y) = (mouse.column, mouse.row);
+                match focus {
+                    Focus::LeftPanel => self.connection_list.handle_click(x, y),
+                    Focus::MainPanel => match self.main_panel {
+                        MainPanel::TableListMySQL => self.table_list_mysql.handle_click(x, y),
+                        MainPanel::TableListPG => self.table_list_pg.handle_click(x, y),
+                        MainPanel::DataListMySQL => self.data_list_mysql.handle_click(x, y),
+                        MainPanel::DataListPG => self.data_list_pg.handle_click(x, y),
+                        MainPanel::TableDetailMySQL => self.table_detail_mysql.handle_click(x, y),
+                        MainPanel::TableDetailPG => self.table_detail_pg.handle_click(x, y),
                         _ => (),
                     },
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+    pub async fn handle_input_event(&mut self, key: &Key) -> Result<()> {
+        match self.focus {
+            Focus::LeftPanel => {
+                match self.connection_list.handle_event(key).await? {
+                    ComponentResult::Goto(goto) => {
+                        self.active_conn_id = Some(goto.get_conn_id());
+                        match goto {
+                            Goto::TableListMySQL { conn_id, db_name } => {
+                                self.table_list_mysql.set_data(&conn_id, &db_name).await?;
+                                self.set_session(&conn_id, DatabaseKind::MySQL, &db_name, None);
+                                self.main_panel = MainPanel::TableListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::TableListPG {
+                                conn_id,
+                                db_name,
+                                schema_name,
+                            } => {
+                                self.table_list_pg
+                                    .set_data(&conn_id, &db_name, &schema_name)
+                                    .await?;
+                                self.set_session(
+                                    &conn_id,
+                                    DatabaseKind::PostgreSQL,
+                                    &db_name,
+                                    Some(&schema_name),
+                                );
+                                self.main_panel = MainPanel::TableListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::QueryList {
+                                conn_id,
+                                db_name,
+                                kind,
+                            } => {
+                                self.query_list.set_data(&conn_id, &db_name, kind)?;
+                                self.main_panel = MainPanel::QueryList;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::ViewListMySQL { conn_id, db_name } => {
+                                self.view_list_mysql.set_data(&conn_id, &db_name).await?;
+                                self.main_panel = MainPanel::ViewListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::ViewListPG {
+                                conn_id,
+                                db_name,
+                                schema_name,
+                            } => {
+                                self.view_list_pg
+                                    .set_data(&conn_id, &db_name, &schema_name)
+                                    .await?;
+                                self.main_panel = MainPanel::ViewListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::RoutineListMySQL { conn_id, db_name } => {
+                                self.routine_list_mysql.set_data(&conn_id, &db_name).await?;
+                                self.main_panel = MainPanel::RoutineListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::EventListMySQL { conn_id, db_name } => {
+                                self.event_list_mysql.set_data(&conn_id, &db_name).await?;
+                                self.main_panel = MainPanel::EventListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::FunctionListPG {
+                                conn_id,
+                                db_name,
+                                schema_name,
+                            } => {
+                                self.function_list_pg
+                                    .set_data(&conn_id, &db_name, &schema_name)
+                                    .await?;
+                                self.main_panel = MainPanel::FunctionListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::SequenceListPG {
+                                conn_id,
+                                db_name,
+                                schema_name,
+                            } => {
+                                self.sequence_list_pg
+                                    .set_data(&conn_id, &db_name, &schema_name)
+                                    .await?;
+                                self.main_panel = MainPanel::SequenceListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::UserListMySQL { conn_id } => {
+                                self.user_list_mysql.set_data(&conn_id).await?;
+                                self.main_panel = MainPanel::UserListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::ProcessListMySQL { conn_id } => {
+                                self.process_list_mysql.set_data(&conn_id).await?;
+                                self.main_panel = MainPanel::ProcessListMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::RoleListPG { conn_id } => {
+                                self.role_list_pg.set_data(&conn_id).await?;
+                                self.main_panel = MainPanel::RoleListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            Goto::ActivityListPG { conn_id } => {
+                                self.activity_list_pg.set_data(&conn_id).await?;
+                                self.main_panel = MainPanel::ActivityListPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            _ => (),
+                        }
+                    }
                     ComponentResult::Focus(focus) => {
                         self.focus = focus;
                     }
@@ -425,6 +1000,10 @@ impl<'a> App<'a> {
                                     self.table_detail_mysql
                                         .set_data(&conn_id, &db_name, table_name.as_deref())
                                         .await?;
+                                    self.set_session_table(
+                                        table_name.as_deref(),
+                                        SessionPanel::TableDetail,
+                                    );
                                     self.main_panel = MainPanel::TableDetailMySQL;
                                 }
                                 Goto::DataListMySQL {
@@ -440,8 +1019,20 @@ impl<'a> App<'a> {
                                             MainPanel::TableListMySQL,
                                         )
                                         .await?;
+                                    self.set_session_table(
+                                        Some(&table_name),
+                                        SessionPanel::DataList,
+                                    );
                                     self.main_panel = MainPanel::DataListMySQL;
                                 }
+                                Goto::QueryDetailMySQL {
+                                    conn_id,
+                                    db_name,
+                                    sql,
+                                    ..
+                                } => {
+                                    self.open_query_mysql(&conn_id, &db_name, sql.as_deref())?;
+                                }
                                 _ => (),
                             },
                             ComponentResult::Focus(focus) => {
@@ -466,6 +1057,10 @@ impl<'a> App<'a> {
                                         table_name.as_deref(),
                                     )
                                     .await?;
+                                self.set_session_table(
+                                    table_name.as_deref(),
+                                    SessionPanel::TableDetail,
+                                );
                                 self.main_panel = MainPanel::TableDetailPG;
                             }
                             Goto::DataListPG {
@@ -483,8 +1078,17 @@ impl<'a> App<'a> {
                                         MainPanel::TableListPG,
                                     )
                                     .await?;
+                                self.set_session_table(Some(&table_name), SessionPanel::DataList);
                                 self.main_panel = MainPanel::DataListPG;
                             }
+                            Goto::QueryDetailPG {
+                                conn_id,
+                                db_name,
+                                sql,
+                                ..
+                            } => {
+                                self.open_query_pg(&conn_id, &db_name, sql.as_deref())?;
+                            }
                             _ => (),
                         },
                         ComponentResult::Focus(focus) => {
@@ -493,10 +1097,19 @@ impl<'a> App<'a> {
                         _ => (),
                     },
                     MainPanel::TableDetailMySQL => {
-                        if let ComponentResult::Back(_) =
-                            self.table_detail_mysql.handle_event(key).await?
-                        {
-                            self.main_panel = MainPanel::TableListMySQL;
+                        match self.table_detail_mysql.handle_event(key).await? {
+                            ComponentResult::Back(_) => {
+                                self.main_panel = MainPanel::TableListMySQL;
+                            }
+                            ComponentResult::Goto(Goto::QueryDetailMySQL {
+                                conn_id,
+                                db_name,
+                                sql,
+                                ..
+                            }) => {
+                                self.open_query_mysql(&conn_id, &db_name, sql.as_deref())?;
+                            }
+                            _ => (),
                         }
                     }
                     MainPanel::TableDetailPG => {
@@ -508,34 +1121,55 @@ impl<'a> App<'a> {
                                 self.main_panel = MainPanel::TableListPG;
                                 self.table_list_pg.refresh().await?;
                             }
+                            ComponentResult::Goto(Goto::QueryDetailPG {
+                                conn_id,
+                                db_name,
+                                sql,
+                                ..
+                            }) => {
+                                self.open_query_pg(&conn_id, &db_name, sql.as_deref())?;
+                            }
                             _ => (),
                         }
                     }
-                    MainPanel::DataListMySQL => {
-                        if let ComponentResult::Back(panel) =
-                            self.data_list_mysql.handle_event(key).await?
-                        {
-                            self.main_panel = panel;
+                    MainPanel::DataListMySQL => match self.data_list_mysql.handle_event(key).await?
+                    {
+                        ComponentResult::Back(panel) => self.main_panel = panel,
+                        ComponentResult::Goto(Goto::QueryDetailMySQL {
+                            conn_id,
+                            db_name,
+                            sql,
+                            ..
+                        }) => {
+                            self.open_query_mysql(&conn_id, &db_name, sql.as_deref())?;
                         }
-                    }
-                    MainPanel::DataListPG => {
-                        if let ComponentResult::Back(panel) =
-                            self.data_list_pg.handle_event(key).await?
-                        {
-                            self.main_panel = panel;
+                        _ => (),
+                    },
+                    MainPanel::DataListPG => match self.data_list_pg.handle_event(key).await? {
+                        ComponentResult::Back(panel) => self.main_panel = panel,
+                        ComponentResult::Goto(Goto::QueryDetailPG {
+                            conn_id,
+                            db_name,
+                            sql,
+                            ..
+                        }) => {
+                            self.open_query_pg(&conn_id, &db_name, sql.as_deref())?;
                         }
-                    }
+                        _ => (),
+                    },
                     MainPanel::QueryList => match self.query_list.handle_event(key)? {
                         ComponentResult::Goto(goto) => match goto {
                             Goto::QueryDetailMySQL {
                                 conn_id,
                                 db_name,
                                 query_name,
+                                sql,
                             } => {
                                 self.query_detail_mysql.set_data(
                                     &conn_id,
                                     &db_name,
                                     query_name.as_deref(),
+                                    sql.as_deref(),
                                 )?;
                                 self.main_panel = MainPanel::QueryDetailMySQL;
                             }
@@ -543,11 +1177,13 @@ impl<'a> App<'a> {
                                 conn_id,
                                 db_name,
                                 query_name,
+                                sql,
                             } => {
                                 self.query_detail_pg.set_data(
                                     &conn_id,
                                     &db_name,
                                     query_name.as_deref(),
+                                    sql.as_deref(),
                                 )?;
                                 self.main_panel = MainPanel::QueryDetailPG;
                             }
@@ -661,6 +1297,85 @@ impl<'a> App<'a> {
                         ComponentResult::Focus(focus) => self.focus = focus,
                         _ => (),
                     },
+                    MainPanel::RoutineListMySQL => {
+                        match self.routine_list_mysql.handle_event(key).await? {
+                            ComponentResult::Goto(Goto::RoutineDetailMySQL {
+                                conn_id,
+                                db_name,
+                                routine_kind,
+                                routine_name,
+                            }) => {
+                                self.routine_detail_mysql
+                                    .set_data(
+                                        &conn_id,
+                                        &db_name,
+                                        routine_kind.as_deref(),
+                                        routine_name.as_deref(),
+                                    )
+                                    .await?;
+                                self.main_panel = MainPanel::RoutineDetailMySQL;
+                                self.focus = Focus::MainPanel;
+                            }
+                            ComponentResult::Focus(focus) => self.focus = focus,
+                            _ => (),
+                        }
+                    }
+                    MainPanel::RoutineDetailMySQL => {
+                        match self.routine_detail_mysql.handle_event(key).await? {
+                            ComponentResult::Back(_) => {
+                                self.main_panel = MainPanel::RoutineListMySQL
+                            }
+                            ComponentResult::BackRefresh(_) => {
+                                self.main_panel = MainPanel::RoutineListMySQL;
+                                self.routine_list_mysql.refresh().await?;
+                            }
+                            ComponentResult::Focus(focus) => self.focus = focus,
+                            _ => (),
+                        }
+                    }
+                    MainPanel::EventListMySQL => {
+                        if let ComponentResult::Focus(focus) =
+                            self.event_list_mysql.handle_event(key).await?
+                        {
+                            self.focus = focus;
+                        }
+                    }
+                    MainPanel::FunctionListPG => {
+                        match self.function_list_pg.handle_event(key).await? {
+                            ComponentResult::Goto(Goto::FunctionDetailPG {
+                                conn_id,
+                                db_name,
+                                schema_name,
+                                function_oid,
+                            }) => {
+                                self.function_detail_pg
+                                    .set_data(&conn_id, &db_name, &schema_name, function_oid)
+                                    .await?;
+                                self.main_panel = MainPanel::FunctionDetailPG;
+                                self.focus = Focus::MainPanel;
+                            }
+                            ComponentResult::Focus(focus) => self.focus = focus,
+                            _ => (),
+                        }
+                    }
+                    MainPanel::FunctionDetailPG => {
+                        match self.function_detail_pg.handle_event(key).await? {
+                            ComponentResult::Back(_) => self.main_panel = MainPanel::FunctionListPG,
+                            ComponentResult::BackRefresh(_) => {
+                                self.main_panel = MainPanel::FunctionListPG;
+                                self.function_list_pg.refresh().await?;
+                            }
+                            ComponentResult::Focus(focus) => self.focus = focus,
+                            _ => (),
+                        }
+                    }
+                    MainPanel::SequenceListPG => {
+                        if let ComponentResult::Focus(focus) =
+                            self.sequence_list_pg.handle_event(key).await?
+                        {
+                            self.focus = focus;
+                        }
+                    }
                     MainPanel::UserListMySQL => {
                         match self.user_list_mysql.handle_event(key).await? {
                             ComponentResult::Goto(Goto::UserDetailMySQL {
@@ -685,6 +1400,13 @@ impl<'a> App<'a> {
                             _ => (),
                         }
                     }
+                    MainPanel::ProcessListMySQL => {
+                        if let ComponentResult::Focus(focus) =
+                            self.process_list_mysql.handle_event(key).await?
+                        {
+                            self.focus = focus;
+                        }
+                    }
                     MainPanel::RoleListPG => match self.role_list_pg.handle_event(key).await? {
                         ComponentResult::Goto(Goto::RoleDetailPG { conn_id, role_name }) => {
                             self.role_detail_pg
@@ -701,6 +1423,13 @@ impl<'a> App<'a> {
                         ComponentResult::Focus(focus) => self.focus = focus,
                         _ => (),
                     },
+                    MainPanel::ActivityListPG => {
+                        if let ComponentResult::Focus(focus) =
+                            self.activity_list_pg.handle_event(key).await?
+                        {
+                            self.focus = focus;
+                        }
+                    }
                 };
             }
         }
@@ -714,15 +1443,24 @@ impl<'a> App<'a> {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(2), Constraint::Min(2)].as_ref())
+            .constraints(
+                [
+                    Constraint::Length(2),
+                    Constraint::Min(2),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
             .split(fsize);
 
         self.command_bar.borrow().draw(f, chunks[0]);
+        self.status_line.borrow().draw(f, chunks[2]);
 
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Ratio(1, 4), Constraint::Ratio(3, 4)])
             .split(chunks[1]);
+        self.main_panel_rect = chunks[1];
 
         self.connection_list
             .draw(f, chunks[0], self.focus == Focus::LeftPanel);
@@ -783,6 +1521,30 @@ impl<'a> App<'a> {
                 self.view_detail_pg
                     .draw(f, chunks[1], self.focus == Focus::MainPanel);
             }
+            MainPanel::RoutineListMySQL => {
+                self.routine_list_mysql
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+            MainPanel::RoutineDetailMySQL => {
+                self.routine_detail_mysql
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+            MainPanel::EventListMySQL => {
+                self.event_list_mysql
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+            MainPanel::FunctionListPG => {
+                self.function_list_pg
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+            MainPanel::FunctionDetailPG => {
+                self.function_detail_pg
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+            MainPanel::SequenceListPG => {
+                self.sequence_list_pg
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
             MainPanel::UserListMySQL => {
                 self.user_list_mysql
                     .draw(f, chunks[1], self.focus == Focus::MainPanel);
@@ -791,6 +1553,10 @@ impl<'a> App<'a> {
                 self.user_detail_mysql
                     .draw(f, chunks[1], self.focus == Focus::MainPanel)?;
             }
+            MainPanel::ProcessListMySQL => {
+                self.process_list_mysql
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
             MainPanel::RoleListPG => {
                 self.role_list_pg
                     .draw(f, chunks[1], self.focus == Focus::MainPanel);
@@ -799,6 +1565,20 @@ impl<'a> App<'a> {
                 self.role_detail_pg
                     .draw(f, chunks[1], self.focus == Focus::MainPanel)?;
             }
+            MainPanel::ActivityListPG => {
+                self.activity_list_pg
+                    .draw(f, chunks[1], self.focus == Focus::MainPanel);
+            }
+        }
+        if !matches!(self.main_panel, MainPanel::Home) {
+            if let Some(conn) = self.active_conn_id.and_then(|conn_id| {
+                get_connection(self.mysql_conns.clone(), self.pg_conns.clone(), &conn_id)
+            }) {
+                f.render_widget(
+                    ConnectionAccent::new(conn.get_color().to_color(), conn.get_environment()),
+                    chunks[1],
+                );
+            }
         }
         Ok(())
     }
@@ -824,15 +1604,47 @@ impl<'a> App<'a> {
                 MainPanel::ViewDetailMySQL => self.view_detail_mysql.draw_dialog(f),
                 MainPanel::ViewListPG => self.view_list_pg.draw_dialog(f),
                 MainPanel::ViewDetailPG => self.view_detail_pg.draw_dialog(f),
+                MainPanel::RoutineListMySQL => self.routine_list_mysql.draw_dialog(f),
+                MainPanel::RoutineDetailMySQL => self.routine_detail_mysql.draw_dialog(f),
+                MainPanel::EventListMySQL => self.event_list_mysql.draw_dialog(f),
+                MainPanel::FunctionListPG => self.function_list_pg.draw_dialog(f),
+                MainPanel::FunctionDetailPG => self.function_detail_pg.draw_dialog(f),
+                MainPanel::SequenceListPG => self.sequence_list_pg.draw_dialog(f),
                 MainPanel::UserListMySQL => self.user_list_mysql.draw_dialog(f),
                 MainPanel::UserDetailMySQL => self.user_detail_mysql.draw_dialog(f),
+                MainPanel::ProcessListMySQL => self.process_list_mysql.draw_dialog(f),
                 MainPanel::RoleListPG => self.role_list_pg.draw_dialog(f),
                 MainPanel::RoleDetailPG => self.role_detail_pg.draw_dialog(f),
+                MainPanel::ActivityListPG => self.activity_list_pg.draw_dialog(f),
                 _ => (),
             },
         }
+        if let Some(dlg) = self.command_palette_dlg.as_mut() {
+            dlg.draw(f);
+            self.command_bar
+                .borrow_mut()
+                .set_commands(&mut dlg.get_commands());
+        }
+        if let Some(dlg) = self.connection_switch_dlg.as_mut() {
+            dlg.draw(f);
+            self.command_bar
+                .borrow_mut()
+                .set_commands(&mut dlg.get_commands());
+        }
+        if let Some(dlg) = self.favorite_dlg.as_mut() {
+            dlg.draw(f);
+            self.command_bar
+                .borrow_mut()
+                .set_commands(&mut dlg.get_commands());
+        }
         if let Some(dlg) = &self.error_dlg {
             dlg.draw(f);
         }
+        if let Some(dlg) = &self.quit_dlg {
+            dlg.draw(f);
+            self.command_bar
+                .borrow_mut()
+                .set_commands(&mut dlg.get_commands());
+        }
     }
 }
@@ -0,0 +1,241 @@
+use crate::{
+    config::Config,
+    model::{
+        export::{export_mysql_query, export_pg_query, ExportFormat},
+        mysql::Connections as MySQLConnections,
+        pg::Connections as PGConnections,
+        session::{Session, SessionPanel},
+        Connect, DatabaseKind,
+    },
+    pool::{get_mysql_pool, get_pg_pool, MySQLPools, PGPools},
+};
+use anyhow::{Error, Result};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    env,
+    fs::File,
+    io::{self, BufWriter, Write},
+    rc::Rc,
+    str::FromStr,
+};
+
+const USAGE: &str = "Usage: sql-tui [OPTIONS]
+       sql-tui export [EXPORT OPTIONS]
+
+Options:
+  -c, --connection <NAME>  Open the connection with this name
+  -d, --database <NAME>    Open this database of the connection
+  -s, --schema <NAME>      Open this schema (PostgreSQL only, defaults to public)
+  -t, --table <NAME>       Open the data of this table
+  -h, --help               Print help
+  -V, --version            Print version
+
+Export options:
+  -c, --connection <NAME>  Connection to run the query on
+  -d, --database <NAME>    Database to run the query in
+  -q, --query <SQL>        Query to export
+  -f, --format <FORMAT>    csv, tsv, json or jsonl (defaults to csv)
+  -o, --out <FILE>         Output file (defaults to stdout)";
+
+const OPTIONS: [(&str, &str); 7] = [
+    ("-c", "--connection"),
+    ("-d", "--database"),
+    ("-s", "--schema"),
+    ("-t", "--table"),
+    ("-q", "--query"),
+    ("-f", "--format"),
+    ("-o", "--out"),
+];
+
+pub enum Cli {
+    Tui(Args),
+    Export(ExportArgs),
+}
+
+#[derive(Default)]
+pub struct Args {
+    pub connection: Option<String>,
+    pub database: Option<String>,
+    pub schema: Option<String>,
+    pub table: Option<String>,
+}
+
+pub struct ExportArgs {
+    pub connection: String,
+    pub database: Option<String>,
+    pub query: String,
+    pub format: ExportFormat,
+    pub out: Option<String>,
+}
+
+fn usage_error(msg: &str) -> Error {
+    Error::msg(format!("{}\n\n{}", msg, USAGE))
+}
+
+fn parse_options(
+    args: impl Iterator<Item = String>,
+    allowed: &[&str],
+) -> Result<HashMap<&'static str, String>> {
+    let mut options = HashMap::new();
+    let mut iter = args;
+    while let Some(arg) = iter.next() {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        match name.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            "-V" | "--version" => {
+                println!("sql-tui {}", env!("CARGO_PKG_VERSION"));
+                std::process::exit(0);
+            }
+            _ => (),
+        }
+        let long = OPTIONS
+            .iter()
+            .find(|(short, long)| *short == name || *long == name)
+            .map(|(_, long)| *long)
+            .filter(|long| allowed.contains(long))
+            .ok_or_else(|| usage_error(&format!("unknown argument: {}", name)))?;
+        let value = value
+            .or_else(|| iter.next())
+            .ok_or_else(|| usage_error(&format!("missing value for {}", name)))?;
+        options.insert(long, value);
+    }
+    Ok(options)
+}
+
+pub fn parse_cli() -> Result<Cli> {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().map(|a| a.as_str()) == Some("export") {
+        args.next();
+        let mut options = parse_options(
+            args,
+            &["--connection", "--database", "--query", "--format", "--out"],
+        )?;
+        Ok(Cli::Export(ExportArgs {
+            connection: options
+                .remove("--connection")
+                .ok_or_else(|| usage_error("--connection is required"))?,
+            database: options.remove("--database"),
+            query: options
+                .remove("--query")
+                .ok_or_else(|| usage_error("--query is required"))?,
+            format: match options.remove("--format") {
+                Some(format) => ExportFormat::from_str(&format.to_lowercase())
+                    .map_err(|_| usage_error(&format!("unknown format: {}", format)))?,
+                None => ExportFormat::Csv,
+            },
+            out: options.remove("--out").filter(|out| out != "-"),
+        }))
+    } else {
+        let mut options =
+            parse_options(args, &["--connection", "--database", "--schema", "--table"])?;
+        let args = Args {
+            connection: options.remove("--connection"),
+            database: options.remove("--database"),
+            schema: options.remove("--schema"),
+            table: options.remove("--table"),
+        };
+        if args.connection.is_none()
+            && (args.database.is_some() || args.schema.is_some() || args.table.is_some())
+        {
+            return Err(usage_error("--connection is required"));
+        }
+        Ok(Cli::Tui(args))
+    }
+}
+
+impl Args {
+    pub fn get_session(
+        &self,
+        mysql_conns: &MySQLConnections,
+        pg_conns: &PGConnections,
+    ) -> Result<Option<Session>> {
+        let name = match self.connection.as_deref() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let (conn_id, kind, db_name, schema_name) =
+            if let Some(conn) = mysql_conns.values().find(|c| c.get_name() == name) {
+                let db_name = self
+                    .database
+                    .clone()
+                    .ok_or_else(|| Error::msg("--database is required for MySQL connections"))?;
+                (*conn.get_id(), DatabaseKind::MySQL, db_name, None)
+            } else if let Some(conn) = pg_conns.values().find(|c| c.get_name() == name) {
+                let db_name = self
+                    .database
+                    .clone()
+                    .or_else(|| conn.get_init_db().map(|db| db.to_string()))
+                    .unwrap_or_else(|| String::from("postgres"));
+                let schema_name = self
+                    .schema
+                    .clone()
+                    .unwrap_or_else(|| String::from("public"));
+                (
+                    *conn.get_id(),
+                    DatabaseKind::PostgreSQL,
+                    db_name,
+                    Some(schema_name),
+                )
+            } else {
+                return Err(Error::msg(format!("connection {} not found", name)));
+            };
+        Ok(Some(Session {
+            conn_id: Some(conn_id),
+            kind: Some(kind),
+            db_name: Some(db_name),
+            schema_name,
+            panel: Some(if self.table.is_some() {
+                SessionPanel::DataList
+            } else {
+                SessionPanel::TableList
+            }),
+            table_name: self.table.clone(),
+        }))
+    }
+}
+
+impl ExportArgs {
+    pub async fn run(
+        &self,
+        config: &Config,
+        mysql_conns: MySQLConnections,
+        pg_conns: PGConnections,
+    ) -> Result<usize> {
+        let writer: Box<dyn Write> = match self.out.as_deref() {
+            Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+            None => Box::new(BufWriter::new(io::stdout())),
+        };
+        let mut serializer = self.format.new_serializer(writer);
+        let display_format = self.format.get_display_format(&config.get_display_format());
+        let name = self.connection.as_str();
+        let find_id = |conn: &dyn Connect| (conn.get_name() == name).then(|| *conn.get_id());
+        if let Some(conn_id) = mysql_conns.values().find_map(|c| find_id(c)) {
+            let pool = get_mysql_pool(
+                Rc::new(RefCell::new(mysql_conns)),
+                Rc::new(RefCell::new(MySQLPools::new())),
+                &conn_id,
+                self.database.as_deref(),
+            )
+            .await?;
+            export_mysql_query(&pool, &self.query, serializer.as_mut(), &display_format).await
+        } else if let Some(conn_id) = pg_conns.values().find_map(|c| find_id(c)) {
+            let pool = get_pg_pool(
+                Rc::new(RefCell::new(pg_conns)),
+                Rc::new(RefCell::new(PGPools::new())),
+                &conn_id,
+                self.database.as_deref(),
+            )
+            .await?;
+            export_pg_query(&pool, &self.query, serializer.as_mut(), &display_format).await
+        } else {
+            Err(Error::msg(format!("connection {} not found", name)))
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::event::{config::*, Key};
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     style::{Modifier, Style},
@@ -48,4 +48,11 @@ impl CommandBarComponent {
         });
         self.commands = cmds.to_vec();
     }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.commands
+            .iter()
+            .filter(|c| c.key != QUIT_APP_KEY)
+            .cloned()
+            .collect()
+    }
 }
@@ -1,62 +1,83 @@
 use crate::{
     app::{ComponentResult, DialogResult, Focus, Goto},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    component::{
+        get_clicked_row, get_table_down_index, get_table_up_index, Command, CommandBarComponent,
+        StatusLineComponent,
+    },
     config::Config,
     dialog::{
         confirm::{ConfirmDialog, Kind as ConfirmKind},
         database::{DatabaseDialog, Mode as DatabaseMode},
         schema::{Mode as SchemaMode, SchemaDialog},
-        ConnectionDialog,
+        ConnectionDialog, ExportDialog, InputDialog, TextDialog,
     },
     event::{config::*, Key},
     model::{
-        get_all_connections,
+        get_all_connections, get_connection,
         mysql::{
-            delete_mysql_connection, get_mysql_connection, get_mysql_database, get_mysql_databases,
+            delete_mysql_connection, dump_mysql_database, get_mysql_connection, get_mysql_database,
+            get_mysql_databases, get_mysql_server_info, quote_ident as quote_mysql_ident,
             save_mysql_connection, Connection as MySQLConnection, Connections as MySQLConnections,
             Database as MySQLDatabase,
         },
         pg::{
-            delete_pg_connection, get_pg_connection, get_pg_database, get_pg_databases,
-            get_pg_role_names, get_pg_schema, get_pg_schemas, save_pg_connection,
-            Connection as PGConnection, Connections as PGConnections, Database as PGDatabase,
-            Schema,
+            delete_pg_connection, dump_pg_schema, get_pg_connection, get_pg_database,
+            get_pg_databases, get_pg_role_names, get_pg_schema, get_pg_schemas, get_pg_server_info,
+            quote_ident as quote_pg_ident, save_pg_connection, Connection as PGConnection,
+            Connections as PGConnections, Database as PGDatabase, Schema,
         },
-        Connect, DatabaseKind, DB,
+        split_sql_statements, Connect, ConnectionColor, DatabaseKind, DumpOptions, DB,
     },
     pool::{
-        close_mysql_pool, close_pg_pool, execute_mysql_query, execute_mysql_query_unprepared,
-        execute_pg_query, execute_pg_query_unprepared, get_mysql_pool, get_pg_pool, MySQLPools,
-        PGPools,
+        close_mysql_pool, close_mysql_pools, close_pg_pool, close_pg_pools, execute_mysql_query,
+        execute_mysql_query_unprepared, execute_pg_query, execute_pg_query_unprepared,
+        get_mysql_pool, get_pg_pool, MySQLPools, PGPools,
     },
     widget::Select,
 };
 use anyhow::{Error, Result};
 use chrono::Utc;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-use strum::IntoEnumIterator;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState},
+    text::{Span, Spans},
+    widgets::{Block, BorderType, Borders, Clear, Gauge, List, ListItem, ListState},
     Frame,
 };
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 #[derive(Clone)]
 enum TreeItem {
+    Group(GroupItem),
     Connection(ConnectionItem),
     Database(DatabaseItem),
     Schema(SchemaItem),
     Query(DatabaseSubItem),
     Table(DatabaseSubItem),
     View(DatabaseSubItem),
+    Routine(DatabaseSubItem),
+    Event(DatabaseSubItem),
+    Sequence(DatabaseSubItem),
+}
+#[derive(Clone)]
+struct GroupItem {
+    pub name: String,
+    pub is_collapsed: bool,
 }
 #[derive(Clone)]
 struct ConnectionItem {
     pub id: Uuid,
     pub name: String,
+    pub group: Option<String>,
     pub kind: DatabaseKind,
     pub is_collapsed: bool,
     pub is_open: bool,
@@ -92,8 +113,19 @@ struct DatabaseSubItem {
     is_parent_collapsed: bool,
 }
 
+struct ImportTask {
+    conn_id: Uuid,
+    db_name: String,
+    kind: DatabaseKind,
+    path: String,
+    statements: Vec<String>,
+    executed: usize,
+}
+
 pub struct ConnectionListComponent<'a> {
     state: ListState,
+    list_rect: Rect,
+    list_offset: usize,
     tree_items: Vec<TreeItem>,
     show_items: Vec<TreeItem>,
     new_select: Option<Select>,
@@ -103,7 +135,13 @@ pub struct ConnectionListComponent<'a> {
     conn_dlg: Option<ConnectionDialog<'a>>,
     db_dlg: Option<DatabaseDialog<'a>>,
     schema_dlg: Option<SchemaDialog<'a>>,
+    export_dlg: Option<ExportDialog<'a>>,
+    info_dlg: Option<ConfirmDialog>,
+    server_info_dlg: Option<TextDialog>,
+    import_dlg: Option<InputDialog<'a>>,
+    import_task: Option<ImportTask>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    status_line: Rc<RefCell<StatusLineComponent>>,
     mysql_conns: Rc<RefCell<MySQLConnections>>,
     pg_conns: Rc<RefCell<PGConnections>>,
     mysql_pools: Rc<RefCell<MySQLPools>>,
@@ -114,30 +152,26 @@ pub struct ConnectionListComponent<'a> {
 impl<'a> ConnectionListComponent<'a> {
     pub fn new(
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        status_line: Rc<RefCell<StatusLineComponent>>,
         mysql_conns: Rc<RefCell<MySQLConnections>>,
         pg_conns: Rc<RefCell<PGConnections>>,
         mysql_pools: Rc<RefCell<MySQLPools>>,
         pg_pools: Rc<RefCell<PGPools>>,
         config: Rc<RefCell<Config>>,
     ) -> Self {
-        let tree_items: Vec<TreeItem> = get_all_connections(mysql_conns.clone(), pg_conns.clone())
-            .iter()
-            .map(|c| {
-                TreeItem::Connection(ConnectionItem {
-                    id: *c.get_id(),
-                    kind: c.get_kind().clone(),
-                    name: c.get_name().to_owned(),
-                    is_collapsed: true,
-                    is_open: false,
-                })
-            })
-            .collect();
+        let tree_items = Self::create_connection_items(&get_all_connections(
+            mysql_conns.clone(),
+            pg_conns.clone(),
+        ));
 
         ConnectionListComponent {
             state: ListState::default(),
+            list_rect: Rect::default(),
+            list_offset: 0,
             tree_items: tree_items.clone(),
             show_items: tree_items,
             cmd_bar,
+            status_line,
             new_select: None,
             delete_conn_dlg: None,
             delete_db_dlg: None,
@@ -145,6 +179,11 @@ impl<'a> ConnectionListComponent<'a> {
             conn_dlg: None,
             db_dlg: None,
             schema_dlg: None,
+            export_dlg: None,
+            info_dlg: None,
+            server_info_dlg: None,
+            import_dlg: None,
+            import_task: None,
             mysql_conns,
             pg_conns,
             mysql_pools,
@@ -152,36 +191,6 @@ impl<'a> ConnectionListComponent<'a> {
             config,
         }
     }
-    fn add_database_item(&mut self, db: &dyn DB) {
-        if let Some(show_index) = self.state.selected() {
-            if let TreeItem::Connection(conn) = &self.show_items[show_index] {
-                let tree_index = self
-                    .tree_items
-                    .iter()
-                    .position(|item| {
-                        if let TreeItem::Connection(c) = item {
-                            c.id == conn.id
-                        } else {
-                            false
-                        }
-                    })
-                    .unwrap();
-                let new_items = [TreeItem::Database(DatabaseItem {
-                    id: Uuid::new_v4(),
-                    name: db.name().to_string(),
-                    kind: db.kind(),
-                    is_collapsed: true,
-                    conn_id: conn.id,
-                    is_conn_collapsed: conn.is_collapsed,
-                    is_open: false,
-                })];
-                self.tree_items
-                    .splice(tree_index + 1..tree_index + 1, new_items.clone());
-                self.show_items
-                    .splice(show_index + 1..show_index + 1, new_items);
-            }
-        }
-    }
     fn add_schema_item(&mut self, schema: &Schema) {
         if let Some(show_index) = self.state.selected() {
             if let TreeItem::Database(db) = &self.show_items[show_index] {
@@ -236,6 +245,24 @@ impl<'a> ConnectionListComponent<'a> {
                         schema_name: Some(schema.name().to_string()),
                         is_parent_collapsed: true,
                     }),
+                    TreeItem::Routine(DatabaseSubItem {
+                        conn_id: db.conn_id,
+                        db_id: db.id,
+                        db_name: db.name.to_string(),
+                        kind: DatabaseKind::PostgreSQL,
+                        schema_id: Some(schema_id),
+                        schema_name: Some(schema.name().to_string()),
+                        is_parent_collapsed: true,
+                    }),
+                    TreeItem::Sequence(DatabaseSubItem {
+                        conn_id: db.conn_id,
+                        db_id: db.id,
+                        db_name: db.name.to_string(),
+                        kind: DatabaseKind::PostgreSQL,
+                        schema_id: Some(schema_id),
+                        schema_name: Some(schema.name().to_string()),
+                        is_parent_collapsed: true,
+                    }),
                 ];
                 self.tree_items
                     .splice(tree_index + 1..tree_index + 1, new_items);
@@ -245,45 +272,236 @@ impl<'a> ConnectionListComponent<'a> {
         }
     }
     fn save_connection_item(&mut self, conn: &dyn Connect) {
-        let conn_item = self.tree_items.iter_mut().find(|item| {
-            if let TreeItem::Connection(c) = item {
-                c.id == *conn.get_id()
-            } else {
-                false
-            }
+        let group = conn.get_group().map(|g| g.to_string());
+        let index = self.tree_items.iter().position(|item| match item {
+            TreeItem::Connection(c) => c.id == *conn.get_id(),
+            _ => false,
         });
-        if let Some(item) = conn_item {
-            if let TreeItem::Connection(item) = item {
-                if item.name.as_str() != conn.get_name() {
+        let items = match index {
+            Some(index) => {
+                if let TreeItem::Connection(item) = &mut self.tree_items[index] {
                     item.name = conn.get_name().to_string();
-                    let show_item = self.show_items.iter_mut().find(|item| {
-                        if let TreeItem::Connection(c) = item {
-                            c.id == *conn.get_id()
-                        } else {
-                            false
-                        }
-                    });
-                    if let Some(TreeItem::Connection(item)) = show_item {
-                        item.name = conn.get_name().to_string();
+                    if item.group == group {
+                        self.update_show_items();
+                        return;
                     }
+                    item.group = group.clone();
                 }
+                let end = self
+                    .tree_items
+                    .iter()
+                    .enumerate()
+                    .skip(index + 1)
+                    .find(|(_, item)| matches!(item, TreeItem::Group(_) | TreeItem::Connection(_)))
+                    .map_or(self.tree_items.len(), |(i, _)| i);
+                self.tree_items.drain(index..end).collect()
+            }
+            None => vec![Self::create_connection_item(conn)],
+        };
+        self.remove_empty_groups();
+        let position = self.get_group_end(group.as_deref());
+        self.tree_items.splice(position..position, items);
+        self.update_show_items();
+        self.select_conn_item(conn.get_id());
+    }
+    fn remove_connection_item(&mut self, conn_id: &Uuid) {
+        self.tree_items.retain(|item| match item {
+            TreeItem::Group(_) => true,
+            TreeItem::Connection(conn) => conn.id != *conn_id,
+            TreeItem::Database(db) => db.conn_id != *conn_id,
+            TreeItem::Schema(schema) => schema.conn_id != *conn_id,
+            TreeItem::Query(query) => query.conn_id != *conn_id,
+            TreeItem::Table(table) => table.conn_id != *conn_id,
+            TreeItem::View(view) => view.conn_id != *conn_id,
+            TreeItem::Routine(routine) => routine.conn_id != *conn_id,
+            TreeItem::Event(event) => event.conn_id != *conn_id,
+            TreeItem::Sequence(sequence) => sequence.conn_id != *conn_id,
+        });
+        self.remove_empty_groups();
+        self.update_show_items();
+    }
+    async fn reload_config(&mut self) -> Result<()> {
+        let config = Config::init()?;
+        let old_config = self.config.borrow().clone();
+        let mut removed = 0;
+        let mut changed = 0;
+
+        for conn in old_config.get_mysql_connections().iter() {
+            if !config
+                .get_mysql_connections()
+                .iter()
+                .any(|c| c.id == conn.id)
+            {
+                close_mysql_pools(self.mysql_pools.clone(), &conn.id).await;
+                self.mysql_conns.borrow_mut().remove(&conn.id);
+                self.remove_connection_item(&conn.id);
+                removed += 1;
             }
-        } else {
-            self.tree_items.push(TreeItem::Connection(ConnectionItem {
-                id: *conn.get_id(),
-                kind: conn.get_kind().clone(),
-                name: conn.get_name().to_owned(),
-                is_collapsed: true,
-                is_open: false,
-            }));
-            self.show_items.push(TreeItem::Connection(ConnectionItem {
-                id: *conn.get_id(),
-                kind: conn.get_kind().clone(),
-                name: conn.get_name().to_owned(),
-                is_collapsed: true,
-                is_open: false,
-            }));
         }
+        for conn in config.get_mysql_connections().iter() {
+            let old = self.mysql_conns.borrow().get(&conn.id).cloned();
+            if old.as_ref() != Some(conn) {
+                if old.is_some() {
+                    close_mysql_pools(self.mysql_pools.clone(), &conn.id).await;
+                }
+                self.mysql_conns.borrow_mut().insert(conn.id, conn.clone());
+                self.save_connection_item(conn);
+                changed += 1;
+            }
+        }
+        for conn in old_config.get_pg_connections().iter() {
+            if !config.get_pg_connections().iter().any(|c| c.id == conn.id) {
+                close_pg_pools(self.pg_pools.clone(), &conn.id).await;
+                self.pg_conns.borrow_mut().remove(&conn.id);
+                self.remove_connection_item(&conn.id);
+                removed += 1;
+            }
+        }
+        for conn in config.get_pg_connections().iter() {
+            let old = self.pg_conns.borrow().get(&conn.id).cloned();
+            if old.as_ref() != Some(conn) {
+                if old.is_some() {
+                    close_pg_pools(self.pg_pools.clone(), &conn.id).await;
+                }
+                self.pg_conns.borrow_mut().insert(conn.id, conn.clone());
+                self.save_connection_item(conn);
+                changed += 1;
+            }
+        }
+
+        *self.config.borrow_mut() = config;
+        if removed > 0 {
+            self.state.select(None);
+        }
+        self.status_line.borrow_mut().info(
+            format!(
+                "Config reloaded, {} connections added or changed, {} removed",
+                changed, removed
+            )
+            .as_str(),
+        );
+        Ok(())
+    }
+    fn get_group_end(&mut self, group: Option<&str>) -> usize {
+        let group = match group {
+            Some(group) => group,
+            None => return self.tree_items.len(),
+        };
+        let start = self.tree_items.iter().position(|item| match item {
+            TreeItem::Group(g) => g.name == group,
+            _ => false,
+        });
+        match start {
+            Some(start) => self
+                .tree_items
+                .iter()
+                .enumerate()
+                .skip(start + 1)
+                .find(|(_, item)| match item {
+                    TreeItem::Group(_) => true,
+                    TreeItem::Connection(c) => c.group.is_none(),
+                    _ => false,
+                })
+                .map_or(self.tree_items.len(), |(i, _)| i),
+            None => {
+                let position = self
+                    .tree_items
+                    .iter()
+                    .position(|item| match item {
+                        TreeItem::Group(g) => g.name.as_str() > group,
+                        TreeItem::Connection(c) => c.group.is_none(),
+                        _ => false,
+                    })
+                    .unwrap_or(self.tree_items.len());
+                self.tree_items.insert(
+                    position,
+                    TreeItem::Group(GroupItem {
+                        name: group.to_string(),
+                        is_collapsed: false,
+                    }),
+                );
+                position + 1
+            }
+        }
+    }
+    fn remove_empty_groups(&mut self) {
+        let groups = self
+            .tree_items
+            .iter()
+            .filter_map(|item| match item {
+                TreeItem::Connection(c) => c.group.clone(),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        self.tree_items.retain(|item| match item {
+            TreeItem::Group(g) => groups.contains(&g.name),
+            _ => true,
+        });
+    }
+    fn set_group_collapsed(&mut self, group_item: &GroupItem, is_collapsed: bool) {
+        self.tree_items.iter_mut().for_each(|item| {
+            if let TreeItem::Group(g) = item {
+                if g.name == group_item.name {
+                    g.is_collapsed = is_collapsed;
+                }
+            }
+        });
+        self.update_show_items();
+    }
+    fn update_show_items(&mut self) {
+        let collapsed_groups = self
+            .tree_items
+            .iter()
+            .filter_map(|item| match item {
+                TreeItem::Group(g) if g.is_collapsed => Some(g.name.clone()),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        let hidden = self
+            .tree_items
+            .iter()
+            .filter_map(|item| match item {
+                TreeItem::Connection(c)
+                    if c.group
+                        .as_ref()
+                        .map_or(false, |g| collapsed_groups.contains(g)) =>
+                {
+                    Some(c.id)
+                }
+                _ => None,
+            })
+            .collect::<Vec<Uuid>>();
+        self.show_items = self
+            .tree_items
+            .iter()
+            .filter(|i| match i {
+                TreeItem::Group(_) => true,
+                TreeItem::Connection(conn) => !hidden.contains(&conn.id),
+                TreeItem::Database(db) => !db.is_conn_collapsed && !hidden.contains(&db.conn_id),
+                TreeItem::Schema(schema) => {
+                    !schema.is_db_collapsed && !hidden.contains(&schema.conn_id)
+                }
+                TreeItem::Query(query) => {
+                    !query.is_parent_collapsed && !hidden.contains(&query.conn_id)
+                }
+                TreeItem::Table(table) => {
+                    !table.is_parent_collapsed && !hidden.contains(&table.conn_id)
+                }
+                TreeItem::View(view) => {
+                    !view.is_parent_collapsed && !hidden.contains(&view.conn_id)
+                }
+                TreeItem::Routine(routine) => {
+                    !routine.is_parent_collapsed && !hidden.contains(&routine.conn_id)
+                }
+                TreeItem::Event(event) => {
+                    !event.is_parent_collapsed && !hidden.contains(&event.conn_id)
+                }
+                TreeItem::Sequence(sequence) => {
+                    !sequence.is_parent_collapsed && !hidden.contains(&sequence.conn_id)
+                }
+            })
+            .cloned()
+            .collect();
     }
     fn rename_pg_db_item(&mut self, db_id: &Uuid, name: &str) {
         self.tree_items.iter_mut().for_each(|item| match item {
@@ -312,6 +530,21 @@ impl<'a> ConnectionListComponent<'a> {
                     view.db_name = name.to_string();
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.db_id == *db_id {
+                    routine.db_name = name.to_string();
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.db_id == *db_id {
+                    event.db_name = name.to_string();
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.db_id == *db_id {
+                    sequence.db_name = name.to_string();
+                }
+            }
             _ => (),
         });
         self.show_items.iter_mut().for_each(|item| match item {
@@ -340,6 +573,21 @@ impl<'a> ConnectionListComponent<'a> {
                     view.db_name = name.to_string();
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.db_id == *db_id {
+                    routine.db_name = name.to_string();
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.db_id == *db_id {
+                    event.db_name = name.to_string();
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.db_id == *db_id {
+                    sequence.db_name = name.to_string();
+                }
+            }
 
             _ => (),
         });
@@ -366,6 +614,21 @@ impl<'a> ConnectionListComponent<'a> {
                     view.schema_name = Some(name.to_string());
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.schema_id == Some(*schema_id) {
+                    routine.schema_name = Some(name.to_string());
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.schema_id == Some(*schema_id) {
+                    event.schema_name = Some(name.to_string());
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.schema_id == Some(*schema_id) {
+                    sequence.schema_name = Some(name.to_string());
+                }
+            }
             _ => (),
         });
         self.show_items.iter_mut().for_each(|item| match item {
@@ -389,6 +652,21 @@ impl<'a> ConnectionListComponent<'a> {
                     view.schema_name = Some(name.to_string());
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.schema_id == Some(*schema_id) {
+                    routine.schema_name = Some(name.to_string());
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.schema_id == Some(*schema_id) {
+                    event.schema_name = Some(name.to_string());
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.schema_id == Some(*schema_id) {
+                    sequence.schema_name = Some(name.to_string());
+                }
+            }
             _ => (),
         });
     }
@@ -409,21 +687,71 @@ impl<'a> ConnectionListComponent<'a> {
             });
         f.render_widget(block, r);
 
+        let grouped = self
+            .tree_items
+            .iter()
+            .filter_map(|item| match item {
+                TreeItem::Connection(c) if c.group.is_some() => Some(c.id),
+                _ => None,
+            })
+            .collect::<Vec<Uuid>>();
+        let indent = |conn_id: &Uuid| if grouped.contains(conn_id) { "  " } else { "" };
         let items: Vec<ListItem> = self
             .show_items
             .iter()
             .map(|i| match i {
-                TreeItem::Connection(conn) => ListItem::new(format!(
-                    "{}  {}",
-                    if conn.is_collapsed {
-                        '\u{25b8}'
-                    } else {
-                        '\u{25be}'
-                    },
-                    conn.name,
+                TreeItem::Group(group) => ListItem::new(Span::styled(
+                    format!(
+                        "{}  {}",
+                        if group.is_collapsed {
+                            '\u{25b8}'
+                        } else {
+                            '\u{25be}'
+                        },
+                        group.name
+                    ),
+                    Style::default().add_modifier(Modifier::BOLD),
                 )),
+                TreeItem::Connection(conn) => {
+                    let conn_info =
+                        get_connection(self.mysql_conns.clone(), self.pg_conns.clone(), &conn.id);
+                    let mut style = conn_info
+                        .as_ref()
+                        .and_then(|c| c.get_color().to_color())
+                        .map_or(Style::default(), |c| Style::default().fg(c));
+                    if conn_info.as_ref().map_or(false, |c| c.is_ephemeral()) {
+                        style = style.add_modifier(Modifier::ITALIC);
+                    }
+                    let mut spans = vec![Span::styled(
+                        format!(
+                            "{}{}  {} {}",
+                            indent(&conn.id),
+                            if conn.is_collapsed {
+                                '\u{25b8}'
+                            } else {
+                                '\u{25be}'
+                            },
+                            conn.name,
+                            if self.is_connected(conn) {
+                                '\u{25cf}'
+                            } else {
+                                '\u{25cb}'
+                            },
+                        ),
+                        style,
+                    )];
+                    if let Some(environment) = conn_info.as_ref().and_then(|c| c.get_environment())
+                    {
+                        spans.push(Span::styled(
+                            format!(" [{}]", environment),
+                            style.add_modifier(Modifier::BOLD),
+                        ));
+                    }
+                    ListItem::new(Spans::from(spans))
+                }
                 TreeItem::Database(db) => ListItem::new(format!(
-                    "  {}  {}",
+                    "{}  {}  {}",
+                    indent(&db.conn_id),
                     if db.is_collapsed {
                         '\u{25b8}'
                     } else {
@@ -432,7 +760,8 @@ impl<'a> ConnectionListComponent<'a> {
                     db.name
                 )),
                 TreeItem::Schema(schema) => ListItem::new(format!(
-                    "    {}  {}",
+                    "{}    {}  {}",
+                    indent(&schema.conn_id),
                     if schema.is_collapsed {
                         '\u{25b8}'
                     } else {
@@ -440,28 +769,74 @@ impl<'a> ConnectionListComponent<'a> {
                     },
                     schema.name
                 )),
-                TreeItem::Query(query) => Self::generate_sub_list_item(query, "Query"),
-                TreeItem::Table(table) => Self::generate_sub_list_item(table, "Table"),
-                TreeItem::View(view) => Self::generate_sub_list_item(view, "View"),
+                TreeItem::Query(query) => {
+                    Self::generate_sub_list_item(query, "Query", indent(&query.conn_id))
+                }
+                TreeItem::Table(table) => {
+                    Self::generate_sub_list_item(table, "Table", indent(&table.conn_id))
+                }
+                TreeItem::View(view) => {
+                    Self::generate_sub_list_item(view, "View", indent(&view.conn_id))
+                }
+                TreeItem::Routine(routine) => Self::generate_sub_list_item(
+                    routine,
+                    match routine.kind {
+                        DatabaseKind::MySQL => "Routine",
+                        DatabaseKind::PostgreSQL => "Function",
+                    },
+                    indent(&routine.conn_id),
+                ),
+                TreeItem::Event(event) => {
+                    Self::generate_sub_list_item(event, "Event", indent(&event.conn_id))
+                }
+                TreeItem::Sequence(sequence) => {
+                    Self::generate_sub_list_item(sequence, "Sequence", indent(&sequence.conn_id))
+                }
             })
             .collect();
 
+        self.list_rect = r.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        if let Some(selected) = self.state.selected() {
+            let height = self.list_rect.height as usize;
+            if selected < self.list_offset {
+                self.list_offset = selected;
+            } else if height > 0 && selected >= self.list_offset + height {
+                self.list_offset = selected + 1 - height;
+            }
+        }
         f.render_stateful_widget(
             List::new(items).highlight_style(
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            r.inner(&Margin {
-                vertical: 1,
-                horizontal: 1,
-            }),
+            self.list_rect,
             &mut self.state,
         );
         if is_active {
             self.update_commands();
         }
     }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.conn_dlg.is_some()
+            || self.db_dlg.is_some()
+            || self.schema_dlg.is_some()
+            || self.delete_conn_dlg.is_some()
+            || self.delete_db_dlg.is_some()
+            || self.delete_schema_dlg.is_some()
+            || self.new_select.is_some()
+        {
+            return;
+        }
+        if let Some(index) = get_clicked_row(self.list_rect, 0, self.list_offset, x, y) {
+            if index < self.show_items.len() {
+                self.state.select(Some(index));
+            }
+        }
+    }
     pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
     where
         B: Backend,
@@ -487,9 +862,54 @@ impl<'a> ConnectionListComponent<'a> {
         if let Some(schema_dlg) = self.schema_dlg.as_mut() {
             schema_dlg.draw(f);
         }
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.server_info_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.import_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(task) = self.import_task.as_ref() {
+            let bounds = f.size();
+            let width = std::cmp::min(bounds.width - 2, 60);
+            let rect = Rect::new(
+                (bounds.width - width) / 2,
+                (bounds.height - 3) / 2,
+                width,
+                3,
+            );
+            f.render_widget(Clear, rect);
+            f.render_widget(
+                Gauge::default()
+                    .block(
+                        Block::default()
+                            .title(format!("Importing {}", task.path))
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .border_style(Style::default().fg(Color::Green)),
+                    )
+                    .gauge_style(Style::default().fg(Color::Green))
+                    .ratio(task.executed as f64 / task.statements.len().max(1) as f64)
+                    .label(format!(
+                        "{}/{} statements",
+                        task.executed,
+                        task.statements.len()
+                    )),
+                rect,
+            );
+        }
     }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
-        let result = if self.delete_conn_dlg.is_some() {
+        let result = if self.import_task.is_some() {
+            self.handle_import_task_event(key)
+        } else if self.import_dlg.is_some() {
+            self.handle_import_dlg_event(key)?
+        } else if self.delete_conn_dlg.is_some() {
             self.handle_delete_conn_dlg_event(key).await?
         } else if self.delete_db_dlg.is_some() {
             self.handle_delete_db_dlg_event(key).await?
@@ -503,6 +923,12 @@ impl<'a> ConnectionListComponent<'a> {
             self.handle_db_dlg_event(key).await?
         } else if self.schema_dlg.is_some() {
             self.handle_schema_dlg_event(key).await?
+        } else if self.export_dlg.is_some() {
+            self.handle_export_dlg_event(key).await?
+        } else if self.info_dlg.is_some() {
+            self.handle_info_dlg_event(key)
+        } else if self.server_info_dlg.is_some() {
+            self.handle_server_info_dlg_event(key)
         } else {
             self.handle_main_event(key).await?
         };
@@ -548,22 +974,7 @@ impl<'a> ConnectionListComponent<'a> {
                                     )?;
                                 }
                             }
-                            self.tree_items.retain(|item| match item {
-                                TreeItem::Connection(conn) => conn.id != conn_item.id,
-                                TreeItem::Database(db) => db.conn_id != conn_item.id,
-                                TreeItem::Schema(schema) => schema.conn_id != conn_item.id,
-                                TreeItem::Query(query) => query.conn_id != conn_item.id,
-                                TreeItem::Table(table) => table.conn_id != conn_item.id,
-                                TreeItem::View(view) => view.conn_id != conn_item.id,
-                            });
-                            self.show_items.retain(|item| match item {
-                                TreeItem::Connection(conn) => conn.id != conn_item.id,
-                                TreeItem::Database(db) => db.conn_id != conn_item.id,
-                                TreeItem::Schema(schema) => schema.conn_id != conn_item.id,
-                                TreeItem::Query(query) => query.conn_id != conn_item.id,
-                                TreeItem::Table(table) => table.conn_id != conn_item.id,
-                                TreeItem::View(view) => view.conn_id != conn_item.id,
-                            });
+                            self.remove_connection_item(&conn_item.id);
 
                             self.state.select(None);
                             self.delete_conn_dlg = None;
@@ -591,7 +1002,8 @@ impl<'a> ConnectionListComponent<'a> {
                                     self.mysql_pools.clone(),
                                     &db_item.conn_id,
                                     None,
-                                    format!("DROP DATABASE `{}`", db_item.name).as_str(),
+                                    format!("DROP DATABASE {}", quote_mysql_ident(&db_item.name))
+                                        .as_str(),
                                 )
                                 .await?;
                                 close_mysql_pool(
@@ -603,41 +1015,26 @@ impl<'a> ConnectionListComponent<'a> {
                                 .await;
                             }
                             DatabaseKind::PostgreSQL => {
-                                execute_pg_query(
+                                close_pg_pool(
                                     self.pg_conns.clone(),
                                     self.pg_pools.clone(),
                                     &db_item.conn_id,
-                                    None,
-                                    format!("DROP DATABASE \"{}\"", db_item.name).as_str(),
+                                    Some(&db_item.name),
                                 )
-                                .await?;
-                                close_pg_pool(
+                                .await;
+                                execute_pg_query(
                                     self.pg_conns.clone(),
                                     self.pg_pools.clone(),
                                     &db_item.conn_id,
-                                    Some(&db_item.name),
+                                    None,
+                                    format!("DROP DATABASE {}", quote_pg_ident(&db_item.name))
+                                        .as_str(),
                                 )
-                                .await;
+                                .await?;
                             }
                         }
-                        self.tree_items.retain(|item| match item {
-                            TreeItem::Connection(_) => true,
-                            TreeItem::Database(db) => db.id != db_item.id,
-                            TreeItem::Schema(schema) => schema.db_id != db_item.id,
-                            TreeItem::Query(query) => query.db_id != db_item.id,
-                            TreeItem::Table(table) => table.db_id != db_item.id,
-                            TreeItem::View(view) => view.db_id != db_item.id,
-                        });
-                        self.show_items.retain(|item| match item {
-                            TreeItem::Connection(_) => true,
-                            TreeItem::Database(db) => db.id != db_item.id,
-                            TreeItem::Schema(schema) => schema.db_id != db_item.id,
-                            TreeItem::Query(query) => query.db_id != db_item.id,
-                            TreeItem::Table(table) => table.db_id != db_item.id,
-                            TreeItem::View(view) => view.db_id != db_item.id,
-                        });
-
-                        self.state.select(None);
+                        self.reload_conn_items(&db_item.conn_id).await?;
+                        self.select_conn_item(&db_item.conn_id);
                         self.delete_db_dlg = None;
                     }
                 }
@@ -661,24 +1058,41 @@ impl<'a> ConnectionListComponent<'a> {
                                 self.pg_pools.clone(),
                                 &schema_item.conn_id,
                                 Some(schema_item.db_name.as_str()),
-                                format!("DROP SCHEMA \"{}\"", schema_item.name).as_str(),
+                                format!("DROP SCHEMA {}", quote_pg_ident(&schema_item.name))
+                                    .as_str(),
                             )
                             .await?;
                             self.tree_items.retain(|item| match item {
+                                TreeItem::Group(_) => true,
                                 TreeItem::Connection(_) => true,
                                 TreeItem::Database(_) => true,
                                 TreeItem::Schema(schema) => schema.id != schema_item.id,
                                 TreeItem::Query(query) => query.schema_id != Some(schema_item.id),
                                 TreeItem::Table(table) => table.schema_id != Some(schema_item.id),
                                 TreeItem::View(view) => view.schema_id != Some(schema_item.id),
+                                TreeItem::Routine(routine) => {
+                                    routine.schema_id != Some(schema_item.id)
+                                }
+                                TreeItem::Event(event) => event.schema_id != Some(schema_item.id),
+                                TreeItem::Sequence(sequence) => {
+                                    sequence.schema_id != Some(schema_item.id)
+                                }
                             });
                             self.show_items.retain(|item| match item {
+                                TreeItem::Group(_) => true,
                                 TreeItem::Connection(_) => true,
                                 TreeItem::Database(_) => true,
                                 TreeItem::Schema(schema) => schema.id != schema_item.id,
                                 TreeItem::Query(query) => query.schema_id != Some(schema_item.id),
                                 TreeItem::Table(table) => table.schema_id != Some(schema_item.id),
                                 TreeItem::View(view) => view.schema_id != Some(schema_item.id),
+                                TreeItem::Routine(routine) => {
+                                    routine.schema_id != Some(schema_item.id)
+                                }
+                                TreeItem::Event(event) => event.schema_id != Some(schema_item.id),
+                                TreeItem::Sequence(sequence) => {
+                                    sequence.schema_id != Some(schema_item.id)
+                                }
                             });
                             self.state.select(None);
                             self.delete_schema_dlg = None;
@@ -762,8 +1176,231 @@ impl<'a> ConnectionListComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    async fn handle_export_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.export_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let path = self.export(&map).await?;
+                    self.export_dlg = None;
+                    self.status_line
+                        .borrow_mut()
+                        .info(format!("Exported to {}", path).as_str());
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_info_dlg_event(&mut self, key: &Key) -> ComponentResult {
+        if let Some(dlg) = self.info_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel | DialogResult::Confirm(_) => {
+                    self.info_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        ComponentResult::Done
+    }
+    fn handle_server_info_dlg_event(&mut self, key: &Key) -> ComponentResult {
+        if let Some(dlg) = self.server_info_dlg.as_mut() {
+            if let DialogResult::Cancel = dlg.handle_event(key) {
+                self.server_info_dlg = None;
+            }
+        }
+        ComponentResult::Done
+    }
+    async fn export(&mut self, map: &HashMap<String, Option<String>>) -> Result<String> {
+        let path = map.get("file").unwrap().clone().unwrap();
+        let options = DumpOptions {
+            with_data: map.get("with data").unwrap().as_deref() == Some("true"),
+            views: map.get("views").unwrap().as_deref() == Some("true"),
+            triggers: map.get("triggers").unwrap().as_deref() == Some("true"),
+            tables: None,
+        };
+        let sql = if let Some(index) = self.state.selected() {
+            match self.show_items[index].clone() {
+                TreeItem::Database(db) => {
+                    let pool = get_mysql_pool(
+                        self.mysql_conns.clone(),
+                        self.mysql_pools.clone(),
+                        &db.conn_id,
+                        Some("information_schema"),
+                    )
+                    .await?;
+                    dump_mysql_database(&pool, &db.name, &options).await?
+                }
+                TreeItem::Schema(schema) => {
+                    let pool = get_pg_pool(
+                        self.pg_conns.clone(),
+                        self.pg_pools.clone(),
+                        &schema.conn_id,
+                        Some(&schema.db_name),
+                    )
+                    .await?;
+                    dump_pg_schema(&pool, &schema.name, &options).await?
+                }
+                _ => return Err(Error::msg("Please select a database or schema")),
+            }
+        } else {
+            return Err(Error::msg("Please select a database or schema"));
+        };
+        std::fs::write(&path, sql)?;
+        Ok(path)
+    }
+    fn handle_import_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.import_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.import_dlg = None;
+                }
+                DialogResult::Confirm(path) => {
+                    if let Some(index) = self.state.selected() {
+                        if let TreeItem::Database(db) = &self.show_items[index] {
+                            let path = path.trim().to_string();
+                            let sql = std::fs::read_to_string(&path)?;
+                            self.import_task = Some(ImportTask {
+                                conn_id: db.conn_id,
+                                db_name: db.name.clone(),
+                                kind: db.kind.clone(),
+                                statements: split_sql_statements(&sql, &db.kind),
+                                path,
+                                executed: 0,
+                            });
+                        }
+                    }
+                    self.import_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_import_task_event(&mut self, key: &Key) -> ComponentResult {
+        if *key == CANCEL_KEY {
+            if let Some(task) = self.import_task.take() {
+                self.info_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Info,
+                    "Import",
+                    format!(
+                        "Import cancelled after {} of {} statements",
+                        task.executed,
+                        task.statements.len()
+                    )
+                    .as_str(),
+                ));
+            }
+        }
+        ComponentResult::Done
+    }
+    pub async fn handle_tick(&mut self) -> Result<()> {
+        if let Some(task) = self.import_task.as_mut() {
+            let start = Instant::now();
+            while task.executed < task.statements.len()
+                && start.elapsed() < Duration::from_millis(200)
+            {
+                let sql = task.statements[task.executed].as_str();
+                let result = match task.kind {
+                    DatabaseKind::MySQL => {
+                        execute_mysql_query_unprepared(
+                            self.mysql_conns.clone(),
+                            self.mysql_pools.clone(),
+                            &task.conn_id,
+                            Some(&task.db_name),
+                            sql,
+                        )
+                        .await
+                    }
+                    DatabaseKind::PostgreSQL => {
+                        execute_pg_query_unprepared(
+                            self.pg_conns.clone(),
+                            self.pg_pools.clone(),
+                            &task.conn_id,
+                            Some(&task.db_name),
+                            sql,
+                        )
+                        .await
+                    }
+                };
+                if let Err(e) = result {
+                    let msg = format!(
+                        "Statement {} of {} failed: {}\n{}",
+                        task.executed + 1,
+                        task.statements.len(),
+                        e.root_cause(),
+                        sql
+                    );
+                    self.import_task = None;
+                    return Err(Error::msg(msg));
+                }
+                task.executed += 1;
+            }
+            if task.executed == task.statements.len() {
+                self.info_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Info,
+                    "Import",
+                    format!("Executed {} statements from {}", task.executed, task.path).as_str(),
+                ));
+                self.import_task = None;
+            }
+        }
+        Ok(())
+    }
     async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
+            SERVER_INFO_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let (conn_id, db_name, kind) = match &self.show_items[index] {
+                        TreeItem::Connection(c) => (c.id, None, c.kind.clone()),
+                        TreeItem::Database(db) => {
+                            (db.conn_id, Some(db.name.clone()), db.kind.clone())
+                        }
+                        _ => return Ok(ComponentResult::Done),
+                    };
+                    let info = match kind {
+                        DatabaseKind::MySQL => {
+                            get_mysql_server_info(
+                                self.mysql_conns.clone(),
+                                self.mysql_pools.clone(),
+                                &conn_id,
+                                db_name.as_deref(),
+                            )
+                            .await?
+                        }
+                        DatabaseKind::PostgreSQL => {
+                            get_pg_server_info(
+                                self.pg_conns.clone(),
+                                self.pg_pools.clone(),
+                                &conn_id,
+                                db_name.as_deref(),
+                            )
+                            .await?
+                        }
+                    };
+                    self.server_info_dlg = Some(TextDialog::new("Server Info", &info.get_text()));
+                }
+            }
+            PROCESS_KEY => {
+                if let Some(index) = self.state.selected() {
+                    if let TreeItem::Connection(c) = &self.show_items[index] {
+                        match c.kind {
+                            DatabaseKind::MySQL => {
+                                return Ok(ComponentResult::Goto(Goto::ProcessListMySQL {
+                                    conn_id: c.id,
+                                }));
+                            }
+                            DatabaseKind::PostgreSQL => {
+                                return Ok(ComponentResult::Goto(Goto::ActivityListPG {
+                                    conn_id: c.id,
+                                }));
+                            }
+                        }
+                    }
+                }
+            }
             USER_KEY => {
                 if let Some(index) = self.state.selected() {
                     if let TreeItem::Connection(c) = &self.show_items[index] {
@@ -835,9 +1472,54 @@ impl<'a> ConnectionListComponent<'a> {
             EDIT_KEY => {
                 self.handle_edit_event().await?;
             }
+            RELOAD_CONFIG_KEY => {
+                self.reload_config().await?;
+            }
+            REFRESH_KEY => {
+                if let Some(index) = self.state.selected() {
+                    if let TreeItem::Connection(conn) = self.show_items[index].clone() {
+                        if conn.is_open {
+                            self.reload_conn_items(&conn.id).await?;
+                        }
+                    }
+                }
+            }
             DELETE_KEY => {
                 self.handle_delete_event();
             }
+            IMPORT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    if let TreeItem::Database(_) = &self.show_items[index] {
+                        let path = dirs_next::home_dir()
+                            .map(|p| format!("{}/", p.display()))
+                            .unwrap_or_default();
+                        self.import_dlg = Some(InputDialog::new("SQL File Path", Some(&path)));
+                    }
+                }
+            }
+            EXPORT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    match &self.show_items[index] {
+                        TreeItem::Database(db) if db.kind == DatabaseKind::MySQL => {
+                            self.export_dlg = Some(ExportDialog::new(&db.name));
+                        }
+                        TreeItem::Schema(schema) => {
+                            self.export_dlg = Some(ExportDialog::new(&format!(
+                                "{}.{}",
+                                schema.db_name, schema.name
+                            )));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            DISCONNECT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    if let TreeItem::Connection(conn) = self.show_items[index].clone() {
+                        self.disconnect(&conn).await?;
+                    }
+                }
+            }
             UP_KEY => {
                 if !self.show_items.is_empty() {
                     let index = get_table_up_index(self.state.selected());
@@ -859,6 +1541,9 @@ impl<'a> ConnectionListComponent<'a> {
             CONFIRM_KEY => {
                 if let Some(index) = self.state.selected() {
                     match self.show_items[index].clone() {
+                        TreeItem::Group(group_item) => {
+                            self.set_group_collapsed(&group_item, !group_item.is_collapsed);
+                        }
                         TreeItem::Connection(conn_item) => {
                             self.set_conn_items_collapsed(&conn_item, !conn_item.is_collapsed)
                                 .await?;
@@ -910,6 +1595,34 @@ impl<'a> ConnectionListComponent<'a> {
                                 }));
                             }
                         },
+                        TreeItem::Routine(routine) => match routine.kind {
+                            DatabaseKind::MySQL => {
+                                return Ok(ComponentResult::Goto(Goto::RoutineListMySQL {
+                                    conn_id: routine.conn_id,
+                                    db_name: routine.db_name,
+                                }));
+                            }
+                            DatabaseKind::PostgreSQL => {
+                                return Ok(ComponentResult::Goto(Goto::FunctionListPG {
+                                    conn_id: routine.conn_id,
+                                    db_name: routine.db_name.clone(),
+                                    schema_name: routine.schema_name.unwrap(),
+                                }));
+                            }
+                        },
+                        TreeItem::Event(event) => {
+                            return Ok(ComponentResult::Goto(Goto::EventListMySQL {
+                                conn_id: event.conn_id,
+                                db_name: event.db_name,
+                            }));
+                        }
+                        TreeItem::Sequence(sequence) => {
+                            return Ok(ComponentResult::Goto(Goto::SequenceListPG {
+                                conn_id: sequence.conn_id,
+                                db_name: sequence.db_name.clone(),
+                                schema_name: sequence.schema_name.unwrap(),
+                            }));
+                        }
                     }
                 }
             }
@@ -1009,18 +1722,26 @@ impl<'a> ConnectionListComponent<'a> {
                         "Are you sure to delete this connection?",
                     ));
                 }
-                TreeItem::Database(_) => {
+                TreeItem::Database(db) => {
                     self.delete_db_dlg = Some(ConfirmDialog::new(
                         ConfirmKind::Warning,
-                        "Delete Database",
-                        "Are you sure to delete this database?",
+                        "Drop Database",
+                        format!(
+                            "Database {} and ALL of its data will be permanently dropped. This cannot be undone!",
+                            db.name
+                        )
+                        .as_str(),
                     ));
                 }
-                TreeItem::Schema(_) => {
+                TreeItem::Schema(schema) => {
                     self.delete_schema_dlg = Some(ConfirmDialog::new(
                         ConfirmKind::Warning,
-                        "Delete Schema",
-                        "Are you sure to delete this schema?",
+                        "Drop Schema",
+                        format!(
+                            "Schema {} and ALL of its objects will be permanently dropped. This cannot be undone!",
+                            schema.name
+                        )
+                        .as_str(),
                     ));
                 }
                 _ => (),
@@ -1065,7 +1786,6 @@ impl<'a> ConnectionListComponent<'a> {
                     &db.get_create_ddl(),
                 )
                 .await?;
-                self.add_database_item(&db);
             }
             DatabaseKind::PostgreSQL => {
                 let db = self.generate_pg_database(map)?;
@@ -1077,9 +1797,9 @@ impl<'a> ConnectionListComponent<'a> {
                     &db.get_create_ddl(),
                 )
                 .await?;
-                self.add_database_item(&db);
             }
         }
+        self.reload_conn_items(&conn_id).await?;
         Ok(())
     }
 
@@ -1134,6 +1854,7 @@ impl<'a> ConnectionListComponent<'a> {
                                 self.pg_conns.clone(),
                                 self.pg_pools.clone(),
                                 &conn_id,
+                                None,
                                 &db.get_rename_ddl(old_db.name()),
                             )
                             .await?;
@@ -1286,9 +2007,33 @@ impl<'a> ConnectionListComponent<'a> {
                 .get("password")
                 .unwrap()
                 .as_ref()
-                .ok_or_else(|| Error::msg("cannot get password"))?
-                .to_string(),
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+            password_command: map
+                .get("password command")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.to_string()),
+            environment: map
+                .get("environment")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.to_string()),
+            group: map
+                .get("group")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            color: map
+                .get("color")
+                .unwrap()
+                .as_ref()
+                .map(|c| ConnectionColor::from_str(c))
+                .transpose()?
+                .unwrap_or_default(),
             add_at: Utc::now(),
+            ephemeral: false,
         })
     }
     fn generate_pg_connection(
@@ -1324,6 +2069,12 @@ impl<'a> ConnectionListComponent<'a> {
                 .map(|p| p.to_string())
                 .unwrap_or(String::from("3306")),
             init_db: map.get("init db").unwrap().as_ref().map(|s| s.to_string()),
+            default_schema: map
+                .get("default schema")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
             user: map
                 .get("user")
                 .unwrap()
@@ -1334,9 +2085,33 @@ impl<'a> ConnectionListComponent<'a> {
                 .get("password")
                 .unwrap()
                 .as_ref()
-                .ok_or_else(|| Error::msg("cannot get passowrd"))?
-                .to_string(),
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+            password_command: map
+                .get("password command")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.to_string()),
+            environment: map
+                .get("environment")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.to_string()),
+            group: map
+                .get("group")
+                .unwrap()
+                .as_ref()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            color: map
+                .get("color")
+                .unwrap()
+                .as_ref()
+                .map(|c| ConnectionColor::from_str(c))
+                .transpose()?
+                .unwrap_or_default(),
             add_at: Utc::now(),
+            ephemeral: false,
         })
     }
     fn generate_mysql_database(
@@ -1372,6 +2147,7 @@ impl<'a> ConnectionListComponent<'a> {
                 .ok_or_else(|| Error::msg("cannot get name"))?
                 .to_string(),
             owner: map.get("owner").unwrap().as_ref().map(|s| s.to_string()),
+            encoding: map.get("encoding").unwrap().as_ref().map(|s| s.to_string()),
             collation_order: map
                 .get("collation order")
                 .unwrap()
@@ -1422,12 +2198,148 @@ impl<'a> ConnectionListComponent<'a> {
             owner: map.get("owner").unwrap().as_ref().map(|s| s.to_string()),
         })
     }
+    fn is_connected(&self, conn: &ConnectionItem) -> bool {
+        match conn.kind {
+            DatabaseKind::MySQL => self
+                .mysql_pools
+                .borrow()
+                .keys()
+                .any(|(id, _)| *id == conn.id),
+            DatabaseKind::PostgreSQL => self.pg_pools.borrow().keys().any(|(id, _)| *id == conn.id),
+        }
+    }
+    async fn disconnect(&mut self, conn_item: &ConnectionItem) -> Result<()> {
+        match conn_item.kind {
+            DatabaseKind::MySQL => close_mysql_pools(self.mysql_pools.clone(), &conn_item.id).await,
+            DatabaseKind::PostgreSQL => close_pg_pools(self.pg_pools.clone(), &conn_item.id).await,
+        }
+        self.remove_conn_items(&conn_item.id);
+        let conn_item = self.tree_items.iter_mut().find_map(|item| match item {
+            TreeItem::Connection(c) if c.id == conn_item.id => {
+                c.is_open = false;
+                Some(c.clone())
+            }
+            _ => None,
+        });
+        if let Some(conn_item) = conn_item {
+            self.set_conn_items_collapsed(&conn_item, true).await?;
+            self.select_conn_item(&conn_item.id);
+        }
+        Ok(())
+    }
+    fn remove_conn_items(&mut self, conn_id: &Uuid) {
+        self.tree_items.retain(|item| match item {
+            TreeItem::Group(_) | TreeItem::Connection(_) => true,
+            TreeItem::Database(db) => db.conn_id != *conn_id,
+            TreeItem::Schema(schema) => schema.conn_id != *conn_id,
+            TreeItem::Query(query) => query.conn_id != *conn_id,
+            TreeItem::Table(table) => table.conn_id != *conn_id,
+            TreeItem::View(view) => view.conn_id != *conn_id,
+            TreeItem::Routine(routine) => routine.conn_id != *conn_id,
+            TreeItem::Event(event) => event.conn_id != *conn_id,
+            TreeItem::Sequence(sequence) => sequence.conn_id != *conn_id,
+        });
+    }
+    async fn reload_conn_items(&mut self, conn_id: &Uuid) -> Result<()> {
+        self.remove_conn_items(conn_id);
+        let conn_item = self.tree_items.iter_mut().find_map(|item| match item {
+            TreeItem::Connection(c) if c.id == *conn_id => {
+                c.is_open = false;
+                Some(c.clone())
+            }
+            _ => None,
+        });
+        if let Some(conn_item) = conn_item {
+            self.set_conn_items_collapsed(&conn_item, false).await?;
+        }
+        Ok(())
+    }
+    pub async fn goto_connection(&mut self, conn_id: &Uuid) -> Result<()> {
+        let conn_item = self.tree_items.iter().find_map(|item| match item {
+            TreeItem::Connection(c) if c.id == *conn_id => Some(c.clone()),
+            _ => None,
+        });
+        if let Some(conn_item) = conn_item {
+            if let Some(group) = conn_item.group.as_ref() {
+                let group_item = self.tree_items.iter().find_map(|item| match item {
+                    TreeItem::Group(g) if &g.name == group => Some(g.clone()),
+                    _ => None,
+                });
+                if let Some(group_item) = group_item {
+                    self.set_group_collapsed(&group_item, false);
+                }
+            }
+            self.set_conn_items_collapsed(&conn_item, false).await?;
+            self.select_conn_item(conn_id);
+        }
+        Ok(())
+    }
+    fn select_conn_item(&mut self, conn_id: &Uuid) {
+        let index = self.show_items.iter().position(|item| {
+            if let TreeItem::Connection(c) = item {
+                c.id == *conn_id
+            } else {
+                false
+            }
+        });
+        self.state.select(index);
+    }
+    pub async fn restore_session(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: Option<&str>,
+    ) -> Result<bool> {
+        let conn_item = self.tree_items.iter().find_map(|item| match item {
+            TreeItem::Connection(c) if c.id == *conn_id => Some(c.clone()),
+            _ => None,
+        });
+        let conn_item = match conn_item {
+            Some(c) => c,
+            None => return Ok(false),
+        };
+        self.set_conn_items_collapsed(&conn_item, false).await?;
+        let db_item = self.tree_items.iter().find_map(|item| match item {
+            TreeItem::Database(db) if db.conn_id == *conn_id && db.name == db_name => {
+                Some(db.clone())
+            }
+            _ => None,
+        });
+        let db_item = match db_item {
+            Some(db) => db,
+            None => return Ok(false),
+        };
+        self.set_db_items_collapsed(&db_item, false).await?;
+        if let Some(schema_name) = schema_name {
+            let schema_item = self.tree_items.iter().find_map(|item| match item {
+                TreeItem::Schema(s) if s.db_id == db_item.id && s.name == schema_name => {
+                    Some(s.clone())
+                }
+                _ => None,
+            });
+            match schema_item {
+                Some(s) => self.set_schema_items_collapsed(&s, false),
+                None => return Ok(false),
+            }
+        }
+        self.state
+            .select(self.show_items.iter().position(|item| match item {
+                TreeItem::Table(t) => {
+                    t.conn_id == *conn_id
+                        && t.db_id == db_item.id
+                        && t.schema_name.as_deref() == schema_name
+                }
+                _ => false,
+            }));
+        Ok(true)
+    }
     async fn set_conn_items_collapsed(
         &mut self,
         conn_item: &ConnectionItem,
         is_collapsed: bool,
     ) -> Result<()> {
         self.tree_items.iter_mut().for_each(|item| match item {
+            TreeItem::Group(_) => {}
             TreeItem::Connection(conn) => {
                 if conn.id == conn_item.id {
                     conn.is_collapsed = is_collapsed;
@@ -1462,6 +2374,21 @@ impl<'a> ConnectionListComponent<'a> {
                     view.is_parent_collapsed = true;
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.conn_id == conn_item.id && is_collapsed {
+                    routine.is_parent_collapsed = true;
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.conn_id == conn_item.id && is_collapsed {
+                    event.is_parent_collapsed = true;
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.conn_id == conn_item.id && is_collapsed {
+                    sequence.is_parent_collapsed = true;
+                }
+            }
         });
         if !is_collapsed && !conn_item.is_open {
             let tree_items = match conn_item.kind {
@@ -1511,19 +2438,7 @@ impl<'a> ConnectionListComponent<'a> {
                 }
             });
         }
-        self.show_items = self
-            .tree_items
-            .iter()
-            .cloned()
-            .filter(|i| match i {
-                TreeItem::Connection(_) => true,
-                TreeItem::Database(db) => !db.is_conn_collapsed,
-                TreeItem::Schema(schema) => !schema.is_db_collapsed,
-                TreeItem::Query(query) => !query.is_parent_collapsed,
-                TreeItem::Table(table) => !table.is_parent_collapsed,
-                TreeItem::View(view) => !view.is_parent_collapsed,
-            })
-            .collect();
+        self.update_show_items();
 
         Ok(())
     }
@@ -1533,7 +2448,7 @@ impl<'a> ConnectionListComponent<'a> {
         is_collapsed: bool,
     ) -> Result<()> {
         self.tree_items.iter_mut().for_each(|item| match item {
-            TreeItem::Connection(_) => {}
+            TreeItem::Group(_) | TreeItem::Connection(_) => {}
             TreeItem::Database(database) => {
                 if database.id == db_item.id {
                     database.is_collapsed = is_collapsed;
@@ -1574,6 +2489,33 @@ impl<'a> ConnectionListComponent<'a> {
                     }
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.db_id == db_item.id {
+                    if routine.schema_name.is_none() {
+                        routine.is_parent_collapsed = is_collapsed;
+                    } else if is_collapsed {
+                        routine.is_parent_collapsed = true;
+                    }
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.db_id == db_item.id {
+                    if event.schema_name.is_none() {
+                        event.is_parent_collapsed = is_collapsed;
+                    } else if is_collapsed {
+                        event.is_parent_collapsed = true;
+                    }
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.db_id == db_item.id {
+                    if sequence.schema_name.is_none() {
+                        sequence.is_parent_collapsed = is_collapsed;
+                    } else if is_collapsed {
+                        sequence.is_parent_collapsed = true;
+                    }
+                }
+            }
         });
 
         if !is_collapsed && !db_item.is_open {
@@ -1611,26 +2553,33 @@ impl<'a> ConnectionListComponent<'a> {
                     }
                 }
             });
+            let default_schema = match db_item.kind {
+                DatabaseKind::PostgreSQL => self
+                    .pg_conns
+                    .borrow()
+                    .get(&db_item.conn_id)
+                    .and_then(|conn| conn.default_schema.clone()),
+                DatabaseKind::MySQL => None,
+            };
+            let schema_item = default_schema.and_then(|name| {
+                self.tree_items.iter().find_map(|item| match item {
+                    TreeItem::Schema(s) if s.db_id == db_item.id && s.name == name => {
+                        Some(s.clone())
+                    }
+                    _ => None,
+                })
+            });
+            if let Some(schema_item) = schema_item {
+                self.set_schema_items_collapsed(&schema_item, false);
+            }
         }
-        self.show_items = self
-            .tree_items
-            .iter()
-            .cloned()
-            .filter(|i| match i {
-                TreeItem::Connection(_) => true,
-                TreeItem::Database(db) => !db.is_conn_collapsed,
-                TreeItem::Schema(schema) => !schema.is_db_collapsed,
-                TreeItem::Query(query) => !query.is_parent_collapsed,
-                TreeItem::Table(table) => !table.is_parent_collapsed,
-                TreeItem::View(view) => !view.is_parent_collapsed,
-            })
-            .collect();
+        self.update_show_items();
 
         Ok(())
     }
     fn set_schema_items_collapsed(&mut self, schema_item: &SchemaItem, is_collapsed: bool) {
         self.tree_items.iter_mut().for_each(|item| match item {
-            TreeItem::Connection(_) => (),
+            TreeItem::Group(_) | TreeItem::Connection(_) => (),
             TreeItem::Database(_) => (),
             TreeItem::Schema(s) => {
                 if schema_item.id == s.id {
@@ -1652,36 +2601,52 @@ impl<'a> ConnectionListComponent<'a> {
                     view.is_parent_collapsed = is_collapsed;
                 }
             }
+            TreeItem::Routine(routine) => {
+                if routine.schema_id == Some(schema_item.id) {
+                    routine.is_parent_collapsed = is_collapsed;
+                }
+            }
+            TreeItem::Event(event) => {
+                if event.schema_id == Some(schema_item.id) {
+                    event.is_parent_collapsed = is_collapsed;
+                }
+            }
+            TreeItem::Sequence(sequence) => {
+                if sequence.schema_id == Some(schema_item.id) {
+                    sequence.is_parent_collapsed = is_collapsed;
+                }
+            }
         });
-        self.show_items = self
-            .tree_items
-            .iter()
-            .cloned()
-            .filter(|i| match i {
-                TreeItem::Connection(_) => true,
-                TreeItem::Database(db) => !db.is_conn_collapsed,
-                TreeItem::Schema(schema) => !schema.is_db_collapsed,
-                TreeItem::Query(query) => !query.is_parent_collapsed,
-                TreeItem::Table(table) => !table.is_parent_collapsed,
-                TreeItem::View(view) => !view.is_parent_collapsed,
-            })
-            .collect();
+        self.update_show_items();
     }
     fn update_commands(&self) {
-        let mut cmds = if let Some(dlg) = self.conn_dlg.as_ref() {
+        let mut cmds = if self.import_task.is_some() {
+            vec![Command {
+                name: "Cancel Import",
+                key: CANCEL_KEY,
+            }]
+        } else if let Some(dlg) = self.import_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.conn_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.db_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.schema_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.export_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.server_info_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(dlg) = self.delete_conn_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.delete_db_dlg.as_ref() {
             dlg.get_commands()
-
+        } else if let Some(dlg) = self.delete_schema_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(select) = self.new_select.as_ref() {
             select.get_commands()
-        
         } else {
             self.get_main_commands()
         };
@@ -1692,36 +2657,67 @@ impl<'a> ConnectionListComponent<'a> {
         if let Some(index) = self.state.selected() {
             let item = &self.show_items[index];
             match item {
-                TreeItem::Connection(_) => cmds.append(&mut vec![
-                    Command {
-                        name: "New Database",
-                        key: NEW_KEY,
-                    },
-                    Command {
-                        name: "Edit",
-                        key: EDIT_KEY,
-                    },
-                    Command {
-                        name: "Delete",
-                        key: DELETE_KEY,
-                    },
-                    Command {
-                        name: "Users",
-                        key: USER_KEY,
-                    },
-                    Command {
-                        name: "Open/Close",
-                        key: CONFIRM_KEY,
-                    },
-                ]),
+                TreeItem::Connection(_) => {
+                    cmds.append(&mut vec![
+                        Command {
+                            name: "New Database",
+                            key: NEW_KEY,
+                        },
+                        Command {
+                            name: "Edit",
+                            key: EDIT_KEY,
+                        },
+                        Command {
+                            name: "Delete",
+                            key: DELETE_KEY,
+                        },
+                        Command {
+                            name: "Users",
+                            key: USER_KEY,
+                        },
+                        Command {
+                            name: "Processes",
+                            key: PROCESS_KEY,
+                        },
+                        Command {
+                            name: "Server Info",
+                            key: SERVER_INFO_KEY,
+                        },
+                        Command {
+                            name: "Refresh",
+                            key: REFRESH_KEY,
+                        },
+                        Command {
+                            name: "Disconnect",
+                            key: DISCONNECT_KEY,
+                        },
+                        Command {
+                            name: "Open/Close",
+                            key: CONFIRM_KEY,
+                        },
+                    ]);
+                }
                 TreeItem::Database(db) => {
                     if db.kind == DatabaseKind::PostgreSQL {
                         cmds.push(Command {
                             name: "New Schema",
                             key: NEW_KEY,
                         });
+                    } else {
+                        cmds.push(Command {
+                            name: "Export",
+                            key: EXPORT_KEY,
+                        });
                     }
                     cmds.append(&mut vec![
+                        Command {
+                            name: "Import SQL",
+                            key: IMPORT_KEY,
+                        },
+                        Command {
+                            name: "Server Info",
+                            key: SERVER_INFO_KEY,
+                        },
                         Command {
                             name: "Edit",
                             key: EDIT_KEY,
@@ -1741,6 +2737,10 @@ impl<'a> ConnectionListComponent<'a> {
                         name: "Edit",
                         key: EDIT_KEY,
                     },
+                    Command {
+                        name: "Export",
+                        key: EXPORT_KEY,
+                    },
                     Command {
                         name: "Delete",
                         key: DELETE_KEY,
@@ -1750,6 +2750,10 @@ impl<'a> ConnectionListComponent<'a> {
                         key: CONFIRM_KEY,
                     },
                 ]),
+                TreeItem::Group(_) => cmds.push(Command {
+                    name: "Open/Close",
+                    key: CONFIRM_KEY,
+                }),
                 _ => cmds.append(&mut vec![Command {
                     name: "Open",
                     key: CONFIRM_KEY,
@@ -1762,7 +2766,7 @@ impl<'a> ConnectionListComponent<'a> {
                 });
             }
 
-            if index < self.show_items.len() - 1 {
+            if index + 1 < self.show_items.len() {
                 cmds.push(Command {
                     name: "Down",
                     key: DOWN_KEY,
@@ -1784,6 +2788,10 @@ impl<'a> ConnectionListComponent<'a> {
                 });
             }
         }
+        cmds.push(Command {
+            name: "Reload Config",
+            key: RELOAD_CONFIG_KEY,
+        });
         cmds
     }
     fn create_mysql_database_items(
@@ -1834,6 +2842,24 @@ impl<'a> ConnectionListComponent<'a> {
                 schema_name: None,
                 is_parent_collapsed: false,
             }),
+            TreeItem::Routine(DatabaseSubItem {
+                conn_id: db_item.conn_id,
+                db_id: db_item.id,
+                db_name: db_item.name.to_string(),
+                kind: DatabaseKind::MySQL,
+                schema_id: None,
+                schema_name: None,
+                is_parent_collapsed: false,
+            }),
+            TreeItem::Event(DatabaseSubItem {
+                conn_id: db_item.conn_id,
+                db_id: db_item.id,
+                db_name: db_item.name.to_string(),
+                kind: DatabaseKind::MySQL,
+                schema_id: None,
+                schema_name: None,
+                is_parent_collapsed: false,
+            }),
         ]
     }
     fn create_pg_database_items(
@@ -1897,15 +2923,75 @@ impl<'a> ConnectionListComponent<'a> {
                         schema_name: Some(schema.name().to_string()),
                         is_parent_collapsed: true,
                     }),
+                    TreeItem::Routine(DatabaseSubItem {
+                        conn_id: db_item.conn_id,
+                        db_id: db_item.id,
+                        db_name: db_item.name.clone(),
+                        kind: DatabaseKind::PostgreSQL,
+                        schema_id: Some(schema_id),
+                        schema_name: Some(schema.name().to_string()),
+                        is_parent_collapsed: true,
+                    }),
+                    TreeItem::Sequence(DatabaseSubItem {
+                        conn_id: db_item.conn_id,
+                        db_id: db_item.id,
+                        db_name: db_item.name.clone(),
+                        kind: DatabaseKind::PostgreSQL,
+                        schema_id: Some(schema_id),
+                        schema_name: Some(schema.name().to_string()),
+                        is_parent_collapsed: true,
+                    }),
                 ]
             })
             .collect::<Vec<TreeItem>>()
     }
-    fn generate_sub_list_item<'b>(item: &DatabaseSubItem, title: &str) -> ListItem<'b> {
+    fn generate_sub_list_item<'b>(
+        item: &DatabaseSubItem,
+        title: &str,
+        indent: &str,
+    ) -> ListItem<'b> {
         if item.schema_name.is_some() {
-            ListItem::new(format!("      {}  {}", '\u{25b8}', title,))
+            ListItem::new(format!("{}      {}  {}", indent, '\u{25b8}', title,))
         } else {
-            ListItem::new(format!("    {}  {}", '\u{25b8}', title,))
+            ListItem::new(format!("{}    {}  {}", indent, '\u{25b8}', title,))
+        }
+    }
+    fn create_connection_item(conn: &dyn Connect) -> TreeItem {
+        TreeItem::Connection(ConnectionItem {
+            id: *conn.get_id(),
+            kind: conn.get_kind().clone(),
+            name: conn.get_name().to_owned(),
+            group: conn.get_group().map(|g| g.to_string()),
+            is_collapsed: true,
+            is_open: false,
+        })
+    }
+    fn create_connection_items(conns: &[Box<dyn Connect>]) -> Vec<TreeItem> {
+        let mut groups = conns
+            .iter()
+            .filter_map(|c| c.get_group())
+            .collect::<Vec<&str>>();
+        groups.sort_unstable();
+        groups.dedup();
+        let mut items = Vec::new();
+        for group in groups {
+            items.push(TreeItem::Group(GroupItem {
+                name: group.to_string(),
+                is_collapsed: false,
+            }));
+            items.extend(
+                conns
+                    .iter()
+                    .filter(|c| c.get_group() == Some(group))
+                    .map(|c| Self::create_connection_item(c.as_ref())),
+            );
         }
+        items.extend(
+            conns
+                .iter()
+                .filter(|c| c.get_group().is_none())
+                .map(|c| Self::create_connection_item(c.as_ref())),
+        );
+        items
     }
 }
@@ -2,7 +2,7 @@ use crate::{
     app::{ComponentResult, Focus},
     event::{config::*, Key},
 };
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
@@ -4,7 +4,20 @@ mod home;
 mod mysql;
 mod pg;
 mod query_list;
+mod status_line;
 
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+use uuid::Uuid;
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+pub fn get_spinner_frame(tick: usize) -> &'static str {
+    SPINNER_FRAMES[tick % SPINNER_FRAMES.len()]
+}
 pub fn get_table_up_index(index: Option<usize>) -> usize {
     if let Some(i) = index {
         if i > 0 {
@@ -18,14 +31,117 @@ pub fn get_table_up_index(index: Option<usize>) -> usize {
 }
 pub fn get_table_down_index(index: Option<usize>, len: usize) -> usize {
     if let Some(i) = index {
-        if i < len - 1 {
+        if i + 1 < len {
             i + 1
         } else {
-            len - 1
+            len.saturating_sub(1)
         }
     } else {
         0
     }
 }
+pub fn get_clicked_row(rect: Rect, header: u16, offset: usize, x: u16, y: u16) -> Option<usize> {
+    if x < rect.x || x >= rect.right() || y < rect.y + header || y >= rect.bottom() {
+        None
+    } else {
+        Some((y - rect.y - header) as usize + offset)
+    }
+}
+pub fn get_clicked_tab(titles: &[&str], rect: Rect, x: u16, y: u16) -> Option<usize> {
+    if y != rect.y {
+        return None;
+    }
+    let mut start = rect.x + 1;
+    for (i, title) in titles.iter().enumerate() {
+        let end = start + title.chars().count() as u16;
+        if x >= start && x < end {
+            return Some(i);
+        }
+        start = end + 3;
+    }
+    None
+}
+pub fn get_diff_spans<'b, T>(
+    label: &str,
+    old: &[T],
+    new: &[T],
+    get_id: impl Fn(&T) -> Uuid,
+    get_def: impl Fn(&T) -> String,
+) -> Vec<Spans<'b>> {
+    let mut spans = Vec::new();
+    new.iter().for_each(|item| {
+        let def = get_def(item);
+        if let Some(old_item) = old.iter().find(|o| get_id(o) == get_id(item)) {
+            let old_def = get_def(old_item);
+            if old_def != def {
+                spans.push(get_diff_line(
+                    "~",
+                    label,
+                    format!("{} -> {}", old_def, def),
+                    Color::Yellow,
+                ));
+            }
+        } else {
+            spans.push(get_diff_line("+", label, def, Color::Green));
+        }
+    });
+    old.iter()
+        .filter(|o| !new.iter().any(|n| get_id(n) == get_id(o)))
+        .for_each(|o| spans.push(get_diff_line("-", label, get_def(o), Color::Red)));
+    spans
+}
+pub fn get_value_diff_spans<'b>(
+    label: &str,
+    old: Option<String>,
+    new: Option<String>,
+) -> Option<Spans<'b>> {
+    if old == new {
+        None
+    } else {
+        Some(get_diff_line(
+            "~",
+            label,
+            format!(
+                "{} -> {}",
+                old.unwrap_or_else(|| "(none)".to_string()),
+                new.unwrap_or_else(|| "(none)".to_string())
+            ),
+            Color::Yellow,
+        ))
+    }
+}
+fn get_diff_line<'b>(sign: &str, label: &str, text: String, color: Color) -> Spans<'b> {
+    Spans::from(vec![
+        Span::styled(format!("{} {}: ", sign, label), Style::default().fg(color)),
+        Span::raw(text),
+    ])
+}
+
+pub use self::{
+    command_bar::*, connection_list::*, home::*, mysql::*, pg::*, query_list::*, status_line::*,
+};
 
-pub use self::{command_bar::*, connection_list::*, home::*, mysql::*, pg::*, query_list::*};
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_table_down_index() {
+        assert_eq!(get_table_down_index(None, 0), 0);
+        assert_eq!(get_table_down_index(Some(0), 0), 0);
+        assert_eq!(get_table_down_index(None, 1), 0);
+        assert_eq!(get_table_down_index(Some(0), 1), 0);
+        assert_eq!(get_table_down_index(None, 5), 0);
+        assert_eq!(get_table_down_index(Some(2), 5), 3);
+        assert_eq!(get_table_down_index(Some(4), 5), 4);
+        assert_eq!(get_table_down_index(Some(9), 5), 4);
+    }
+
+    #[test]
+    fn test_get_table_up_index() {
+        assert_eq!(get_table_up_index(None), 0);
+        assert_eq!(get_table_up_index(Some(0)), 0);
+        assert_eq!(get_table_up_index(Some(1)), 0);
+        assert_eq!(get_table_up_index(Some(4)), 3);
+    }
+}
@@ -1,40 +1,64 @@
 use crate::{
-    app::{ComponentResult, DialogResult, MainPanel},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
-    dialog::DetailDialog,
+    app::{ComponentResult, DialogResult, Goto, MainPanel},
+    clipboard::copy_to_clipboard,
+    component::{Command, CommandBarComponent, StatusLineComponent},
+    config::Config,
+    dialog::{
+        confirm::Kind as ConfirmKind, BulkUpdateDialog, ConfirmDialog, DetailDialog, ExportDialog,
+        InsertDialog, TextDialog,
+    },
     event::{config::*, Key},
-    model::mysql::{
-        convert_show_column_to_mysql_fields, get_mysql_field_value, Connections, Field,
+    model::{
+        export::{export_mysql_query, ExportFormat},
+        mysql::{
+            convert_show_column_to_mysql_fields, get_mysql_field_literal, get_mysql_field_text,
+            get_mysql_field_value, quote_ident, value_literal, Connections, Field, DECODE_ERROR,
+        },
+        table_view::{TableView, TableViews},
+        DisplayFormat,
     },
-    pool::{fetch_mysql_query, fetch_one_mysql, MySQLPools},
+    pool::{execute_mysql_query, fetch_mysql_query, fetch_one_mysql, get_mysql_pool, MySQLPools},
+    widget::Grid,
 };
 use anyhow::Result;
-use sqlx::{mysql::MySqlRow, Row};
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table as TableUI, TableState},
+    widgets::{Block, BorderType, Borders},
     Frame,
 };
+use sqlx::{mysql::MySqlRow, Row};
+use std::{cell::RefCell, collections::HashMap, fs::File, io::BufWriter, rc::Rc, str::FromStr};
 use uuid::Uuid;
 
 pub struct DataListComponent<'a> {
     conn_id: Option<Uuid>,
     db_name: Option<String>,
     table_name: Option<String>,
-    state: TableState,
+    grid: Grid,
     rows: Vec<MySqlRow>,
     page: usize,
     page_size: usize,
+    display_format: DisplayFormat,
     total_page: usize,
     fields: Vec<Field>,
+    unique_key: Vec<String>,
+    unique_fields: Vec<String>,
+    generated_fields: Vec<String>,
     parent: Option<MainPanel>,
     detail_dlg: Option<DetailDialog<'a>>,
+    text_dlg: Option<TextDialog>,
+    export_dlg: Option<ExportDialog<'a>>,
+    insert_dlg: Option<InsertDialog<'a>>,
+    bulk_update_dlg: Option<BulkUpdateDialog<'a>>,
+    bulk_update_confirm_dlg: Option<ConfirmDialog>,
+    bulk_update_sql: Option<String>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    status_line: Rc<RefCell<StatusLineComponent>>,
+    table_views: Rc<RefCell<TableViews>>,
 }
 
 impl<'a> DataListComponent<'a> {
@@ -42,22 +66,37 @@ impl<'a> DataListComponent<'a> {
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<MySQLPools>>,
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        status_line: Rc<RefCell<StatusLineComponent>>,
+        table_views: Rc<RefCell<TableViews>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         DataListComponent {
             conn_id: None,
             db_name: None,
             table_name: None,
-            state: TableState::default(),
+            grid: Grid::new(config.borrow().get_display_format()),
             parent: None,
             page: 0,
             total_page: 0,
-            page_size: 100,
+            page_size: config.borrow().get_page_size(),
+            display_format: config.borrow().get_display_format(),
             rows: Vec::new(),
             fields: Vec::new(),
+            unique_key: Vec::new(),
+            unique_fields: Vec::new(),
+            generated_fields: Vec::new(),
             detail_dlg: None,
+            text_dlg: None,
+            export_dlg: None,
+            insert_dlg: None,
+            bulk_update_dlg: None,
+            bulk_update_confirm_dlg: None,
+            bulk_update_sql: None,
             conns,
             pools,
             cmd_bar,
+            status_line,
+            table_views,
         }
     }
     pub async fn set_data(
@@ -70,31 +109,66 @@ impl<'a> DataListComponent<'a> {
         self.conn_id = Some(*conn_id);
         self.db_name = Some(db_name.to_string());
         self.table_name = Some(table_name.to_string());
-        self.state = TableState::default();
+        self.grid.clear();
         self.parent = Some(parent);
         let fields = fetch_mysql_query(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             Some(db_name),
-            &format!("SHOW FULL COLUMNS FROM `{}`", table_name),
+            &format!("SHOW FULL COLUMNS FROM {}", quote_ident(table_name)),
         )
         .await?;
+        self.unique_key = fields
+            .iter()
+            .filter(|r| r.try_get::<String, _>("Key").unwrap() == "UNI")
+            .map(|r| r.try_get::<String, _>("Field").unwrap())
+            .take(1)
+            .collect();
+        self.unique_fields = fetch_mysql_query(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            Some(db_name),
+            &format!(
+                "SHOW INDEX FROM {} WHERE Key_name != 'PRIMARY' AND Non_unique = 0",
+                quote_ident(table_name)
+            ),
+        )
+        .await?
+        .iter()
+        .filter_map(|r| r.try_get::<Option<String>, _>("Column_name").ok().flatten())
+        .collect();
+        self.generated_fields = fields
+            .iter()
+            .filter(|r| {
+                let extra = r.try_get::<String, _>("Extra").unwrap();
+                extra.contains("auto_increment")
+                    || extra.contains("VIRTUAL GENERATED")
+                    || extra.contains("STORED GENERATED")
+            })
+            .map(|r| r.try_get::<String, _>("Field").unwrap())
+            .collect();
         self.fields = convert_show_column_to_mysql_fields(fields);
         self.rows = fetch_mysql_query(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             Some(db_name),
-            &format!("SELECT * FROM `{}` LIMIT 0, {}", table_name, self.page_size),
+            &format!(
+                "SELECT * FROM {} LIMIT 0, {}",
+                quote_ident(table_name),
+                self.page_size
+            ),
         )
         .await?;
+        self.update_grid();
         let total_count: i64 = fetch_one_mysql(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             Some(db_name),
-            &format!("SELECT count(*) FROM `{}`", table_name),
+            &format!("SELECT count(*) FROM {}", quote_ident(table_name)),
         )
         .await?
         .try_get(0)
@@ -102,13 +176,16 @@ impl<'a> DataListComponent<'a> {
 
         self.page = if total_count > 0 { 1 } else { 0 };
         self.total_page = (total_count as f64 / self.page_size as f64).ceil() as usize;
+        self.restore_view().await?;
         Ok(())
     }
     pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
     where
         B: Backend,
     {
-        f.render_widget(
+        self.grid.draw(
+            f,
+            r,
             Block::default()
                 .title(format!(
                     "{} ({}/{})",
@@ -125,50 +202,22 @@ impl<'a> DataListComponent<'a> {
                 } else {
                     Style::default()
                 }),
-            r,
-        );
-        let columns = &self
-            .fields
-            .iter()
-            .map(|_| Constraint::Ratio(1, self.fields.len() as u32))
-            .collect::<Vec<Constraint>>();
-
-        let table = TableUI::new(
-            self.rows
-                .iter()
-                .map(|r| {
-                    let d = self
-                        .fields
-                        .iter()
-                        .map(|field| get_mysql_field_value(field, r).unwrap_or_default())
-                        .collect::<Vec<String>>();
-                    RowUI::new(d)
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(
-            self.fields
-                .iter()
-                .clone()
-                .map(|field| field.name().to_string())
-                .collect::<Vec<String>>(),
-        ))
-        .block(Block::default())
-        .widths(&columns[..])
-        .highlight_style(Style::default().fg(Color::Green));
-
-        f.render_stateful_widget(
-            table,
-            r.inner(&Margin {
-                vertical: 1,
-                horizontal: 1,
-            }),
-            &mut self.state,
         );
         if is_focus {
             self.update_commands();
         }
     }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.detail_dlg.is_none()
+            && self.text_dlg.is_none()
+            && self.export_dlg.is_none()
+            && self.insert_dlg.is_none()
+            && self.bulk_update_dlg.is_none()
+            && self.bulk_update_confirm_dlg.is_none()
+        {
+            self.grid.handle_click(x, y);
+        }
+    }
     pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
     where
         B: Backend,
@@ -176,12 +225,77 @@ impl<'a> DataListComponent<'a> {
         if let Some(dlg) = self.detail_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.insert_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.bulk_update_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.bulk_update_confirm_dlg.as_ref() {
+            dlg.draw(f);
+        }
     }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if self.detail_dlg.is_some() {
             self.handle_detail_dlg_event(key).await
+        } else if self.text_dlg.is_some() {
+            self.handle_text_dlg_event(key).await
+        } else if self.export_dlg.is_some() {
+            self.handle_export_dlg_event(key).await
+        } else if self.insert_dlg.is_some() {
+            self.handle_insert_dlg_event(key).await
+        } else if self.bulk_update_dlg.is_some() {
+            self.handle_bulk_update_dlg_event(key).await
+        } else if self.bulk_update_confirm_dlg.is_some() {
+            self.handle_bulk_update_confirm_dlg_event(key).await
         } else {
-            self.handle_main_event(key).await
+            let result = self.handle_main_event(key).await;
+            self.save_view();
+            result
+        }
+    }
+    async fn restore_view(&mut self) -> Result<()> {
+        let view = self
+            .table_views
+            .borrow()
+            .get(
+                self.conn_id.as_ref().unwrap(),
+                self.db_name.as_ref().unwrap(),
+                None,
+                self.table_name.as_ref().unwrap(),
+            )
+            .cloned();
+        if let Some(view) = view {
+            self.grid.select_column(view.column);
+            if view.page > 1 && view.page <= self.total_page {
+                self.page = view.page;
+                self.refresh().await?;
+            }
+        }
+        Ok(())
+    }
+    fn save_view(&self) {
+        if let (Some(conn_id), Some(db_name), Some(table_name)) = (
+            self.conn_id.as_ref(),
+            self.db_name.as_ref(),
+            self.table_name.as_ref(),
+        ) {
+            self.table_views.borrow_mut().set(
+                conn_id,
+                db_name,
+                None,
+                table_name,
+                TableView {
+                    page: self.page,
+                    column: self.grid.column_index(),
+                },
+            );
         }
     }
     async fn handle_detail_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
@@ -194,20 +308,190 @@ impl<'a> DataListComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
-    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
-        match *key {
-            UP_KEY => {
-                if !self.rows.is_empty() {
-                    let index = get_table_up_index(self.state.selected());
-                    self.state.select(Some(index));
+    async fn handle_text_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            if let DialogResult::Cancel = dlg.handle_event(key) {
+                self.text_dlg = None;
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_export_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.export_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let path = map.get("file").unwrap().clone().unwrap();
+                    let format = ExportFormat::from_str(
+                        map.get("format").unwrap().as_deref().unwrap_or_default(),
+                    )?;
+                    let count = self.export(&path, &format).await?;
+                    self.export_dlg = None;
+                    self.status_line
+                        .borrow_mut()
+                        .info(format!("Exported {} rows to {}", count, path).as_str());
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_insert_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.insert_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.insert_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let fields = self
+                        .fields
+                        .iter()
+                        .filter(|field| map.contains_key(field.name()))
+                        .collect::<Vec<&Field>>();
+                    let sql = format!(
+                        "INSERT INTO {} ({}) VALUES ({})",
+                        quote_ident(self.table_name.as_ref().unwrap()),
+                        fields
+                            .iter()
+                            .map(|field| quote_ident(field.name()))
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                        fields
+                            .iter()
+                            .map(|field| value_literal(map[field.name()].as_deref()))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                    execute_mysql_query(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        self.db_name.as_deref(),
+                        &sql,
+                    )
+                    .await?;
+                    self.insert_dlg = None;
+                    if self.page == 0 {
+                        self.page = 1;
+                    }
+                    self.refresh().await?;
+                    self.status_line.borrow_mut().info(
+                        format!("Inserted 1 row into {}", self.table_name.as_ref().unwrap())
+                            .as_str(),
+                    );
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_bulk_update_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.bulk_update_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.bulk_update_dlg = None;
+                }
+                DialogResult::Confirm((column, value, condition)) => {
+                    let value = match self.fields.iter().find(|field| field.name() == column) {
+                        Some(Field::TinyInt(f))
+                            if self.display_format.tinyint_as_bool && f.is_bool() =>
+                        {
+                            value.map(|v| match v.as_str() {
+                                "true" => String::from("1"),
+                                "false" => String::from("0"),
+                                _ => v,
+                            })
+                        }
+                        _ => value,
+                    };
+                    let table = quote_ident(self.table_name.as_ref().unwrap());
+                    let sql = format!(
+                        "UPDATE {} SET {} = {} WHERE {}",
+                        table,
+                        quote_ident(&column),
+                        value_literal(value.as_deref()),
+                        condition
+                    );
+                    let count: i64 = fetch_one_mysql(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        self.db_name.as_deref(),
+                        &format!("SELECT count(*) FROM {} WHERE {}", table, condition),
+                    )
+                    .await?
+                    .try_get(0)
+                    .unwrap();
+                    self.bulk_update_confirm_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Bulk Update",
+                        &format!("{} rows will be updated by: {}", count, sql),
+                    ));
+                    self.bulk_update_sql = Some(sql);
+                    self.bulk_update_dlg = None;
                 }
+                _ => (),
             }
-            DOWN_KEY => {
-                if !self.rows.is_empty() {
-                    let index = get_table_down_index(self.state.selected(), self.rows.len());
-                    self.state.select(Some(index));
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_bulk_update_confirm_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.bulk_update_confirm_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.bulk_update_confirm_dlg = None;
+                    self.bulk_update_sql = None;
                 }
+                DialogResult::Confirm(_) => {
+                    if let Some(sql) = self.bulk_update_sql.take() {
+                        execute_mysql_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &sql,
+                        )
+                        .await?;
+                        self.bulk_update_confirm_dlg = None;
+                        self.refresh().await?;
+                        self.status_line.borrow_mut().info(
+                            format!("Updated rows in {}", self.table_name.as_ref().unwrap())
+                                .as_str(),
+                        );
+                    }
+                }
+                _ => (),
             }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn export(&self, path: &str, format: &ExportFormat) -> Result<usize> {
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+        )
+        .await?;
+        let mut serializer = format.new_serializer(Box::new(BufWriter::new(File::create(path)?)));
+        export_mysql_query(
+            &pool,
+            &format!(
+                "SELECT * FROM {}",
+                quote_ident(self.table_name.as_ref().unwrap())
+            ),
+            serializer.as_mut(),
+            &format.get_display_format(&self.display_format),
+        )
+        .await
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.grid.handle_event(key) {
+            return Ok(ComponentResult::Done);
+        }
+        match *key {
             PAGE_NEXT_KEY => {
                 if self.page < self.total_page {
                     self.page += 1;
@@ -224,15 +508,134 @@ impl<'a> DataListComponent<'a> {
                 return Ok(ComponentResult::Back(self.parent.clone().unwrap()));
             }
             CONFIRM_KEY => {
-                if let Some(index) = self.state.selected() {
+                if let Some(index) = self.grid.selected() {
                     let detail_dlg = DetailDialog::from_mysql_row(
                         self.table_name.as_ref().unwrap().to_string(),
                         &self.fields,
                         &self.rows[index],
+                        &self.display_format,
                     );
                     self.detail_dlg = Some(detail_dlg);
                 }
             }
+            EXPAND_KEY => {
+                if let Some((header, value)) = self.grid.get_selected_cell() {
+                    self.text_dlg = Some(TextDialog::new(
+                        header,
+                        value.unwrap_or(&self.display_format.null_text),
+                    ));
+                }
+            }
+            COPY_MARKDOWN_KEY => {
+                copy_to_clipboard(&self.grid.to_markdown())?;
+                self.status_line
+                    .borrow_mut()
+                    .info(format!("Copied {} rows as Markdown table", self.grid.len()).as_str());
+            }
+            NEW_KEY => {
+                self.insert_dlg = Some(InsertDialog::from_mysql_fields(
+                    format!("Insert Into {}", self.table_name.as_ref().unwrap()),
+                    &self.fields,
+                    &self.generated_fields,
+                    &self.display_format,
+                ));
+            }
+            CLONE_ROW_KEY => {
+                if let Some(index) = self.grid.selected() {
+                    // Key and unique columns are cleared, ones without a text form are left out.
+                    let mut values = HashMap::new();
+                    let mut uncopied = Vec::new();
+                    for field in self.fields.iter().filter(|field| {
+                        !field.key()
+                            && !field.auto_increment()
+                            && !self.unique_fields.iter().any(|k| k == field.name())
+                            && !self.generated_fields.iter().any(|g| g == field.name())
+                    }) {
+                        match get_mysql_field_text(field, &self.rows[index]) {
+                            Ok(value) => {
+                                values.insert(field.name().to_string(), value);
+                            }
+                            Err(_) => uncopied.push(field.name().to_string()),
+                        }
+                    }
+                    if !uncopied.is_empty() {
+                        self.status_line.borrow_mut().warning(&format!(
+                            "Not cloned, left to their defaults: {}",
+                            uncopied.join(", ")
+                        ));
+                    }
+                    self.insert_dlg = Some(InsertDialog::from_mysql_values(
+                        format!("Clone Row Into {}", self.table_name.as_ref().unwrap()),
+                        &self.fields,
+                        &[self.generated_fields.clone(), uncopied].concat(),
+                        &self.display_format,
+                        &values,
+                    ));
+                }
+            }
+            BULK_UPDATE_KEY => {
+                if !self.fields.is_empty() {
+                    self.bulk_update_dlg = Some(BulkUpdateDialog::new(
+                        self.table_name.as_ref().unwrap(),
+                        self.fields
+                            .iter()
+                            .map(|field| field.name().to_string())
+                            .collect(),
+                        self.fields
+                            .get(self.grid.column_index())
+                            .map(|field| field.name().to_string()),
+                    ));
+                }
+            }
+            EXPORT_KEY => {
+                self.export_dlg = Some(ExportDialog::new_data(self.table_name.as_ref().unwrap()));
+            }
+            COPY_WHERE_KEY => {
+                if let Some((condition, unique)) = self.get_selected_row_condition() {
+                    copy_to_clipboard(&condition)?;
+                    if unique {
+                        self.status_line
+                            .borrow_mut()
+                            .info(format!("Copied {}", condition).as_str());
+                    } else {
+                        self.status_line.borrow_mut().warning(
+                            format!("Copied {}, it may match duplicate rows", condition).as_str(),
+                        );
+                    }
+                }
+            }
+            QUERY_ROW_KEY => {
+                if let Some((condition, unique)) = self.get_selected_row_condition() {
+                    if !unique {
+                        self.status_line.borrow_mut().warning(
+                            "Table has no unique key, the query may return duplicate rows",
+                        );
+                    }
+                    let sql = format!(
+                        "SELECT * FROM {} {};",
+                        quote_ident(self.table_name.as_ref().unwrap()),
+                        condition
+                    );
+                    return Ok(ComponentResult::Goto(Goto::QueryDetailMySQL {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        query_name: None,
+                        sql: Some(sql),
+                    }));
+                }
+            }
+            QUERY_KEY => {
+                let sql = format!(
+                    "SELECT * FROM {} LIMIT 100;",
+                    quote_ident(self.table_name.as_ref().unwrap())
+                );
+                return Ok(ComponentResult::Goto(Goto::QueryDetailMySQL {
+                    conn_id: self.conn_id.unwrap(),
+                    db_name: self.db_name.clone().unwrap(),
+                    query_name: None,
+                    sql: Some(sql),
+                }));
+            }
             REFRESH_KEY => {
                 self.refresh().await?;
             }
@@ -240,6 +643,85 @@ impl<'a> DataListComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn get_selected_row_condition(&self) -> Option<(String, bool)> {
+        let row = &self.rows[self.grid.selected()?];
+        let get_value = |field: &Field| get_mysql_field_literal(field, row);
+        // Only called with fields whose values decoded, so a failed decode never becomes IS NULL.
+        let condition = |fields: Vec<&Field>| {
+            fields
+                .iter()
+                .map(|field| match get_value(field).ok().flatten() {
+                    Some(value) => format!("{} = {}", quote_ident(field.name()), value),
+                    None => format!("{} IS NULL", quote_ident(field.name())),
+                })
+                .collect::<Vec<String>>()
+                .join(" AND ")
+        };
+        let keys = self
+            .fields
+            .iter()
+            .filter(|field| field.key())
+            .collect::<Vec<&Field>>();
+        if !keys.is_empty() {
+            if keys.iter().any(|field| get_value(field).is_err()) {
+                self.status_line
+                    .borrow_mut()
+                    .warning("Primary key value of the row could not be decoded");
+                return None;
+            }
+            return Some((format!("WHERE {}", condition(keys)), true));
+        }
+        let uniques = self
+            .fields
+            .iter()
+            .filter(|field| self.unique_key.iter().any(|k| k == field.name()))
+            .filter(|field| matches!(get_value(field), Ok(Some(_))))
+            .collect::<Vec<&Field>>();
+        if !uniques.is_empty() {
+            return Some((format!("WHERE {}", condition(uniques)), true));
+        }
+        let comparable = self
+            .fields
+            .iter()
+            .filter(|field| field.is_comparable() && get_value(field).is_ok())
+            .collect::<Vec<&Field>>();
+        if comparable.is_empty() {
+            self.status_line
+                .borrow_mut()
+                .warning("Table has no primary key or comparable columns to identify the row");
+            return None;
+        }
+        Some((format!("WHERE {}", condition(comparable)), false))
+    }
+    fn update_grid(&mut self) {
+        let values = self
+            .rows
+            .iter()
+            .map(|r| {
+                self.fields
+                    .iter()
+                    .map(|field| {
+                        get_mysql_field_value(field, r, &self.display_format)
+                            .unwrap_or_else(|_| Some(DECODE_ERROR.to_string()))
+                    })
+                    .collect()
+            })
+            .collect();
+        self.grid.set_data(
+            self.fields
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect(),
+            self.fields
+                .iter()
+                .map(|field| match field {
+                    Field::TinyInt(f) => self.display_format.tinyint_as_bool && f.is_bool(),
+                    _ => false,
+                })
+                .collect(),
+            values,
+        );
+    }
     pub async fn refresh(&mut self) -> Result<()> {
         self.rows = fetch_mysql_query(
             self.conns.clone(),
@@ -247,21 +729,22 @@ impl<'a> DataListComponent<'a> {
             self.conn_id.as_ref().unwrap(),
             self.db_name.as_deref(),
             &format!(
-                "SELECT * FROM `{}` LIMIT {}, {}",
-                self.table_name.as_ref().unwrap(),
+                "SELECT * FROM {} LIMIT {}, {}",
+                quote_ident(self.table_name.as_ref().unwrap()),
                 (self.page - 1) * self.page_size,
                 self.page_size
             ),
         )
         .await?;
+        self.update_grid();
         let total_count: i64 = fetch_one_mysql(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             self.db_name.as_deref(),
             &format!(
-                "SELECT count(*) FROM `{}`",
-                self.table_name.as_ref().unwrap()
+                "SELECT count(*) FROM {}",
+                quote_ident(self.table_name.as_ref().unwrap())
             ),
         )
         .await?
@@ -274,6 +757,16 @@ impl<'a> DataListComponent<'a> {
     fn update_commands(&mut self) {
         let mut cmds = if let Some(dlg) = self.detail_dlg.as_mut() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.text_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.export_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.insert_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.bulk_update_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.bulk_update_confirm_dlg.as_ref() {
+            dlg.get_commands()
         } else {
             self.get_main_commands()
         };
@@ -289,6 +782,14 @@ impl<'a> DataListComponent<'a> {
                 name: "Down",
                 key: DOWN_KEY,
             },
+            Command {
+                name: "Left",
+                key: LEFT_KEY,
+            },
+            Command {
+                name: "Right",
+                key: RIGHT_KEY,
+            },
             Command {
                 name: "Page Next",
                 key: PAGE_NEXT_KEY,
@@ -298,12 +799,54 @@ impl<'a> DataListComponent<'a> {
                 key: PAGE_PRIV_KEY,
             },
         ];
-        if self.state.selected().is_some() {
-            cmds.append(&mut vec![Command {
-                name: "Open",
-                key: CONFIRM_KEY,
-            }]);
+        if self.grid.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Open",
+                    key: CONFIRM_KEY,
+                },
+                Command {
+                    name: "Expand Cell",
+                    key: EXPAND_KEY,
+                },
+                Command {
+                    name: "Copy Where",
+                    key: COPY_WHERE_KEY,
+                },
+                Command {
+                    name: "Query Row",
+                    key: QUERY_ROW_KEY,
+                },
+                Command {
+                    name: "Clone Row",
+                    key: CLONE_ROW_KEY,
+                },
+            ]);
+        }
+        if !self.grid.is_empty() {
+            cmds.push(Command {
+                name: "Copy as Markdown",
+                key: COPY_MARKDOWN_KEY,
+            });
+        }
+        cmds.push(Command {
+            name: "Insert Row",
+            key: NEW_KEY,
+        });
+        if !self.fields.is_empty() {
+            cmds.push(Command {
+                name: "Bulk Update",
+                key: BULK_UPDATE_KEY,
+            });
         }
+        cmds.push(Command {
+            name: "Export Data",
+            key: EXPORT_KEY,
+        });
+        cmds.push(Command {
+            name: "New Query",
+            key: QUERY_KEY,
+        });
         cmds.push(Command {
             name: "Refresh",
             key: REFRESH_KEY,
@@ -0,0 +1,361 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::{mysql::EventDialog, ConfirmDialog, DetailDialog, Kind as ConfirmKind},
+    event::{config::*, Key},
+    model::mysql::{
+        get_mysql_events, quote_ident, Connections, Event, EventIntervalUnit, EventSchedule,
+        EventStatus,
+    },
+    pool::{
+        execute_mysql_query, execute_mysql_query_unprepared, fetch_one_mysql, get_mysql_pool,
+        MySQLPools,
+    },
+    widget::DataTable,
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use sqlx::Row;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use uuid::Uuid;
+
+pub struct EventListComponent<'a> {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    events: Vec<Event>,
+    state: TableState,
+    event_dlg: Option<EventDialog<'a>>,
+    detail_dlg: Option<DetailDialog<'a>>,
+    delete_dlg: Option<ConfirmDialog>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+}
+
+impl<'a> EventListComponent<'a> {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<MySQLPools>>,
+    ) -> Self {
+        EventListComponent {
+            conn_id: None,
+            db_name: None,
+            events: Vec::new(),
+            state: TableState::default(),
+            event_dlg: None,
+            detail_dlg: None,
+            delete_dlg: None,
+            cmd_bar,
+            conns,
+            pools,
+        }
+    }
+    pub async fn set_data(&mut self, conn_id: &Uuid, db_name: &str) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Events")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        let table = DataTable::new(self.events.iter().map(|event| {
+            vec![
+                event.name.clone(),
+                event.get_schedule_str(),
+                event.status.to_string(),
+                event.definer.clone().unwrap_or_default(),
+                event.comment.clone().unwrap_or_default(),
+            ]
+        }))
+        .header(vec!["Name", "Schedule", "Status", "Definer", "Comment"]);
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.event_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.detail_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.events.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.events.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.events.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            NEW_KEY => {
+                self.event_dlg = Some(EventDialog::new(None));
+            }
+            EDIT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    self.event_dlg = Some(EventDialog::new(Some(&self.events[index])));
+                }
+            }
+            CONFIRM_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let row = fetch_one_mysql(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        self.db_name.as_deref(),
+                        &format!(
+                            "SHOW CREATE EVENT {}",
+                            quote_ident(&self.events[index].name)
+                        ),
+                    )
+                    .await?;
+                    let values = vec![
+                        ("name".to_string(), Some(self.events[index].name.clone())),
+                        ("sql_mode".to_string(), row.try_get(1).unwrap()),
+                        ("time_zone".to_string(), row.try_get(2).unwrap()),
+                        ("create event".to_string(), row.try_get(3).unwrap()),
+                    ];
+                    self.detail_dlg = Some(DetailDialog::from_values("Event".to_string(), &values));
+                }
+            }
+            DELETE_KEY => {
+                if self.state.selected().is_some() {
+                    self.delete_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Event",
+                        "Are you sure to delete this event?",
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            Some("information_schema"),
+        )
+        .await?;
+        self.events = get_mysql_events(&pool, self.db_name.as_ref().unwrap()).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.events.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    async fn handle_delete_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.delete_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.delete_dlg = None;
+                }
+                DialogResult::Confirm(_) => {
+                    if let Some(i) = self.state.selected() {
+                        execute_mysql_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &self.events[i].get_drop_ddl(),
+                        )
+                        .await?;
+                        self.events.remove(i);
+                        self.delete_dlg = None;
+                        self.state.select(None);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_event_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.event_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.event_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let event = Self::map_to_event(&map);
+                    event.validate()?;
+                    let sql = if let Some(name) = dlg.get_name() {
+                        let old_event = self.events.iter().find(|e| e.name == name).unwrap();
+                        event.get_alter_ddl(old_event)
+                    } else {
+                        event.get_create_ddl()
+                    };
+                    if !sql.is_empty() {
+                        execute_mysql_query_unprepared(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &sql,
+                        )
+                        .await?;
+                    }
+                    self.event_dlg = None;
+                    self.refresh().await?;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_detail_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.detail_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => self.detail_dlg = None,
+                DialogResult::Confirm(_) => self.detail_dlg = None,
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn map_to_event(map: &HashMap<String, Option<String>>) -> Event {
+        let get_value = |name: &str| {
+            map.get(name)
+                .unwrap()
+                .clone()
+                .filter(|v| !v.trim().is_empty())
+        };
+        Event {
+            name: map.get("name").unwrap().clone().unwrap(),
+            definer: None,
+            schedule: EventSchedule::try_from(map.get("schedule").unwrap().as_deref().unwrap())
+                .unwrap(),
+            execute_at: get_value("execute at"),
+            interval_value: get_value("interval value"),
+            interval_unit: map
+                .get("interval unit")
+                .unwrap()
+                .as_deref()
+                .map(|u| EventIntervalUnit::try_from(u).unwrap()),
+            starts: get_value("starts"),
+            ends: get_value("ends"),
+            preserve: map.get("on completion preserve").unwrap().as_deref() == Some("true"),
+            status: EventStatus::try_from(map.get("status").unwrap().as_deref().unwrap()).unwrap(),
+            comment: get_value("comment"),
+            body: map.get("body").unwrap().clone().unwrap(),
+        }
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.event_dlg.is_some() {
+            self.handle_event_dlg_event(key).await
+        } else if self.detail_dlg.is_some() {
+            self.handle_detail_dlg_event(key)
+        } else if self.delete_dlg.is_some() {
+            self.handle_delete_dlg_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.event_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.detail_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "New Event",
+                key: NEW_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Show Event",
+                    key: CONFIRM_KEY,
+                },
+                Command {
+                    name: "Edit Event",
+                    key: EDIT_KEY,
+                },
+                Command {
+                    name: "Delete Event",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
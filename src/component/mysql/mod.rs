@@ -1,5 +1,9 @@
 mod data_list;
+mod event_list;
+mod process_list;
 mod query_detail;
+mod routine_detail;
+mod routine_list;
 mod table_detail;
 mod table_list;
 mod user_detail;
@@ -9,7 +13,11 @@ mod view_list;
 
 pub use self::{
     data_list::DataListComponent as DataListComponentMySQL,
+    event_list::EventListComponent as EventListComponentMySQL,
+    process_list::ProcessListComponent as ProcessListComponentMySQL,
     query_detail::QueryDetailComponent as QueryDetailComponentMySQL,
+    routine_detail::RoutineDetailComponent as RoutineDetailComponentMySQL,
+    routine_list::RoutineListComponent as RoutineListComponentMySQL,
     table_detail::TableDetailComponent as TableDetailComponentMySQL,
     table_list::TableListComponent as TableListComponentMySQL,
     user_detail::UserDetailComponent as UserDetailComponentMySQL,
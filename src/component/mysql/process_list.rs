@@ -0,0 +1,223 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
+    event::{config::*, Key},
+    model::mysql::{get_mysql_processes, Connections, Process},
+    pool::{execute_mysql_query, get_mysql_pool, MySQLPools},
+    widget::DataTable,
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use uuid::Uuid;
+
+pub struct ProcessListComponent {
+    state: TableState,
+    conn_id: Option<Uuid>,
+    processes: Vec<Process>,
+    kill_dlg: Option<ConfirmDialog>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+}
+
+impl ProcessListComponent {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<MySQLPools>>,
+    ) -> Self {
+        ProcessListComponent {
+            state: TableState::default(),
+            conn_id: None,
+            processes: Vec::new(),
+            kill_dlg: None,
+            conns,
+            pools,
+            cmd_bar,
+        }
+    }
+    pub async fn set_data(&mut self, conn_id: &Uuid) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_active: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Processes")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_active {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+
+        let table = DataTable::new(self.processes.iter().map(|p| {
+            vec![
+                Some(p.id.to_string()),
+                Some(p.user.clone()),
+                Some(p.host.clone()),
+                p.db.clone(),
+                Some(p.command.clone()),
+                Some(p.time.to_string()),
+                p.state.clone(),
+                p.info.clone(),
+            ]
+        }))
+        .header(vec![
+            "Id", "User", "Host", "Db", "Command", "Time", "State", "Info",
+        ]);
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_active {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.kill_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.kill_dlg.is_some() {
+            self.handle_kill_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.processes.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.processes.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.processes.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            DELETE_KEY => {
+                if let Some(index) = self.state.selected() {
+                    self.kill_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Kill Process",
+                        format!("Are you sure to kill process {}?", self.processes[index].id)
+                            .as_str(),
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_kill_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.kill_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.kill_dlg = None,
+                DialogResult::Confirm(_) => {
+                    if let Some(index) = self.state.selected() {
+                        execute_mysql_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            None,
+                            &self.processes[index].get_kill_ddl(),
+                        )
+                        .await?;
+                        self.kill_dlg = None;
+                        self.refresh().await?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            None,
+        )
+        .await?;
+        self.processes = get_mysql_processes(&pool).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.processes.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.kill_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.push(Command {
+                name: "Kill Process",
+                key: DELETE_KEY,
+            });
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
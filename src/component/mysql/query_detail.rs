@@ -1,35 +1,45 @@
 use crate::{
     app::{ComponentResult, DialogResult, MainPanel},
-    component::{Command, CommandBarComponent},
-    dialog::{confirm::Kind as ConfirmKind, ConfirmDialog, DetailDialog, InputDialog},
-    event::{config::*, Key},
+    clipboard::copy_to_clipboard,
+    component::{get_spinner_frame, Command, CommandBarComponent, StatusLineComponent},
+    config::Config,
+    dialog::{
+        confirm::Kind as ConfirmKind, ConfirmDialog, DetailDialog, InputDialog, ParamDialog,
+        TextDialog,
+    },
+    event::{config::*, Key, KeyCode, KeyModifier},
     model::{
-        mysql::{get_mysql_column_value, Connections},
+        get_param_count,
+        mysql::{
+            get_mysql_column_value, get_mysql_field_names, get_mysql_table_names, Connections,
+            DECODE_ERROR,
+        },
         query::{Queries, Query},
+        DatabaseKind, DisplayFormat,
+    },
+    pool::{get_mysql_pool, spawn_mysql_query, MySQLPools, QueryCursor},
+    widget::{
+        delete_pair_char, draw_sql_textarea, get_completion_context, insert_pair_char,
+        resolve_table_alias, toggle_line_comment, Completion, DataTable, Grid, SyntaxTheme,
+        SQL_KEYWORDS,
     },
-    pool::{fetch_mysql_query, MySQLPools},
 };
-use anyhow::{Error, Result};
+use anyhow::Result;
 use sqlx::{
     mysql::{MySqlColumn, MySqlRow},
-    Column as SqlxColumn, Row as SqlxRow,
-};
-use std::{
-    cell::RefCell,
-    cmp::{max, min},
-    collections::HashMap,
-    rc::Rc,
+    Column as SqlxColumn, Row as SqlxRow, TypeInfo,
 };
+use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
 
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::Span,
-    widgets::{Block, BorderType, Borders, Paragraph, Row as RowUI, Table, TableState},
+    widgets::{Block, BorderType, Borders, Paragraph, TableState},
     Frame,
 };
-use tui_textarea::{Input, TextArea};
+use tui_textarea::{CursorMove, Input, TextArea};
 use uuid::Uuid;
 
 enum FocusPanel {
@@ -43,18 +53,39 @@ pub struct QueryDetailComponent<'a> {
     db_name: Option<String>,
     query: Option<Query>,
     input: TextArea<'a>,
+    selection_anchor: Option<usize>,
     rows: Vec<MySqlRow>,
     columns: Vec<MySqlColumn>,
-    row_state: TableState,
+    grid: Grid,
+    show_columns: bool,
+    columns_state: TableState,
     is_result: bool,
+    is_truncated: bool,
+    rows_affected: Option<u64>,
+    sql: String,
+    params: Vec<Option<String>>,
+    page_size: usize,
+    display_format: DisplayFormat,
+    max_rows: usize,
+    input_scroll: (u16, u16),
+    input_rect: Rect,
+    syntax_theme: SyntaxTheme,
+    auto_pairs: bool,
+    completion: Option<Completion>,
+    table_names: Option<Vec<String>>,
+    column_names: HashMap<String, Vec<String>>,
+    query_cursor: Option<QueryCursor<MySqlRow>>,
+    spinner: usize,
     detail_dlg: Option<DetailDialog<'a>>,
+    text_dlg: Option<TextDialog>,
     input_dlg: Option<InputDialog<'a>>,
-    info_dlg: Option<ConfirmDialog>,
+    param_dlg: Option<ParamDialog<'a>>,
     exit_dlg: Option<ConfirmDialog>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
     queries: Rc<RefCell<Queries>>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    status_line: Rc<RefCell<StatusLineComponent>>,
 }
 
 impl<'a> QueryDetailComponent<'a> {
@@ -63,25 +94,48 @@ impl<'a> QueryDetailComponent<'a> {
         pools: Rc<RefCell<MySQLPools>>,
         queries: Rc<RefCell<Queries>>,
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        status_line: Rc<RefCell<StatusLineComponent>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         QueryDetailComponent {
             focus: FocusPanel::TextArea,
             input: TextArea::default(),
+            selection_anchor: None,
             rows: Vec::new(),
             columns: Vec::new(),
-            row_state: TableState::default(),
+            grid: Grid::new(config.borrow().get_display_format()),
+            show_columns: false,
+            columns_state: TableState::default(),
             is_result: false,
+            is_truncated: false,
+            rows_affected: None,
+            sql: String::new(),
+            params: Vec::new(),
+            page_size: config.borrow().get_page_size(),
+            display_format: config.borrow().get_display_format(),
+            max_rows: config.borrow().get_max_rows(),
+            input_scroll: (0, 0),
+            input_rect: Rect::default(),
+            syntax_theme: config.borrow().get_syntax_theme(),
+            auto_pairs: config.borrow().auto_pairs,
+            completion: None,
+            table_names: None,
+            column_names: HashMap::new(),
+            query_cursor: None,
+            spinner: 0,
             conn_id: None,
             db_name: None,
             query: None,
             detail_dlg: None,
+            text_dlg: None,
             input_dlg: None,
-            info_dlg: None,
+            param_dlg: None,
             exit_dlg: None,
             conns,
             pools,
             queries,
             cmd_bar,
+            status_line,
         }
     }
     pub fn set_data(
@@ -89,9 +143,13 @@ impl<'a> QueryDetailComponent<'a> {
         conn_id: &Uuid,
         db_name: &str,
         query_name: Option<&str>,
+        sql: Option<&str>,
     ) -> Result<()> {
         self.conn_id = Some(*conn_id);
         self.db_name = Some(db_name.to_string());
+        self.selection_anchor = None;
+        self.table_names = None;
+        self.column_names.clear();
 
         if let Some(name) = query_name {
             let mut query = self
@@ -102,6 +160,9 @@ impl<'a> QueryDetailComponent<'a> {
 
             self.input = TextArea::from(query.load_file()?.0.split('\n'));
             self.query = Some(query);
+        } else if let Some(sql) = sql {
+            self.input = TextArea::from(sql.lines());
+            self.query = None;
         }
         Ok(())
     }
@@ -113,12 +174,17 @@ impl<'a> QueryDetailComponent<'a> {
             Block::default()
                 .title(if let Some(q) = &self.query {
                     format!(
-                        "Query `{}`({})",
+                        "Query `{}`({}) {}",
                         q.name.as_str(),
-                        self.db_name.as_ref().unwrap()
+                        self.db_name.as_ref().unwrap(),
+                        self.get_cursor_info()
                     )
                 } else {
-                    format!("New Query({})", self.db_name.as_ref().unwrap())
+                    format!(
+                        "New Query({}) {}",
+                        self.db_name.as_ref().unwrap(),
+                        self.get_cursor_info()
+                    )
                 })
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
@@ -161,21 +227,62 @@ impl<'a> QueryDetailComponent<'a> {
         if let Some(dlg) = self.input_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.param_dlg.as_mut() {
+            dlg.draw(f);
+        }
         if let Some(dlg) = self.detail_dlg.as_mut() {
             dlg.draw(f);
         }
-        if let Some(dlg) = self.exit_dlg.as_mut() {
+        if let Some(dlg) = self.text_dlg.as_mut() {
             dlg.draw(f);
         }
-        if let Some(dlg) = self.info_dlg.as_ref() {
+        if let Some(dlg) = self.exit_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(completion) = self.completion.as_mut() {
+            let (row, col) = self.input.cursor();
+            completion.draw(
+                f,
+                self.input_rect.x + (col as u16).saturating_sub(self.input_scroll.1),
+                self.input_rect.y + (row as u16).saturating_sub(self.input_scroll.0),
+            );
+        }
+    }
+    fn get_cursor_info(&self) -> String {
+        let (row, col) = self.input.cursor();
+        let selection = match self.selection_anchor {
+            Some(anchor) => format!(
+                "Ln {}-{} selected, ",
+                anchor.min(row) + 1,
+                anchor.max(row) + 1
+            ),
+            None => String::new(),
+        };
+        format!(
+            "[{}Ln {}, Col {}, {} lines, {} chars]",
+            selection,
+            row + 1,
+            col + 1,
+            self.input.lines().len(),
+            self.input
+                .lines()
+                .iter()
+                .map(|l| l.chars().count())
+                .sum::<usize>()
+        )
     }
     fn draw_query<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        f.render_widget(self.input.widget(), r);
+        self.input_rect = r;
+        draw_sql_textarea(
+            f,
+            r,
+            &self.input,
+            &mut self.input_scroll,
+            &self.syntax_theme,
+        );
     }
     fn draw_result<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
@@ -184,7 +291,15 @@ impl<'a> QueryDetailComponent<'a> {
         let block = Block::default()
             .borders(Borders::TOP)
             .title(Span::styled(
-                "Result",
+                if self.is_running() {
+                    format!("Result {} Running...", get_spinner_frame(self.spinner))
+                } else if self.is_truncated && self.rows.len() >= self.max_rows {
+                    format!("Result (showing first {} rows)", self.rows.len())
+                } else if self.is_truncated {
+                    format!("Result ({} rows, scroll for more)", self.rows.len())
+                } else {
+                    "Result".to_string()
+                },
                 if let FocusPanel::Result = self.focus {
                     Style::default().fg(Color::Green)
                 } else {
@@ -197,95 +312,278 @@ impl<'a> QueryDetailComponent<'a> {
                 Style::default()
             });
 
-        if !self.rows.is_empty() {
-            let constraints: Vec<Constraint> = self
-                .columns
-                .iter()
-                .map(|_| Constraint::Ratio(1, self.columns.len() as u32))
-                .collect();
-            let table = Table::new(
-                self.rows
-                    .iter()
-                    .map(|r| {
-                        RowUI::new(
-                            self.columns
-                                .iter()
-                                .map(|column| get_mysql_column_value(column, r).unwrap_or_default())
-                                .collect::<Vec<String>>(),
-                        )
-                    })
-                    .collect::<Vec<RowUI>>(),
-            )
-            .header(RowUI::new(
-                self.columns.iter().map(|c| c.name()).collect::<Vec<&str>>(),
-            ))
-            .block(block)
-            .widths(&constraints[..])
-            .highlight_style(Style::default().fg(Color::Green));
-
-            f.render_stateful_widget(table, r, &mut self.row_state);
+        if !self.grid.is_empty() && self.show_columns {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(20), Constraint::Length(40)])
+                .split(r);
+            self.grid.draw(f, chunks[0], block);
+            self.draw_columns(f, chunks[1]);
+        } else if !self.grid.is_empty() {
+            self.grid.draw(f, r, block);
+        } else if self.is_running() {
+            f.render_widget(block, r);
         } else {
             f.render_widget(
-                Paragraph::new("Success,no data returned.")
-                    .block(block)
-                    .style(if let FocusPanel::Result = self.focus {
-                        Style::default().fg(Color::Green)
-                    } else {
-                        Style::default()
-                    }),
+                Paragraph::new(match self.rows_affected {
+                    Some(count) => format!("Statement executed, {} rows affected.", count),
+                    None => String::from("0 rows"),
+                })
+                .block(block)
+                .style(if let FocusPanel::Result = self.focus {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                }),
                 r,
             );
         }
     }
+    fn draw_columns<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let block = Block::default()
+            .borders(Borders::TOP | Borders::LEFT)
+            .title("Columns");
+        let table = DataTable::new(self.columns.iter().map(|column| {
+            vec![
+                (column.ordinal() + 1).to_string(),
+                column.name().to_string(),
+                column.type_info().to_string(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["#", "Name", "Type"]);
+        let inner = block.inner(r);
+        f.render_widget(block, r);
+        f.render_stateful_widget(table, inner, &mut self.columns_state);
+    }
     async fn handle_textarea_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        // Shift+Up/Down select lines for the comment toggle, other keys end the selection.
+        if !matches!(*key, MOVE_UP_KEY | MOVE_DOWN_KEY | COMMENT_KEY) {
+            self.selection_anchor = None;
+        }
         match *key {
             SWITCH_KEY => {
                 if self.is_result {
                     self.focus = FocusPanel::Result;
-                    if !self.rows.is_empty() {
-                        self.row_state.select(Some(0));
+                    if !self.grid.is_empty() {
+                        self.grid.select(Some(0));
                     }
                 }
             }
+            COMPLETE_KEY => self.open_completion(false).await?,
+            COMMENT_KEY => toggle_line_comment(&mut self.input, self.selection_anchor),
+            MOVE_UP_KEY | MOVE_DOWN_KEY => {
+                if self.selection_anchor.is_none() {
+                    self.selection_anchor = Some(self.input.cursor().0);
+                }
+                self.input.move_cursor(if *key == MOVE_UP_KEY {
+                    CursorMove::Up
+                } else {
+                    CursorMove::Down
+                });
+            }
+            CLEAR_KEY if self.auto_pairs && delete_pair_char(&mut self.input) => (),
+            Key {
+                code: KeyCode::Char(c),
+                modifier: KeyModifier::None | KeyModifier::Shift,
+            } if self.auto_pairs && insert_pair_char(&mut self.input, c) => (),
             _ => {
-                let key: Input = key.to_owned().into();
-                self.input.input(key);
+                let input: Input = key.to_owned().into();
+                self.input.input(input);
+                if key.code == KeyCode::Char('.') {
+                    self.open_completion(true).await?;
+                }
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn open_completion(&mut self, qualified_only: bool) -> Result<()> {
+        let (qualifier, prefix) = get_completion_context(&self.input);
+        if qualified_only && qualifier.is_none() {
+            return Ok(());
+        }
+        let candidates = self.get_completion_candidates(qualifier.as_deref()).await?;
+        self.completion = Completion::new(&candidates, &prefix);
+        Ok(())
+    }
+    async fn get_completion_candidates(&mut self, qualifier: Option<&str>) -> Result<Vec<String>> {
+        if self.table_names.is_none() {
+            let pool = get_mysql_pool(
+                self.conns.clone(),
+                self.pools.clone(),
+                self.conn_id.as_ref().unwrap(),
+                Some("information_schema"),
+            )
+            .await?;
+            self.table_names =
+                Some(get_mysql_table_names(&pool, self.db_name.as_ref().unwrap()).await?);
+        }
+        let tables = self.table_names.clone().unwrap_or_default();
+        if let Some(qualifier) = qualifier {
+            let sql = self.input.lines().join("\n");
+            let table = match resolve_table_alias(&sql, qualifier, &tables) {
+                Some(table) => table,
+                None => return Ok(Vec::new()),
+            };
+            if !self.column_names.contains_key(&table) {
+                let pool = get_mysql_pool(
+                    self.conns.clone(),
+                    self.pools.clone(),
+                    self.conn_id.as_ref().unwrap(),
+                    self.db_name.as_deref(),
+                )
+                .await?;
+                let names = get_mysql_field_names(&pool, &table).await?;
+                self.column_names.insert(table.clone(), names);
+            }
+            Ok(self.column_names[&table].clone())
+        } else {
+            Ok(SQL_KEYWORDS
+                .iter()
+                .map(|k| k.to_string())
+                .chain(tables)
+                .collect())
+        }
+    }
+    async fn handle_completion_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(completion) = self.completion.as_mut() {
+            match completion.handle_event(key) {
+                DialogResult::Confirm(item) => {
+                    for _ in 0..completion.prefix().chars().count() {
+                        self.input.delete_char();
+                    }
+                    self.input.insert_str(item);
+                    self.completion = None;
+                }
+                DialogResult::Cancel => {
+                    self.completion = None;
+                    if *key != CANCEL_KEY {
+                        self.handle_main_event(key).await?;
+                        if let KeyCode::Char(c) = key.code {
+                            if key.modifier == KeyModifier::None
+                                && (c.is_alphanumeric() || c == '_')
+                            {
+                                self.open_completion(false).await?;
+                            }
+                        }
+                    }
+                }
+                _ => (),
             }
         }
         Ok(ComponentResult::Done)
     }
-    fn handle_result_event(&mut self, key: &Key) -> Result<ComponentResult> {
+    fn is_running(&self) -> bool {
+        self.query_cursor
+            .as_ref()
+            .map_or(false, |cursor| cursor.is_pending())
+    }
+    pub async fn handle_tick(&mut self) -> Result<()> {
+        let page = match self.query_cursor.as_mut() {
+            Some(cursor) => cursor.try_next_page(),
+            None => return Ok(()),
+        };
+        let page = match page {
+            Some(Ok(page)) => page,
+            Some(Err(e)) => {
+                self.query_cursor = None;
+                return Err(e);
+            }
+            None => {
+                if self.is_running() {
+                    self.spinner = self.spinner.wrapping_add(1);
+                }
+                return Ok(());
+            }
+        };
+        self.rows_affected = page.rows_affected;
+        self.is_truncated = page.has_more;
+        self.rows.extend(page.rows);
+        if !page.has_more || self.rows.len() >= self.max_rows {
+            self.query_cursor = None;
+        }
+        self.set_rows();
+        if self.grid.selected().is_none() && !self.grid.is_empty() {
+            self.grid.select(Some(0));
+        }
+        Ok(())
+    }
+    fn set_rows(&mut self) {
+        self.columns = self
+            .rows
+            .first()
+            .map(|row| row.columns().to_vec())
+            .unwrap_or_default();
+        let values = self
+            .rows
+            .iter()
+            .map(|r| {
+                self.columns
+                    .iter()
+                    .map(|column| {
+                        get_mysql_column_value(column, r, &self.display_format)
+                            .unwrap_or_else(|_| Some(DECODE_ERROR.to_string()))
+                    })
+                    .collect()
+            })
+            .collect();
+        self.grid.set_data(
+            self.columns.iter().map(|c| c.name().to_string()).collect(),
+            self.columns
+                .iter()
+                .map(|c| self.display_format.tinyint_as_bool && c.type_info().name() == "BOOLEAN")
+                .collect(),
+            values,
+        );
+    }
+    async fn handle_result_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if *key == DOWN_KEY
+            && self.rows.len() < self.max_rows
+            && self
+                .grid
+                .selected()
+                .map_or(false, |i| i + 1 == self.grid.len())
+        {
+            let count = min(self.page_size, self.max_rows - self.rows.len());
+            if let Some(cursor) = self.query_cursor.as_mut() {
+                cursor.fetch_more(count);
+            }
+        }
+        if self.grid.handle_event(key) {
+            return Ok(ComponentResult::Done);
+        }
         match *key {
             SWITCH_KEY => {
                 self.focus = FocusPanel::TextArea;
-                self.row_state.select(None);
+                self.grid.select(None);
             }
-            UP_KEY => {
-                if !self.rows.is_empty() {
-                    self.row_state.select(Some(
-                        max(1, self.row_state.selected().unwrap_or_default()) - 1,
+            CONFIRM_KEY => {
+                if let Some(index) = self.grid.selected() {
+                    self.detail_dlg = Some(DetailDialog::from_values(
+                        "Result".to_string(),
+                        &self.grid.get_row_values(index),
                     ));
                 }
             }
-            DOWN_KEY => {
-                if !self.rows.is_empty() {
-                    self.row_state.select(Some(min(
-                        self.rows.len() - 1,
-                        self.row_state.selected().unwrap_or_default() + 1,
-                    )));
+            EXPAND_KEY => {
+                if let Some((header, value)) = self.grid.get_selected_cell() {
+                    self.text_dlg = Some(TextDialog::new(
+                        header,
+                        value.unwrap_or(&self.display_format.null_text),
+                    ));
                 }
             }
-            CONFIRM_KEY => {
-                if let Some(index) = self.row_state.selected() {
-                    let mut map = HashMap::new();
-                    self.columns.iter().for_each(|col| {
-                        map.insert(
-                            col.name().to_string(),
-                            get_mysql_column_value(col, &self.rows[index]),
-                        );
-                    });
-                    self.detail_dlg = Some(DetailDialog::from_map("Result".to_string(), &map));
-                }
+            COLUMN_INFO_KEY => {
+                self.show_columns = !self.show_columns;
+            }
+            COPY_MARKDOWN_KEY => {
+                copy_to_clipboard(&self.grid.to_markdown())?;
+                self.status_line
+                    .borrow_mut()
+                    .info(format!("Copied {} rows as Markdown table", self.grid.len()).as_str());
             }
             _ => (),
         }
@@ -303,38 +601,26 @@ impl<'a> QueryDetailComponent<'a> {
             let sql = sql.trim();
 
             if sql.is_empty() {
-                return Err(Error::msg("no query content"));
+                self.status_line.borrow_mut().warning("No query content");
+                return Ok(ComponentResult::Done);
             }
             if let Some(query) = self.query.as_mut() {
                 query.save_file(&sql)?;
                 self.queries.borrow_mut().save_query(query)?;
-                self.info_dlg = Some(ConfirmDialog::new(
-                    ConfirmKind::Info,
-                    "Success",
-                    "Query Save Success!",
-                ));
+                self.status_line.borrow_mut().info("Query saved");
             } else {
                 self.input_dlg = Some(InputDialog::new("Query Name", None));
             }
         } else if matches!(*key, RUN_KEY) {
             let sql = self.input.lines().join("\n");
             let sql = sql.trim();
-            if !sql.is_empty() {
-                self.rows = fetch_mysql_query(
-                    self.conns.clone(),
-                    self.pools.clone(),
-                    self.conn_id.as_ref().unwrap(),
-                    self.db_name.as_deref(),
-                    &sql,
-                )
-                .await?;
-                self.is_result = true;
-                if !self.rows.is_empty() {
-                    self.columns = self.rows[0].columns().to_vec();
-                    self.row_state.select(Some(0));
+            if !sql.is_empty() && !self.is_running() {
+                let count = get_param_count(sql, &DatabaseKind::MySQL);
+                if count > 0 {
+                    self.param_dlg = Some(ParamDialog::new("?", count, &self.params));
                 } else {
-                    self.columns = vec![];
-                    self.row_state.select(None);
+                    self.params.clear();
+                    self.run_sql(sql.to_string()).await?;
                 }
             }
         } else {
@@ -343,35 +629,66 @@ impl<'a> QueryDetailComponent<'a> {
                     self.handle_textarea_event(key).await?;
                 }
                 FocusPanel::Result => {
-                    self.handle_result_event(key)?;
+                    self.handle_result_event(key).await?;
                 }
             }
         }
         Ok(ComponentResult::Done)
     }
+    async fn run_sql(&mut self, sql: String) -> Result<()> {
+        self.sql = sql;
+        self.rows = Vec::new();
+        self.columns = Vec::new();
+        self.grid.clear();
+        self.is_truncated = false;
+        self.rows_affected = None;
+        self.query_cursor = Some(
+            spawn_mysql_query(
+                self.conns.clone(),
+                self.pools.clone(),
+                self.conn_id.as_ref().unwrap(),
+                self.db_name.as_deref(),
+                &self.sql,
+                &self.params,
+                self.page_size,
+            )
+            .await?,
+        );
+        self.is_result = true;
+        Ok(())
+    }
+    async fn handle_param_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.param_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => self.param_dlg = None,
+                DialogResult::Confirm(params) => {
+                    self.param_dlg = None;
+                    self.params = params;
+                    let sql = self.input.lines().join("\n");
+                    self.run_sql(sql.trim().to_string()).await?;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
-        if self.detail_dlg.is_some() {
+        if self.completion.is_some() {
+            self.handle_completion_event(key).await
+        } else if self.detail_dlg.is_some() {
             self.handle_detail_dlg_event(key)
+        } else if self.text_dlg.is_some() {
+            self.handle_text_dlg_event(key)
         } else if self.input_dlg.is_some() {
             self.handle_input_dlg_event(key)
+        } else if self.param_dlg.is_some() {
+            self.handle_param_dlg_event(key).await
         } else if self.exit_dlg.is_some() {
             self.handle_exit_dlg_event(key)
-        } else if self.info_dlg.is_some() {
-            self.handle_info_dlg_event(key)
         } else {
             self.handle_main_event(key).await
         }
     }
-    fn handle_info_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
-        if let Some(dlg) = self.info_dlg.as_mut() {
-            match dlg.handle_event(key) {
-                DialogResult::Cancel => self.info_dlg = None,
-                DialogResult::Confirm(_) => self.info_dlg = None,
-                _ => (),
-            }
-        }
-        Ok(ComponentResult::Done)
-    }
     fn handle_exit_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if let Some(dlg) = self.exit_dlg.as_mut() {
             match dlg.handle_event(key) {
@@ -399,6 +716,7 @@ impl<'a> QueryDetailComponent<'a> {
                     self.queries.borrow_mut().save_query(&query)?;
                     self.query = Some(query);
                     self.input_dlg = None;
+                    self.status_line.borrow_mut().info("Query saved");
                 }
                 _ => (),
             }
@@ -415,27 +733,50 @@ impl<'a> QueryDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn handle_text_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            if let DialogResult::Cancel = dlg.handle_event(key) {
+                self.text_dlg = None;
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
     fn clear(&mut self) {
+        self.query_cursor = None;
         self.focus = FocusPanel::TextArea;
         self.conn_id = None;
         self.db_name = None;
         self.query = None;
         self.input = TextArea::default();
+        self.selection_anchor = None;
+        self.input_scroll = (0, 0);
+        self.completion = None;
+        self.table_names = None;
+        self.column_names.clear();
         self.rows = Vec::new();
         self.columns = Vec::new();
-        self.row_state = TableState::default();
+        self.grid.clear();
         self.detail_dlg = None;
+        self.text_dlg = None;
         self.input_dlg = None;
+        self.param_dlg = None;
         self.exit_dlg = None;
-        self.info_dlg = None;
         self.is_result = false;
+        self.is_truncated = false;
+        self.rows_affected = None;
+        self.sql = String::new();
+        self.params.clear();
     }
     fn update_commands(&self) {
-        let mut cmds = if let Some(dlg) = self.input_dlg.as_ref() {
+        let mut cmds = if let Some(completion) = self.completion.as_ref() {
+            completion.get_commands()
+        } else if let Some(dlg) = self.input_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.param_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.detail_dlg.as_ref() {
             dlg.get_commands()
-        } else if let Some(dlg) = self.info_dlg.as_ref() {
+        } else if let Some(dlg) = self.text_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.exit_dlg.as_ref() {
             dlg.get_commands()
@@ -463,10 +804,28 @@ impl<'a> QueryDetailComponent<'a> {
         self.cmd_bar.borrow_mut().set_commands(&mut cmds);
     }
     fn get_textarea_commands(&self) -> Vec<Command> {
-        vec![Command {
-            name: "Toggle Focus",
-            key: SWITCH_KEY,
-        }]
+        vec![
+            Command {
+                name: "Toggle Focus",
+                key: SWITCH_KEY,
+            },
+            Command {
+                name: "Complete",
+                key: COMPLETE_KEY,
+            },
+            Command {
+                name: "Toggle Comment",
+                key: COMMENT_KEY,
+            },
+            Command {
+                name: "Select Line Up",
+                key: MOVE_UP_KEY,
+            },
+            Command {
+                name: "Select Line Down",
+                key: MOVE_DOWN_KEY,
+            },
+        ]
     }
     fn get_result_commands(&self) -> Vec<Command> {
         let mut cmds = vec![
@@ -478,16 +837,46 @@ impl<'a> QueryDetailComponent<'a> {
                 name: "Down",
                 key: DOWN_KEY,
             },
+            Command {
+                name: "Left",
+                key: LEFT_KEY,
+            },
+            Command {
+                name: "Right",
+                key: RIGHT_KEY,
+            },
             Command {
                 name: "Toggle Focus",
                 key: SWITCH_KEY,
             },
         ];
-        if self.row_state.selected().is_some() {
-            cmds.push(Command {
-                name: "Open",
-                key: CONFIRM_KEY,
-            });
+        if self.grid.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Open",
+                    key: CONFIRM_KEY,
+                },
+                Command {
+                    name: "Expand Cell",
+                    key: EXPAND_KEY,
+                },
+            ]);
+        }
+        if !self.grid.is_empty() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Copy as Markdown",
+                    key: COPY_MARKDOWN_KEY,
+                },
+                Command {
+                    name: if self.show_columns {
+                        "Hide Columns"
+                    } else {
+                        "Show Columns"
+                    },
+                    key: COLUMN_INFO_KEY,
+                },
+            ]);
         }
         cmds
     }
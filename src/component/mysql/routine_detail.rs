@@ -0,0 +1,266 @@
+use crate::{
+    app::{ComponentResult, DialogResult, MainPanel},
+    component::{Command, CommandBarComponent},
+    dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
+    event::{config::*, Key},
+    model::mysql::{quote_ident, Connections},
+    pool::{execute_mysql_query, execute_mysql_query_unprepared, fetch_one_mysql, MySQLPools},
+};
+use anyhow::{Error, Result};
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders},
+    Frame,
+};
+use regex::Regex;
+use sqlx::Row;
+use std::{cell::RefCell, rc::Rc};
+use tui_textarea::{Input, TextArea};
+use uuid::Uuid;
+
+pub struct RoutineDetailComponent<'a> {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    routine_kind: Option<String>,
+    routine_name: Option<String>,
+    definition: TextArea<'a>,
+    exit_dlg: Option<ConfirmDialog>,
+    info_dlg: Option<ConfirmDialog>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+}
+
+impl<'a> RoutineDetailComponent<'a> {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<MySQLPools>>,
+    ) -> Self {
+        RoutineDetailComponent {
+            conn_id: None,
+            db_name: None,
+            routine_kind: None,
+            routine_name: None,
+            definition: TextArea::default(),
+            exit_dlg: None,
+            info_dlg: None,
+            conns,
+            pools,
+            cmd_bar,
+        }
+    }
+    pub async fn set_data(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        routine_kind: Option<&str>,
+        routine_name: Option<&str>,
+    ) -> Result<()> {
+        self.clear();
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        if let (Some(kind), Some(name)) = (routine_kind, routine_name) {
+            let row = fetch_one_mysql(
+                self.conns.clone(),
+                self.pools.clone(),
+                conn_id,
+                self.db_name.as_deref(),
+                &format!("SHOW CREATE {} {}", kind, quote_ident(name)),
+            )
+            .await?;
+            let create_sql: Option<String> = row.try_get(2).unwrap();
+            let create_sql = create_sql.ok_or_else(|| {
+                Error::msg(format!(
+                    "no privilege to show the definition of {} `{}`",
+                    kind.to_lowercase(),
+                    name
+                ))
+            })?;
+            self.definition = TextArea::from(create_sql.lines());
+            self.routine_kind = Some(kind.to_string());
+            self.routine_name = Some(name.to_string());
+        } else {
+            self.definition =
+                TextArea::from("CREATE PROCEDURE `new_procedure`()\nBEGIN\n\nEND".lines());
+        }
+        Ok(())
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title(
+                    if let (Some(kind), Some(name)) =
+                        (self.routine_kind.as_ref(), self.routine_name.as_ref())
+                    {
+                        format!("Edit {} `{}`", kind.to_lowercase(), name)
+                    } else {
+                        "New Routine".to_string()
+                    },
+                )
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        f.render_widget(
+            self.definition.widget(),
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.exit_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.exit_dlg.is_some() {
+            self.handle_exit_dlg_event(key)
+        } else if self.info_dlg.is_some() {
+            self.handle_info_dlg_event(key)
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            BACK_KEY => {
+                self.exit_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Confirm,
+                    "Exit",
+                    "Be sure to exit?",
+                ));
+            }
+            SAVE_KEY => {
+                self.handle_save().await?;
+            }
+            _ => {
+                let key: Input = key.to_owned().into();
+                self.definition.input(key);
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_save(&mut self) -> Result<()> {
+        let sql = self.definition.lines().join("\n");
+        let sql = sql.trim();
+        if sql.is_empty() {
+            return Err(Error::msg("no routine definition"));
+        }
+        let reg = Regex::new(
+            r"(?is)^CREATE\s+(?:DEFINER\s*=\s*\S+\s+)?(?P<kind>PROCEDURE|FUNCTION)\s+(?:IF\s+NOT\s+EXISTS\s+)?`?(?P<name>[^`\s(]+)`?",
+        )
+        .unwrap();
+        let caps = reg.captures(sql).ok_or_else(|| {
+            Error::msg("definition must start with CREATE PROCEDURE or CREATE FUNCTION")
+        })?;
+        let kind = caps.name("kind").unwrap().as_str().to_uppercase();
+        let name = caps.name("name").unwrap().as_str().to_string();
+
+        if let (Some(old_kind), Some(old_name)) =
+            (self.routine_kind.as_ref(), self.routine_name.as_ref())
+        {
+            execute_mysql_query(
+                self.conns.clone(),
+                self.pools.clone(),
+                self.conn_id.as_ref().unwrap(),
+                self.db_name.as_deref(),
+                &format!("DROP {} IF EXISTS {}", old_kind, quote_ident(old_name)),
+            )
+            .await?;
+        }
+        execute_mysql_query_unprepared(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+            sql,
+        )
+        .await?;
+        self.routine_kind = Some(kind);
+        self.routine_name = Some(name);
+        self.info_dlg = Some(ConfirmDialog::new(
+            ConfirmKind::Info,
+            "Success",
+            "Save Success",
+        ));
+        Ok(())
+    }
+    fn handle_exit_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.exit_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.exit_dlg = None,
+                DialogResult::Confirm(_) => {
+                    self.clear();
+                    return Ok(ComponentResult::BackRefresh(MainPanel::RoutineListMySQL));
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_info_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.info_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.info_dlg = None,
+                DialogResult::Confirm(_) => self.info_dlg = None,
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn clear(&mut self) {
+        self.conn_id = None;
+        self.db_name = None;
+        self.routine_kind = None;
+        self.routine_name = None;
+        self.definition = TextArea::default();
+        self.exit_dlg = None;
+        self.info_dlg = None;
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.exit_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Back",
+                key: BACK_KEY,
+            },
+            Command {
+                name: "Save",
+                key: SAVE_KEY,
+            },
+        ]
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus, Goto},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::{ConfirmDialog, Kind as ConfirmKind},
+    event::{config::*, Key},
+    model::mysql::{get_mysql_routines, Connections, Routine},
+    pool::{execute_mysql_query, get_mysql_pool, MySQLPools},
+    widget::DataTable,
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use uuid::Uuid;
+
+pub struct RoutineListComponent {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    routines: Vec<Routine>,
+    state: TableState,
+    delete_dlg: Option<ConfirmDialog>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+}
+
+impl RoutineListComponent {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<MySQLPools>>,
+    ) -> Self {
+        RoutineListComponent {
+            conn_id: None,
+            db_name: None,
+            routines: Vec::new(),
+            state: TableState::default(),
+            delete_dlg: None,
+            cmd_bar,
+            conns,
+            pools,
+        }
+    }
+    pub async fn set_data(&mut self, conn_id: &Uuid, db_name: &str) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Routines")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        let table = DataTable::new(self.routines.iter().map(|routine| {
+            vec![
+                routine.name.clone(),
+                routine.kind.clone(),
+                routine.data_type.clone().unwrap_or_default(),
+                routine.definer.clone().unwrap_or_default(),
+                routine.comment.clone().unwrap_or_default(),
+            ]
+        }))
+        .header(vec!["Name", "Type", "Returns", "Definer", "Comment"]);
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.routines.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.routines.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.routines.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            NEW_KEY => {
+                return Ok(ComponentResult::Goto(Goto::RoutineDetailMySQL {
+                    conn_id: self.conn_id.unwrap(),
+                    db_name: self.db_name.clone().unwrap(),
+                    routine_kind: None,
+                    routine_name: None,
+                }));
+            }
+            CONFIRM_KEY | EDIT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let routine = &self.routines[index];
+                    return Ok(ComponentResult::Goto(Goto::RoutineDetailMySQL {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        routine_kind: Some(routine.kind.clone()),
+                        routine_name: Some(routine.name.clone()),
+                    }));
+                }
+            }
+            DELETE_KEY => {
+                if self.state.selected().is_some() {
+                    self.delete_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Routine",
+                        "Are you sure to delete this routine?",
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            Some("information_schema"),
+        )
+        .await?;
+        self.routines = get_mysql_routines(&pool, self.db_name.as_ref().unwrap()).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.routines.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    async fn handle_delete_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.delete_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.delete_dlg = None;
+                }
+                DialogResult::Confirm(_) => {
+                    if let Some(i) = self.state.selected() {
+                        execute_mysql_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &self.routines[i].get_drop_ddl(),
+                        )
+                        .await?;
+                        self.routines.remove(i);
+                        self.delete_dlg = None;
+                        self.state.select(None);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.delete_dlg.is_some() {
+            self.handle_delete_dlg_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "New Routine",
+                key: NEW_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Edit Routine",
+                    key: EDIT_KEY,
+                },
+                Command {
+                    name: "Delete Routine",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
@@ -1,90 +1,134 @@
 use crate::{
-    app::{ComponentResult, DialogResult, MainPanel},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    app::{ComponentResult, DialogResult, Goto, MainPanel},
+    clipboard::copy_to_clipboard,
+    component::{
+        get_clicked_tab, get_diff_spans, get_table_down_index, get_table_up_index,
+        get_value_diff_spans, Command, CommandBarComponent,
+    },
+    config::Config,
     dialog::{
         confirm::{ConfirmDialog, Kind as ConfirmKind},
-        mysql::{CheckDialog, FieldDialog, ForeignKeyDialog, IndexDialog, TriggerDialog},
+        mysql::{
+            CheckDialog, FieldDialog, ForeignKeyDialog, IndexDialog, PartitionDialog, TriggerDialog,
+        },
         InputDialog,
     },
     event::{config::*, Key},
-    model::mysql::{
-        convert_show_column_to_mysql_fields, convert_show_fk_to_mysql_fk,
-        convert_show_index_to_mysql_indexes, get_mysql_version, BinaryField, CharField, Check,
-        Connections, DateField, DateTimeField, DecimalField, EnumField, Field, FieldKind,
-        FloatField, ForeignKey, Index, IndexField, IndexKind, IndexMethod, IntField, OnDeleteKind,
-        OnUpdateKind, SimpleField, TextField, TimeField, Trigger, TriggerAction, TriggerTime,
-        Version,
+    model::{
+        mysql::{
+            convert_show_column_to_mysql_fields, convert_show_fk_to_mysql_fk,
+            convert_show_index_to_mysql_indexes, convert_show_reference_to_mysql_references,
+            get_mysql_version, get_partition_by_ddl, parse_mysql_table_options, quote_ident,
+            quote_literal, BinaryField, CharField, Check, Connections, DateField, DateTimeField,
+            DecimalField, EnumField, Field, FieldKind, FloatField, ForeignKey, Index, IndexField,
+            IndexKind, IndexMethod, IntField, OnDeleteKind, OnUpdateKind, Partition,
+            PartitionMethod, SimpleField, TextField, TimeField, Trigger, TriggerAction,
+            TriggerTime, Version,
+        },
+        DisplayFormat, Reference,
+    },
+    pool::{
+        execute_mysql_query_unprepared, fetch_mysql_query, fetch_mysql_query_with_params,
+        fetch_one_mysql, MySQLPools,
     },
-    pool::{execute_mysql_query_unprepared, fetch_mysql_query, fetch_one_mysql, MySQLPools},
-    widget::{Form, FormItem, Select},
+    widget::{highlight_sql, DataTable, Form, FormItem, InputKind, Scrollbar, Select, SyntaxTheme},
 };
-use anyhow::Result;
-use regex::Regex;
-use sqlx::Row;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-use strum::IntoEnumIterator;
-use tui::{
+use anyhow::{Error, Result};
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::Spans,
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState, Tabs},
+    widgets::{Block, BorderType, Borders, Paragraph, TableState, Tabs, Wrap},
     Frame,
 };
+use sqlx::Row;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use strum::IntoEnumIterator;
 use tui_textarea::{Input, TextArea};
 use uuid::Uuid;
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum PanelKind {
     Fields,
     Indexes,
     ForeignKeys,
+    References,
     Triggers,
     Checks,
+    Partitions,
     Options,
     Comment,
+    Changes,
     SQLPreview,
 }
 
+const UNDO_LIMIT: usize = 20;
+const NEW_TABLE_NAME: &str = "new_table";
+
+#[derive(Clone)]
+struct Snapshot {
+    fields: Vec<Field>,
+    indexes: Vec<Index>,
+    foreign_keys: Vec<ForeignKey>,
+    triggers: Vec<Trigger>,
+    checks: Vec<Check>,
+    partitions: Vec<Partition>,
+}
+
 pub struct TableDetailComponent<'a> {
     conn_id: Option<Uuid>,
     db_name: Option<String>,
     db_version: Version,
     table_name: Option<String>,
     panel: PanelKind,
+    tabs_rect: Rect,
     fields: Vec<Field>,
     old_fields: Vec<Field>,
     indexes: Vec<Index>,
     old_indexes: Vec<Index>,
     foreign_keys: Vec<ForeignKey>,
     old_foreign_keys: Vec<ForeignKey>,
+    references: Vec<Reference>,
     triggers: Vec<Trigger>,
     old_triggers: Vec<Trigger>,
     checks: Vec<Check>,
     old_checks: Vec<Check>,
+    partitions: Vec<Partition>,
+    old_partitions: Vec<Partition>,
     form: Form<'a>,
     old_form: Form<'a>,
     comment: TextArea<'a>,
     old_comment: TextArea<'a>,
-    sql_preview: TextArea<'a>,
+    sql_preview_scroll: u16,
+    syntax_theme: SyntaxTheme,
+    display_format: DisplayFormat,
+    changes_scroll: u16,
     fields_state: TableState,
     indexes_state: TableState,
     foreign_keys_state: TableState,
+    references_state: TableState,
     triggers_state: TableState,
     checks_state: TableState,
+    partitions_state: TableState,
     exit_dlg: Option<ConfirmDialog>,
     input_dlg: Option<InputDialog<'a>>,
     info_dlg: Option<ConfirmDialog>,
+    error_dlg: Option<ConfirmDialog>,
     delete_field_dlg: Option<ConfirmDialog>,
     delete_index_dlg: Option<ConfirmDialog>,
     delete_foreign_key_dlg: Option<ConfirmDialog>,
     delete_trigger_dlg: Option<ConfirmDialog>,
     delete_check_dlg: Option<ConfirmDialog>,
+    delete_partition_dlg: Option<ConfirmDialog>,
     kind_sel: Option<Select>,
     field_dlg: Option<FieldDialog<'a>>,
     index_dlg: Option<IndexDialog<'a>>,
     foreign_key_dlg: Option<ForeignKeyDialog<'a>>,
     trigger_dlg: Option<TriggerDialog<'a>>,
     check_dlg: Option<CheckDialog<'a>>,
+    partition_dlg: Option<PartitionDialog<'a>>,
+    undo_stack: Vec<Snapshot>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
@@ -95,20 +139,25 @@ impl<'a> TableDetailComponent<'a> {
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<MySQLPools>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         TableDetailComponent {
             table_name: None,
             panel: PanelKind::Fields,
+            tabs_rect: Rect::default(),
             fields: Vec::new(),
             old_fields: Vec::new(),
             indexes: Vec::new(),
             old_indexes: Vec::new(),
             foreign_keys: Vec::new(),
             old_foreign_keys: Vec::new(),
+            references: Vec::new(),
             triggers: Vec::new(),
             old_triggers: Vec::new(),
             checks: Vec::new(),
             old_checks: Vec::new(),
+            partitions: Vec::new(),
+            old_partitions: Vec::new(),
             form: Form::default(),
             old_form: Form::default(),
             conn_id: None,
@@ -116,26 +165,35 @@ impl<'a> TableDetailComponent<'a> {
             db_version: Version::Eight,
             comment: TextArea::default(),
             old_comment: TextArea::default(),
-            sql_preview: TextArea::default(),
+            sql_preview_scroll: 0,
+            syntax_theme: config.borrow().get_syntax_theme(),
+            display_format: config.borrow().get_display_format(),
+            changes_scroll: 0,
             input_dlg: None,
             exit_dlg: None,
             info_dlg: None,
+            error_dlg: None,
             delete_field_dlg: None,
             delete_index_dlg: None,
             delete_foreign_key_dlg: None,
             delete_trigger_dlg: None,
             delete_check_dlg: None,
+            delete_partition_dlg: None,
             fields_state: TableState::default(),
             indexes_state: TableState::default(),
             foreign_keys_state: TableState::default(),
+            references_state: TableState::default(),
             triggers_state: TableState::default(),
             checks_state: TableState::default(),
+            partitions_state: TableState::default(),
             field_dlg: None,
             index_dlg: None,
             foreign_key_dlg: None,
             trigger_dlg: None,
             check_dlg: None,
+            partition_dlg: None,
             kind_sel: None,
+            undo_stack: Vec::new(),
             cmd_bar,
             conns,
             pools,
@@ -147,6 +205,7 @@ impl<'a> TableDetailComponent<'a> {
         db_name: &str,
         table_name: Option<&str>,
     ) -> Result<()> {
+        self.undo_stack.clear();
         self.conn_id = Some(*conn_id);
         self.db_name = Some(db_name.to_string());
         self.table_name = table_name.map(|s| s.to_string());
@@ -181,7 +240,7 @@ impl<'a> TableDetailComponent<'a> {
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!("SHOW FULL COLUMNS FROM `{}`", table_name),
+                &format!("SHOW FULL COLUMNS FROM {}", quote_ident(table_name)),
             )
             .await?;
             self.fields = convert_show_column_to_mysql_fields(fields);
@@ -193,83 +252,123 @@ impl<'a> TableDetailComponent<'a> {
                 conn_id,
                 Some(db_name),
                 &format!(
-                    "SHOW INDEX FROM `{}` WHERE Key_name != 'PRIMARY'",
-                    table_name
+                    "SHOW INDEX FROM {} WHERE Key_name != 'PRIMARY'",
+                    quote_ident(table_name)
                 ),
             )
             .await?;
             self.indexes = convert_show_index_to_mysql_indexes(indexes);
             self.old_indexes = self.indexes.clone();
 
-            let foreign_keys = fetch_mysql_query(
+            let foreign_keys = fetch_mysql_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some("information_schema"),
-                format!("
+                "
                     SELECT 
                      K.CONSTRAINT_NAME,
                      K.COLUMN_NAME,
                      K.REFERENCED_TABLE_SCHEMA,
                      K.REFERENCED_TABLE_NAME,
-                     K.REFERENCED_COLUMN_NAME
+                     K.REFERENCED_COLUMN_NAME,
+                     R.UPDATE_RULE,
+                     R.DELETE_RULE
                     FROM
                      KEY_COLUMN_USAGE AS K
                     JOIN
-                     TABLE_CONSTRAINTS AS T ON K.CONSTRAINT_NAME = T.CONSTRAINT_NAME
+                     TABLE_CONSTRAINTS AS T ON K.CONSTRAINT_NAME = T.CONSTRAINT_NAME AND K.TABLE_SCHEMA = T.TABLE_SCHEMA AND K.TABLE_NAME = T.TABLE_NAME
+                    LEFT JOIN
+                     REFERENTIAL_CONSTRAINTS AS R ON K.CONSTRAINT_SCHEMA = R.CONSTRAINT_SCHEMA AND K.CONSTRAINT_NAME = R.CONSTRAINT_NAME
                     WHERE
-                     K.TABLE_SCHEMA = '{}' AND K.TABLE_NAME = '{}' AND T.CONSTRAINT_TYPE = 'FOREIGN KEY'",
-                    db_name, table_name
-                )
-                .as_str(),
+                     K.TABLE_SCHEMA = ? AND K.TABLE_NAME = ? AND T.CONSTRAINT_TYPE = 'FOREIGN KEY'
+                    ORDER BY
+                     K.CONSTRAINT_NAME, K.ORDINAL_POSITION",
+                &[db_name, table_name],
             )
             .await?;
             self.foreign_keys = convert_show_fk_to_mysql_fk(foreign_keys);
             self.old_foreign_keys = self.foreign_keys.clone();
 
-            let triggers = fetch_mysql_query(
+            let references = fetch_mysql_query_with_params(
+                self.conns.clone(),
+                self.pools.clone(),
+                conn_id,
+                Some("information_schema"),
+                "SELECT
+                     K.CONSTRAINT_NAME,
+                     K.TABLE_SCHEMA,
+                     K.TABLE_NAME,
+                     K.COLUMN_NAME,
+                     K.REFERENCED_COLUMN_NAME,
+                     R.UPDATE_RULE,
+                     R.DELETE_RULE
+                    FROM
+                     KEY_COLUMN_USAGE AS K
+                    LEFT JOIN
+                     REFERENTIAL_CONSTRAINTS AS R ON K.CONSTRAINT_SCHEMA = R.CONSTRAINT_SCHEMA AND K.CONSTRAINT_NAME = R.CONSTRAINT_NAME
+                    WHERE
+                     K.REFERENCED_TABLE_SCHEMA = ? AND K.REFERENCED_TABLE_NAME = ?
+                    ORDER BY
+                     K.TABLE_SCHEMA, K.TABLE_NAME, K.CONSTRAINT_NAME, K.ORDINAL_POSITION",
+                &[db_name, table_name],
+            )
+            .await?;
+            self.references = convert_show_reference_to_mysql_references(references);
+
+            let triggers = fetch_mysql_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
-                None,
-                &format!("SHOW TRIGGERS FROM `{}` LIKE '{}' ", db_name, table_name),
+                Some("information_schema"),
+                "
+                    SELECT
+                    TRIGGER_NAME,
+                    ACTION_TIMING,
+                    EVENT_MANIPULATION,
+                    ACTION_STATEMENT
+                    FROM
+                    TRIGGERS
+                    WHERE EVENT_OBJECT_SCHEMA = ? AND EVENT_OBJECT_TABLE = ?
+                    ORDER BY ACTION_TIMING, EVENT_MANIPULATION, ACTION_ORDER",
+                &[db_name, table_name],
             )
             .await?;
             self.triggers = triggers
                 .iter()
                 .map(|row| Trigger {
                     id: Uuid::new_v4(),
-                    name: row.try_get("Trigger").unwrap(),
+                    name: row.try_get("TRIGGER_NAME").unwrap(),
                     time: TriggerTime::try_from(
-                        row.try_get::<String, _>("Timing").unwrap().as_str(),
+                        row.try_get::<String, _>("ACTION_TIMING").unwrap().as_str(),
                     )
                     .unwrap(),
                     action: TriggerAction::try_from(
-                        row.try_get::<String, _>("Event").unwrap().as_str(),
+                        row.try_get::<String, _>("EVENT_MANIPULATION")
+                            .unwrap()
+                            .as_str(),
                     )
                     .unwrap(),
-                    statement: row.try_get("Statement").unwrap(),
+                    statement: row.try_get("ACTION_STATEMENT").unwrap(),
                 })
                 .collect();
             self.old_triggers = self.triggers.clone();
             if self.db_version == Version::Eight {
-                let checks = fetch_mysql_query(
+                let checks = fetch_mysql_query_with_params(
                     self.conns.clone(),
                     self.pools.clone(),
                     conn_id,
                     Some("information_schema"),
-                    &format!(
-                        "
+                    "
                     SELECT 
                     C.CONSTRAINT_NAME,
                     C.CHECK_CLAUSE,
                     T.ENFORCED
                     FROM
                     CHECK_CONSTRAINTS AS C
-                    JOIN TABLE_CONSTRAINTS AS T ON C.CONSTRAINT_NAME = T.CONSTRAINT_NAME 
-                    WHERE C.CONSTRAINT_SCHEMA='{}' AND T.TABLE_NAME='{}'",
-                        db_name, table_name
-                    ),
+                    JOIN TABLE_CONSTRAINTS AS T ON C.CONSTRAINT_SCHEMA = T.CONSTRAINT_SCHEMA AND C.CONSTRAINT_NAME = T.CONSTRAINT_NAME
+                    WHERE C.CONSTRAINT_SCHEMA = ? AND T.TABLE_NAME = ?",
+                    &[db_name, table_name],
                 )
                 .await?;
                 self.checks = checks
@@ -287,30 +386,79 @@ impl<'a> TableDetailComponent<'a> {
                 self.old_checks = self.checks.clone();
             }
 
+            let partitions = fetch_mysql_query_with_params(
+                self.conns.clone(),
+                self.pools.clone(),
+                conn_id,
+                Some("information_schema"),
+                "
+                    SELECT
+                    PARTITION_NAME,
+                    PARTITION_METHOD,
+                    PARTITION_EXPRESSION,
+                    PARTITION_DESCRIPTION,
+                    PARTITION_COMMENT
+                    FROM
+                    PARTITIONS
+                    WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND PARTITION_NAME IS NOT NULL
+                    AND (SUBPARTITION_ORDINAL_POSITION IS NULL OR SUBPARTITION_ORDINAL_POSITION = 1)
+                    ORDER BY PARTITION_ORDINAL_POSITION",
+                &[db_name, table_name],
+            )
+            .await?;
+            self.partitions = partitions
+                .iter()
+                .filter_map(|row| {
+                    let method = PartitionMethod::try_from(
+                        row.try_get::<String, _>("PARTITION_METHOD")
+                            .unwrap()
+                            .as_str(),
+                    )
+                    .ok()?;
+                    let comment: String = row.try_get("PARTITION_COMMENT").unwrap();
+                    Some(Partition {
+                        id: Uuid::new_v4(),
+                        name: row.try_get("PARTITION_NAME").unwrap(),
+                        method,
+                        expression: row.try_get("PARTITION_EXPRESSION").unwrap(),
+                        values: row.try_get("PARTITION_DESCRIPTION").unwrap(),
+                        comment: if comment.is_empty() {
+                            None
+                        } else {
+                            Some(comment)
+                        },
+                    })
+                })
+                .collect();
+            self.old_partitions = self.partitions.clone();
+
             let create_table = fetch_one_mysql(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!("SHOW CREATE TABLE `{}`", table_name),
+                &format!("SHOW CREATE TABLE {}", quote_ident(table_name)),
             )
             .await?;
             let def: String = create_table.try_get(1).unwrap();
 
-            let reg = Regex::new(
-                r"CREATE\s+TABLE\s+`\w+`\s+\(\s*(.+\s)+\)\s*(ENGINE=(?P<engine>\w+)\s*)?((DEFAULT\s+)?CHARSET=(?P<charset>\w+)\s*)?((DEFAULT\s+)?COLLATE=(?P<collation>\w+)\s*)?(MIN_ROWS=(?P<min_rows>\w+)\s*)?(MAX_ROWS=(?P<max_rows>\w+)\s*)?(AVG_ROW_LENGTH=(?P<avg_row>\w+)\s*)?(KEY_BLOCK_SIZE=(?P<kbs>\w+)\s*)?(COMMENT='(?P<comment>\w+)'\s*)?",
-            )
-            .unwrap();
-
-            let caps = reg.captures(def.as_str()).unwrap();
+            let options = parse_mysql_table_options(def.as_str());
+            if options.is_none() {
+                self.info_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Warning,
+                    "Table Options",
+                    "Could not parse the table options from SHOW CREATE TABLE, they are shown empty.",
+                ));
+            }
+            let options = options.unwrap_or_default();
 
-            let collations = if let Some(charset) = caps.name("charset") {
+            let collations = if let Some(charset) = options.charset.as_ref() {
                 fetch_mysql_query(
                     self.conns.clone(),
                     self.pools.clone(),
                     self.conn_id.as_ref().unwrap(),
                     None,
-                    format!("SHOW COLLATION WHERE Charset='{}'", charset.as_str()).as_str(),
+                    format!("SHOW COLLATION WHERE Charset='{}'", charset).as_str(),
                 )
                 .await?
                 .iter()
@@ -324,56 +472,74 @@ impl<'a> TableDetailComponent<'a> {
                 FormItem::new_select(
                     "engine".to_string(),
                     engines,
-                    caps.name("engine").map(|e| e.as_str().to_string()),
+                    options.engine.clone(),
                     true,
                     false,
                 ),
                 FormItem::new_select(
                     "default character set".to_string(),
                     charsets,
-                    caps.name("charset").map(|c| c.as_str().to_string()),
+                    options.charset.clone(),
                     true,
                     false,
                 ),
                 FormItem::new_select(
                     "default collation".to_string(),
                     collations,
-                    caps.name("collation").map(|c| c.as_str().to_string()),
+                    options.collation.clone(),
                     true,
                     false,
                 ),
                 FormItem::new_input(
                     "avg row length".to_string(),
-                    caps.name("avg_row").map(|a| a.as_str()),
+                    options.avg_row_length.as_deref(),
                     true,
                     false,
                     false,
-                ),
+                )
+                .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "min rows".to_string(),
-                    caps.name("min_rows").map(|m| m.as_str()),
+                    options.min_rows.as_deref(),
                     true,
                     false,
                     false,
-                ),
+                )
+                .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "max rows".to_string(),
-                    caps.name("max_rows").map(|m| m.as_str()),
+                    options.max_rows.as_deref(),
                     true,
                     false,
                     false,
-                ),
+                )
+                .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "key block size".to_string(),
-                    caps.name("kbs").map(|k| k.as_str()),
+                    options.key_block_size.as_deref(),
+                    true,
+                    false,
+                    false,
+                )
+                .input_kind(InputKind::Numeric),
+                FormItem::new_input(
+                    "tablespace".to_string(),
+                    options.tablespace.as_deref(),
                     true,
                     false,
                     false,
                 ),
+                FormItem::new_select(
+                    "encryption".to_string(),
+                    vec![String::from("Y"), String::from("N")],
+                    options.encryption.clone(),
+                    true,
+                    false,
+                ),
             ]);
             self.old_form = self.form.clone();
-            if let Some(comment) = caps.name("comment") {
-                self.comment = TextArea::from([comment.as_str()]);
+            if let Some(comment) = options.comment.as_ref() {
+                self.comment = TextArea::from(comment.lines());
                 self.old_comment = self.comment.clone();
             }
         } else {
@@ -393,10 +559,22 @@ impl<'a> TableDetailComponent<'a> {
                     true,
                     false,
                 ),
-                FormItem::new_input("avg row length".to_string(), None, true, false, false),
-                FormItem::new_input("min rows".to_string(), None, true, false, false),
-                FormItem::new_input("max rows".to_string(), None, true, false, false),
-                FormItem::new_input("key block size".to_string(), None, true, false, false),
+                FormItem::new_input("avg row length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("min rows".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("max rows".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("key block size".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("tablespace".to_string(), None, true, false, false),
+                FormItem::new_select(
+                    "encryption".to_string(),
+                    vec![String::from("Y"), String::from("N")],
+                    None,
+                    true,
+                    false,
+                ),
             ]);
         }
 
@@ -432,64 +610,35 @@ impl<'a> TableDetailComponent<'a> {
                 horizontal: 1,
             }));
 
-        let select_tab = match self.db_version {
-            Version::Eight => match self.panel {
-                PanelKind::Fields => 0,
-                PanelKind::Indexes => 1,
-                PanelKind::ForeignKeys => 2,
-                PanelKind::Triggers => 3,
-                PanelKind::Checks => 4,
-                PanelKind::Options => 5,
-                PanelKind::Comment => 6,
-                PanelKind::SQLPreview => 7,
-            },
-            Version::Five => match self.panel {
-                PanelKind::Fields => 0,
-                PanelKind::Indexes => 1,
-                PanelKind::ForeignKeys => 2,
-                PanelKind::Triggers => 3,
-                PanelKind::Options => 4,
-                PanelKind::Comment => 5,
-                PanelKind::SQLPreview => 6,
-                _ => 0,
-            },
-        };
-        let tabs = match self.db_version {
-            Version::Eight => vec![
-                Spans::from("Fields"),
-                Spans::from("Indexes"),
-                Spans::from("Foreign Keys"),
-                Spans::from("Triggers"),
-                Spans::from("Checks"),
-                Spans::from("Options"),
-                Spans::from("Comment"),
-                Spans::from("SQL Preview"),
-            ],
-            Version::Five => vec![
-                Spans::from("Fields"),
-                Spans::from("Indexes"),
-                Spans::from("Foreign Keys"),
-                Spans::from("Triggers"),
-                Spans::from("Options"),
-                Spans::from("Comment"),
-                Spans::from("SQL Preview"),
-            ],
-        };
+        let panels = self.get_panels();
+        let select_tab = panels
+            .iter()
+            .position(|(panel, _)| *panel == self.panel)
+            .unwrap_or_default();
+        self.tabs_rect = chunks[0];
         f.render_widget(
-            Tabs::new(tabs)
-                .block(Block::default().borders(Borders::BOTTOM))
-                .highlight_style(Style::default().fg(Color::Green))
-                .select(select_tab),
+            Tabs::new(
+                panels
+                    .iter()
+                    .map(|(_, title)| Spans::from(*title))
+                    .collect(),
+            )
+            .block(Block::default().borders(Borders::BOTTOM))
+            .highlight_style(Style::default().fg(Color::Green))
+            .select(select_tab),
             chunks[0],
         );
         match self.panel {
             PanelKind::Fields => self.draw_fields(f, chunks[1]),
             PanelKind::Indexes => self.draw_indexes(f, chunks[1]),
             PanelKind::ForeignKeys => self.draw_foreign_keys(f, chunks[1]),
+            PanelKind::References => self.draw_references(f, chunks[1]),
             PanelKind::Triggers => self.draw_triggers(f, chunks[1]),
             PanelKind::Checks => self.draw_checks(f, chunks[1]),
+            PanelKind::Partitions => self.draw_partitions(f, chunks[1]),
             PanelKind::Options => self.draw_options(f, chunks[1]),
             PanelKind::Comment => self.draw_comment(f, chunks[1]),
+            PanelKind::Changes => self.draw_changes(f, chunks[1]),
             PanelKind::SQLPreview => self.draw_sql_preview(f, chunks[1]),
         }
 
@@ -529,6 +678,9 @@ impl<'a> TableDetailComponent<'a> {
         if let Some(dlg) = self.check_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.partition_dlg.as_mut() {
+            dlg.draw(f);
+        }
         if let Some(dlg) = self.delete_field_dlg.as_mut() {
             dlg.draw(f);
         }
@@ -544,6 +696,62 @@ impl<'a> TableDetailComponent<'a> {
         if let Some(dlg) = self.delete_check_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.delete_partition_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.error_dlg.as_mut() {
+            dlg.draw(f);
+        }
+    }
+    fn get_panels(&self) -> Vec<(PanelKind, &'static str)> {
+        let mut panels = vec![
+            (PanelKind::Fields, "Fields"),
+            (PanelKind::Indexes, "Indexes"),
+            (PanelKind::ForeignKeys, "Foreign Keys"),
+            (PanelKind::References, "Referenced By"),
+            (PanelKind::Triggers, "Triggers"),
+        ];
+        if let Version::Eight = self.db_version {
+            panels.push((PanelKind::Checks, "Checks"));
+        }
+        panels.append(&mut vec![
+            (PanelKind::Partitions, "Partitions"),
+            (PanelKind::Options, "Options"),
+            (PanelKind::Comment, "Comment"),
+            (PanelKind::Changes, "Changes"),
+            (PanelKind::SQLPreview, "SQL Preview"),
+        ]);
+        panels
+    }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.input_dlg.is_some()
+            || self.exit_dlg.is_some()
+            || self.info_dlg.is_some()
+            || self.error_dlg.is_some()
+            || self.kind_sel.is_some()
+            || self.delete_field_dlg.is_some()
+            || self.delete_index_dlg.is_some()
+            || self.delete_check_dlg.is_some()
+            || self.delete_trigger_dlg.is_some()
+            || self.delete_partition_dlg.is_some()
+            || self.delete_foreign_key_dlg.is_some()
+            || self.field_dlg.is_some()
+            || self.index_dlg.is_some()
+            || self.foreign_key_dlg.is_some()
+            || self.trigger_dlg.is_some()
+            || self.check_dlg.is_some()
+            || self.partition_dlg.is_some()
+        {
+            return;
+        }
+        let panels = self.get_panels();
+        let titles = panels
+            .iter()
+            .map(|(_, title)| *title)
+            .collect::<Vec<&str>>();
+        if let Some(index) = get_clicked_tab(&titles, self.tabs_rect, x, y) {
+            self.panel = panels[index].0;
+        }
     }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if self.input_dlg.is_some() {
@@ -552,6 +760,8 @@ impl<'a> TableDetailComponent<'a> {
             self.handle_exit_dlg_event(key)
         } else if self.info_dlg.is_some() {
             self.handle_info_dlg_event(key)
+        } else if self.error_dlg.is_some() {
+            Ok(self.handle_error_dlg_event(key))
         } else if self.kind_sel.is_some() {
             self.handle_kind_select_event(key).await
         } else if self.delete_field_dlg.is_some() {
@@ -562,6 +772,8 @@ impl<'a> TableDetailComponent<'a> {
             self.handle_delete_check_event(key)
         } else if self.delete_trigger_dlg.is_some() {
             self.handle_delete_trigger_event(key)
+        } else if self.delete_partition_dlg.is_some() {
+            self.handle_delete_partition_event(key)
         } else if self.delete_foreign_key_dlg.is_some() {
             self.handle_delete_foreign_key_event(key)
         } else if self.field_dlg.is_some() {
@@ -574,6 +786,8 @@ impl<'a> TableDetailComponent<'a> {
             self.handle_trigger_dlg_event(key)
         } else if self.check_dlg.is_some() {
             self.handle_check_dlg_event(key)
+        } else if self.partition_dlg.is_some() {
+            self.handle_partition_dlg_event(key)
         } else {
             self.handle_main_event(key).await
         }
@@ -583,45 +797,49 @@ impl<'a> TableDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.fields
-                .iter()
-                .map(|f| {
-                    RowUI::new(vec![
-                        f.name(),
-                        f.kind_str(),
-                        if f.not_null() { "\u{2705}" } else { "" },
-                        if f.key() { "\u{2705}" } else { "" },
-                        f.default_value().unwrap_or_default(),
-                        f.extra().unwrap_or_default(),
-                        f.comment().unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.fields.iter().map(|f| {
+            vec![
+                f.name(),
+                f.kind_str(),
+                if f.not_null() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                },
+                if f.key() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                },
+                f.default_value().unwrap_or_default(),
+                f.collation().unwrap_or_default(),
+                f.extra().unwrap_or_default(),
+                f.comment().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "Type",
             "Not Null",
             "Key",
             "Default Value",
+            "Collation",
             "Extra",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.fields_state);
     }
 
+    pub fn has_unsaved_changes(&self) -> bool {
+        if self.conn_id.is_none() {
+            false
+        } else if self.table_name.is_some() {
+            !self.build_alter_ddl().trim().is_empty()
+        } else {
+            !self.fields.is_empty()
+        }
+    }
     fn build_sql(&self, table_name: Option<&str>) -> String {
         if self.table_name.is_some() {
             self.build_alter_ddl()
@@ -633,7 +851,7 @@ impl<'a> TableDetailComponent<'a> {
         let table_name = if let Some(name) = table_name {
             name
         } else {
-            "new table"
+            NEW_TABLE_NAME
         };
 
         let mut ddl_sql = vec![];
@@ -646,10 +864,7 @@ impl<'a> TableDetailComponent<'a> {
 
         let key_fields: Vec<&Field> = self.fields.iter().filter(|f| f.key()).collect();
         if !key_fields.is_empty() {
-            let names: Vec<String> = key_fields
-                .iter()
-                .map(|f| format!("`{}`", f.name()))
-                .collect();
+            let names: Vec<String> = key_fields.iter().map(|f| quote_ident(f.name())).collect();
 
             ddl_sql.push(format!("\nPRIMARY KEY ({})", names.join(",")));
         }
@@ -688,11 +903,18 @@ impl<'a> TableDetailComponent<'a> {
             vec![]
         };
 
+        let partition_sql = if !self.partitions.is_empty() {
+            format!("\n{}", get_partition_by_ddl(&self.partitions))
+        } else {
+            String::new()
+        };
+
         format!(
-            "CREATE TABLE `{}` (\n{}\n){};\n{}",
-            table_name,
+            "CREATE TABLE {} (\n{}\n){}{};\n{}",
+            quote_ident(table_name),
             ddl_sql.join(",\n"),
             self.build_options_sql(),
+            partition_sql,
             trigger_sqls.join("\n")
         )
     }
@@ -710,74 +932,18 @@ impl<'a> TableDetailComponent<'a> {
 
         if !alter_ddl.is_empty() {
             ddl.push(format!(
-                "ALTER TABLE `{}`\n{};",
-                self.table_name.as_ref().unwrap(),
+                "ALTER TABLE {}\n{};",
+                quote_ident(self.table_name.as_ref().unwrap()),
                 alter_ddl.join(",\n")
             ));
         }
 
+        ddl.extend(self.build_partition_alter_ddl());
         ddl.extend(self.build_trigger_alter_ddl());
         ddl.join("\n")
     }
     fn build_field_alter_ddl(&self) -> Vec<String> {
-        let mut ddl = Vec::new();
-        let field_ids = self
-            .fields
-            .iter()
-            .map(|f| f.id().to_owned())
-            .collect::<Vec<Uuid>>();
-        let old_field_ids = self
-            .old_fields
-            .iter()
-            .map(|f| f.id().to_owned())
-            .collect::<Vec<Uuid>>();
-        let mut drop_fields_str = self
-            .old_fields
-            .iter()
-            .filter(|field| !field_ids.contains(field.id()))
-            .map(|field| field.get_drop_str())
-            .collect();
-        self.fields.iter().for_each(|field| {
-            if !old_field_ids.contains(field.id()) {
-                ddl.push(field.get_add_str());
-            } else {
-                let same_field = self
-                    .old_fields
-                    .iter()
-                    .find(|f| f.id() == field.id())
-                    .unwrap();
-                if let Some(str) = field.get_change_str(same_field) {
-                    ddl.push(str);
-                }
-            }
-        });
-        ddl.append(&mut drop_fields_str);
-        let old_key_fields = self
-            .old_fields
-            .iter()
-            .filter(|f| f.key())
-            .map(|f| f.name().to_string())
-            .collect::<Vec<String>>();
-        let key_fields = self
-            .fields
-            .iter()
-            .filter(|f| f.key())
-            .map(|f| f.name().to_string())
-            .collect::<Vec<String>>();
-        if old_key_fields != key_fields {
-            if !old_key_fields.is_empty() {
-                ddl.push(format!("DROP PRIMARY KEY"));
-            }
-            ddl.push(format!(
-                "ADD PRIMARY KEY ({})",
-                key_fields
-                    .iter()
-                    .map(|f| format!("`{}`", f))
-                    .collect::<Vec<String>>()
-                    .join(",")
-            ));
-        }
-        ddl
+        get_field_alter_ddl(&self.old_fields, &self.fields)
     }
     fn build_index_alter_ddl(&self) -> Vec<String> {
         let mut ddl = Vec::new();
@@ -915,6 +1081,74 @@ impl<'a> TableDetailComponent<'a> {
         ddl.append(&mut drop_check_str);
         ddl
     }
+    fn build_partition_alter_ddl(&self) -> Vec<String> {
+        let table_name = self.table_name.as_deref().unwrap();
+        if self.partitions.is_empty() {
+            if self.old_partitions.is_empty() {
+                return vec![];
+            }
+            return vec![format!(
+                "ALTER TABLE {} REMOVE PARTITIONING;",
+                quote_ident(table_name)
+            )];
+        }
+        let is_same_scheme = self
+            .old_partitions
+            .first()
+            .map(|old| self.partitions.iter().all(|p| p.is_same_scheme(old)))
+            .unwrap_or(false);
+        if !is_same_scheme {
+            return vec![format!(
+                "ALTER TABLE {} {};",
+                quote_ident(table_name),
+                get_partition_by_ddl(&self.partitions)
+            )];
+        }
+
+        let mut ddl = Vec::new();
+        let ids = self
+            .partitions
+            .iter()
+            .map(|p| p.id().to_owned())
+            .collect::<Vec<Uuid>>();
+        let old_ids = self
+            .old_partitions
+            .iter()
+            .map(|p| p.id().to_owned())
+            .collect::<Vec<Uuid>>();
+        let drop_partitions = self
+            .old_partitions
+            .iter()
+            .filter(|p| !ids.contains(p.id()))
+            .map(|p| quote_ident(p.name()))
+            .collect::<Vec<String>>();
+        if !drop_partitions.is_empty() {
+            ddl.push(format!(
+                "ALTER TABLE {} DROP PARTITION {};",
+                quote_ident(table_name),
+                drop_partitions.join(",")
+            ));
+        }
+        self.partitions.iter().for_each(|partition| {
+            if !old_ids.contains(partition.id()) {
+                ddl.push(format!(
+                    "ALTER TABLE {} {};",
+                    quote_ident(table_name),
+                    partition.get_add_ddl()
+                ));
+            } else {
+                let same_partition = self
+                    .old_partitions
+                    .iter()
+                    .find(|p| p.id() == partition.id())
+                    .unwrap();
+                if let Some(str) = partition.get_alter_ddl(same_partition) {
+                    ddl.push(format!("ALTER TABLE {} {};", quote_ident(table_name), str));
+                }
+            }
+        });
+        ddl
+    }
 
     fn build_options_alter_ddl(&self) -> String {
         let mut str = String::new();
@@ -971,10 +1205,25 @@ impl<'a> TableDetailComponent<'a> {
             ));
         }
 
+        let tablespace = self.form.get_value("tablespace");
+        if self.old_form.get_value("tablespace") != tablespace {
+            if let Some(t) = tablespace.filter(|t| !t.is_empty()) {
+                str.push_str(&format!(" TABLESPACE = {}", quote_ident(&t)));
+            }
+        }
+
+        let encryption = self.form.get_value("encryption");
+        if self.old_form.get_value("encryption") != encryption {
+            str.push_str(&format!(
+                " ENCRYPTION = '{}'",
+                encryption.unwrap_or(String::from("N"))
+            ));
+        }
+
         let comment = self.comment.lines().join("\n");
         let old_comment = self.old_comment.lines().join("\n");
         if comment != old_comment {
-            str.push_str(&format!(" COMMENT = '{}'", comment));
+            str.push_str(&format!(" COMMENT = {}", quote_literal(&comment)));
         }
         str
     }
@@ -1008,16 +1257,30 @@ impl<'a> TableDetailComponent<'a> {
         }
         if let Some(mr) = map.get("max rows").unwrap() {
             if !mr.is_empty() {
-                sql = format!("{}MAX_ROWS = {}", sql, mr);
+                sql = format!("{}MAX_ROWS = {} ", sql, mr);
             }
         }
         if let Some(mr) = map.get("min rows").unwrap() {
             if !mr.is_empty() {
-                sql = format!("{}MIN_ROWS = {}", sql, mr);
+                sql = format!("{}MIN_ROWS = {} ", sql, mr);
+            }
+        }
+        if let Some(ts) = map.get("tablespace").unwrap() {
+            if !ts.is_empty() {
+                sql = format!("{}TABLESPACE = {} ", sql, quote_ident(ts));
+            }
+        }
+        if let Some(en) = map.get("encryption").unwrap() {
+            if !en.is_empty() {
+                sql = format!("{}ENCRYPTION = '{}' ", sql, en);
             }
         }
         if !self.comment.is_empty() {
-            sql = format!("{}COMMENT = '{}'", sql, self.comment.lines().join("\n"));
+            sql = format!(
+                "{}COMMENT = {}",
+                sql,
+                quote_literal(&self.comment.lines().join("\n"))
+            );
         }
         sql
     }
@@ -1026,128 +1289,130 @@ impl<'a> TableDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.indexes
-                .iter()
-                .map(|i| {
-                    RowUI::new(vec![
-                        i.name.clone(),
-                        i.fields
-                            .iter()
-                            .map(|f| f.to_string())
-                            .collect::<Vec<String>>()
-                            .join(","),
-                        i.kind.to_string(),
-                        i.method.clone().map(|s| s.to_string()).unwrap_or_default(),
-                        i.comment.clone().unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.indexes.iter().map(|i| {
+            vec![
+                i.name.clone(),
+                i.fields
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+                i.kind.to_string(),
+                i.method.clone().map(|s| s.to_string()).unwrap_or_default(),
+                i.cardinality.map(|c| c.to_string()).unwrap_or_default(),
+                i.comment.clone().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "Fields",
             "Index Type",
             "Method",
+            "Cardinality",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.indexes_state);
     }
     fn draw_foreign_keys<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.foreign_keys
-                .iter()
-                .map(|f| {
-                    RowUI::new(vec![
-                        f.name(),
-                        f.field(),
-                        f.ref_table(),
-                        f.ref_field(),
-                        f.on_delete().unwrap_or(""),
-                        f.on_update().unwrap_or(""),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.foreign_keys.iter().map(|f| {
+            vec![
+                f.name().to_string(),
+                f.fields().join(","),
+                f.ref_table().to_string(),
+                f.ref_fields().join(","),
+                f.on_delete().unwrap_or("").to_string(),
+                f.on_update().unwrap_or("").to_string(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "Fields",
             "Ref Table",
             "Ref Fields",
             "On Delete",
             "On Update",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.foreign_keys_state);
     }
+    fn draw_references<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let table = DataTable::new(self.references.iter().map(|r| {
+            vec![
+                r.name.clone(),
+                format!("{}.{}", r.schema_name, r.table_name),
+                r.fields.join(","),
+                r.ref_fields.join(","),
+                r.on_delete.clone().unwrap_or_default(),
+                r.on_update.clone().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
+            "Name",
+            "Table",
+            "Fields",
+            "Ref Fields",
+            "On Delete",
+            "On Update",
+        ]);
+        f.render_stateful_widget(table, r, &mut self.references_state);
+    }
     fn draw_triggers<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
+        let table = DataTable::new(
             self.triggers
                 .iter()
-                .map(|t| RowUI::new(vec![t.name(), t.time(), t.action(), t.statement()]))
-                .collect::<Vec<RowUI>>(),
+                .map(|t| vec![t.name(), t.time(), t.action(), t.statement()]),
         )
-        .header(RowUI::new(vec!["Name", "Time", "Action", "Statement"]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Time", "Action", "Statement"]);
         f.render_stateful_widget(table, r, &mut self.triggers_state);
     }
     fn draw_checks<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.checks
-                .iter()
-                .map(|c| {
-                    RowUI::new(vec![
-                        c.name(),
-                        c.expression(),
-                        if c.not_enforced() { "\u{2705}" } else { "" },
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec!["Name", "Expression", "Not Enforced"]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(self.checks.iter().map(|c| {
+            vec![
+                c.name(),
+                c.expression(),
+                if c.not_enforced() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                },
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Expression", "Not Enforced"]);
         f.render_stateful_widget(table, r, &mut self.checks_state);
     }
+    fn draw_partitions<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let table = DataTable::new(self.partitions.iter().map(|p| {
+            vec![
+                p.name(),
+                p.method(),
+                p.expression(),
+                p.values(),
+                p.comment().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Method", "Expression", "Values", "Comment"]);
+        f.render_stateful_widget(table, r, &mut self.partitions_state);
+    }
     fn draw_options<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
@@ -1160,38 +1425,188 @@ impl<'a> TableDetailComponent<'a> {
                 Constraint::Ratio(1, 3),
             ])
             .split(r);
+        self.validate_options();
         self.form.draw(f, chunks[0]);
     }
+    fn validate_options(&mut self) {
+        ["avg row length", "min rows", "max rows", "key block size"]
+            .iter()
+            .for_each(|name| match self.form.get_value(name) {
+                Some(value) if !value.is_empty() && value.parse::<u64>().is_err() => {
+                    self.form.set_error(name, "must be a non-negative integer")
+                }
+                _ => self.form.clear_error(name),
+            });
+    }
     fn draw_comment<B>(&self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
         f.render_widget(self.comment.widget(), r);
     }
+    fn draw_changes<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let mut changes = self.build_changes();
+        if changes.is_empty() {
+            changes.push(Spans::from("No changes."));
+        }
+        self.changes_scroll = self.changes_scroll.min(changes.len() as u16 - 1);
+        f.render_widget(
+            Paragraph::new(changes)
+                .wrap(Wrap { trim: false })
+                .scroll((self.changes_scroll, 0)),
+            r,
+        );
+    }
+    fn build_changes(&self) -> Vec<Spans<'static>> {
+        let table_name = self.table_name.as_deref().unwrap_or(NEW_TABLE_NAME);
+        let mut changes = Vec::new();
+        changes.extend(get_diff_spans(
+            "field",
+            &self.old_fields,
+            &self.fields,
+            |f| *f.id(),
+            |f| f.get_create_str(),
+        ));
+        changes.extend(get_diff_spans(
+            "index",
+            &self.old_indexes,
+            &self.indexes,
+            |i| *i.id(),
+            |i| i.get_create_ddl(),
+        ));
+        changes.extend(get_diff_spans(
+            "foreign key",
+            &self.old_foreign_keys,
+            &self.foreign_keys,
+            |k| *k.id(),
+            |k| k.get_create_ddl(),
+        ));
+        changes.extend(get_diff_spans(
+            "trigger",
+            &self.old_triggers,
+            &self.triggers,
+            |t| *t.id(),
+            |t| t.get_create_ddl(table_name),
+        ));
+        changes.extend(get_diff_spans(
+            "check",
+            &self.old_checks,
+            &self.checks,
+            |c| *c.id(),
+            |c| c.get_create_ddl(),
+        ));
+        changes.extend(get_diff_spans(
+            "partition",
+            &self.old_partitions,
+            &self.partitions,
+            |p| *p.id(),
+            |p| p.get_create_ddl(),
+        ));
+        [
+            "engine",
+            "default character set",
+            "default collation",
+            "avg row length",
+            "min rows",
+            "max rows",
+            "key block size",
+            "tablespace",
+            "encryption",
+        ]
+        .iter()
+        .for_each(|name| {
+            changes.extend(get_value_diff_spans(
+                name,
+                self.old_form.get_value(name),
+                self.form.get_value(name),
+            ));
+        });
+        let comment = self.comment.lines().join("\n");
+        let old_comment = self.old_comment.lines().join("\n");
+        changes.extend(get_value_diff_spans(
+            "comment",
+            Some(old_comment).filter(|c| !c.is_empty()),
+            Some(comment).filter(|c| !c.is_empty()),
+        ));
+        changes
+    }
     fn draw_sql_preview<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
         let sql = self.build_sql(None);
-        self.sql_preview = TextArea::from(sql.lines());
-        f.render_widget(self.sql_preview.widget(), r);
+        let lines = sql.lines().count().max(1);
+        self.sql_preview_scroll = self.sql_preview_scroll.min(lines as u16 - 1);
+        f.render_widget(
+            Paragraph::new(highlight_sql(&sql, &self.syntax_theme))
+                .scroll((self.sql_preview_scroll, 0)),
+            r,
+        );
+        f.render_widget(
+            Scrollbar::new(lines, self.sql_preview_scroll as usize, r.height as usize),
+            r,
+        );
+    }
+    fn push_undo(&mut self) {
+        self.undo_stack.push(Snapshot {
+            fields: self.fields.clone(),
+            indexes: self.indexes.clone(),
+            foreign_keys: self.foreign_keys.clone(),
+            triggers: self.triggers.clone(),
+            checks: self.checks.clone(),
+            partitions: self.partitions.clone(),
+        });
+        if self.undo_stack.len() > UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+    }
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo_stack.pop() {
+            self.fields = snapshot.fields;
+            self.indexes = snapshot.indexes;
+            self.foreign_keys = snapshot.foreign_keys;
+            self.triggers = snapshot.triggers;
+            self.checks = snapshot.checks;
+            self.partitions = snapshot.partitions;
+            for (state, len) in [
+                (&mut self.fields_state, self.fields.len()),
+                (&mut self.indexes_state, self.indexes.len()),
+                (&mut self.foreign_keys_state, self.foreign_keys.len()),
+                (&mut self.triggers_state, self.triggers.len()),
+                (&mut self.checks_state, self.checks.len()),
+                (&mut self.partitions_state, self.partitions.len()),
+            ] {
+                if state.selected().map_or(false, |i| i >= len) {
+                    state.select(None);
+                }
+            }
+        }
     }
     pub fn clear(&mut self) {
+        self.undo_stack.clear();
         self.conn_id = None;
         self.db_name = None;
         self.db_version = Version::Eight;
         self.table_name = None;
         self.panel = PanelKind::Fields;
+        self.changes_scroll = 0;
+        self.sql_preview_scroll = 0;
         self.fields = Vec::new();
         self.old_fields = Vec::new();
         self.indexes = Vec::new();
         self.old_indexes = Vec::new();
         self.foreign_keys = Vec::new();
         self.old_foreign_keys = Vec::new();
+        self.references = Vec::new();
         self.triggers = Vec::new();
         self.old_triggers = Vec::new();
         self.checks = Vec::new();
         self.old_checks = Vec::new();
+        self.partitions = Vec::new();
+        self.old_partitions = Vec::new();
         self.form = Form::default();
         self.old_form = Form::default();
         self.comment = TextArea::default();
@@ -1199,8 +1614,10 @@ impl<'a> TableDetailComponent<'a> {
         self.fields_state = TableState::default();
         self.indexes_state = TableState::default();
         self.foreign_keys_state = TableState::default();
+        self.references_state = TableState::default();
         self.triggers_state = TableState::default();
         self.checks_state = TableState::default();
+        self.partitions_state = TableState::default();
         self.exit_dlg = None;
         self.input_dlg = None;
         self.delete_field_dlg = None;
@@ -1208,12 +1625,14 @@ impl<'a> TableDetailComponent<'a> {
         self.delete_foreign_key_dlg = None;
         self.delete_trigger_dlg = None;
         self.delete_check_dlg = None;
+        self.delete_partition_dlg = None;
         self.kind_sel = None;
         self.field_dlg = None;
         self.index_dlg = None;
         self.foreign_key_dlg = None;
         self.trigger_dlg = None;
         self.check_dlg = None;
+        self.partition_dlg = None;
     }
     async fn handle_kind_select_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if let Some(select) = self.kind_sel.as_mut() {
@@ -1247,6 +1666,7 @@ impl<'a> TableDetailComponent<'a> {
             self.old_foreign_keys = self.foreign_keys.clone();
             self.old_triggers = self.triggers.clone();
             self.old_checks = self.checks.clone();
+            self.old_partitions = self.partitions.clone();
             self.old_form = self.form.clone();
             self.old_comment = self.comment.clone();
             self.info_dlg = None;
@@ -1266,6 +1686,33 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn handle_error_dlg_event(&mut self, key: &Key) -> ComponentResult {
+        if let Some(dlg) = self.error_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel | DialogResult::Confirm(_) => {
+                    self.error_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        ComponentResult::Done
+    }
+    fn validate_field(&self, field: &Field) -> Option<String> {
+        let name = field.name();
+        if name.trim().is_empty() {
+            Some("Field name can not be empty".to_string())
+        } else if name.trim() != name || name.contains('`') || name.chars().count() > 64 {
+            Some(format!("Field name '{}' is not a valid identifier", name))
+        } else if self
+            .fields
+            .iter()
+            .any(|f| f.id() != field.id() && f.name().eq_ignore_ascii_case(name))
+        {
+            Some(format!("Field '{}' already exists", name))
+        } else {
+            None
+        }
+    }
     async fn handle_field_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if let Some(dlg) = self.field_dlg.as_mut() {
             match dlg.handle_event(key).await? {
@@ -1274,7 +1721,15 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(map) => {
                     let field = Self::map_to_mysql_field(dlg.get_kind(), &map);
-                    match dlg.get_id() {
+                    if let Some(msg) = self.validate_field(&field) {
+                        self.error_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Error,
+                            "Invalid Field",
+                            msg.as_str(),
+                        ));
+                        return Ok(ComponentResult::Done);
+                    }
+                    match self.field_dlg.as_ref().unwrap().get_id() {
                         None => self.fields.push(field),
                         Some(_) => {
                             if let Some(index) = self.fields_state.selected() {
@@ -1383,6 +1838,29 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn handle_partition_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.partition_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.partition_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let partition = Self::map_to_partition(&map);
+                    match dlg.get_id() {
+                        None => self.partitions.push(partition),
+                        Some(_) => {
+                            if let Some(index) = self.partitions_state.selected() {
+                                self.partitions.splice(index..index + 1, [partition]);
+                            }
+                        }
+                    }
+                    self.partition_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
     fn map_to_mysql_field(kind: &FieldKind, map: &HashMap<String, Option<String>>) -> Field {
         match kind {
             FieldKind::BigInt => Field::BigInt(Self::map_to_int_field(map)),
@@ -1444,12 +1922,14 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             auto_increment: map.get("auto increment").unwrap().as_ref().unwrap() == "true",
             unsigned: map.get("unsigned").unwrap().as_ref().unwrap() == "true",
             zerofill: map.get("zerofill").unwrap().as_ref().unwrap() == "true",
@@ -1470,12 +1950,14 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             character_set: map
                 .get("character set")
                 .unwrap()
@@ -1503,12 +1985,14 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().unwrap().to_string(),
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
         }
     }
     fn map_to_date_field(map: &HashMap<String, Option<String>>) -> DateField {
@@ -1526,11 +2010,13 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
         }
     }
     fn map_to_simple_field(map: &HashMap<String, Option<String>>) -> SimpleField {
@@ -1548,6 +2034,7 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
         }
     }
     fn map_to_decimal_field(map: &HashMap<String, Option<String>>) -> DecimalField {
@@ -1565,6 +2052,7 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             decimal: map.get("decimal").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
@@ -1572,6 +2060,7 @@ impl<'a> TableDetailComponent<'a> {
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             unsigned: map.get("unsigned").unwrap().as_ref().unwrap() == "true",
             zerofill: map.get("zerofill").unwrap().as_ref().unwrap() == "true",
         }
@@ -1591,6 +2080,7 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             options: map
                 .get("options")
                 .unwrap()
@@ -1604,6 +2094,7 @@ impl<'a> TableDetailComponent<'a> {
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             character_set: map
                 .get("character set")
                 .unwrap()
@@ -1632,6 +2123,7 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             decimal: map.get("decimal").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
@@ -1639,6 +2131,7 @@ impl<'a> TableDetailComponent<'a> {
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             auto_increment: map.get("auto increment").unwrap().as_ref().unwrap() == "true",
             unsigned: map.get("unsigned").unwrap().as_ref().unwrap() == "true",
             zerofill: map.get("zerofill").unwrap().as_ref().unwrap() == "true",
@@ -1659,12 +2152,14 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
             on_update: map.get("on update").unwrap().as_ref().unwrap() == "true",
         }
     }
@@ -1683,12 +2178,14 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             length: map.get("length").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
                 .get("default value")
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            default_expression: map.get("default expression").unwrap().as_ref().unwrap() == "true",
         }
     }
     fn map_to_text_field(map: &HashMap<String, Option<String>>) -> TextField {
@@ -1706,6 +2203,7 @@ impl<'a> TableDetailComponent<'a> {
             not_null: map.get("not null").unwrap().as_ref().unwrap() == "true",
             key: map.get("key").unwrap().as_ref().unwrap() == "true",
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map.get("invisible").unwrap().as_ref().unwrap() == "true",
             character_set: map
                 .get("character set")
                 .unwrap()
@@ -1750,6 +2248,11 @@ impl<'a> TableDetailComponent<'a> {
                 .as_ref()
                 .map(|method| IndexMethod::try_from(method.as_str()).unwrap()),
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            invisible: map
+                .get("invisible")
+                .and_then(|i| i.as_ref())
+                .map_or(false, |i| i == "true"),
+            cardinality: None,
         }
     }
     fn map_to_foreign_key(map: &HashMap<String, Option<String>>) -> ForeignKey {
@@ -1764,7 +2267,11 @@ impl<'a> TableDetailComponent<'a> {
                 Uuid::new_v4()
             },
             name: map.get("name").unwrap().as_ref().unwrap().to_string(),
-            field: map.get("field").unwrap().as_ref().unwrap().to_string(),
+            fields: map
+                .get("fields")
+                .and_then(|f| f.as_ref())
+                .map(|f| f.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
             ref_db: map
                 .get("reference db")
                 .unwrap()
@@ -1777,12 +2284,11 @@ impl<'a> TableDetailComponent<'a> {
                 .as_ref()
                 .unwrap()
                 .to_string(),
-            ref_field: map
-                .get("reference field")
-                .unwrap()
-                .as_ref()
-                .unwrap()
-                .to_string(),
+            ref_fields: map
+                .get("reference fields")
+                .and_then(|f| f.as_ref())
+                .map(|f| f.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
             on_delete: map
                 .get("on delete")
                 .unwrap()
@@ -1831,6 +2337,25 @@ impl<'a> TableDetailComponent<'a> {
             not_enforced: map.get("not enforced").unwrap().as_ref().unwrap() == "true",
         }
     }
+    fn map_to_partition(map: &HashMap<String, Option<String>>) -> Partition {
+        Partition {
+            id: if let Some(Some(id)) = map.get("id") {
+                Uuid::parse_str(id).unwrap()
+            } else {
+                Uuid::new_v4()
+            },
+            name: map.get("name").unwrap().as_ref().unwrap().to_string(),
+            method: PartitionMethod::try_from(map.get("method").unwrap().as_deref().unwrap())
+                .unwrap(),
+            expression: map.get("expression").unwrap().as_ref().unwrap().to_string(),
+            values: map.get("values").unwrap().as_ref().unwrap().to_string(),
+            comment: map
+                .get("comment")
+                .unwrap()
+                .clone()
+                .filter(|c| !c.is_empty()),
+        }
+    }
     fn handle_back_event(&mut self) -> Result<ComponentResult> {
         self.exit_dlg = Some(ConfirmDialog::new(
             ConfirmKind::Confirm,
@@ -1839,7 +2364,18 @@ impl<'a> TableDetailComponent<'a> {
         ));
         Ok(ComponentResult::Done)
     }
+    fn validate_auto_increment(&self) -> Result<()> {
+        validate_auto_increment(&self.fields, &self.indexes)
+    }
     async fn handle_save_event(&mut self) -> Result<ComponentResult> {
+        self.validate_options();
+        if self.form.has_errors() {
+            return Err(Error::msg(format!(
+                "Invalid options, {}",
+                self.form.get_errors().join(", ")
+            )));
+        }
+        self.validate_auto_increment()?;
         if self.table_name.is_some() {
             let sql = self.build_sql(None);
             let sql = sql.trim();
@@ -1865,14 +2401,21 @@ impl<'a> TableDetailComponent<'a> {
         Ok(ComponentResult::Done)
     }
     async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if matches!(*key, UNDO_KEY) {
+            self.undo();
+            return Ok(ComponentResult::Done);
+        }
         match self.panel {
             PanelKind::Fields => self.handle_panel_fields_event(key).await,
             PanelKind::Indexes => self.handle_panel_indexes_event(key).await,
             PanelKind::ForeignKeys => self.handle_panel_foreign_keys_event(key).await,
+            PanelKind::References => self.handle_panel_references_event(key).await,
             PanelKind::Triggers => self.handle_panel_triggers_event(key).await,
             PanelKind::Checks => self.handle_panel_checks_event(key).await,
+            PanelKind::Partitions => self.handle_panel_partitions_event(key).await,
             PanelKind::Options => self.handle_panel_options_event(key).await,
             PanelKind::Comment => self.handle_panel_comment_event(key).await,
+            PanelKind::Changes => self.handle_panel_changes_event(key).await,
             PanelKind::SQLPreview => self.handle_panel_sql_preview_event(key).await,
         }
     }
@@ -1991,7 +2534,7 @@ impl<'a> TableDetailComponent<'a> {
     async fn handle_panel_foreign_keys_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_RIGHT_KEY => {
-                self.panel = PanelKind::Triggers;
+                self.panel = PanelKind::References;
             }
             TAB_LEFT_KEY => {
                 self.panel = PanelKind::Indexes;
@@ -2043,6 +2586,16 @@ impl<'a> TableDetailComponent<'a> {
                     );
                 }
             }
+            QUERY_KEY => {
+                if self.table_name.is_some() && !self.foreign_keys.is_empty() {
+                    return Ok(ComponentResult::Goto(Goto::QueryDetailMySQL {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        query_name: None,
+                        sql: Some(self.get_join_query()),
+                    }));
+                }
+            }
             DELETE_KEY => {
                 if self.foreign_keys_state.selected().is_some() {
                     self.delete_foreign_key_dlg = Some(ConfirmDialog::new(
@@ -2056,14 +2609,47 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    async fn handle_panel_references_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            TAB_RIGHT_KEY => {
+                self.panel = PanelKind::Triggers;
+            }
+            TAB_LEFT_KEY => {
+                self.panel = PanelKind::ForeignKeys;
+            }
+            BACK_KEY => {
+                self.handle_back_event()?;
+            }
+            SAVE_KEY => {
+                self.handle_save_event().await?;
+            }
+            UP_KEY => {
+                if !self.references.is_empty() {
+                    let index = get_table_up_index(self.references_state.selected());
+                    self.references_state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.references.is_empty() {
+                    let index = get_table_down_index(
+                        self.references_state.selected(),
+                        self.references.len(),
+                    );
+                    self.references_state.select(Some(index));
+                }
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
     async fn handle_panel_triggers_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_RIGHT_KEY => match self.db_version {
                 Version::Eight => self.panel = PanelKind::Checks,
-                Version::Five => self.panel = PanelKind::Options,
+                Version::Five => self.panel = PanelKind::Partitions,
             },
             TAB_LEFT_KEY => {
-                self.panel = PanelKind::ForeignKeys;
+                self.panel = PanelKind::References;
             }
             BACK_KEY => {
                 self.handle_back_event()?;
@@ -2108,7 +2694,7 @@ impl<'a> TableDetailComponent<'a> {
     async fn handle_panel_checks_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_RIGHT_KEY => {
-                self.panel = PanelKind::Options;
+                self.panel = PanelKind::Partitions;
             }
             TAB_LEFT_KEY => {
                 self.panel = PanelKind::Triggers;
@@ -2153,15 +2739,64 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
-    async fn handle_panel_options_event(&mut self, key: &Key) -> Result<ComponentResult> {
+    async fn handle_panel_partitions_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
-            SAVE_KEY => {
-                self.handle_save_event().await?;
+            TAB_RIGHT_KEY => {
+                self.panel = PanelKind::Options;
             }
             TAB_LEFT_KEY => match self.db_version {
                 Version::Eight => self.panel = PanelKind::Checks,
                 Version::Five => self.panel = PanelKind::Triggers,
             },
+            BACK_KEY => {
+                self.handle_back_event()?;
+            }
+            SAVE_KEY => {
+                self.handle_save_event().await?;
+            }
+            UP_KEY => {
+                if !self.partitions.is_empty() {
+                    let index = get_table_up_index(self.partitions_state.selected());
+                    self.partitions_state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.partitions.is_empty() {
+                    let index = get_table_down_index(
+                        self.partitions_state.selected(),
+                        self.partitions.len(),
+                    );
+                    self.partitions_state.select(Some(index));
+                }
+            }
+            NEW_KEY => {
+                self.partition_dlg = Some(PartitionDialog::new(None, self.partitions.first()));
+            }
+            CONFIRM_KEY | EDIT_KEY => {
+                if let Some(index) = self.partitions_state.selected() {
+                    self.partition_dlg =
+                        Some(PartitionDialog::new(Some(&self.partitions[index]), None));
+                }
+            }
+            DELETE_KEY => {
+                if self.partitions_state.selected().is_some() {
+                    self.delete_partition_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Partition",
+                        "Are you sure to delete partition? All data in it will be lost.",
+                    ));
+                }
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_panel_options_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            SAVE_KEY => {
+                self.handle_save_event().await?;
+            }
+            TAB_LEFT_KEY => self.panel = PanelKind::Partitions,
             TAB_RIGHT_KEY => self.panel = PanelKind::Comment,
             _ => match self.form.handle_event(key)? {
                 DialogResult::Changed(name, selected) => {
@@ -2200,7 +2835,7 @@ impl<'a> TableDetailComponent<'a> {
     async fn handle_panel_comment_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_LEFT_KEY => self.panel = PanelKind::Options,
-            TAB_RIGHT_KEY => self.panel = PanelKind::SQLPreview,
+            TAB_RIGHT_KEY => self.panel = PanelKind::Changes,
             BACK_KEY => {
                 self.handle_back_event()?;
             }
@@ -2214,10 +2849,36 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
-    async fn handle_panel_sql_preview_event(&mut self, key: &Key) -> Result<ComponentResult> {
+    async fn handle_panel_changes_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_LEFT_KEY => self.panel = PanelKind::Comment,
+            TAB_RIGHT_KEY => self.panel = PanelKind::SQLPreview,
+            UP_KEY => self.changes_scroll = self.changes_scroll.saturating_sub(1),
+            DOWN_KEY => self.changes_scroll += 1,
+            BACK_KEY => {
+                self.handle_back_event()?;
+            }
+            SAVE_KEY => {
+                self.handle_save_event().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_panel_sql_preview_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            TAB_LEFT_KEY => self.panel = PanelKind::Changes,
             TAB_RIGHT_KEY => self.panel = PanelKind::Fields,
+            UP_KEY => self.sql_preview_scroll = self.sql_preview_scroll.saturating_sub(1),
+            DOWN_KEY => self.sql_preview_scroll += 1,
+            COPY_SQL_KEY => {
+                copy_to_clipboard(&self.build_sql(None))?;
+                self.info_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Info,
+                    "Copied",
+                    "SQL copied to clipboard",
+                ));
+            }
             BACK_KEY => {
                 self.handle_back_event()?;
             }
@@ -2266,6 +2927,7 @@ impl<'a> TableDetailComponent<'a> {
                 DialogResult::Cancel => self.delete_field_dlg = None,
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.fields_state.selected() {
+                        self.push_undo();
                         self.fields.remove(index);
                     }
                     self.delete_field_dlg = None;
@@ -2284,6 +2946,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.indexes_state.selected() {
+                        self.push_undo();
                         self.indexes.remove(index);
                     }
                     self.delete_index_dlg = None;
@@ -2303,6 +2966,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.checks_state.selected() {
+                        self.push_undo();
                         self.checks.remove(index);
                     }
                     self.checks_state.select(None);
@@ -2313,6 +2977,25 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn handle_delete_partition_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.delete_partition_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.delete_partition_dlg = None;
+                }
+                DialogResult::Confirm(_) => {
+                    if let Some(index) = self.partitions_state.selected() {
+                        self.push_undo();
+                        self.partitions.remove(index);
+                    }
+                    self.partitions_state.select(None);
+                    self.delete_partition_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
     fn handle_delete_trigger_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if let Some(dlg) = self.delete_trigger_dlg.as_mut() {
             match dlg.handle_event(key) {
@@ -2321,6 +3004,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.triggers_state.selected() {
+                        self.push_undo();
                         self.triggers.remove(index);
                     }
                     self.triggers_state.select(None);
@@ -2339,6 +3023,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.foreign_keys_state.selected() {
+                        self.push_undo();
                         self.foreign_keys.remove(index);
                     }
                     self.foreign_keys_state.select(None);
@@ -2360,12 +3045,16 @@ impl<'a> TableDetailComponent<'a> {
             dlg.get_commands()
         } else if let Some(dlg) = self.delete_check_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.delete_partition_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(dlg) = self.input_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.exit_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.info_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.error_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(dlg) = self.field_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.index_dlg.as_ref() {
@@ -2376,6 +3065,8 @@ impl<'a> TableDetailComponent<'a> {
             dlg.get_commands()
         } else if let Some(dlg) = self.check_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.partition_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(sel) = self.kind_sel.as_ref() {
             sel.get_commands()
         } else {
@@ -2389,12 +3080,21 @@ impl<'a> TableDetailComponent<'a> {
             PanelKind::Fields => self.get_field_commands(),
             PanelKind::Indexes => self.get_index_commands(),
             PanelKind::ForeignKeys => self.get_foreign_key_commands(),
+            PanelKind::References => self.get_reference_commands(),
             PanelKind::Triggers => self.get_trigger_commands(),
             PanelKind::Checks => self.get_check_commands(),
+            PanelKind::Partitions => self.get_partition_commands(),
             PanelKind::Options => self.get_option_commands(),
             PanelKind::Comment => self.get_comment_commands(),
+            PanelKind::Changes => self.get_changes_commands(),
             PanelKind::SQLPreview => self.get_sql_preview_commands(),
         };
+        if !self.undo_stack.is_empty() {
+            cmds.push(Command {
+                name: "Undo",
+                key: UNDO_KEY,
+            });
+        }
         cmds.push(Command {
             name: "Back",
             key: BACK_KEY,
@@ -2479,6 +3179,44 @@ impl<'a> TableDetailComponent<'a> {
         }
         cmds
     }
+    fn get_join_query(&self) -> String {
+        let mut sql = format!(
+            "SELECT *\nFROM {} AS t0",
+            quote_ident(self.table_name.as_ref().unwrap())
+        );
+        self.foreign_keys.iter().enumerate().for_each(|(i, fk)| {
+            let alias = format!("t{}", i + 1);
+            let table = if fk.ref_db().is_empty() {
+                quote_ident(fk.ref_table())
+            } else {
+                format!(
+                    "{}.{}",
+                    quote_ident(fk.ref_db()),
+                    quote_ident(fk.ref_table())
+                )
+            };
+            let conditions = fk
+                .fields()
+                .iter()
+                .zip(fk.ref_fields().iter())
+                .map(|(field, ref_field)| {
+                    format!(
+                        "t0.{} = {}.{}",
+                        quote_ident(field),
+                        alias,
+                        quote_ident(ref_field)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(" AND ");
+            sql.push_str(&format!(
+                "\n  LEFT JOIN {} AS {} ON {}",
+                table, alias, conditions
+            ));
+        });
+        sql.push_str("\nLIMIT 100;");
+        sql
+    }
     fn get_foreign_key_commands(&self) -> Vec<Command> {
         let mut cmds = vec![
             Command {
@@ -2514,8 +3252,34 @@ impl<'a> TableDetailComponent<'a> {
                 },
             ]);
         }
+        if self.table_name.is_some() && !self.foreign_keys.is_empty() {
+            cmds.push(Command {
+                name: "Join Query",
+                key: QUERY_KEY,
+            });
+        }
         cmds
     }
+    fn get_reference_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Next Panel",
+                key: TAB_RIGHT_KEY,
+            },
+            Command {
+                name: "Previous Panel",
+                key: TAB_LEFT_KEY,
+            },
+        ]
+    }
     fn get_trigger_commands(&self) -> Vec<Command> {
         let mut cmds = vec![
             Command {
@@ -2590,6 +3354,43 @@ impl<'a> TableDetailComponent<'a> {
         }
         cmds
     }
+    fn get_partition_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Next Panel",
+                key: TAB_RIGHT_KEY,
+            },
+            Command {
+                name: "Previous Panel",
+                key: TAB_LEFT_KEY,
+            },
+            Command {
+                name: "Add Partition",
+                key: NEW_KEY,
+            },
+        ];
+        if self.partitions_state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Edit Partition",
+                    key: EDIT_KEY,
+                },
+                Command {
+                    name: "Delete Partition",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds
+    }
     fn get_option_commands(&self) -> Vec<Command> {
         let mut cmds = vec![
             Command {
@@ -2616,6 +3417,26 @@ impl<'a> TableDetailComponent<'a> {
             },
         ]
     }
+    fn get_changes_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Next Panel",
+                key: TAB_RIGHT_KEY,
+            },
+            Command {
+                name: "Previous Panel",
+                key: TAB_LEFT_KEY,
+            },
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ]
+    }
     fn get_sql_preview_commands(&self) -> Vec<Command> {
         vec![
             Command {
@@ -2626,6 +3447,169 @@ impl<'a> TableDetailComponent<'a> {
                 name: "Previous Panel",
                 key: TAB_LEFT_KEY,
             },
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Copy SQL",
+                key: COPY_SQL_KEY,
+            },
         ]
     }
 }
+
+fn get_field_alter_ddl(old_fields: &[Field], fields: &[Field]) -> Vec<String> {
+    let mut ddl = Vec::new();
+    let field_ids = fields
+        .iter()
+        .map(|f| f.id().to_owned())
+        .collect::<Vec<Uuid>>();
+    let old_field_ids = old_fields
+        .iter()
+        .map(|f| f.id().to_owned())
+        .collect::<Vec<Uuid>>();
+    let mut drop_fields_str = old_fields
+        .iter()
+        .filter(|field| !field_ids.contains(field.id()))
+        .map(|field| field.get_drop_str())
+        .collect();
+    fields.iter().for_each(|field| {
+        if !old_field_ids.contains(field.id()) {
+            ddl.push(field.get_add_str());
+        } else {
+            let same_field = old_fields.iter().find(|f| f.id() == field.id()).unwrap();
+            if let Some(str) = field.get_change_str(same_field) {
+                ddl.push(str);
+            }
+        }
+    });
+    ddl.append(&mut drop_fields_str);
+    let old_key_fields = old_fields
+        .iter()
+        .filter(|f| f.key())
+        .map(|f| f.name().to_string())
+        .collect::<Vec<String>>();
+    let key_fields = fields
+        .iter()
+        .filter(|f| f.key())
+        .map(|f| f.name().to_string())
+        .collect::<Vec<String>>();
+    if old_key_fields != key_fields {
+        if !old_key_fields.is_empty() {
+            ddl.push("DROP PRIMARY KEY".to_string());
+        }
+        if !key_fields.is_empty() {
+            ddl.push(format!(
+                "ADD PRIMARY KEY ({})",
+                key_fields
+                    .iter()
+                    .map(|f| quote_ident(f))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ));
+        }
+    }
+    ddl
+}
+fn validate_auto_increment(fields: &[Field], indexes: &[Index]) -> Result<()> {
+    let key_fields = fields
+        .iter()
+        .filter(|f| f.key())
+        .map(|f| f.name())
+        .collect::<Vec<&str>>();
+    for field in fields.iter().filter(|f| f.auto_increment()) {
+        // MySQL requires an AUTO_INCREMENT column to lead some key, or the ALTER fails with 1075.
+        let is_leading = key_fields.first() == Some(&field.name())
+            || indexes.iter().any(|index| {
+                index
+                    .fields()
+                    .first()
+                    .map_or(false, |f| f.name == field.name())
+            });
+        if !is_leading {
+            return Err(Error::msg(format!(
+                "Column {} is AUTO_INCREMENT and must be the first column of the primary key or an index, remove AUTO_INCREMENT before changing the primary key",
+                field.name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_field(name: &str, key: bool, auto_increment: bool) -> Field {
+        Field::Int(IntField::new(
+            name,
+            true,
+            key,
+            Some(""),
+            None,
+            false,
+            false,
+            Some(""),
+            if auto_increment { "auto_increment" } else { "" },
+        ))
+    }
+
+    fn with_key(field: &Field, key: bool, auto_increment: bool) -> Field {
+        let mut field = field.clone();
+        if let Field::Int(f) = &mut field {
+            f.key = key;
+            f.auto_increment = auto_increment;
+        }
+        field
+    }
+
+    #[test]
+    fn test_auto_increment_primary_key_change() {
+        let old_fields = vec![int_field("id", true, true), int_field("code", false, false)];
+
+        // Moving the primary key off the AUTO_INCREMENT column is rejected.
+        let fields = vec![
+            with_key(&old_fields[0], false, true),
+            with_key(&old_fields[1], true, false),
+        ];
+        assert!(validate_auto_increment(&fields, &[]).is_err());
+        let index = Index {
+            id: Uuid::new_v4(),
+            name: String::from("idx_id"),
+            fields: vec![IndexField::try_from("id").unwrap()],
+            kind: IndexKind::Normal,
+            method: None,
+            comment: None,
+            invisible: false,
+            cardinality: None,
+        };
+        assert!(validate_auto_increment(&fields, &[index]).is_ok());
+
+        // Extending the primary key keeps the AUTO_INCREMENT column leading.
+        let fields = vec![
+            with_key(&old_fields[0], true, true),
+            with_key(&old_fields[1], true, false),
+        ];
+        assert!(validate_auto_increment(&fields, &[]).is_ok());
+        assert_eq!(
+            get_field_alter_ddl(&old_fields, &fields),
+            vec!["DROP PRIMARY KEY", "ADD PRIMARY KEY (`id`,`code`)"]
+        );
+
+        // Dropping AUTO_INCREMENT and the primary key together emits no empty key.
+        let fields = vec![
+            with_key(&old_fields[0], false, false),
+            with_key(&old_fields[1], false, false),
+        ];
+        assert!(validate_auto_increment(&fields, &[]).is_ok());
+        let ddl = get_field_alter_ddl(&old_fields, &fields);
+        assert_eq!(ddl.len(), 2);
+        assert!(!ddl[0].contains("AUTO_INCREMENT"));
+        assert_eq!(ddl[1], "DROP PRIMARY KEY");
+    }
+}
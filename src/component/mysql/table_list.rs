@@ -1,53 +1,98 @@
 use crate::{
     app::{ComponentResult, DialogResult, Focus, Goto},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
-    dialog::{confirm::ConfirmDialog, Kind as ConfirmKind},
+    component::{
+        get_clicked_row, get_table_down_index, get_table_up_index, Command, CommandBarComponent,
+    },
+    dialog::{
+        confirm::ConfirmDialog, CompareDialog, ExportDialog, Kind as ConfirmKind,
+        SearchValueDialog, TextDialog,
+    },
     event::{config::*, Key},
-    model::mysql::{execute_mysql_table, get_mysql_tables, Connections, Table},
-    pool::{get_mysql_pool, MySQLPools},
+    model::{
+        favorite::{Favorite, Favorites},
+        mysql::{
+            dump_mysql_database, execute_mysql_table, execute_mysql_table_maintenance,
+            get_mysql_fields, get_mysql_fields_compare_ddl, get_mysql_referencing_tables,
+            get_mysql_tables, quote_ident, search_mysql_value, Connections, Table,
+            TableMaintenance,
+        },
+        DatabaseKind, DumpOptions, SearchColumnKind,
+    },
+    pool::{execute_mysql_query_without_fk_checks, get_mysql_pool, MySQLPools},
+    widget::{Scrollbar, Select},
 };
-use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use anyhow::{Error, Result};
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Margin, Rect},
     style::{Color, Modifier, Style},
     widgets::{Block, BorderType, Borders, Row as RowUI, Table as TableUI, TableState},
     Frame,
 };
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+    str::FromStr,
+};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-pub struct TableListComponent {
+pub struct TableListComponent<'a> {
     conn_id: Option<Uuid>,
     db_name: Option<String>,
     tables: Vec<Table>,
     state: TableState,
+    marked: HashSet<String>,
+    table_rect: Rect,
     delete_dlg: Option<ConfirmDialog>,
+    delete_without_fk_checks: bool,
+    compare_dlg: Option<CompareDialog<'a>>,
+    text_dlg: Option<TextDialog>,
+    export_dlg: Option<ExportDialog<'a>>,
+    search_dlg: Option<SearchValueDialog<'a>>,
+    maintain_select: Option<Select>,
+    maintain_dlg: Option<ConfirmDialog>,
+    maintenance: Option<TableMaintenance>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
+    favorites: Rc<RefCell<Favorites>>,
 }
 
-impl TableListComponent {
+impl<'a> TableListComponent<'a> {
     pub fn new(
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<MySQLPools>>,
+        favorites: Rc<RefCell<Favorites>>,
     ) -> Self {
         TableListComponent {
             conn_id: None,
             db_name: None,
             tables: Vec::new(),
             state: TableState::default(),
+            marked: HashSet::new(),
+            table_rect: Rect::default(),
             delete_dlg: None,
+            delete_without_fk_checks: false,
+            compare_dlg: None,
+            text_dlg: None,
+            export_dlg: None,
+            search_dlg: None,
+            maintain_select: None,
+            maintain_dlg: None,
+            maintenance: None,
             cmd_bar,
             conns,
             pools,
+            favorites,
         }
     }
     pub async fn set_data(&mut self, conn_id: &Uuid, db_name: &str) -> Result<()> {
         self.conn_id = Some(*conn_id);
         self.db_name = Some(db_name.to_string());
+        self.marked.clear();
         self.state = TableState::default();
 
         let pool = get_mysql_pool(
@@ -60,6 +105,10 @@ impl TableListComponent {
         self.tables = get_mysql_tables(&pool, db_name).await?;
         Ok(())
     }
+    pub fn select_table(&mut self, name: &str) {
+        self.state
+            .select(self.tables.iter().position(|t| t.name == name));
+    }
     pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
     where
         B: Backend,
@@ -82,8 +131,13 @@ impl TableListComponent {
             self.tables
                 .iter()
                 .map(|t| {
+                    let marked = self.marked.contains(&t.name);
                     RowUI::new(vec![
-                        t.name.clone(),
+                        if marked {
+                            format!("* {}", t.name)
+                        } else {
+                            t.name.clone()
+                        },
                         t.rows.map(|r| r.to_string()).unwrap_or_default(),
                         t.data_length.map(|dl| dl.to_string()).unwrap_or_default(),
                         t.engine.clone().unwrap_or_default(),
@@ -95,6 +149,11 @@ impl TableListComponent {
                             .unwrap_or_default(),
                         t.collation.clone().unwrap_or_default(),
                     ])
+                    .style(if marked {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default()
+                    })
                 })
                 .collect::<Vec<RowUI>>(),
         )
@@ -118,18 +177,44 @@ impl TableListComponent {
             Constraint::Ratio(1, 7),
         ])
         .highlight_style(Style::default().fg(Color::Green));
-        f.render_stateful_widget(
-            table,
-            r.inner(&Margin {
-                vertical: 1,
-                horizontal: 1,
-            }),
-            &mut self.state,
+        self.table_rect = r.inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        f.render_stateful_widget(table, self.table_rect, &mut self.state);
+        f.render_widget(
+            Scrollbar::new(
+                self.tables.len(),
+                self.state.offset(),
+                self.table_rect.height.saturating_sub(1) as usize,
+            ),
+            Rect::new(
+                r.right().saturating_sub(1),
+                self.table_rect.y + 1,
+                1,
+                self.table_rect.height.saturating_sub(1),
+            ),
         );
         if is_focus {
             self.update_commands();
         }
     }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.delete_dlg.is_none()
+            && self.compare_dlg.is_none()
+            && self.text_dlg.is_none()
+            && self.maintain_select.is_none()
+            && self.maintain_dlg.is_none()
+            && self.export_dlg.is_none()
+            && self.search_dlg.is_none()
+        {
+            if let Some(index) = get_clicked_row(self.table_rect, 1, self.state.offset(), x, y) {
+                if index < self.tables.len() {
+                    self.state.select(Some(index));
+                }
+            }
+        }
+    }
     pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
     where
         B: Backend,
@@ -137,10 +222,40 @@ impl TableListComponent {
         if let Some(dlg) = self.delete_dlg.as_ref() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.compare_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(select) = self.maintain_select.as_mut() {
+            select.draw(f);
+        }
+        if let Some(dlg) = self.maintain_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.search_dlg.as_mut() {
+            dlg.draw(f);
+        }
     }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if self.delete_dlg.is_some() {
             self.handle_delete_dlg_event(key).await
+        } else if self.compare_dlg.is_some() {
+            self.handle_compare_dlg_event(key).await
+        } else if self.text_dlg.is_some() {
+            self.handle_text_dlg_event(key).await
+        } else if self.maintain_select.is_some() {
+            self.handle_maintain_select_event(key)
+        } else if self.maintain_dlg.is_some() {
+            self.handle_maintain_dlg_event(key).await
+        } else if self.export_dlg.is_some() {
+            self.handle_export_dlg_event(key).await
+        } else if self.search_dlg.is_some() {
+            self.handle_search_dlg_event(key).await
         } else {
             self.handle_main_event(key).await
         }
@@ -150,22 +265,118 @@ impl TableListComponent {
             match dlg.handle_event(key) {
                 DialogResult::Cancel => {
                     self.delete_dlg = None;
+                    self.delete_without_fk_checks = false;
                 }
                 DialogResult::Confirm(_) => {
+                    let names = self.get_target_tables();
+                    if !names.is_empty() {
+                        let sql = format!(
+                            "DROP TABLE {}",
+                            names
+                                .iter()
+                                .map(|n| quote_ident(n))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        );
+                        if self.delete_without_fk_checks {
+                            execute_mysql_query_without_fk_checks(
+                                self.conns.clone(),
+                                self.pools.clone(),
+                                &self.conn_id.unwrap(),
+                                self.db_name.as_deref(),
+                                &sql,
+                            )
+                            .await?;
+                        } else {
+                            execute_mysql_table(
+                                self.conns.clone(),
+                                self.pools.clone(),
+                                &self.conn_id.unwrap(),
+                                self.db_name.as_ref().unwrap(),
+                                &sql,
+                            )
+                            .await?;
+                        }
+                        self.tables.retain(|t| !names.contains(&t.name));
+                        self.marked.clear();
+                        self.delete_dlg = None;
+                        self.delete_without_fk_checks = false;
+                        self.state.select(None);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_compare_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.compare_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.compare_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    self.compare_tables(map).await?;
+                    self.compare_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_maintain_select_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(select) = self.maintain_select.as_mut() {
+            match select.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.maintain_select = None;
+                }
+                DialogResult::Confirm(name) => {
+                    let maintenance = TableMaintenance::from_str(name)?;
                     if let Some(index) = self.state.selected() {
-                        let table = &self.tables[index];
-
-                        execute_mysql_table(
+                        self.maintain_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Warning,
+                            &maintenance.to_string(),
+                            &maintenance.get_confirm_msg(&self.tables[index].name),
+                        ));
+                        self.maintenance = Some(maintenance);
+                    }
+                    self.maintain_select = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_maintain_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.maintain_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.maintain_dlg = None;
+                    self.maintenance = None;
+                }
+                DialogResult::Confirm(_) => {
+                    self.maintain_dlg = None;
+                    if let (Some(index), Some(maintenance)) =
+                        (self.state.selected(), self.maintenance.take())
+                    {
+                        let table_name = self.tables[index].name.clone();
+                        let msg = execute_mysql_table_maintenance(
                             self.conns.clone(),
                             self.pools.clone(),
-                            &self.conn_id.unwrap(),
+                            self.conn_id.as_ref().unwrap(),
                             self.db_name.as_ref().unwrap(),
-                            &format!("DROP TABLE `{}`", &table.name),
+                            &table_name,
+                            maintenance,
                         )
                         .await?;
-                        self.tables.remove(index);
-                        self.delete_dlg = None;
-                        self.state.select(None);
+                        self.text_dlg = Some(TextDialog::new(
+                            &maintenance.to_string(),
+                            &if msg.is_empty() {
+                                format!("{} completed on {}.", maintenance, table_name)
+                            } else {
+                                msg
+                            },
+                        ));
                     }
                 }
                 _ => (),
@@ -173,6 +384,155 @@ impl TableListComponent {
         }
         Ok(ComponentResult::Done)
     }
+    fn get_target_tables(&self) -> Vec<String> {
+        if self.marked.is_empty() {
+            self.state
+                .selected()
+                .map(|i| vec![self.tables[i].name.clone()])
+                .unwrap_or_default()
+        } else {
+            self.tables
+                .iter()
+                .filter(|t| self.marked.contains(&t.name))
+                .map(|t| t.name.clone())
+                .collect()
+        }
+    }
+    async fn handle_export_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.export_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let path = self.export_tables(&map).await?;
+                    self.export_dlg = None;
+                    self.text_dlg =
+                        Some(TextDialog::new("Export", &format!("Exported to {}", path)));
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn export_tables(&mut self, map: &HashMap<String, Option<String>>) -> Result<String> {
+        let path = map.get("file").unwrap().clone().unwrap();
+        let options = DumpOptions {
+            with_data: map.get("with data").unwrap().as_deref() == Some("true"),
+            views: false,
+            triggers: map.get("triggers").unwrap().as_deref() == Some("true"),
+            tables: Some(self.get_target_tables()),
+        };
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            Some("information_schema"),
+        )
+        .await?;
+        let sql = dump_mysql_database(&pool, self.db_name.as_ref().unwrap(), &options).await?;
+        std::fs::write(&path, sql)?;
+        Ok(path)
+    }
+    async fn handle_search_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.search_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.search_dlg = None;
+                }
+                DialogResult::Confirm((value, kind)) => {
+                    let text = self.search_value(&value, &kind).await?;
+                    self.search_dlg = None;
+                    self.text_dlg = Some(TextDialog::new("Search Results", &text));
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn search_value(&mut self, value: &str, kind: &SearchColumnKind) -> Result<String> {
+        let names: Vec<String> = if self.marked.is_empty() {
+            self.tables.iter().map(|t| t.name.clone()).collect()
+        } else {
+            self.get_target_tables()
+        };
+        let pool = get_mysql_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            Some("information_schema"),
+        )
+        .await?;
+        let matches =
+            search_mysql_value(&pool, self.db_name.as_ref().unwrap(), &names, value, kind).await?;
+        if matches.is_empty() {
+            Ok(format!(
+                "No matches for {} in {}",
+                value,
+                self.db_name.as_ref().unwrap()
+            ))
+        } else {
+            Ok(matches
+                .iter()
+                .map(|m| format!("{}.{}: {} rows", m.table_name, m.column_name, m.count))
+                .collect::<Vec<String>>()
+                .join("\n"))
+        }
+    }
+    async fn handle_text_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            if let DialogResult::Cancel = dlg.handle_event(key) {
+                self.text_dlg = None;
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn compare_tables(&mut self, map: HashMap<String, Option<String>>) -> Result<()> {
+        if let Some(index) = self.state.selected() {
+            let table_name = self.tables[index].name.clone();
+            let conn_name = map.get("connection").unwrap().as_deref().unwrap();
+            let target_conn_id = self
+                .conns
+                .borrow()
+                .values()
+                .find(|c| c.name == conn_name)
+                .map(|c| c.id)
+                .ok_or_else(|| Error::msg("cannot find target connection"))?;
+            let target_db = map.get("database").unwrap().as_deref().unwrap();
+            let target_table = map.get("table").unwrap().as_deref().unwrap();
+
+            let pool = get_mysql_pool(
+                self.conns.clone(),
+                self.pools.clone(),
+                self.conn_id.as_ref().unwrap(),
+                self.db_name.as_deref(),
+            )
+            .await?;
+            let source = get_mysql_fields(&pool, &table_name).await?;
+            let pool = get_mysql_pool(
+                self.conns.clone(),
+                self.pools.clone(),
+                &target_conn_id,
+                Some(target_db),
+            )
+            .await?;
+            let target = get_mysql_fields(&pool, target_table).await?;
+
+            let ddl = get_mysql_fields_compare_ddl(target_table, &source, &target);
+            self.text_dlg = Some(TextDialog::new(
+                &format!(
+                    "{} -> {}.{}.{}",
+                    table_name, conn_name, target_db, target_table
+                ),
+                if ddl.is_empty() {
+                    "No differences."
+                } else {
+                    ddl.as_str()
+                },
+            ));
+        }
+        Ok(())
+    }
     async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             UP_KEY => {
@@ -200,6 +560,20 @@ impl TableListComponent {
                     }));
                 }
             }
+            QUERY_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let sql = format!(
+                        "SELECT * FROM {} LIMIT 100;",
+                        quote_ident(&self.tables[index].name)
+                    );
+                    return Ok(ComponentResult::Goto(Goto::QueryDetailMySQL {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        query_name: None,
+                        sql: Some(sql),
+                    }));
+                }
+            }
             NEW_KEY => {
                 return Ok(ComponentResult::Goto(Goto::TableDetailMySQL {
                     conn_id: self.conn_id.unwrap(),
@@ -216,12 +590,109 @@ impl TableListComponent {
                     }));
                 }
             }
+            COMPARE_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let conns = self.conns.borrow();
+                    let mut conn_names = conns
+                        .values()
+                        .map(|c| c.name.clone())
+                        .collect::<Vec<String>>();
+                    conn_names.sort();
+                    let conn_name = conns
+                        .get(self.conn_id.as_ref().unwrap())
+                        .unwrap()
+                        .name
+                        .clone();
+                    drop(conns);
+                    self.compare_dlg = Some(CompareDialog::new(
+                        conn_names,
+                        &conn_name,
+                        self.db_name.as_deref().unwrap(),
+                        None,
+                        &self.tables[index].name,
+                    ));
+                }
+            }
             DELETE_KEY => {
-                self.delete_dlg = Some(ConfirmDialog::new(
-                    ConfirmKind::Warning,
-                    "Delete Table",
-                    "All the data in this table will be lost!!!Are you sure to delete this table? ",
-                ));
+                let names = self.get_target_tables();
+                let references = if names.is_empty() {
+                    Vec::new()
+                } else {
+                    let pool = get_mysql_pool(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        Some("information_schema"),
+                    )
+                    .await?;
+                    get_mysql_referencing_tables(&pool, self.db_name.as_ref().unwrap(), &names)
+                        .await?
+                };
+                self.delete_without_fk_checks = !references.is_empty();
+                self.delete_dlg = Some(if !references.is_empty() {
+                    ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Referenced By Foreign Keys",
+                        &format!(
+                            "Referenced by foreign keys on {}. Drop anyway with foreign key checks off? Those foreign keys will point to a missing table.",
+                            references.join(", ")
+                        ),
+                    )
+                } else if self.marked.is_empty() {
+                    ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Table",
+                        "All the data in this table will be lost!!!Are you sure to delete this table? ",
+                    )
+                } else {
+                    ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Tables",
+                        &format!(
+                            "All the data in {} tables will be lost!!!Are you sure to delete these tables? ",
+                            self.marked.len()
+                        ),
+                    )
+                });
+            }
+            PIN_KEY => {
+                if let Some(favorite) = self.get_selected_favorite() {
+                    self.favorites.borrow_mut().toggle(favorite)?;
+                }
+            }
+            SPACE_KEY => {
+                if let Some(index) = self.state.selected() {
+                    let name = self.tables[index].name.clone();
+                    if !self.marked.remove(&name) {
+                        self.marked.insert(name);
+                    }
+                    self.state
+                        .select(Some(get_table_down_index(Some(index), self.tables.len())));
+                }
+            }
+            EXPORT_KEY => {
+                let names = self.get_target_tables();
+                if !names.is_empty() {
+                    self.export_dlg = Some(ExportDialog::new_tables(&if names.len() == 1 {
+                        names[0].clone()
+                    } else {
+                        self.db_name.clone().unwrap()
+                    }));
+                }
+            }
+            MAINTAIN_KEY => {
+                if self.state.selected().is_some() {
+                    self.maintain_select = Some(Select::new(
+                        "Maintenance".to_string(),
+                        TableMaintenance::iter().map(|m| m.to_string()).collect(),
+                        None,
+                    ));
+                }
+            }
+            SEARCH_VALUE_KEY => {
+                if !self.tables.is_empty() {
+                    self.search_dlg = Some(SearchValueDialog::new(self.db_name.as_ref().unwrap()));
+                }
             }
             REFRESH_KEY => {
                 self.refresh().await?;
@@ -239,16 +710,39 @@ impl TableListComponent {
         )
         .await?;
         self.tables = get_mysql_tables(&pool, self.db_name.as_ref().unwrap()).await?;
+        let tables = &self.tables;
+        self.marked.retain(|n| tables.iter().any(|t| &t.name == n));
         Ok(())
     }
     fn update_commands(&self) {
         let mut cmds = if let Some(dlg) = self.delete_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.compare_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.text_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(select) = self.maintain_select.as_ref() {
+            select.get_commands()
+        } else if let Some(dlg) = self.maintain_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.export_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.search_dlg.as_ref() {
+            dlg.get_commands()
         } else {
             self.get_main_commands()
         };
         self.cmd_bar.borrow_mut().set_commands(&mut cmds);
     }
+    fn get_selected_favorite(&self) -> Option<Favorite> {
+        self.state.selected().map(|index| Favorite {
+            conn_id: self.conn_id.unwrap(),
+            kind: DatabaseKind::MySQL,
+            db_name: self.db_name.clone().unwrap(),
+            schema_name: None,
+            table_name: self.tables[index].name.clone(),
+        })
+    }
     fn get_main_commands(&self) -> Vec<Command> {
         let mut cmds = vec![
             Command {
@@ -275,11 +769,61 @@ impl TableListComponent {
                     key: EDIT_KEY,
                 },
                 Command {
-                    name: "Delete Table",
+                    name: if self.marked.is_empty() {
+                        "Delete Table"
+                    } else {
+                        "Delete Marked Tables"
+                    },
                     key: DELETE_KEY,
                 },
+                Command {
+                    name: "New Query",
+                    key: QUERY_KEY,
+                },
+                Command {
+                    name: "Compare Table",
+                    key: COMPARE_KEY,
+                },
+                Command {
+                    name: "Maintenance",
+                    key: MAINTAIN_KEY,
+                },
+                Command {
+                    name: "Mark/Unmark",
+                    key: SPACE_KEY,
+                },
             ]);
         }
+        if let Some(favorite) = self.get_selected_favorite() {
+            cmds.push(Command {
+                name: if self.favorites.borrow().contains(&favorite) {
+                    "Unpin Table"
+                } else {
+                    "Pin Table"
+                },
+                key: PIN_KEY,
+            });
+        }
+        if self.state.selected().is_some() || !self.marked.is_empty() {
+            cmds.push(Command {
+                name: if self.marked.is_empty() {
+                    "Export Table"
+                } else {
+                    "Export Marked Tables"
+                },
+                key: EXPORT_KEY,
+            });
+        }
+        if !self.tables.is_empty() {
+            cmds.push(Command {
+                name: if self.marked.is_empty() {
+                    "Search Value"
+                } else {
+                    "Search Value in Marked Tables"
+                },
+                key: SEARCH_VALUE_KEY,
+            });
+        }
         cmds.append(&mut vec![
             Command {
                 name: "Refresh",
@@ -1,27 +1,31 @@
 use crate::{
     app::{ComponentResult, DialogResult, MainPanel},
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    config::Config,
     dialog::{confirm::Kind as ConfirmKind, mysql::PrivilegeDialog, ConfirmDialog},
     event::{config::*, Key},
-    model::mysql::{
-        get_mysql_user, get_mysql_user_member_ofs, get_mysql_user_members,
-        get_mysql_user_privileges, get_mysql_users, get_mysql_version, Connections, Privilege,
-        User, UserMember, Version,
+    model::{
+        mysql::{
+            get_mysql_user, get_mysql_user_member_ofs, get_mysql_user_members,
+            get_mysql_user_privileges, get_mysql_users, get_mysql_version, quote_ident,
+            quote_literal, Connections, Privilege, User, UserMember, Version,
+        },
+        DisplayFormat,
     },
     pool::{execute_mysql_query_unprepared, fetch_mysql_query, get_mysql_pool, MySQLPools},
-    widget::{Form, FormItem},
+    widget::{DataTable, Form, FormItem},
 };
 use anyhow::{Error, Result};
-use sqlx::Row;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::Spans,
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState, Tabs},
+    widgets::{Block, BorderType, Borders, TableState, Tabs},
     Frame,
 };
+use sqlx::Row;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use tui_textarea::TextArea;
 use uuid::Uuid;
 
@@ -61,6 +65,7 @@ pub struct UserDetailComponent<'a> {
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    display_format: DisplayFormat,
 }
 
 impl<'a> UserDetailComponent<'a> {
@@ -68,6 +73,7 @@ impl<'a> UserDetailComponent<'a> {
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<MySQLPools>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         let mut srv_privs = HashMap::new();
         srv_privs.insert("Alter", false);
@@ -125,6 +131,7 @@ impl<'a> UserDetailComponent<'a> {
             conns,
             pools,
             cmd_bar,
+            display_format: config.borrow().get_display_format(),
         }
     }
     pub async fn set_data(
@@ -439,47 +446,39 @@ impl<'a> UserDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(self.member_ofs.iter().map(|rm| {
-            RowUI::new(vec![
+        let table = DataTable::new(self.member_ofs.iter().map(|rm| {
+            vec![
                 format!("{}@{}", rm.user_name().unwrap(), rm.user_host().unwrap()),
                 String::from(self.bool_str(rm.granted)),
-            ])
+            ]
         }))
-        .header(RowUI::new(vec!["User Name", "Granted"]))
-        .block(Block::default())
-        .widths(&[Constraint::Ratio(1, 3), Constraint::Ratio(1, 3)])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(vec!["User Name", "Granted"]);
         f.render_stateful_widget(table, r, &mut self.member_ofs_state);
     }
     fn draw_members<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(self.members.iter().map(|m| {
-            RowUI::new(vec![
+        let table = DataTable::new(self.members.iter().map(|m| {
+            vec![
                 format!("{}@{}", m.member_name().unwrap(), m.member_host().unwrap()),
                 String::from(self.bool_str(m.granted)),
-            ])
+            ]
         }))
-        .header(RowUI::new(vec!["User Name", "Granted"]))
-        .block(Block::default())
-        .widths(&[Constraint::Ratio(1, 3), Constraint::Ratio(1, 3)])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(vec!["User Name", "Granted"]);
         f.render_stateful_widget(table, r, &mut self.members_state);
     }
-    fn bool_str(&self, val: bool) -> &'static str {
-        if val {
-            "\u{2705}"
-        } else {
-            "\u{274E}"
-        }
+    fn bool_str(&self, val: bool) -> &str {
+        self.display_format.format_bool(val)
     }
     fn draw_privileges<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(self.privileges.iter().map(|p| {
-            RowUI::new([
+        let table = DataTable::new(self.privileges.iter().map(|p| {
+            [
                 p.db.as_str(),
                 p.name.as_str(),
                 self.bool_str(p.alter),
@@ -494,9 +493,10 @@ impl<'a> UserDetailComponent<'a> {
                 self.bool_str(p.show_view),
                 self.bool_str(p.trigger),
                 self.bool_str(p.update),
-            ])
+            ]
         }))
-        .header(RowUI::new([
+        .display_format(&self.display_format)
+        .header([
             "Database",
             "Name",
             "Alter",
@@ -511,42 +511,20 @@ impl<'a> UserDetailComponent<'a> {
             "Show View",
             "Trigger",
             "Update",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-            Constraint::Ratio(1, 15),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.privileges_state);
     }
     fn draw_srv_privs<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
+        let table = DataTable::new(
             self.srv_privs
                 .iter()
-                .map(|(key, val)| RowUI::new([key, self.bool_str(*val)]))
-                .collect::<Vec<RowUI>>(),
+                .map(|(key, val)| [key, self.bool_str(*val)]),
         )
-        .header(RowUI::new(["Privilege", "Granted"]))
-        .block(Block::default())
-        .widths(&[Constraint::Ratio(1, 3), Constraint::Ratio(1, 3)])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(["Privilege", "Granted"]);
         f.render_stateful_widget(table, r, &mut self.srv_priv_state);
     }
     fn draw_sql_preview<B>(&mut self, f: &mut Frame<B>, r: Rect) -> Result<()>
@@ -985,16 +963,16 @@ impl<'a> UserDetailComponent<'a> {
         let old_user = self.old_user.as_ref().unwrap();
         if user.name() != old_user.name() || user.host() != old_user.host() {
             ddl.push(format!(
-                "RENAME USER `{}`@`{}` TO `{}`@`{}`",
-                old_user.name(),
-                old_user.host(),
-                user.name(),
-                user.host()
+                "RENAME USER {}@{} TO {}@{}",
+                quote_ident(old_user.name()),
+                quote_ident(old_user.host()),
+                quote_ident(user.name()),
+                quote_ident(user.host())
             ));
         }
         let pwd_ddl = if let Some(pwd) = user.password() {
             if !pwd.is_empty() {
-                format!(" BY '{}'", pwd)
+                format!(" BY {}", quote_literal(pwd))
             } else {
                 String::new()
             }
@@ -1051,15 +1029,15 @@ impl<'a> UserDetailComponent<'a> {
         }
         if !pwd_ddl.is_empty() || !resource_ddl.is_empty() {
             ddl.push(format!(
-                "ALTER USER `{}`@`{}` {}{};",
-                user.name(),
-                user.host(),
+                "ALTER USER {}@{} {}{};",
+                quote_ident(user.name()),
+                quote_ident(user.host()),
                 identity_ddl,
                 if !resource_ddl.is_empty() {
                     format!(" WITH {}", resource_ddl.join("\n"))
                 } else {
                     String::new()
-                },
+                }
             ));
         }
         let mut srv_grant_ddl = Vec::new();
@@ -1321,18 +1299,18 @@ impl<'a> UserDetailComponent<'a> {
         });
         if !srv_grant_ddl.is_empty() {
             ddl.push(format!(
-                "GRANT {} ON *.* TO `{}`@`{}`;",
+                "GRANT {} ON *.* TO {}@{};",
                 srv_grant_ddl.join(","),
-                user.name(),
-                user.host()
+                quote_ident(user.name()),
+                quote_ident(user.host())
             ));
         }
         if !srv_revoke_ddl.is_empty() {
             ddl.push(format!(
-                "REVOKE {} ON *.* FROM `{}`@`{}`;",
+                "REVOKE {} ON *.* FROM {}@{};",
                 srv_revoke_ddl.join(","),
-                user.name(),
-                user.host()
+                quote_ident(user.name()),
+                quote_ident(user.host())
             ));
         }
 
@@ -1503,11 +1481,11 @@ impl<'a> UserDetailComponent<'a> {
             .filter(|mo| mo.granted)
             .map(|mo| {
                 format!(
-                    "GRANT `{}`@`{}` TO `{}`@`{}`;",
-                    mo.user_name.as_ref().unwrap(),
-                    mo.user_host.as_ref().unwrap(),
-                    name,
-                    host,
+                    "GRANT {}@{} TO {}@{};",
+                    quote_ident(mo.user_name.as_ref().unwrap()),
+                    quote_ident(mo.user_host.as_ref().unwrap()),
+                    quote_ident(&name),
+                    quote_ident(&host)
                 )
             })
             .collect();
@@ -1517,11 +1495,11 @@ impl<'a> UserDetailComponent<'a> {
             .filter(|ms| ms.granted)
             .map(|ms| {
                 format!(
-                    "GRANT `{}`@`{}` TO `{}`@`{}`;",
-                    name,
-                    host,
-                    ms.member_name.as_ref().unwrap(),
-                    ms.member_host.as_ref().unwrap(),
+                    "GRANT {}@{} TO {}@{};",
+                    quote_ident(&name),
+                    quote_ident(&host),
+                    quote_ident(ms.member_name.as_ref().unwrap()),
+                    quote_ident(ms.member_host.as_ref().unwrap())
                 )
             })
             .collect();
@@ -1549,7 +1527,7 @@ impl<'a> UserDetailComponent<'a> {
         }
         let pwd_ddl = if let Some(pwd) = map.get("Password").unwrap() {
             if !pwd.is_empty() {
-                format!(" BY '{}'", pwd)
+                format!(" BY {}", quote_literal(pwd))
             } else {
                 String::new()
             }
@@ -1573,15 +1551,15 @@ impl<'a> UserDetailComponent<'a> {
         };
 
         let user_ddl = format!(
-            "CREATE USER `{}`@`{}`{}{};",
-            name,
-            host,
+            "CREATE USER {}@{}{}{};",
+            quote_ident(&name),
+            quote_ident(&host),
             identity_ddl,
             if !resource_opts.is_empty() {
                 format!(" WITH {}", resource_opts.join("\n"))
             } else {
                 String::new()
-            },
+            }
         );
         let privs_ddl: Vec<String> = self
             .privileges
@@ -1625,12 +1603,12 @@ impl<'a> UserDetailComponent<'a> {
                     p_str.push("Update");
                 }
                 format!(
-                    "GRANT {} ON `{}`.`{}` TO `{}`@`{}`;",
+                    "GRANT {} ON {}.{} TO {}@{};",
                     p_str.join(","),
-                    p.db,
-                    p.name,
-                    name,
-                    host
+                    quote_ident(&p.db),
+                    quote_ident(&p.name),
+                    quote_ident(&name),
+                    quote_ident(&host)
                 )
             })
             .collect();
@@ -1643,10 +1621,10 @@ impl<'a> UserDetailComponent<'a> {
 
         let srv_privs_ddl = if !srv_privs.is_empty() {
             format!(
-                "GRANT {} ON *.* TO `{}`@`{}`;",
+                "GRANT {} ON *.* TO {}@{};",
                 srv_privs.join(","),
-                name,
-                host
+                quote_ident(&name),
+                quote_ident(&host)
             )
         } else {
             String::new()
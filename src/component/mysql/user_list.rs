@@ -3,18 +3,19 @@ use crate::{
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
     dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
     event::{config::*, Key},
-    model::mysql::{get_mysql_users, Connections, User},
+    model::mysql::{get_mysql_users, quote_literal, Connections, User},
     pool::{execute_mysql_query, get_mysql_pool, MySQLPools},
+    widget::DataTable,
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState},
+    widgets::{Block, BorderType, Borders, TableState},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use uuid::Uuid;
 
 pub struct UserListComponent {
@@ -75,23 +76,18 @@ impl UserListComponent {
             r,
         );
 
-        let table = Table::new(
-            self.users
-                .iter()
-                .map(|u| {
-                    RowUI::new(vec![
-                        u.host().to_string(),
-                        u.name().to_string(),
-                        u.plugin().map(|s| s.to_string()).unwrap_or_default(),
-                        u.max_queries().unwrap_or_default(),
-                        u.max_updates().unwrap_or_default(),
-                        u.max_connections().unwrap_or_default(),
-                        u.max_user_connections().unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.users.iter().map(|u| {
+            vec![
+                u.host().to_string(),
+                u.name().to_string(),
+                u.plugin().map(|s| s.to_string()).unwrap_or_default(),
+                u.max_queries().unwrap_or_default(),
+                u.max_updates().unwrap_or_default(),
+                u.max_connections().unwrap_or_default(),
+                u.max_user_connections().unwrap_or_default(),
+            ]
+        }))
+        .header(vec![
             "Host",
             "Name",
             "Plugin",
@@ -99,18 +95,7 @@ impl UserListComponent {
             "Max Updates",
             "Max Connections",
             "Max User Connections",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(
             table,
             r.inner(&Margin {
@@ -198,7 +183,11 @@ impl UserListComponent {
                             self.pools.clone(),
                             &self.conn_id.unwrap(),
                             None,
-                            &format!("DROP USER '{}'@'{}'", user.name(), user.host()),
+                            &format!(
+                                "DROP USER {}@{}",
+                                quote_literal(user.name()),
+                                quote_literal(user.host())
+                            ),
                         )
                         .await?;
                         self.delete_dlg = None;
@@ -3,15 +3,14 @@ use crate::{
     component::{Command, CommandBarComponent},
     dialog::{confirm::Kind as ConfirmKind, ConfirmDialog, InputDialog},
     event::{config::*, Key},
-    model::mysql::Connections,
-    pool::{execute_mysql_query_unprepared, fetch_one_mysql, MySQLPools},
+    model::mysql::{quote_ident, quote_literal, Connections},
+    pool::{
+        execute_mysql_query_unprepared, fetch_mysql_query_with_params, fetch_one_mysql, MySQLPools,
+    },
     widget::{Form, FormItem},
 };
-use anyhow::Result;
-use regex::Regex;
-use sqlx::Row;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use anyhow::{Error, Result};
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
@@ -19,6 +18,9 @@ use tui::{
     widgets::{Block, BorderType, Borders, Tabs},
     Frame,
 };
+use regex::Regex;
+use sqlx::Row;
+use std::{cell::RefCell, rc::Rc};
 use tui_textarea::{Input, TextArea};
 use uuid::Uuid;
 
@@ -85,28 +87,36 @@ impl<'a> ViewDetailComponent<'a> {
                 self.pools.clone(),
                 conn_id,
                 self.db_name.as_deref(),
-                &format!("SHOW CREATE VIEW `{}`", name),
+                &format!("SHOW CREATE VIEW {}", quote_ident(name)),
             )
             .await?;
-            let view = fetch_one_mysql(
+            let view = fetch_mysql_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some("information_schema"),
-                &format!(
-                    "SELECT * FROM VIEWS WHERE TABLE_SCHEMA='{}' AND TABLE_NAME='{}'",
-                    self.db_name.as_ref().unwrap(),
-                    name
-                ),
+                "SELECT * FROM VIEWS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+                &[self.db_name.as_deref().unwrap(), name],
             )
-            .await?;
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::msg(format!("View {} not found", name)))?;
+            let create_sql: String = create_view.try_get(1).unwrap();
             let reg = Regex::new(r"^CREATE\s(ALGORITHM=(?P<algorithm>MERGE|UNDEFINED|TEMPTABLE))?")
                 .unwrap();
-            let caps = reg.captures(create_view.try_get(1).unwrap()).unwrap();
-            self.definition = TextArea::from([view
-                .try_get::<String, _>("VIEW_DEFINITION")
-                .unwrap()
-                .as_str()]);
+            let caps = reg.captures(&create_sql).unwrap();
+            let body_reg = Regex::new(
+                r"(?is)\sVIEW\s+`(?:[^`]|``)+`\s+AS\s+(?P<body>.*?)(?:\s+WITH\s+(?:CASCADED|LOCAL)\s+CHECK\s+OPTION)?$",
+            )
+            .unwrap();
+            let body =
+                if let Some(body) = body_reg.captures(&create_sql).and_then(|c| c.name("body")) {
+                    body.as_str().to_string()
+                } else {
+                    view.try_get::<String, _>("VIEW_DEFINITION").unwrap()
+                };
+            self.definition = TextArea::from(body.lines());
             self.form.set_items(vec![
                 FormItem::new_select(
                     "Algorithm".to_string(),
@@ -453,126 +463,55 @@ impl<'a> ViewDetailComponent<'a> {
         self.sql_preview = TextArea::default();
         self.exit_dlg = None;
     }
-    fn build_create_ddl(&self, view_name: Option<&str>) -> String {
-        let name = if let Some(name) = view_name {
-            name
-        } else {
-            "new view"
-        };
+    fn build_view_ddl(&self, view_name: &str) -> String {
         let map = self.form.get_data();
-        let algorithm = if let Some(algorithm) = map.get("Algorithm") {
-            if let Some(algorithm) = algorithm {
-                format!(" ALGORITHM={}", algorithm)
-            } else {
-                String::new()
-            }
+        let algorithm = if let Some(Some(algorithm)) = map.get("Algorithm") {
+            format!(" ALGORITHM={}", algorithm)
         } else {
             String::new()
         };
-        let definer = if let Some(definer) = map.get("Definer").unwrap() {
+        let definer = if let Some(Some(definer)) = map.get("Definer") {
             if !definer.is_empty() {
-                format!(" DEFINER = {}", definer)
+                format!(" DEFINER={}", quote_definer(definer))
             } else {
                 String::new()
             }
         } else {
             String::new()
         };
-        let security = if let Some(security) = map.get("Security") {
-            if let Some(security) = security {
-                format!(" SQL SECURITY {}", security)
-            } else {
-                String::new()
-            }
+        let security = if let Some(Some(security)) = map.get("Security") {
+            format!(" SQL SECURITY {}", security)
         } else {
             String::new()
         };
-        let check_option = if let Some(check) = map.get("Check Option") {
-            if let Some(check) = check {
-                format!(" WITH {} CHECK OPTION", check)
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
+        let check_option = match map.get("Check Option") {
+            Some(Some(check)) if check != "NONE" => format!(" WITH {} CHECK OPTION", check),
+            _ => String::new(),
         };
+        let definition = self.definition.lines().join("\n");
 
         format!(
-            "CREATE{}{}{} VIEW `{}` AS {}{}",
+            "CREATE OR REPLACE{}{}{} VIEW {} AS {}{}",
             algorithm,
             definer,
             security,
-            name,
-            self.definition.lines().join("\n"),
+            quote_ident(view_name),
+            definition.trim().trim_end_matches(';'),
             check_option
         )
     }
+    fn build_create_ddl(&self, view_name: Option<&str>) -> String {
+        let name = view_name.unwrap_or("new view");
+        self.build_view_ddl(name)
+    }
     fn build_alter_ddl(&self) -> String {
-        let map = self.form.get_data();
-        let old_map = self.old_form.get_data();
-
-        let algorithm = if map.get("Algorithm") != old_map.get("Algorithm") {
-            if let Some(algorithm) = map.get("Algorithm") {
-                if let Some(algorithm) = algorithm {
-                    format!(" ALGORITHM={}", algorithm)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            }
+        if self.form.get_data() != self.old_form.get_data()
+            || self.definition.lines() != self.old_definition.lines()
+        {
+            self.build_view_ddl(self.view_name.as_ref().unwrap())
         } else {
             String::new()
-        };
-        let definer = if map.get("Definer") != old_map.get("Definer") {
-            if let Some(definer) = map.get("Definer") {
-                if let Some(definer) = definer {
-                    format!(" DEFINER={}", definer)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
-        let security = if map.get("Security") != old_map.get("Security") {
-            if let Some(security) = map.get("Security") {
-                if let Some(security) = security {
-                    format!(" SQL SECURITY {}", security)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
-        let check_option = if map.get("Check Option") != old_map.get("Check Option") {
-            if let Some(check_option) = map.get("Check Option") {
-                if let Some(check_option) = check_option {
-                    format!(" WITH {} CHECK OPTION", check_option)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        };
-
-        format!(
-            "ALTER{}{}{} VIEW `{}` AS {}{}",
-            algorithm,
-            definer,
-            security,
-            self.view_name.as_ref().unwrap(),
-            self.definition.lines().join("\n"),
-            check_option
-        )
+        }
     }
     fn build_sql(&self, view_name: Option<&str>) -> String {
         if self.view_name.is_some() {
@@ -649,3 +588,13 @@ impl<'a> ViewDetailComponent<'a> {
         ]
     }
 }
+
+fn quote_definer(definer: &str) -> String {
+    if definer.contains('\'') || definer.contains('`') {
+        definer.to_string()
+    } else if let Some((user, host)) = definer.rsplit_once('@') {
+        format!("{}@{}", quote_literal(user), quote_literal(host))
+    } else {
+        quote_literal(definer)
+    }
+}
@@ -3,18 +3,19 @@ use crate::{
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
     dialog::{ConfirmDialog, Kind as ConfirmKind},
     event::{config::*, Key},
-    model::mysql::{get_mysql_views, Connections, View},
+    model::mysql::{get_mysql_views, quote_ident, Connections, View},
     pool::{execute_mysql_query, get_mysql_pool, MySQLPools},
+    widget::DataTable,
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState},
+    widgets::{Block, BorderType, Borders, TableState},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use uuid::Uuid;
 
 pub struct ViewListComponent {
@@ -76,33 +77,15 @@ impl ViewListComponent {
                 }),
             r,
         );
-        let table = Table::new(
-            self.views
-                .iter()
-                .map(|view| {
-                    RowUI::new(vec![
-                        view.name.clone(),
-                        view.check_option.clone().unwrap_or_default(),
-                        view.definer.clone().unwrap_or_default(),
-                        view.sql_security.clone().unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
-            "Name",
-            "Check Option",
-            "Definer",
-            "SQL Security",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(self.views.iter().map(|view| {
+            vec![
+                view.name.clone(),
+                view.check_option.clone().unwrap_or_default(),
+                view.definer.clone().unwrap_or_default(),
+                view.sql_security.clone().unwrap_or_default(),
+            ]
+        }))
+        .header(vec!["Name", "Check Option", "Definer", "SQL Security"]);
         f.render_stateful_widget(
             table,
             r.inner(&Margin {
@@ -207,7 +190,7 @@ impl ViewListComponent {
                             self.pools.clone(),
                             self.conn_id.as_ref().unwrap(),
                             self.db_name.as_deref(),
-                            &format!("DROP VIEW `{}`", view.name),
+                            &format!("DROP VIEW {}", quote_ident(&view.name)),
                         )
                         .await?;
                         self.views.remove(i);
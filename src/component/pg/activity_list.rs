@@ -0,0 +1,293 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
+    event::{config::*, Key},
+    model::pg::{get_pg_activities, Activity, Connections},
+    pool::{execute_pg_query, get_pg_pool, PGPools},
+    widget::DataTable,
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use uuid::Uuid;
+
+pub struct ActivityListComponent {
+    state: TableState,
+    conn_id: Option<Uuid>,
+    activities: Vec<Activity>,
+    cancel_dlg: Option<ConfirmDialog>,
+    terminate_dlg: Option<ConfirmDialog>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<PGPools>>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+}
+
+impl ActivityListComponent {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<PGPools>>,
+    ) -> Self {
+        ActivityListComponent {
+            state: TableState::default(),
+            conn_id: None,
+            activities: Vec::new(),
+            cancel_dlg: None,
+            terminate_dlg: None,
+            conns,
+            pools,
+            cmd_bar,
+        }
+    }
+    pub async fn set_data(&mut self, conn_id: &Uuid) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_active: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Activity")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_active {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+
+        let table = DataTable::new(self.activities.iter().map(|a| {
+            vec![
+                Some(a.pid.to_string()),
+                a.user.clone(),
+                a.database.clone(),
+                a.client.clone(),
+                a.state.clone(),
+                a.wait_event.clone(),
+                a.duration_str(),
+                a.query.clone(),
+            ]
+        }))
+        .header(vec![
+            "Pid",
+            "User",
+            "Database",
+            "Client",
+            "State",
+            "Wait Event",
+            "Duration",
+            "Query",
+        ])
+        .row_styles(self.activities.iter().map(|a| {
+            if a.is_idle_in_transaction() {
+                Style::default().fg(Color::Red)
+            } else if a.is_long_running() {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            }
+        }));
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_active {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.cancel_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.terminate_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.cancel_dlg.is_some() {
+            self.handle_cancel_event(key).await
+        } else if self.terminate_dlg.is_some() {
+            self.handle_terminate_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.activities.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.activities.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.activities.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            INTERRUPT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    self.cancel_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Cancel Query",
+                        format!(
+                            "Are you sure to cancel the query of backend {}?",
+                            self.activities[index].pid
+                        )
+                        .as_str(),
+                    ));
+                }
+            }
+            DELETE_KEY => {
+                if let Some(index) = self.state.selected() {
+                    self.terminate_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Terminate Backend",
+                        format!(
+                            "Are you sure to terminate backend {}?",
+                            self.activities[index].pid
+                        )
+                        .as_str(),
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_cancel_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.cancel_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.cancel_dlg = None,
+                DialogResult::Confirm(_) => {
+                    if let Some(index) = self.state.selected() {
+                        execute_pg_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            None,
+                            &self.activities[index].get_cancel_ddl(),
+                        )
+                        .await?;
+                        self.cancel_dlg = None;
+                        self.refresh().await?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_terminate_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.terminate_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.terminate_dlg = None,
+                DialogResult::Confirm(_) => {
+                    if let Some(index) = self.state.selected() {
+                        execute_pg_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            None,
+                            &self.activities[index].get_terminate_ddl(),
+                        )
+                        .await?;
+                        self.terminate_dlg = None;
+                        self.refresh().await?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_pg_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            None,
+        )
+        .await?;
+        self.activities = get_pg_activities(&pool).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.activities.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.cancel_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.terminate_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Cancel Query",
+                    key: INTERRUPT_KEY,
+                },
+                Command {
+                    name: "Terminate Backend",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
@@ -1,21 +1,38 @@
 use crate::{
-    app::{ComponentResult, DialogResult, MainPanel},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
-    dialog::DetailDialog,
+    app::{ComponentResult, DialogResult, Goto, MainPanel},
+    clipboard::copy_to_clipboard,
+    component::{Command, CommandBarComponent, StatusLineComponent},
+    config::Config,
+    dialog::{
+        confirm::Kind as ConfirmKind, BulkUpdateDialog, ConfirmDialog, DetailDialog, ExportDialog,
+        InsertDialog, TextDialog,
+    },
     event::{config::*, Key},
-    model::pg::{convert_show_column_to_pg_fields, get_pg_field_value, Connections, Field},
-    pool::{fetch_one_pg, fetch_pg_query, PGPools},
+    model::{
+        export::{export_pg_query, ExportFormat},
+        pg::{
+            convert_show_column_to_pg_fields, get_pg_field_literal, get_pg_field_text,
+            get_pg_field_value, quote_ident, value_literal, Connections, Field, FieldKind,
+        },
+        table_view::{TableView, TableViews},
+        DisplayFormat,
+    },
+    pool::{
+        execute_pg_query, fetch_one_pg, fetch_pg_query, fetch_pg_query_with_params, get_pg_pool,
+        PGPools,
+    },
+    widget::Grid,
 };
 use anyhow::Result;
-use sqlx::{postgres::PgRow, Row};
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table as TableUI, TableState},
+    widgets::{Block, BorderType, Borders},
     Frame,
 };
+use sqlx::{postgres::PgRow, Row};
+use std::{cell::RefCell, collections::HashMap, fs::File, io::BufWriter, rc::Rc, str::FromStr};
 use uuid::Uuid;
 
 pub struct DataListComponent<'a> {
@@ -23,17 +40,29 @@ pub struct DataListComponent<'a> {
     db_name: Option<String>,
     schema_name: Option<String>,
     table_name: Option<String>,
-    state: TableState,
+    grid: Grid,
     rows: Vec<PgRow>,
     page: usize,
     page_size: usize,
+    display_format: DisplayFormat,
     total_page: usize,
     fields: Vec<Field>,
+    unique_key: Vec<String>,
+    unique_fields: Vec<String>,
+    generated_fields: Vec<String>,
     parent: Option<MainPanel>,
     detail_dlg: Option<DetailDialog<'a>>,
+    text_dlg: Option<TextDialog>,
+    export_dlg: Option<ExportDialog<'a>>,
+    insert_dlg: Option<InsertDialog<'a>>,
+    bulk_update_dlg: Option<BulkUpdateDialog<'a>>,
+    bulk_update_confirm_dlg: Option<ConfirmDialog>,
+    bulk_update_sql: Option<String>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<PGPools>>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    status_line: Rc<RefCell<StatusLineComponent>>,
+    table_views: Rc<RefCell<TableViews>>,
 }
 
 impl<'a> DataListComponent<'a> {
@@ -41,23 +70,38 @@ impl<'a> DataListComponent<'a> {
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<PGPools>>,
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        status_line: Rc<RefCell<StatusLineComponent>>,
+        table_views: Rc<RefCell<TableViews>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         DataListComponent {
             conn_id: None,
             db_name: None,
             schema_name: None,
             table_name: None,
-            state: TableState::default(),
+            grid: Grid::new(config.borrow().get_display_format()),
             parent: None,
             detail_dlg: None,
+            text_dlg: None,
+            export_dlg: None,
+            insert_dlg: None,
+            bulk_update_dlg: None,
+            bulk_update_confirm_dlg: None,
+            bulk_update_sql: None,
             page: 0,
             total_page: 0,
-            page_size: 1000,
+            page_size: config.borrow().get_page_size(),
+            display_format: config.borrow().get_display_format(),
             rows: Vec::new(),
             fields: Vec::new(),
+            unique_key: Vec::new(),
+            unique_fields: Vec::new(),
+            generated_fields: Vec::new(),
             conns,
             pools,
             cmd_bar,
+            status_line,
+            table_views,
         }
     }
     pub async fn set_data(
@@ -73,43 +117,96 @@ impl<'a> DataListComponent<'a> {
         self.schema_name = Some(schema_name.to_string());
         self.table_name = Some(table_name.to_string());
         self.parent = Some(parent);
-        self.state = TableState::default();
-        let fields = fetch_pg_query(
+        self.grid.clear();
+        let relation = format!("{}.{}", quote_ident(schema_name), quote_ident(table_name));
+        let fields = fetch_pg_query_with_params(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             None,
-            &format!(
-                "SELECT
+            "SELECT
                     col_description((table_schema||'.'||table_name)::regclass::oid, ordinal_position) as comment,
                     *
                 FROM
                     information_schema.columns
                 WHERE
-                    table_schema = '{}' AND table_name = '{}'
+                    table_schema = $1 AND table_name = $2
                 ORDER BY ordinal_position ASC",
-                schema_name, table_name
-            ),
-       )
+            &[schema_name, table_name],
+        )
         .await?;
-        let keys = fetch_pg_query(
+        let keys = fetch_pg_query_with_params(
             self.conns.clone(),
             self.pools.clone(),
             conn_id,
             None,
-            &format!(
-                "
+            "
                     SELECT
                         a.attname
                     FROM
                         pg_index i
                     JOIN pg_attribute a
                         ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
-                    WHERE i.indrelid = '{}'::regclass AND i.indisprimary",
-                table_name
-            ),
+                    WHERE i.indrelid = $1::regclass AND i.indisprimary",
+            &[&relation],
+        )
+        .await?;
+        let uniques = fetch_pg_query_with_params(
+            self.conns.clone(),
+            self.pools.clone(),
+            conn_id,
+            None,
+            "
+                    SELECT
+                        a.attname
+                    FROM
+                        pg_index i
+                    JOIN pg_attribute a
+                        ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+                    WHERE i.indexrelid = (
+                        SELECT indexrelid FROM pg_index
+                        WHERE indrelid = $1::regclass
+                            AND indisunique AND NOT indisprimary
+                            AND indexprs IS NULL AND indpred IS NULL
+                        ORDER BY indexrelid LIMIT 1
+                    )",
+            &[&relation],
         )
         .await?;
+        self.unique_key = uniques
+            .iter()
+            .map(|k| k.try_get::<String, _>("attname").unwrap())
+            .collect();
+        self.unique_fields = fetch_pg_query_with_params(
+            self.conns.clone(),
+            self.pools.clone(),
+            conn_id,
+            None,
+            "
+                    SELECT DISTINCT
+                        a.attname
+                    FROM
+                        pg_index i
+                    JOIN pg_attribute a
+                        ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+                    WHERE i.indrelid = $1::regclass AND i.indisunique AND NOT i.indisprimary",
+            &[&relation],
+        )
+        .await?
+        .iter()
+        .map(|k| k.try_get::<String, _>("attname").unwrap())
+        .collect();
+        self.generated_fields = fields
+            .iter()
+            .filter(|r| {
+                r.try_get::<String, _>("is_identity").unwrap() == "YES"
+                    || r.try_get::<String, _>("is_generated").unwrap() == "ALWAYS"
+                    || r.try_get::<Option<String>, _>("column_default")
+                        .unwrap()
+                        .map_or(false, |d| d.starts_with("nextval("))
+            })
+            .map(|r| r.try_get::<String, _>("column_name").unwrap())
+            .collect();
 
         self.fields = convert_show_column_to_pg_fields(
             fields,
@@ -123,17 +220,24 @@ impl<'a> DataListComponent<'a> {
             self.conn_id.as_ref().unwrap(),
             Some(db_name),
             &format!(
-                "SELECT * FROM {} LIMIT 0 OFFSET {}",
-                table_name, self.page_size,
+                "SELECT * FROM {}.{} LIMIT {} OFFSET 0",
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                self.page_size,
             ),
         )
         .await?;
+        self.update_grid();
         let total_count: i64 = fetch_one_pg(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             Some(db_name),
-            &format!("SELECT count(*) FROM {}", table_name),
+            &format!(
+                "SELECT count(*) FROM {}.{}",
+                quote_ident(schema_name),
+                quote_ident(table_name)
+            ),
         )
         .await?
         .unwrap()
@@ -142,6 +246,7 @@ impl<'a> DataListComponent<'a> {
 
         self.page = if total_count > 0 { 1 } else { 0 };
         self.total_page = (total_count as f64 / self.page_size as f64).ceil() as usize;
+        self.restore_view().await?;
 
         Ok(())
     }
@@ -149,7 +254,9 @@ impl<'a> DataListComponent<'a> {
     where
         B: Backend,
     {
-        f.render_widget(
+        self.grid.draw(
+            f,
+            r,
             Block::default()
                 .title(format!(
                     "{} ({}/{})",
@@ -166,50 +273,22 @@ impl<'a> DataListComponent<'a> {
                 } else {
                     Style::default()
                 }),
-            r,
-        );
-        let columns = &self
-            .fields
-            .iter()
-            .map(|_c| Constraint::Ratio(1, self.fields.len() as u32))
-            .collect::<Vec<Constraint>>();
-
-        let table = TableUI::new(
-            self.rows
-                .iter()
-                .map(|r| {
-                    let d = self
-                        .fields
-                        .iter()
-                        .map(|field| get_pg_field_value(field, r))
-                        .collect::<Vec<String>>();
-                    RowUI::new(d)
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(
-            self.fields
-                .iter()
-                .clone()
-                .map(|field| field.name().to_string())
-                .collect::<Vec<String>>(),
-        ))
-        .block(Block::default())
-        .widths(&columns[..])
-        .highlight_style(Style::default().fg(Color::Green));
-
-        f.render_stateful_widget(
-            table,
-            r.inner(&Margin {
-                vertical: 1,
-                horizontal: 1,
-            }),
-            &mut self.state,
         );
         if is_focus {
             self.update_commands();
         }
     }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.detail_dlg.is_none()
+            && self.text_dlg.is_none()
+            && self.export_dlg.is_none()
+            && self.insert_dlg.is_none()
+            && self.bulk_update_dlg.is_none()
+            && self.bulk_update_confirm_dlg.is_none()
+        {
+            self.grid.handle_click(x, y);
+        }
+    }
     pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
     where
         B: Backend,
@@ -217,12 +296,77 @@ impl<'a> DataListComponent<'a> {
         if let Some(dlg) = self.detail_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.insert_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.bulk_update_dlg.as_mut() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.bulk_update_confirm_dlg.as_ref() {
+            dlg.draw(f);
+        }
     }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if self.detail_dlg.is_some() {
             self.handle_detail_dlg_event(key).await
+        } else if self.text_dlg.is_some() {
+            self.handle_text_dlg_event(key).await
+        } else if self.export_dlg.is_some() {
+            self.handle_export_dlg_event(key).await
+        } else if self.insert_dlg.is_some() {
+            self.handle_insert_dlg_event(key).await
+        } else if self.bulk_update_dlg.is_some() {
+            self.handle_bulk_update_dlg_event(key).await
+        } else if self.bulk_update_confirm_dlg.is_some() {
+            self.handle_bulk_update_confirm_dlg_event(key).await
         } else {
-            self.handle_main_event(key).await
+            let result = self.handle_main_event(key).await;
+            self.save_view();
+            result
+        }
+    }
+    async fn restore_view(&mut self) -> Result<()> {
+        let view = self
+            .table_views
+            .borrow()
+            .get(
+                self.conn_id.as_ref().unwrap(),
+                self.db_name.as_ref().unwrap(),
+                self.schema_name.as_deref(),
+                self.table_name.as_ref().unwrap(),
+            )
+            .cloned();
+        if let Some(view) = view {
+            self.grid.select_column(view.column);
+            if view.page > 1 && view.page <= self.total_page {
+                self.page = view.page;
+                self.refresh().await?;
+            }
+        }
+        Ok(())
+    }
+    fn save_view(&self) {
+        if let (Some(conn_id), Some(db_name), Some(table_name)) = (
+            self.conn_id.as_ref(),
+            self.db_name.as_ref(),
+            self.table_name.as_ref(),
+        ) {
+            self.table_views.borrow_mut().set(
+                conn_id,
+                db_name,
+                self.schema_name.as_deref(),
+                table_name,
+                TableView {
+                    page: self.page,
+                    column: self.grid.column_index(),
+                },
+            );
         }
     }
     async fn handle_detail_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
@@ -239,20 +383,193 @@ impl<'a> DataListComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
-    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
-        match *key {
-            UP_KEY => {
-                if !self.rows.is_empty() {
-                    let index = get_table_up_index(self.state.selected());
-                    self.state.select(Some(index));
+    async fn handle_text_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.text_dlg.as_mut() {
+            if let DialogResult::Cancel = dlg.handle_event(key) {
+                self.text_dlg = None;
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_export_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.export_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.export_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let path = map.get("file").unwrap().clone().unwrap();
+                    let format = ExportFormat::from_str(
+                        map.get("format").unwrap().as_deref().unwrap_or_default(),
+                    )?;
+                    let count = self.export(&path, &format).await?;
+                    self.export_dlg = None;
+                    self.status_line
+                        .borrow_mut()
+                        .info(format!("Exported {} rows to {}", count, path).as_str());
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_insert_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.insert_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.insert_dlg = None;
+                }
+                DialogResult::Confirm(map) => {
+                    let table = format!(
+                        "{}.{}",
+                        quote_ident(self.schema_name.as_ref().unwrap()),
+                        quote_ident(self.table_name.as_ref().unwrap())
+                    );
+                    let fields = self
+                        .fields
+                        .iter()
+                        .filter(|field| map.contains_key(field.name()))
+                        .collect::<Vec<&Field>>();
+                    let sql = if fields.is_empty() {
+                        format!("INSERT INTO {} DEFAULT VALUES", table)
+                    } else {
+                        format!(
+                            "INSERT INTO {} ({}) VALUES ({})",
+                            table,
+                            fields
+                                .iter()
+                                .map(|field| quote_ident(field.name()))
+                                .collect::<Vec<String>>()
+                                .join(", "),
+                            fields
+                                .iter()
+                                .map(|field| value_literal(map[field.name()].as_deref()))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        )
+                    };
+                    execute_pg_query(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        self.db_name.as_deref(),
+                        &sql,
+                    )
+                    .await?;
+                    self.insert_dlg = None;
+                    if self.page == 0 {
+                        self.page = 1;
+                    }
+                    self.refresh().await?;
+                    self.status_line.borrow_mut().info(
+                        format!("Inserted 1 row into {}", self.table_name.as_ref().unwrap())
+                            .as_str(),
+                    );
                 }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_bulk_update_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.bulk_update_dlg.as_mut() {
+            match dlg.handle_event(key)? {
+                DialogResult::Cancel => {
+                    self.bulk_update_dlg = None;
+                }
+                DialogResult::Confirm((column, value, condition)) => {
+                    let table = format!(
+                        "{}.{}",
+                        quote_ident(self.schema_name.as_ref().unwrap()),
+                        quote_ident(self.table_name.as_ref().unwrap())
+                    );
+                    let sql = format!(
+                        "UPDATE {} SET {} = {} WHERE {}",
+                        table,
+                        quote_ident(&column),
+                        value_literal(value.as_deref()),
+                        condition
+                    );
+                    let count: i64 = fetch_one_pg(
+                        self.conns.clone(),
+                        self.pools.clone(),
+                        self.conn_id.as_ref().unwrap(),
+                        self.db_name.as_deref(),
+                        &format!("SELECT count(*) FROM {} WHERE {}", table, condition),
+                    )
+                    .await?
+                    .unwrap()
+                    .try_get(0)
+                    .unwrap();
+                    self.bulk_update_confirm_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Bulk Update",
+                        &format!("{} rows will be updated by: {}", count, sql),
+                    ));
+                    self.bulk_update_sql = Some(sql);
+                    self.bulk_update_dlg = None;
+                }
+                _ => (),
             }
-            DOWN_KEY => {
-                if !self.rows.is_empty() {
-                    let index = get_table_down_index(self.state.selected(), self.rows.len());
-                    self.state.select(Some(index));
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_bulk_update_confirm_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.bulk_update_confirm_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.bulk_update_confirm_dlg = None;
+                    self.bulk_update_sql = None;
+                }
+                DialogResult::Confirm(_) => {
+                    if let Some(sql) = self.bulk_update_sql.take() {
+                        execute_pg_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &sql,
+                        )
+                        .await?;
+                        self.bulk_update_confirm_dlg = None;
+                        self.refresh().await?;
+                        self.status_line.borrow_mut().info(
+                            format!("Updated rows in {}", self.table_name.as_ref().unwrap())
+                                .as_str(),
+                        );
+                    }
                 }
+                _ => (),
             }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn export(&self, path: &str, format: &ExportFormat) -> Result<usize> {
+        let pool = get_pg_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+        )
+        .await?;
+        let mut serializer = format.new_serializer(Box::new(BufWriter::new(File::create(path)?)));
+        export_pg_query(
+            &pool,
+            &format!(
+                "SELECT * FROM {}.{}",
+                quote_ident(self.schema_name.as_ref().unwrap()),
+                quote_ident(self.table_name.as_ref().unwrap())
+            ),
+            serializer.as_mut(),
+            &format.get_display_format(&self.display_format),
+        )
+        .await
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.grid.handle_event(key) {
+            return Ok(ComponentResult::Done);
+        }
+        match *key {
             PAGE_NEXT_KEY => {
                 if self.page < self.total_page {
                     self.page += 1;
@@ -269,15 +586,133 @@ impl<'a> DataListComponent<'a> {
                 return Ok(ComponentResult::Back(self.parent.clone().unwrap()));
             }
             CONFIRM_KEY => {
-                if let Some(index) = self.state.selected() {
+                if let Some(index) = self.grid.selected() {
                     let detail_dlg = DetailDialog::from_pg_row(
                         self.table_name.as_ref().unwrap().to_string(),
                         &self.fields,
                         &self.rows[index],
+                        &self.display_format,
                     );
                     self.detail_dlg = Some(detail_dlg);
                 }
             }
+            EXPAND_KEY => {
+                if let Some((header, value)) = self.grid.get_selected_cell() {
+                    self.text_dlg = Some(TextDialog::new(
+                        header,
+                        value.unwrap_or(&self.display_format.null_text),
+                    ));
+                }
+            }
+            COPY_MARKDOWN_KEY => {
+                copy_to_clipboard(&self.grid.to_markdown())?;
+                self.status_line
+                    .borrow_mut()
+                    .info(format!("Copied {} rows as Markdown table", self.grid.len()).as_str());
+            }
+            NEW_KEY => {
+                self.insert_dlg = Some(InsertDialog::from_pg_fields(
+                    format!("Insert Into {}", self.table_name.as_ref().unwrap()),
+                    &self.fields,
+                    &self.generated_fields,
+                ));
+            }
+            CLONE_ROW_KEY => {
+                if let Some(index) = self.grid.selected() {
+                    // Key and unique columns are cleared, ones without a text form are left out.
+                    let mut values = HashMap::new();
+                    let mut uncopied = Vec::new();
+                    for field in self.fields.iter().filter(|field| {
+                        !field.key()
+                            && !self.unique_fields.iter().any(|k| k == field.name())
+                            && !self.generated_fields.iter().any(|g| g == field.name())
+                    }) {
+                        match get_pg_field_text(field, &self.rows[index]) {
+                            Ok(value) => {
+                                values.insert(field.name().to_string(), value);
+                            }
+                            Err(_) => uncopied.push(field.name().to_string()),
+                        }
+                    }
+                    if !uncopied.is_empty() {
+                        self.status_line.borrow_mut().warning(&format!(
+                            "Not cloned, left to their defaults: {}",
+                            uncopied.join(", ")
+                        ));
+                    }
+                    self.insert_dlg = Some(InsertDialog::from_pg_values(
+                        format!("Clone Row Into {}", self.table_name.as_ref().unwrap()),
+                        &self.fields,
+                        &[self.generated_fields.clone(), uncopied].concat(),
+                        &values,
+                    ));
+                }
+            }
+            BULK_UPDATE_KEY => {
+                if !self.fields.is_empty() {
+                    self.bulk_update_dlg = Some(BulkUpdateDialog::new(
+                        self.table_name.as_ref().unwrap(),
+                        self.fields
+                            .iter()
+                            .map(|field| field.name().to_string())
+                            .collect(),
+                        self.fields
+                            .get(self.grid.column_index())
+                            .map(|field| field.name().to_string()),
+                    ));
+                }
+            }
+            EXPORT_KEY => {
+                self.export_dlg = Some(ExportDialog::new_data(self.table_name.as_ref().unwrap()));
+            }
+            COPY_WHERE_KEY => {
+                if let Some((condition, unique)) = self.get_selected_row_condition() {
+                    copy_to_clipboard(&condition)?;
+                    if unique {
+                        self.status_line
+                            .borrow_mut()
+                            .info(format!("Copied {}", condition).as_str());
+                    } else {
+                        self.status_line.borrow_mut().warning(
+                            format!("Copied {}, it may match duplicate rows", condition).as_str(),
+                        );
+                    }
+                }
+            }
+            QUERY_ROW_KEY => {
+                if let Some((condition, unique)) = self.get_selected_row_condition() {
+                    if !unique {
+                        self.status_line.borrow_mut().warning(
+                            "Table has no unique key, the query may return duplicate rows",
+                        );
+                    }
+                    let sql = format!(
+                        "SELECT * FROM {}.{} {};",
+                        quote_ident(self.schema_name.as_ref().unwrap()),
+                        quote_ident(self.table_name.as_ref().unwrap()),
+                        condition
+                    );
+                    return Ok(ComponentResult::Goto(Goto::QueryDetailPG {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        query_name: None,
+                        sql: Some(sql),
+                    }));
+                }
+            }
+            QUERY_KEY => {
+                let sql = format!(
+                    "SELECT * FROM {}.{} LIMIT 100;",
+                    quote_ident(self.schema_name.as_ref().unwrap()),
+                    quote_ident(self.table_name.as_ref().unwrap())
+                );
+                return Ok(ComponentResult::Goto(Goto::QueryDetailPG {
+                    conn_id: self.conn_id.unwrap(),
+                    db_name: self.db_name.clone().unwrap(),
+                    query_name: None,
+                    sql: Some(sql),
+                }));
+            }
             REFRESH_KEY => {
                 self.refresh().await?;
             }
@@ -285,6 +720,82 @@ impl<'a> DataListComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    fn get_selected_row_condition(&self) -> Option<(String, bool)> {
+        let row = &self.rows[self.grid.selected()?];
+        let get_value = |field: &Field| get_pg_field_literal(field, row);
+        // Only called with fields whose values decoded, so a failed decode never becomes IS NULL.
+        let condition = |fields: Vec<&Field>| {
+            fields
+                .iter()
+                .map(|field| match get_value(field).ok().flatten() {
+                    Some(value) => format!("{} = {}", quote_ident(field.name()), value),
+                    None => format!("{} IS NULL", quote_ident(field.name())),
+                })
+                .collect::<Vec<String>>()
+                .join(" AND ")
+        };
+        let keys = self
+            .fields
+            .iter()
+            .filter(|field| field.key())
+            .collect::<Vec<&Field>>();
+        if !keys.is_empty() {
+            if keys.iter().any(|field| get_value(field).is_err()) {
+                self.status_line
+                    .borrow_mut()
+                    .warning("Primary key value of the row could not be decoded");
+                return None;
+            }
+            return Some((format!("WHERE {}", condition(keys)), true));
+        }
+        let uniques = self
+            .fields
+            .iter()
+            .filter(|field| self.unique_key.iter().any(|k| k == field.name()))
+            .collect::<Vec<&Field>>();
+        if !uniques.is_empty()
+            && uniques
+                .iter()
+                .all(|field| matches!(get_value(field), Ok(Some(_))))
+        {
+            return Some((format!("WHERE {}", condition(uniques)), true));
+        }
+        let comparable = self
+            .fields
+            .iter()
+            .filter(|field| field.is_comparable() && get_value(field).is_ok())
+            .collect::<Vec<&Field>>();
+        if comparable.is_empty() {
+            self.status_line
+                .borrow_mut()
+                .warning("Table has no primary key or comparable columns to identify the row");
+            return None;
+        }
+        Some((format!("WHERE {}", condition(comparable)), false))
+    }
+    fn update_grid(&mut self) {
+        let values = self
+            .rows
+            .iter()
+            .map(|r| {
+                self.fields
+                    .iter()
+                    .map(|field| get_pg_field_value(field, r, &self.display_format))
+                    .collect()
+            })
+            .collect();
+        self.grid.set_data(
+            self.fields
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect(),
+            self.fields
+                .iter()
+                .map(|field| *field.kind() == FieldKind::Bool && !field.is_array())
+                .collect(),
+            values,
+        );
+    }
     pub async fn refresh(&mut self) -> Result<()> {
         self.rows = fetch_pg_query(
             self.conns.clone(),
@@ -292,19 +803,25 @@ impl<'a> DataListComponent<'a> {
             self.conn_id.as_ref().unwrap(),
             self.db_name.as_deref(),
             &format!(
-                "SELECT * FROM {} LIMIT {} OFFSET {}",
-                self.table_name.as_ref().unwrap(),
+                "SELECT * FROM {}.{} LIMIT {} OFFSET {}",
+                quote_ident(self.schema_name.as_ref().unwrap()),
+                quote_ident(self.table_name.as_ref().unwrap()),
                 self.page_size,
                 (self.page - 1) * self.page_size,
             ),
         )
         .await?;
+        self.update_grid();
         let total_count: i64 = fetch_one_pg(
             self.conns.clone(),
             self.pools.clone(),
             self.conn_id.as_ref().unwrap(),
             self.db_name.as_deref(),
-            &format!("SELECT count(*) FROM {}", self.table_name.as_ref().unwrap(),),
+            &format!(
+                "SELECT count(*) FROM {}.{}",
+                quote_ident(self.schema_name.as_ref().unwrap()),
+                quote_ident(self.table_name.as_ref().unwrap())
+            ),
         )
         .await?
         .unwrap()
@@ -316,6 +833,16 @@ impl<'a> DataListComponent<'a> {
     fn update_commands(&mut self) {
         let mut cmds = if let Some(dlg) = self.detail_dlg.as_mut() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.text_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.export_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.insert_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.bulk_update_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.bulk_update_confirm_dlg.as_ref() {
+            dlg.get_commands()
         } else {
             self.get_main_commands()
         };
@@ -331,6 +858,14 @@ impl<'a> DataListComponent<'a> {
                 name: "Down",
                 key: DOWN_KEY,
             },
+            Command {
+                name: "Left",
+                key: LEFT_KEY,
+            },
+            Command {
+                name: "Right",
+                key: RIGHT_KEY,
+            },
             Command {
                 name: "Page Next",
                 key: PAGE_NEXT_KEY,
@@ -340,12 +875,54 @@ impl<'a> DataListComponent<'a> {
                 key: PAGE_PRIV_KEY,
             },
         ];
-        if self.state.selected().is_some() {
-            cmds.append(&mut vec![Command {
-                name: "Open",
-                key: CONFIRM_KEY,
-            }]);
+        if self.grid.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Open",
+                    key: CONFIRM_KEY,
+                },
+                Command {
+                    name: "Expand Cell",
+                    key: EXPAND_KEY,
+                },
+                Command {
+                    name: "Copy Where",
+                    key: COPY_WHERE_KEY,
+                },
+                Command {
+                    name: "Query Row",
+                    key: QUERY_ROW_KEY,
+                },
+                Command {
+                    name: "Clone Row",
+                    key: CLONE_ROW_KEY,
+                },
+            ]);
         }
+        if !self.grid.is_empty() {
+            cmds.push(Command {
+                name: "Copy as Markdown",
+                key: COPY_MARKDOWN_KEY,
+            });
+        }
+        cmds.push(Command {
+            name: "Insert Row",
+            key: NEW_KEY,
+        });
+        if !self.fields.is_empty() {
+            cmds.push(Command {
+                name: "Bulk Update",
+                key: BULK_UPDATE_KEY,
+            });
+        }
+        cmds.push(Command {
+            name: "Export Data",
+            key: EXPORT_KEY,
+        });
+        cmds.push(Command {
+            name: "New Query",
+            key: QUERY_KEY,
+        });
         cmds.push(Command {
             name: "Refresh",
             key: REFRESH_KEY,
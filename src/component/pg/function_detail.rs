@@ -0,0 +1,238 @@
+use crate::{
+    app::{ComponentResult, DialogResult, MainPanel},
+    component::{Command, CommandBarComponent},
+    dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
+    event::{config::*, Key},
+    model::pg::{get_pg_function, get_pg_function_definition, quote_ident, Connections, Function},
+    pool::{execute_pg_query_unprepared, get_pg_pool, PGPools},
+};
+use anyhow::{Error, Result};
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use tui_textarea::{Input, TextArea};
+use uuid::Uuid;
+
+pub struct FunctionDetailComponent<'a> {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    schema_name: Option<String>,
+    function: Option<Function>,
+    definition: TextArea<'a>,
+    exit_dlg: Option<ConfirmDialog>,
+    info_dlg: Option<ConfirmDialog>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<PGPools>>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+}
+
+impl<'a> FunctionDetailComponent<'a> {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<PGPools>>,
+    ) -> Self {
+        FunctionDetailComponent {
+            conn_id: None,
+            db_name: None,
+            schema_name: None,
+            function: None,
+            definition: TextArea::default(),
+            exit_dlg: None,
+            info_dlg: None,
+            conns,
+            pools,
+            cmd_bar,
+        }
+    }
+    pub async fn set_data(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: &str,
+        function_oid: Option<i64>,
+    ) -> Result<()> {
+        self.clear();
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        self.schema_name = Some(schema_name.to_string());
+        if let Some(oid) = function_oid {
+            let pool = get_pg_pool(
+                self.conns.clone(),
+                self.pools.clone(),
+                conn_id,
+                Some(db_name),
+            )
+            .await?;
+            self.function = Some(get_pg_function(&pool, schema_name, oid).await?);
+            let definition = get_pg_function_definition(&pool, oid).await?;
+            self.definition = TextArea::from(definition.lines());
+        } else {
+            self.definition = TextArea::from(
+                format!(
+                    "CREATE OR REPLACE FUNCTION {}.new_function()\nRETURNS void\nLANGUAGE plpgsql\nAS $function$\nBEGIN\n\nEND;\n$function$",
+                    quote_ident(schema_name)
+                )
+                .lines(),
+            );
+        }
+        Ok(())
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title(if let Some(function) = self.function.as_ref() {
+                    format!(
+                        "Edit {} `{}({})`",
+                        function.kind.to_lowercase(),
+                        function.name,
+                        function.arguments
+                    )
+                } else {
+                    "New Function".to_string()
+                })
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        f.render_widget(
+            self.definition.widget(),
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.exit_dlg.as_ref() {
+            dlg.draw(f);
+        }
+        if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.exit_dlg.is_some() {
+            self.handle_exit_dlg_event(key)
+        } else if self.info_dlg.is_some() {
+            self.handle_info_dlg_event(key)
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            BACK_KEY => {
+                self.exit_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Confirm,
+                    "Exit",
+                    "Be sure to exit?",
+                ));
+            }
+            SAVE_KEY => {
+                self.handle_save().await?;
+            }
+            _ => {
+                let key: Input = key.to_owned().into();
+                self.definition.input(key);
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_save(&mut self) -> Result<()> {
+        let sql = self.definition.lines().join("\n");
+        let sql = sql.trim();
+        if sql.is_empty() {
+            return Err(Error::msg("no function definition"));
+        }
+        execute_pg_query_unprepared(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+            sql,
+        )
+        .await?;
+        self.info_dlg = Some(ConfirmDialog::new(
+            ConfirmKind::Info,
+            "Success",
+            "Save Success",
+        ));
+        Ok(())
+    }
+    fn handle_exit_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.exit_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.exit_dlg = None,
+                DialogResult::Confirm(_) => {
+                    self.clear();
+                    return Ok(ComponentResult::BackRefresh(MainPanel::FunctionListPG));
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn handle_info_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.info_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => self.info_dlg = None,
+                DialogResult::Confirm(_) => self.info_dlg = None,
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    fn clear(&mut self) {
+        self.conn_id = None;
+        self.db_name = None;
+        self.schema_name = None;
+        self.function = None;
+        self.definition = TextArea::default();
+        self.exit_dlg = None;
+        self.info_dlg = None;
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.exit_dlg.as_ref() {
+            dlg.get_commands()
+        } else if let Some(dlg) = self.info_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Back",
+                key: BACK_KEY,
+            },
+            Command {
+                name: "Save",
+                key: SAVE_KEY,
+            },
+        ]
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus, Goto},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::confirm::{ConfirmDialog, Kind as ConfirmKind},
+    event::{config::*, Key},
+    model::pg::{get_pg_functions, Connections, Function},
+    pool::{execute_pg_query, get_pg_pool, PGPools},
+    widget::DataTable,
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use uuid::Uuid;
+
+pub struct FunctionListComponent {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    schema_name: Option<String>,
+    functions: Vec<Function>,
+    state: TableState,
+    delete_dlg: Option<ConfirmDialog>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<PGPools>>,
+}
+
+impl FunctionListComponent {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<PGPools>>,
+    ) -> Self {
+        FunctionListComponent {
+            conn_id: None,
+            db_name: None,
+            schema_name: None,
+            functions: Vec::new(),
+            state: TableState::default(),
+            delete_dlg: None,
+            cmd_bar,
+            conns,
+            pools,
+        }
+    }
+    pub async fn set_data(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: &str,
+    ) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        self.schema_name = Some(schema_name.to_string());
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Functions")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        let table = DataTable::new(self.functions.iter().map(|function| {
+            vec![
+                function.name.clone(),
+                function.kind.clone(),
+                function.arguments.clone(),
+                function.result.clone().unwrap_or_default(),
+                function.language.clone(),
+                function.owner.clone(),
+                function.comment.clone().unwrap_or_default(),
+            ]
+        }))
+        .header(vec![
+            "Name",
+            "Type",
+            "Arguments",
+            "Returns",
+            "Language",
+            "Owner",
+            "Comment",
+        ]);
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.functions.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.functions.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.functions.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            NEW_KEY => {
+                return Ok(ComponentResult::Goto(Goto::FunctionDetailPG {
+                    conn_id: self.conn_id.unwrap(),
+                    db_name: self.db_name.clone().unwrap(),
+                    schema_name: self.schema_name.clone().unwrap(),
+                    function_oid: None,
+                }));
+            }
+            CONFIRM_KEY | EDIT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    return Ok(ComponentResult::Goto(Goto::FunctionDetailPG {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        schema_name: self.schema_name.clone().unwrap(),
+                        function_oid: Some(self.functions[index].oid),
+                    }));
+                }
+            }
+            DELETE_KEY => {
+                if self.state.selected().is_some() {
+                    self.delete_dlg = Some(ConfirmDialog::new(
+                        ConfirmKind::Warning,
+                        "Delete Function",
+                        "Are you sure to delete this function?",
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_pg_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+        )
+        .await?;
+        self.functions = get_pg_functions(&pool, self.schema_name.as_ref().unwrap()).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.functions.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    async fn handle_delete_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.delete_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.delete_dlg = None;
+                }
+                DialogResult::Confirm(_) => {
+                    if let Some(i) = self.state.selected() {
+                        execute_pg_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &self.functions[i].get_drop_ddl(self.schema_name.as_ref().unwrap()),
+                        )
+                        .await?;
+                        self.functions.remove(i);
+                        self.delete_dlg = None;
+                        self.state.select(None);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.delete_dlg.is_some() {
+            self.handle_delete_dlg_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.delete_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "New Function",
+                key: NEW_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Edit Function",
+                    key: EDIT_KEY,
+                },
+                Command {
+                    name: "Delete Function",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
@@ -1,17 +1,25 @@
+mod activity_list;
 mod data_list;
+mod function_detail;
+mod function_list;
 mod query_detail;
 mod role_detail;
 mod role_list;
+mod sequence_list;
 mod table_detail;
 mod table_list;
 mod view_detail;
 mod view_list;
 
 pub use self::{
+    activity_list::ActivityListComponent as ActivityListComponentPG,
     data_list::DataListComponent as DataListComponentPG,
+    function_detail::FunctionDetailComponent as FunctionDetailComponentPG,
+    function_list::FunctionListComponent as FunctionListComponentPG,
     query_detail::QueryDetailComponent as QueryDetailComponentPG,
     role_detail::RoleDetailComponent as RoleDetailComponentPG,
     role_list::RoleListComponent as RoleListComponentPG,
+    sequence_list::SequenceListComponent as SequenceListComponentPG,
     table_detail::TableDetailComponent as TableDetailComponentPG,
     table_list::TableListComponent as TableListComponentPG,
     view_detail::ViewDetailComponent as ViewDetailComponentPG,
@@ -1,31 +1,35 @@
 use crate::{
     app::{ComponentResult, DialogResult, MainPanel},
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    config::Config,
     dialog::{
         confirm::Kind as ConfirmKind,
         pg::{PrivilegeDialog, RoleMemberDialog},
         ConfirmDialog, InputDialog,
     },
     event::{config::*, Key},
-    model::pg::{
-        get_pg_role, get_pg_role_member_ofs, get_pg_role_members, get_pg_role_privileges,
-        get_pg_roles, Connections, Privilege, Role, RoleMember,
+    model::{
+        pg::{
+            get_pg_role, get_pg_role_member_ofs, get_pg_role_members, get_pg_role_privileges,
+            get_pg_roles, quote_ident, quote_literal, Connections, Privilege, Role, RoleMember,
+        },
+        DisplayFormat,
     },
     pool::{execute_pg_query_unprepared, get_pg_pool, PGPools},
-    widget::{Form, FormItem},
+    widget::{DataTable, Form, FormItem},
 };
 use anyhow::Result;
 use chrono::{Local, NaiveDateTime, TimeZone};
-use sqlx::postgres::types::Oid;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState, Tabs},
+    widgets::{Block, BorderType, Borders, TableState, Tabs},
     Frame,
 };
+use sqlx::postgres::types::Oid;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use tui_textarea::{Input, TextArea};
 use uuid::Uuid;
 
@@ -64,6 +68,7 @@ pub struct RoleDetailComponent<'a> {
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<PGPools>>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    display_format: DisplayFormat,
 }
 
 impl<'a> RoleDetailComponent<'a> {
@@ -71,6 +76,7 @@ impl<'a> RoleDetailComponent<'a> {
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<PGPools>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         RoleDetailComponent {
             conn_id: None,
@@ -98,6 +104,7 @@ impl<'a> RoleDetailComponent<'a> {
             conns,
             pools,
             cmd_bar,
+            display_format: config.borrow().get_display_format(),
         }
     }
     pub async fn set_data(&mut self, conn_id: &Uuid, role_name: Option<&str>) -> Result<()> {
@@ -325,19 +332,15 @@ impl<'a> RoleDetailComponent<'a> {
             .split(r);
         self.form.draw(f, chunks[0]);
     }
-    fn bool_str(&self, val: bool) -> &'static str {
-        if val {
-            "\u{2705}"
-        } else {
-            "\u{274E}"
-        }
+    fn bool_str(&self, val: bool) -> &str {
+        self.display_format.format_bool(val)
     }
     fn draw_privileges<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(self.privileges.iter().map(|p| {
-            RowUI::new([
+        let table = DataTable::new(self.privileges.iter().map(|p| {
+            [
                 p.db.as_str(),
                 p.schema.as_str(),
                 p.name.as_str(),
@@ -348,9 +351,10 @@ impl<'a> RoleDetailComponent<'a> {
                 self.bool_str(p.trigger),
                 self.bool_str(p.truncate),
                 self.bool_str(p.update),
-            ])
+            ]
         }))
-        .header(RowUI::new([
+        .display_format(&self.display_format)
+        .header([
             "Database",
             "Schema",
             "Name",
@@ -361,71 +365,37 @@ impl<'a> RoleDetailComponent<'a> {
             "Trigger",
             "Truncate",
             "Update",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-            Constraint::Ratio(1, 10),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.privileges_state);
     }
     fn draw_member_of<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(self.member_ofs.iter().map(|rm| {
-            RowUI::new(vec![
+        let table = DataTable::new(self.member_ofs.iter().map(|rm| {
+            vec![
                 rm.role_name.as_deref().unwrap(),
-                if rm.granted { "\u{2705}" } else { "\u{274E}" },
-                if rm.admin_option {
-                    "\u{2705}"
-                } else {
-                    "\u{274E}"
-                },
-            ])
+                self.bool_str(rm.granted),
+                self.bool_str(rm.admin_option),
+            ]
         }))
-        .header(RowUI::new(vec!["Role Name", "Granted", "Admin Option"]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(vec!["Role Name", "Granted", "Admin Option"]);
         f.render_stateful_widget(table, r, &mut self.member_ofs_state);
     }
     fn draw_members<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(self.members.iter().map(|m| {
-            RowUI::new(vec![
+        let table = DataTable::new(self.members.iter().map(|m| {
+            vec![
                 m.member_name.as_deref().unwrap(),
-                if m.granted { "\u{2705}" } else { "\u{274E}" },
-                if m.admin_option {
-                    "\u{2705}"
-                } else {
-                    "\u{274E}"
-                },
-            ])
+                self.bool_str(m.granted),
+                self.bool_str(m.admin_option),
+            ]
         }))
-        .header(RowUI::new(vec!["Role Name", "Granted", "Admin Option"]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        .display_format(&self.display_format)
+        .header(vec!["Role Name", "Granted", "Admin Option"]);
         f.render_stateful_widget(table, r, &mut self.members_state);
     }
     fn draw_comment<B>(&mut self, f: &mut Frame<B>, r: Rect)
@@ -642,6 +612,7 @@ impl<'a> RoleDetailComponent<'a> {
                         self.conns.clone(),
                         self.pools.clone(),
                         &self.conn_id.unwrap(),
+                        None,
                         &sql,
                     )
                     .await?;
@@ -670,6 +641,7 @@ impl<'a> RoleDetailComponent<'a> {
                     self.conns.clone(),
                     self.pools.clone(),
                     &self.conn_id.unwrap(),
+                    None,
                     sql,
                 )
                 .await?;
@@ -893,7 +865,11 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOSUPERUSER"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.inherit() != old_role.inherit() {
             let str = if role.inherit() {
@@ -901,7 +877,11 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOINHERIT"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.create_role() != old_role.create_role() {
             let str = if role.create_role() {
@@ -909,7 +889,11 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOCREATEROLE"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.create_db() != old_role.create_db() {
             let str = if role.create_db() {
@@ -917,11 +901,19 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOCREATEDB"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.can_login() != old_role.can_login() {
             let str = if role.can_login() { "LOGIN" } else { "NOLOGIN" };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.replication() != old_role.replication() {
             let str = if role.replication() {
@@ -929,12 +921,16 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOREPLICATION"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.conn_limit() != old_role.conn_limit() {
             ddl.push(format!(
-                "ALTER ROLE \"{}\" WITH CONNECTION LIIMIT {}",
-                role.name(),
+                "ALTER ROLE {} WITH CONNECTION LIIMIT {}",
+                quote_ident(role.name()),
                 role.conn_limit()
             ));
         }
@@ -944,22 +940,29 @@ impl<'a> RoleDetailComponent<'a> {
             } else {
                 "NOBYPASSRLS"
             };
-            ddl.push(format!("ALTER ROLE \"{}\" WITH {}", role.name(), str));
+            ddl.push(format!(
+                "ALTER ROLE {} WITH {}",
+                quote_ident(role.name()),
+                str
+            ));
         }
         if role.expiry_date() != old_role.expiry_date() {
             ddl.push(format!(
-                "ALTER ROLE \"{}\" WITH VALID UNTIL '{}'",
-                role.name(),
-                role.expiry_date()
-                    .map(|ed| format!("{}", ed.format("%Y-%m-%d %H:%M:%S")))
-                    .unwrap_or("infinity".to_string()),
+                "ALTER ROLE {} WITH VALID UNTIL {}",
+                quote_ident(role.name()),
+                quote_literal(
+                    &role
+                        .expiry_date()
+                        .map(|ed| format!("{}", ed.format("%Y-%m-%d %H:%M:%S")))
+                        .unwrap_or("infinity".to_string())
+                )
             ));
         }
         if role.comment() != old_role.comment() {
             ddl.push(format!(
-                "COMMENT ON ROLE \"{}\" IS '{}'",
-                role.name(),
-                role.comment()
+                "COMMENT ON ROLE {} IS {}",
+                quote_ident(role.name()),
+                quote_literal(role.comment())
             ));
         }
 
@@ -1062,24 +1065,24 @@ impl<'a> RoleDetailComponent<'a> {
             .member_ofs
             .iter()
             .filter(|mo| mo.granted)
-            .map(|mo| format!("IN ROLE \"{}\"", mo.role_name.as_ref().unwrap()))
+            .map(|mo| format!("IN ROLE {}", quote_ident(mo.role_name.as_ref().unwrap())))
             .collect();
         let roles: Vec<String> = self
             .members
             .iter()
             .filter(|ms| ms.granted && !ms.admin_option)
-            .map(|ms| format!("ROLE \"{}\"", ms.member_name.as_ref().unwrap()))
+            .map(|ms| format!("ROLE {}", quote_ident(ms.member_name.as_ref().unwrap())))
             .collect();
         let admin_roles: Vec<String> = self
             .members
             .iter()
             .filter(|ms| ms.granted && ms.admin_option)
-            .map(|ms| format!("ADMIN \"{}\"", ms.member_name.as_ref().unwrap()))
+            .map(|ms| format!("ADMIN {}", quote_ident(ms.member_name.as_ref().unwrap())))
             .collect();
 
         let role_ddl = format!(
-            "CREATE ROLE \"{}\" WITH {} {} {} {} {} {} {} {}{}{}{}{}{}",
-            role_name,
+            "CREATE ROLE {} WITH {} {} {} {} {} {} {} {}{}{}{}{}{}",
+            quote_ident(role_name),
             self.map_bool_str(&map, "superuser"),
             self.map_bool_str(&map, "can create databases"),
             self.map_bool_str(&map, "can create roles"),
@@ -1089,7 +1092,7 @@ impl<'a> RoleDetailComponent<'a> {
             self.map_bool_str(&map, "can bypass rls"),
             if let Some(password) = map.get("password") {
                 if let Some(password) = password {
-                    format!(" PASSWORD '{}'", password)
+                    format!(" PASSWORD {}", quote_literal(password))
                 } else {
                     String::new()
                 }
@@ -1098,7 +1101,7 @@ impl<'a> RoleDetailComponent<'a> {
             },
             if let Some(expiry) = map.get("expiry date") {
                 if let Some(expiry) = expiry {
-                    format!(" VALID UNTIL '{}'", expiry)
+                    format!(" VALID UNTIL {}", quote_literal(expiry))
                 } else {
                     String::new()
                 }
@@ -1157,18 +1160,22 @@ impl<'a> RoleDetailComponent<'a> {
                     p_str.push("UPDATE");
                 }
                 format!(
-                    "GRANT {} ON TABLE \"{}\".\"{}\".\"{}\" TO \"{}\"",
+                    "GRANT {} ON TABLE {}.{}.{} TO {}",
                     p_str.join(","),
-                    p.db,
-                    p.schema,
-                    p.name,
-                    role_name,
+                    quote_ident(&p.db),
+                    quote_ident(&p.schema),
+                    quote_ident(&p.name),
+                    quote_ident(role_name)
                 )
             })
             .collect();
         let comment = self.comment.lines().join("\n");
         let comment_ddl = if !comment.is_empty() {
-            format!("COMMET ON ROLE \"{}\" IS '{}'", role_name, comment)
+            format!(
+                "COMMET ON ROLE {} IS {}",
+                quote_ident(role_name),
+                quote_literal(&comment)
+            )
         } else {
             "".to_string()
         };
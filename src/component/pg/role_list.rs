@@ -3,18 +3,19 @@ use crate::{
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
     dialog::{confirm::Kind as ConfirmKind, ConfirmDialog},
     event::{config::*, Key},
-    model::pg::{get_pg_roles, Connections, Role},
+    model::pg::{get_pg_roles, quote_ident, Connections, Role},
     pool::{execute_pg_query, get_pg_pool, PGPools},
+    widget::DataTable,
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState},
+    widgets::{Block, BorderType, Borders, TableState},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use uuid::Uuid;
 
 pub struct RoleListComponent {
@@ -75,38 +76,24 @@ impl RoleListComponent {
             r,
         );
 
-        let table = Table::new(
-            self.roles
-                .iter()
-                .map(|r| {
-                    RowUI::new(vec![
-                        r.name().to_string(),
-                        if r.can_login() { "Y" } else { "N" }.to_string(),
-                        r.conn_limit().to_string(),
-                        r.expiry_date()
-                            .map(|ed| ed.format("%Y-%m-%d %H:%M:%S").to_string())
-                            .unwrap_or_default(),
-                        r.comment().to_string(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.roles.iter().map(|r| {
+            vec![
+                r.name().to_string(),
+                if r.can_login() { "Y" } else { "N" }.to_string(),
+                r.conn_limit().to_string(),
+                r.expiry_date()
+                    .map(|ed| ed.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                r.comment().to_string(),
+            ]
+        }))
+        .header(vec![
             "Name",
             "Can Login",
             "Conn Limit",
             "Expiry Date",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-            Constraint::Ratio(1, 5),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(
             table,
             r.inner(&Margin {
@@ -194,7 +181,7 @@ impl RoleListComponent {
                             self.pools.clone(),
                             &self.conn_id.unwrap(),
                             None,
-                            &format!("DROP ROLE \"{}\"", role.name()),
+                            &format!("DROP ROLE {}", quote_ident(role.name())),
                         )
                         .await?;
                         self.delete_dlg = None;
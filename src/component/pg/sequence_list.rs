@@ -0,0 +1,253 @@
+use crate::{
+    app::{ComponentResult, DialogResult, Focus},
+    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    dialog::InputDialog,
+    event::{config::*, Key},
+    model::pg::{get_pg_sequences, Connections, Sequence},
+    pool::{execute_pg_query, get_pg_pool, PGPools},
+    widget::DataTable,
+};
+use anyhow::{Error, Result};
+use ratatui::{
+    backend::Backend,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, TableState},
+    Frame,
+};
+use std::{cell::RefCell, rc::Rc};
+use uuid::Uuid;
+
+pub struct SequenceListComponent<'a> {
+    conn_id: Option<Uuid>,
+    db_name: Option<String>,
+    schema_name: Option<String>,
+    sequences: Vec<Sequence>,
+    state: TableState,
+    restart_dlg: Option<InputDialog<'a>>,
+    cmd_bar: Rc<RefCell<CommandBarComponent>>,
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<PGPools>>,
+}
+
+impl<'a> SequenceListComponent<'a> {
+    pub fn new(
+        cmd_bar: Rc<RefCell<CommandBarComponent>>,
+        conns: Rc<RefCell<Connections>>,
+        pools: Rc<RefCell<PGPools>>,
+    ) -> Self {
+        SequenceListComponent {
+            conn_id: None,
+            db_name: None,
+            schema_name: None,
+            sequences: Vec::new(),
+            state: TableState::default(),
+            restart_dlg: None,
+            cmd_bar,
+            conns,
+            pools,
+        }
+    }
+    pub async fn set_data(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: &str,
+    ) -> Result<()> {
+        self.conn_id = Some(*conn_id);
+        self.db_name = Some(db_name.to_string());
+        self.schema_name = Some(schema_name.to_string());
+        self.state = TableState::default();
+        self.refresh().await
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, is_focus: bool)
+    where
+        B: Backend,
+    {
+        f.render_widget(
+            Block::default()
+                .title("Sequences")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(if is_focus {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                }),
+            r,
+        );
+        let table = DataTable::new(self.sequences.iter().map(|sequence| {
+            vec![
+                Some(sequence.name.clone()),
+                Some(sequence.data_type.clone()),
+                sequence.last_value.map(|v| v.to_string()),
+                Some(sequence.start_value.to_string()),
+                Some(sequence.increment_by.to_string()),
+                Some(sequence.min_value.to_string()),
+                Some(sequence.max_value.to_string()),
+                Some(sequence.cycle.to_string()),
+                sequence.owned_by.clone(),
+                Some(sequence.owner.clone()),
+            ]
+        }))
+        .header(vec![
+            "Name",
+            "Type",
+            "Current Value",
+            "Start",
+            "Increment",
+            "Min",
+            "Max",
+            "Cycle",
+            "Owned By",
+            "Owner",
+        ]);
+        f.render_stateful_widget(
+            table,
+            r.inner(&Margin {
+                vertical: 1,
+                horizontal: 1,
+            }),
+            &mut self.state,
+        );
+        if is_focus {
+            self.update_commands();
+        }
+    }
+    pub fn draw_dialog<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        if let Some(dlg) = self.restart_dlg.as_ref() {
+            dlg.draw(f);
+        }
+    }
+    async fn handle_main_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            UP_KEY => {
+                if !self.sequences.is_empty() {
+                    let index = get_table_up_index(self.state.selected());
+                    self.state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.sequences.is_empty() {
+                    let index = get_table_down_index(self.state.selected(), self.sequences.len());
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                return Ok(ComponentResult::Focus(Focus::LeftPanel));
+            }
+            EDIT_KEY => {
+                if let Some(index) = self.state.selected() {
+                    self.restart_dlg = Some(InputDialog::new(
+                        "Restart With",
+                        Some(&self.sequences[index].start_value.to_string()),
+                    ));
+                }
+            }
+            REFRESH_KEY => {
+                self.refresh().await?;
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn refresh(&mut self) -> Result<()> {
+        let pool = get_pg_pool(
+            self.conns.clone(),
+            self.pools.clone(),
+            self.conn_id.as_ref().unwrap(),
+            self.db_name.as_deref(),
+        )
+        .await?;
+        self.sequences = get_pg_sequences(&pool, self.schema_name.as_ref().unwrap()).await?;
+        if let Some(index) = self.state.selected() {
+            if index >= self.sequences.len() {
+                self.state.select(None);
+            }
+        }
+        Ok(())
+    }
+    async fn handle_restart_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if let Some(dlg) = self.restart_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel => {
+                    self.restart_dlg = None;
+                }
+                DialogResult::Confirm(value) => {
+                    if let Some(i) = self.state.selected() {
+                        let value = value.trim();
+                        let value = if value.is_empty() {
+                            None
+                        } else {
+                            Some(value.parse::<i64>().map_err(|_| {
+                                Error::msg(format!("Invalid sequence value: {}", value))
+                            })?)
+                        };
+                        execute_pg_query(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                            &self.sequences[i]
+                                .get_restart_ddl(self.schema_name.as_ref().unwrap(), value),
+                        )
+                        .await?;
+                        self.restart_dlg = None;
+                        self.refresh().await?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(ComponentResult::Done)
+    }
+    pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        if self.restart_dlg.is_some() {
+            self.handle_restart_dlg_event(key).await
+        } else {
+            self.handle_main_event(key).await
+        }
+    }
+    fn update_commands(&self) {
+        let mut cmds = if let Some(dlg) = self.restart_dlg.as_ref() {
+            dlg.get_commands()
+        } else {
+            self.get_main_commands()
+        };
+        self.cmd_bar.borrow_mut().set_commands(&mut cmds);
+    }
+    fn get_main_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.push(Command {
+                name: "Restart Sequence",
+                key: EDIT_KEY,
+            });
+        }
+        cmds.append(&mut vec![
+            Command {
+                name: "Refresh",
+                key: REFRESH_KEY,
+            },
+            Command {
+                name: "To Connections",
+                key: LEFT_KEY,
+            },
+        ]);
+        cmds
+    }
+}
@@ -1,6 +1,11 @@
 use crate::{
-    app::{ComponentResult, DialogResult, MainPanel},
-    component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
+    app::{ComponentResult, DialogResult, Goto, MainPanel},
+    clipboard::copy_to_clipboard,
+    component::{
+        get_clicked_tab, get_diff_spans, get_table_down_index, get_table_up_index,
+        get_value_diff_spans, Command, CommandBarComponent,
+    },
+    config::Config,
     dialog::{
         confirm::{ConfirmDialog, Kind as ConfirmKind},
         pg::{
@@ -10,43 +15,67 @@ use crate::{
         InputDialog,
     },
     event::{config::*, Key},
-    model::pg::{
-        convert_row_to_pg_check, convert_row_to_pg_exclude, convert_row_to_pg_rule,
-        convert_row_to_pg_trigger, convert_show_column_to_pg_fields, convert_show_fk_to_pg_fk,
-        convert_show_index_to_pg_indexes, convert_show_unique_to_pg_unique, get_all_pg_schemas,
-        get_pg_field_names, get_pg_schemas, get_pg_table_names, Check, Connections, DoInstead,
-        EventKind, Exclude, ExcludeElement, Field, FieldKind, FiresKind, ForEachKind, ForeignKey,
-        Index, IndexField, IndexMethod, OnDeleteKind, OnUpdateKind, Rule, Trigger, Unique,
+    model::{
+        pg::{
+            comment_literal, convert_row_to_pg_check, convert_row_to_pg_exclude,
+            convert_row_to_pg_rule, convert_row_to_pg_trigger, convert_show_column_to_pg_fields,
+            convert_show_fk_to_pg_fk, convert_show_index_to_pg_indexes,
+            convert_show_reference_to_pg_references, convert_show_unique_to_pg_unique,
+            get_all_pg_schemas, get_pg_field_names, get_pg_schemas, get_pg_table_names,
+            quote_ident, quote_literal, split_top_level, Check, Connections, DoInstead, EventKind,
+            Exclude, ExcludeElement, Field, FieldKind, FiresKind, ForEachKind, ForeignKey, Index,
+            IndexField, IndexMethod, MatchKind, OnDeleteKind, OnUpdateKind, Rule, Trigger, Unique,
+        },
+        DisplayFormat, Reference,
     },
-    pool::{execute_pg_query_unprepared, fetch_one_pg, fetch_pg_query, get_pg_pool, PGPools},
+    pool::{execute_pg_query_unprepared, fetch_pg_query_with_params, get_pg_pool, PGPools},
+    widget::{highlight_sql, DataTable, Scrollbar, SyntaxTheme},
 };
 use anyhow::Result;
-use sqlx::Row;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
-    text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState, Tabs},
+    text::Spans,
+    widgets::{Block, BorderType, Borders, Paragraph, TableState, Tabs, Wrap},
     Frame,
 };
+use sqlx::Row;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use tui_textarea::{Input, TextArea};
 use uuid::Uuid;
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum PanelKind {
     Fields,
     Indexes,
     ForeignKeys,
+    References,
     Uniques,
     Checks,
     Excludes,
     Rules,
     Triggers,
     Comment,
+    Changes,
     SQLPreview,
 }
 
+const UNDO_LIMIT: usize = 20;
+const NEW_TABLE_NAME: &str = "new_table";
+
+#[derive(Clone)]
+struct Snapshot {
+    fields: Vec<Field>,
+    indexes: Vec<Index>,
+    foreign_keys: Vec<ForeignKey>,
+    uniques: Vec<Unique>,
+    checks: Vec<Check>,
+    excludes: Vec<Exclude>,
+    rules: Vec<Rule>,
+    triggers: Vec<Trigger>,
+}
+
 pub struct TableDetailComponent<'a> {
     conn_id: Option<Uuid>,
     db_name: Option<String>,
@@ -54,12 +83,14 @@ pub struct TableDetailComponent<'a> {
     table_name: Option<String>,
     key_name: Option<String>,
     panel: PanelKind,
+    tabs_rect: Rect,
     fields: Vec<Field>,
     old_fields: Vec<Field>,
     indexes: Vec<Index>,
     old_indexes: Vec<Index>,
     foreign_keys: Vec<ForeignKey>,
     old_foreign_keys: Vec<ForeignKey>,
+    references: Vec<Reference>,
     uniques: Vec<Unique>,
     old_uniques: Vec<Unique>,
     checks: Vec<Check>,
@@ -72,10 +103,14 @@ pub struct TableDetailComponent<'a> {
     old_triggers: Vec<Trigger>,
     comment: TextArea<'a>,
     old_comment: TextArea<'a>,
-    sql_preview: TextArea<'a>,
+    sql_preview_scroll: u16,
+    syntax_theme: SyntaxTheme,
+    display_format: DisplayFormat,
+    changes_scroll: u16,
     fields_state: TableState,
     indexes_state: TableState,
     foreign_keys_state: TableState,
+    references_state: TableState,
     uniques_state: TableState,
     checks_state: TableState,
     excludes_state: TableState,
@@ -84,6 +119,7 @@ pub struct TableDetailComponent<'a> {
     input_dlg: Option<InputDialog<'a>>,
     exit_dlg: Option<ConfirmDialog>,
     info_dlg: Option<ConfirmDialog>,
+    error_dlg: Option<ConfirmDialog>,
     delete_field_dlg: Option<ConfirmDialog>,
     delete_index_dlg: Option<ConfirmDialog>,
     delete_foreign_key_dlg: Option<ConfirmDialog>,
@@ -100,6 +136,7 @@ pub struct TableDetailComponent<'a> {
     rule_dlg: Option<RuleDialog<'a>>,
     check_dlg: Option<CheckDialog<'a>>,
     trigger_dlg: Option<TriggerDialog<'a>>,
+    undo_stack: Vec<Snapshot>,
     cmd_bar: Rc<RefCell<CommandBarComponent>>,
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<PGPools>>,
@@ -110,10 +147,12 @@ impl<'a> TableDetailComponent<'a> {
         cmd_bar: Rc<RefCell<CommandBarComponent>>,
         conns: Rc<RefCell<Connections>>,
         pools: Rc<RefCell<PGPools>>,
+        config: Rc<RefCell<Config>>,
     ) -> Self {
         TableDetailComponent {
             table_name: None,
             panel: PanelKind::Fields,
+            tabs_rect: Rect::default(),
             fields: Vec::new(),
             old_fields: Vec::new(),
             indexes: Vec::new(),
@@ -124,6 +163,7 @@ impl<'a> TableDetailComponent<'a> {
             old_checks: Vec::new(),
             foreign_keys: Vec::new(),
             old_foreign_keys: Vec::new(),
+            references: Vec::new(),
             excludes: Vec::new(),
             old_excludes: Vec::new(),
             rules: Vec::new(),
@@ -132,7 +172,10 @@ impl<'a> TableDetailComponent<'a> {
             old_triggers: Vec::new(),
             comment: TextArea::default(),
             old_comment: TextArea::default(),
-            sql_preview: TextArea::default(),
+            sql_preview_scroll: 0,
+            syntax_theme: config.borrow().get_syntax_theme(),
+            display_format: config.borrow().get_display_format(),
+            changes_scroll: 0,
             conn_id: None,
             db_name: None,
             schema_name: None,
@@ -140,6 +183,7 @@ impl<'a> TableDetailComponent<'a> {
             input_dlg: None,
             exit_dlg: None,
             info_dlg: None,
+            error_dlg: None,
             delete_field_dlg: None,
             delete_index_dlg: None,
             delete_unique_dlg: None,
@@ -151,6 +195,7 @@ impl<'a> TableDetailComponent<'a> {
             fields_state: TableState::default(),
             indexes_state: TableState::default(),
             foreign_keys_state: TableState::default(),
+            references_state: TableState::default(),
             uniques_state: TableState::default(),
             excludes_state: TableState::default(),
             rules_state: TableState::default(),
@@ -164,6 +209,7 @@ impl<'a> TableDetailComponent<'a> {
             rule_dlg: None,
             trigger_dlg: None,
             check_dlg: None,
+            undo_stack: Vec::new(),
             cmd_bar,
             conns,
             pools,
@@ -176,29 +222,30 @@ impl<'a> TableDetailComponent<'a> {
         schema_name: &str,
         table_name: Option<&str>,
     ) -> Result<()> {
+        self.undo_stack.clear();
         self.conn_id = Some(*conn_id);
         self.db_name = Some(db_name.to_string());
         self.schema_name = Some(schema_name.to_string());
         self.table_name = table_name.map(|s| s.to_string());
 
         if let Some(table_name) = &self.table_name.as_ref() {
-            let fields = fetch_pg_query(
+            let relation = format!("{}.{}", quote_ident(schema_name), quote_ident(table_name));
+            let fields = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    "SELECT col_description((table_schema||'.'||table_name)::regclass::oid, ordinal_position) as comment,* FROM information_schema.columns WHERE table_schema = '{}' and table_name = '{}' order by ordinal_position ASC",
-                    schema_name, table_name
-                ),
+                "SELECT col_description((table_schema||'.'||table_name)::regclass::oid, ordinal_position) as comment,* FROM information_schema.columns WHERE table_schema = $1 and table_name = $2 order by ordinal_position ASC",
+                &[schema_name, table_name],
             )
             .await?;
-            let keys = fetch_pg_query(
+            let keys = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!("SELECT a.attname FROM pg_index i JOIN pg_attribute a ON a.attrelid = i.indrelid and a.attnum = ANY(i.indkey) WHERE i.indrelid = '{}'::regclass AND i.indisprimary", table_name),
+                "SELECT a.attname FROM pg_index i JOIN pg_attribute a ON a.attrelid = i.indrelid and a.attnum = ANY(i.indkey) WHERE i.indrelid = $1::regclass AND i.indisprimary",
+                &[&relation],
             )
             .await?;
             self.fields = convert_show_column_to_pg_fields(
@@ -209,61 +256,79 @@ impl<'a> TableDetailComponent<'a> {
             );
             self.old_fields = self.fields.clone();
 
-            let pr_key = fetch_one_pg(
+            let pr_key = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!("select conname from pg_constraint where conrelid = '{}'::regclass and contype='p'",table_name)
-            ).await?;
-            self.key_name = pr_key.map(|key| key.try_get("conname").unwrap());
+                "select conname from pg_constraint where conrelid = $1::regclass and contype='p'",
+                &[&relation],
+            )
+            .await?;
+            self.key_name = pr_key.first().map(|key| key.try_get("conname").unwrap());
 
-            let indexes = fetch_pg_query(
+            let indexes = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    "SELECT obj_description(indexname::regclass) as comment, inds.* FROM pg_indexes AS inds JOIN pg_index AS ind ON inds.indexname::regclass = ind.indexrelid WHERE inds.tablename='{}' AND inds.schemaname='{}' AND ind.indisprimary = false",
-                    table_name, schema_name
-                ),
+                "SELECT obj_description(indexname::regclass) as comment, st.idx_scan, pg_size_pretty(pg_relation_size(ind.indexrelid)) AS index_size, inds.* FROM pg_indexes AS inds JOIN pg_index AS ind ON inds.indexname::regclass = ind.indexrelid LEFT JOIN pg_stat_user_indexes AS st ON st.indexrelid = ind.indexrelid WHERE inds.tablename = $1 AND inds.schemaname = $2 AND ind.indisprimary = false",
+                &[table_name, schema_name],
             )
             .await?;
             self.indexes = convert_show_index_to_pg_indexes(indexes);
             self.old_indexes = self.indexes.clone();
 
-            let foreign_keys = fetch_pg_query(
+            let foreign_keys = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    r"
+                r"
                     SELECT 
                         obj_description(oid) as comment,
                         conrelid::regclass AS table_name,
                         conname AS foreign_key,
                         pg_get_constraintdef(oid) AS def
                     FROM pg_constraint
-                    WHERE contype = 'f' and conrelid::regclass::text = '{}'
-                    AND connamespace = '{}'::regnamespace
+                    WHERE contype = 'f' and conrelid = $1::regclass
                     ORDER BY conrelid::regclass::text, contype DESC
                     ",
-                    table_name, schema_name,
-                ),
+                &[&relation],
             )
             .await?;
 
             self.foreign_keys = convert_show_fk_to_pg_fk(schema_name, foreign_keys);
             self.old_foreign_keys = self.foreign_keys.clone();
 
-            let uniques = fetch_pg_query(
+            let references = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    r"
+                r"
+                    SELECT
+                        con.conname AS name,
+                        ns.nspname AS schema_name,
+                        cls.relname AS table_name,
+                        pg_get_constraintdef(con.oid) AS def
+                    FROM pg_constraint AS con
+                    JOIN pg_class AS cls ON cls.oid = con.conrelid
+                    JOIN pg_namespace AS ns ON ns.oid = cls.relnamespace
+                    WHERE con.contype = 'f' AND con.confrelid = $1::regclass
+                    ORDER BY ns.nspname, cls.relname, con.conname
+                    ",
+                &[&relation],
+            )
+            .await?;
+            self.references = convert_show_reference_to_pg_references(references);
+
+            let uniques = fetch_pg_query_with_params(
+                self.conns.clone(),
+                self.pools.clone(),
+                conn_id,
+                Some(db_name),
+                r"
                     SELECT 
                         obj_description(c.oid) as comment,
                         c.conname AS constraint_name,
@@ -273,23 +338,20 @@ impl<'a> TableDetailComponent<'a> {
                     JOIN pg_attribute AS a
                     ON a.attnum = k.c AND a.attrelid = c.conrelid
                     WHERE c.contype = 'u'
-                    AND c.connamespace  = '{}'::regnamespace
-                    AND c.conrelid = '{}'::regclass
+                    AND c.conrelid = $1::regclass
                     GROUP BY c.oid, c.conrelid, c.conname
                     ",
-                    schema_name, table_name,
-                ),
+                &[&relation],
             )
             .await?;
             self.uniques = convert_show_unique_to_pg_unique(uniques);
             self.old_uniques = self.uniques.clone();
-            let checks = fetch_pg_query(
+            let checks = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    r"
+                r"
                     SELECT
                         obj_description(pgc.oid) as comment,
                         pgc.conname AS constraint_name,
@@ -301,66 +363,59 @@ impl<'a> TableDetailComponent<'a> {
                     ON pgc.conname = ccu.constraint_name
                     AND nsp.nspname = ccu.constraint_schema
                     WHERE contype = 'c'
-                    AND ccu.table_schema = '{}'
-                    AND ccu.table_name = '{}'
+                    AND ccu.table_schema = $1
+                    AND ccu.table_name = $2
                     ORDER BY pgc.conname
                 ",
-                    schema_name, table_name
-                ),
+                &[schema_name, table_name],
             )
             .await?;
             self.checks = convert_row_to_pg_check(checks);
             self.old_checks = self.checks.clone();
 
-            let excludes = fetch_pg_query(
+            let excludes = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    r"
+                r"
                     SELECT
                         obj_description(oid) as comment,
                         pg_catalog.pg_get_constraintdef(oid,true) AS def,
                         conname
                     FROM pg_constraint
                     WHERE contype = 'x'
-                    AND conrelid = '{}'::regclass
-                    AND connamespace = '{}'::regnamespace
+                    AND conrelid = $1::regclass
                     ",
-                    table_name, schema_name
-                ),
+                &[&relation],
             )
             .await?;
             self.excludes = convert_row_to_pg_exclude(excludes);
             self.old_excludes = self.excludes.clone();
 
-            let rules = fetch_pg_query(
+            let rules = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    r"
+                r"
                     SELECT
                         *
                     FROM
                         pg_rules
-                    WHERE schemaname='{}' and tablename='{}'",
-                    schema_name, table_name,
-                ),
+                    WHERE schemaname = $1 and tablename = $2",
+                &[schema_name, table_name],
             )
             .await?;
 
             self.rules = convert_row_to_pg_rule(rules);
             self.old_rules = self.rules.clone();
 
-            let triggers = fetch_pg_query(
+            let triggers = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
                     r"
                     SELECT
                         tgname,
@@ -370,35 +425,34 @@ impl<'a> TableDetailComponent<'a> {
 	                    nspname,
 	                    tgargs,
 	                    tgqual,
-	                    array_agg(attname) AS columns
+	                    array_agg(attname) AS columns,
+	                    obj_description(pg_trigger.oid, 'pg_trigger') AS comment
                     FROM
 	                    pg_trigger
 	                JOIN pg_proc ON pg_proc.OID = tgfoid
 	                JOIN pg_namespace ON pg_namespace.OID = pronamespace 
 	                LEFT JOIN pg_attribute ON pg_attribute.attrelid = tgrelid and pg_attribute.attnum = ANY(tgattr)
                     WHERE
-	                    tgrelid = '{}'::regclass
+	                    tgrelid = $1::regclass
 	                GROUP BY
-                        tgname, proname, tgtype, tgenabled, nspname, tgargs, tgqual",
-                    table_name,
-                ),
+                        pg_trigger.oid, tgname, proname, tgtype, tgenabled, nspname, tgargs, tgqual",
+                &[&relation],
             )
             .await?;
 
             self.triggers = convert_row_to_pg_trigger(&triggers);
             self.old_triggers = self.triggers.clone();
 
-            self.comment = if let Some(c) = fetch_one_pg(
+            self.comment = if let Some(c) = fetch_pg_query_with_params(
                 self.conns.clone(),
                 self.pools.clone(),
                 conn_id,
                 Some(db_name),
-                &format!(
-                    "SELECT obj_description('{}'::regclass) as comment",
-                    table_name
-                ),
+                "SELECT obj_description($1::regclass) as comment",
+                &[&relation],
             )
             .await?
+            .first()
             .unwrap()
             .try_get::<Option<&str>, _>("comment")
             .unwrap()
@@ -443,36 +497,18 @@ impl<'a> TableDetailComponent<'a> {
                 vertical: 1,
                 horizontal: 1,
             }));
-        let select_tab = match self.panel {
-            PanelKind::Fields => 0,
-            PanelKind::Indexes => 1,
-            PanelKind::ForeignKeys => 2,
-            PanelKind::Uniques => 3,
-            PanelKind::Checks => 4,
-            PanelKind::Excludes => 5,
-            PanelKind::Rules => 6,
-            PanelKind::Triggers => 7,
-            PanelKind::Comment => 8,
-            PanelKind::SQLPreview => 9,
-        };
+        let panels = Self::get_panels();
+        let select_tab = panels
+            .iter()
+            .position(|(panel, _)| *panel == self.panel)
+            .unwrap_or_default();
+        self.tabs_rect = chunks[0];
         f.render_widget(
             Tabs::new(
-                [
-                    Span::raw("Fields"),
-                    Span::raw("Indexes"),
-                    Span::raw("Foreign Keys"),
-                    Span::raw("Uniques"),
-                    Span::raw("Checks"),
-                    Span::raw("Excludes"),
-                    Span::raw("Rules"),
-                    Span::raw("Triggers"),
-                    Span::raw("Comment"),
-                    Span::raw("SQL Preview"),
-                ]
-                .iter()
-                .cloned()
-                .map(Spans::from)
-                .collect(),
+                panels
+                    .iter()
+                    .map(|(_, title)| Spans::from(*title))
+                    .collect(),
             )
             .block(Block::default().borders(Borders::BOTTOM))
             .highlight_style(Style::default().fg(Color::Green))
@@ -483,12 +519,14 @@ impl<'a> TableDetailComponent<'a> {
             PanelKind::Fields => self.draw_fields(f, chunks[1]),
             PanelKind::Indexes => self.draw_indexes(f, chunks[1]),
             PanelKind::ForeignKeys => self.draw_foreign_keys(f, chunks[1]),
+            PanelKind::References => self.draw_references(f, chunks[1]),
             PanelKind::Uniques => self.draw_uniques(f, chunks[1]),
             PanelKind::Checks => self.draw_checks(f, chunks[1]),
             PanelKind::Excludes => self.draw_excludes(f, chunks[1]),
             PanelKind::Rules => self.draw_rules(f, chunks[1]),
             PanelKind::Triggers => self.draw_triggers(f, chunks[1]),
             PanelKind::Comment => self.draw_comment(f, chunks[1]),
+            PanelKind::Changes => self.draw_changes(f, chunks[1]),
             PanelKind::SQLPreview => self.draw_sql_preview(f, chunks[1]),
         }
 
@@ -557,8 +595,60 @@ impl<'a> TableDetailComponent<'a> {
         if let Some(dlg) = self.delete_check_dlg.as_mut() {
             dlg.draw(f);
         }
+        if let Some(dlg) = self.error_dlg.as_mut() {
+            dlg.draw(f);
+        }
     }
 
+    fn get_panels() -> Vec<(PanelKind, &'static str)> {
+        vec![
+            (PanelKind::Fields, "Fields"),
+            (PanelKind::Indexes, "Indexes"),
+            (PanelKind::ForeignKeys, "Foreign Keys"),
+            (PanelKind::References, "Referenced By"),
+            (PanelKind::Uniques, "Uniques"),
+            (PanelKind::Checks, "Checks"),
+            (PanelKind::Excludes, "Excludes"),
+            (PanelKind::Rules, "Rules"),
+            (PanelKind::Triggers, "Triggers"),
+            (PanelKind::Comment, "Comment"),
+            (PanelKind::Changes, "Changes"),
+            (PanelKind::SQLPreview, "SQL Preview"),
+        ]
+    }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if self.input_dlg.is_some()
+            || self.exit_dlg.is_some()
+            || self.info_dlg.is_some()
+            || self.error_dlg.is_some()
+            || self.delete_field_dlg.is_some()
+            || self.delete_index_dlg.is_some()
+            || self.delete_check_dlg.is_some()
+            || self.delete_trigger_dlg.is_some()
+            || self.delete_foreign_key_dlg.is_some()
+            || self.delete_exclude_dlg.is_some()
+            || self.delete_unique_dlg.is_some()
+            || self.delete_rule_dlg.is_some()
+            || self.field_dlg.is_some()
+            || self.index_dlg.is_some()
+            || self.foreign_key_dlg.is_some()
+            || self.unique_dlg.is_some()
+            || self.rule_dlg.is_some()
+            || self.exclude_dlg.is_some()
+            || self.trigger_dlg.is_some()
+            || self.check_dlg.is_some()
+        {
+            return;
+        }
+        let panels = Self::get_panels();
+        let titles = panels
+            .iter()
+            .map(|(_, title)| *title)
+            .collect::<Vec<&str>>();
+        if let Some(index) = get_clicked_tab(&titles, self.tabs_rect, x, y) {
+            self.panel = panels[index].0;
+        }
+    }
     pub async fn handle_event(&mut self, key: &Key) -> Result<ComponentResult> {
         let result = if self.input_dlg.is_some() {
             self.handle_input_dlg_event(key).await?
@@ -566,6 +656,8 @@ impl<'a> TableDetailComponent<'a> {
             self.handle_exit_event(key)
         } else if self.info_dlg.is_some() {
             self.handle_info_event(key)
+        } else if self.error_dlg.is_some() {
+            self.handle_error_dlg_event(key)
         } else if self.delete_field_dlg.is_some() {
             self.handle_delete_field_event(key)
         } else if self.delete_index_dlg.is_some() {
@@ -608,36 +700,25 @@ impl<'a> TableDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.fields
-                .iter()
-                .map(|f| {
-                    RowUI::new(vec![
-                        f.name().to_string(),
-                        f.kind().to_string(),
-                        f.length().unwrap_or_default(),
-                        f.default_value().map(|s| s.to_string()).unwrap_or_default(),
-                        if f.not_null() { "\u{2705}" } else { "\u{274E}" }.to_string(),
-                        if f.key() { "\u{2705}" } else { "\u{274E}" }.to_string(),
-                        f.comment().map(|s| s.to_string()).unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.fields.iter().map(|f| {
+            vec![
+                f.name().to_string(),
+                if f.is_array() {
+                    format!("{}[]", f.kind())
+                } else {
+                    f.kind().to_string()
+                },
+                f.length().unwrap_or_default(),
+                f.default_value().map(|s| s.to_string()).unwrap_or_default(),
+                self.display_format.format_bool(f.not_null()).to_string(),
+                self.display_format.format_bool(f.key()).to_string(),
+                f.comment().map(|s| s.to_string()).unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name", "Type", "Length", "Default", "Not Null", "Key", "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.fields_state);
     }
 
@@ -645,86 +726,54 @@ impl<'a> TableDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.uniques
-                .iter()
-                .map(|u| {
-                    RowUI::new(vec![
-                        u.name().to_string(),
-                        u.fields().join(","),
-                        u.comment().map(|c| c.to_string()).unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec!["Name", "Fields", "Comment"]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(self.uniques.iter().map(|u| {
+            vec![
+                u.name().to_string(),
+                u.fields().join(","),
+                u.comment().map(|c| c.to_string()).unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Fields", "Comment"]);
         f.render_stateful_widget(table, r, &mut self.uniques_state);
     }
     fn draw_excludes<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.excludes
-                .iter()
-                .map(|e| {
-                    RowUI::new(vec![
-                        e.name().to_string(),
-                        e.index_method().unwrap_or("").to_string(),
-                        e.element()
-                            .iter()
-                            .map(|e| e.to_string())
-                            .collect::<Vec<String>>()
-                            .join(","),
-                        e.comment().map(|s| s.to_string()).unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
-            "Name",
-            "Index Method",
-            "Element",
-            "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(self.excludes.iter().map(|e| {
+            vec![
+                e.name().to_string(),
+                e.index_method().unwrap_or("").to_string(),
+                e.element()
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+                e.comment().map(|s| s.to_string()).unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Index Method", "Element", "Comment"]);
         f.render_stateful_widget(table, r, &mut self.excludes_state);
     }
     fn draw_rules<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.rules
-                .iter()
-                .map(|r| {
-                    RowUI::new(vec![
-                        r.name(),
-                        r.event(),
-                        r.do_instead().unwrap_or(""),
-                        if r.enable() { "true" } else { "false" },
-                        r.where_condition().unwrap_or(""),
-                        r.definition().unwrap_or(""),
-                        r.comment().unwrap_or(""),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.rules.iter().map(|r| {
+            vec![
+                r.name(),
+                r.event(),
+                r.do_instead().unwrap_or(""),
+                if r.enable() { "true" } else { "false" },
+                r.where_condition().unwrap_or(""),
+                r.definition().unwrap_or(""),
+                r.comment().unwrap_or(""),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "Event",
             "Do Instead",
@@ -732,20 +781,18 @@ impl<'a> TableDetailComponent<'a> {
             "Where",
             "Definition",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-            Constraint::Ratio(1, 7),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.rules_state);
     }
+    pub fn has_unsaved_changes(&self) -> bool {
+        if self.conn_id.is_none() {
+            false
+        } else if self.table_name.is_some() {
+            !self.build_alter_ddl().trim().is_empty()
+        } else {
+            !self.fields.is_empty()
+        }
+    }
     fn build_sql(&self, table_name: Option<&str>) -> String {
         if self.table_name.is_some() {
             self.build_alter_ddl()
@@ -758,7 +805,7 @@ impl<'a> TableDetailComponent<'a> {
         let table_name = if let Some(name) = table_name {
             name
         } else {
-            "new_table"
+            NEW_TABLE_NAME
         };
         let mut table_ddl = Vec::new();
         let mut fields_ddl = Vec::new();
@@ -776,10 +823,7 @@ impl<'a> TableDetailComponent<'a> {
 
         let key_fields: Vec<&Field> = self.fields.iter().filter(|f| f.key()).collect();
         if !key_fields.is_empty() {
-            let names: Vec<String> = key_fields
-                .iter()
-                .map(|f| format!(r#""{}""#, f.name()))
-                .collect();
+            let names: Vec<String> = key_fields.iter().map(|f| quote_ident(f.name())).collect();
             table_ddl.push(format!("\nPRIMARY KEY ({})", names.join(",")));
         }
         self.foreign_keys.iter().for_each(|fk| {
@@ -820,25 +864,21 @@ impl<'a> TableDetailComponent<'a> {
         let rule_sqls: Vec<String> = if !self.rules.is_empty() {
             self.rules
                 .iter()
-                .map(|rule| {
-                    rule.get_create_ddl(
-                        self.schema_name.as_deref().unwrap(),
-                        self.table_name.as_deref().unwrap(),
-                    )
-                })
+                .map(|rule| rule.get_create_ddl(self.schema_name.as_deref().unwrap(), table_name))
                 .collect()
         } else {
             vec![]
         };
 
-        let trigger_sqls = if !self.triggers.is_empty() {
-            self.triggers
-                .iter()
-                .map(|t| t.get_create_ddl(self.schema_name.as_deref().unwrap(), table_name))
-                .collect()
-        } else {
-            vec![]
-        };
+        let mut trigger_sqls = Vec::new();
+        self.triggers.iter().for_each(|trigger| {
+            let (trigger_ddl, comment_ddl) =
+                trigger.get_create_ddl(self.schema_name.as_deref().unwrap(), table_name);
+            trigger_sqls.push(trigger_ddl);
+            if let Some(c) = comment_ddl {
+                comments_ddl.push(c);
+            }
+        });
         let mut indexes_ddl = Vec::new();
         self.indexes.iter().for_each(|index| {
             let (index_ddl, comment_ddl) =
@@ -848,15 +888,24 @@ impl<'a> TableDetailComponent<'a> {
                 comments_ddl.push(c);
             }
         });
+        let comment = self.comment.lines().join("\n");
+        if !comment.trim().is_empty() {
+            comments_ddl.push(format!(
+                "COMMENT ON TABLE {}.{} IS {};",
+                quote_ident(self.schema_name.as_deref().unwrap()),
+                quote_ident(table_name),
+                quote_literal(&comment)
+            ));
+        }
 
         format!(
-            "CREATE TABLE \"{}\".\"{}\" (\n{}\n);
+            "CREATE TABLE {}.{} (\n{}\n);
             {}
             {}
             {}
             {}",
-            self.schema_name.as_deref().unwrap(),
-            table_name,
+            quote_ident(self.schema_name.as_deref().unwrap()),
+            quote_ident(table_name),
             table_ddl.join(",\n"),
             indexes_ddl.join("\n"),
             rule_sqls.join("\n"),
@@ -891,7 +940,7 @@ impl<'a> TableDetailComponent<'a> {
             .count();
         if key_fields.len() != old_key_fields.len() || match_len != key_fields.len() {
             if let Some(kname) = self.key_name.as_ref() {
-                main_table_ddl.push(format!(r#"DROP CONSTRAINT "{}""#, kname,));
+                main_table_ddl.push(format!("DROP CONSTRAINT {}", quote_ident(kname)));
             }
             main_table_ddl.push(format!("ADD PRIMARY KEY ({})", key_fields.join(",")));
         }
@@ -925,9 +974,9 @@ impl<'a> TableDetailComponent<'a> {
 
         if !main_table_ddl.is_empty() {
             ddl.push(format!(
-                r#"ALTER TABLE "{}"."{}""#,
-                self.schema_name.as_deref().unwrap(),
-                self.table_name.as_deref().unwrap()
+                "ALTER TABLE {}.{}",
+                quote_ident(self.schema_name.as_deref().unwrap()),
+                quote_ident(self.table_name.as_deref().unwrap())
             ));
             ddl.push(format!("{};", main_table_ddl.join(",\n")));
         }
@@ -943,10 +992,10 @@ impl<'a> TableDetailComponent<'a> {
 
         if self.comment.lines() != self.old_comment.lines() {
             ddl.push(format!(
-                r#"COMMENT ON TABLE "{}"."{}" IS '{}';"#,
-                self.schema_name.as_deref().unwrap(),
-                self.table_name.as_deref().unwrap(),
-                self.comment.lines().join("\n")
+                "COMMENT ON TABLE {}.{} IS {};",
+                quote_ident(self.schema_name.as_deref().unwrap()),
+                quote_ident(self.table_name.as_deref().unwrap()),
+                comment_literal(Some(&self.comment.lines().join("\n")))
             ));
         }
         ddl.join("\n")
@@ -1059,8 +1108,11 @@ impl<'a> TableDetailComponent<'a> {
                     rename_ddl.append(&mut rename_index);
                 }
 
-                let (mut alter_ddl, comment_ddl) =
-                    index.get_alter_ddl(same_index, self.schema_name.as_deref().unwrap());
+                let (mut alter_ddl, comment_ddl) = index.get_alter_ddl(
+                    same_index,
+                    self.schema_name.as_deref().unwrap(),
+                    self.table_name.as_deref().unwrap(),
+                );
                 if !alter_ddl.is_empty() {
                     ddl.append(&mut alter_ddl);
                 }
@@ -1310,10 +1362,14 @@ impl<'a> TableDetailComponent<'a> {
         ddl.append(&mut drop_trigger_ddl);
         self.triggers.iter().for_each(|trigger| {
             if !old_trigger_ids.contains(trigger.id()) {
-                ddl.push(trigger.get_add_ddl(
+                let (add_ddl, comment_ddl) = trigger.get_add_ddl(
                     self.schema_name.as_deref().unwrap(),
                     self.table_name.as_deref().unwrap(),
-                ));
+                );
+                ddl.push(add_ddl);
+                if let Some(comment) = comment_ddl {
+                    comments_ddl.push(comment);
+                }
             } else {
                 let same_trigger = self
                     .old_triggers
@@ -1419,6 +1475,8 @@ impl<'a> TableDetailComponent<'a> {
             dlg.get_commands()
         } else if let Some(dlg) = self.info_dlg.as_ref() {
             dlg.get_commands()
+        } else if let Some(dlg) = self.error_dlg.as_ref() {
+            dlg.get_commands()
         } else if let Some(dlg) = self.field_dlg.as_ref() {
             dlg.get_commands()
         } else if let Some(dlg) = self.index_dlg.as_ref() {
@@ -1446,14 +1504,22 @@ impl<'a> TableDetailComponent<'a> {
             PanelKind::Fields => self.get_field_commands(),
             PanelKind::Indexes => self.get_index_commands(),
             PanelKind::ForeignKeys => self.get_foreign_key_commands(),
+            PanelKind::References => self.get_reference_commands(),
             PanelKind::Uniques => self.get_unique_commands(),
             PanelKind::Excludes => self.get_exclude_commands(),
             PanelKind::Rules => self.get_rule_commands(),
             PanelKind::Triggers => self.get_trigger_commands(),
             PanelKind::Checks => self.get_check_commands(),
             PanelKind::Comment => self.get_comment_commands(),
+            PanelKind::Changes => self.get_changes_commands(),
             PanelKind::SQLPreview => self.get_sql_preview_commands(),
         };
+        if !self.undo_stack.is_empty() {
+            cmds.push(Command {
+                name: "Undo",
+                key: UNDO_KEY,
+            });
+        }
         cmds.push(Command {
             name: "Save",
             key: SAVE_KEY,
@@ -1520,6 +1586,39 @@ impl<'a> TableDetailComponent<'a> {
         }
         cmds
     }
+    fn get_join_query(&self) -> String {
+        let mut sql = format!(
+            "SELECT *\nFROM {}.{} AS t0",
+            quote_ident(self.schema_name.as_ref().unwrap()),
+            quote_ident(self.table_name.as_ref().unwrap())
+        );
+        self.foreign_keys.iter().enumerate().for_each(|(i, fk)| {
+            let alias = format!("t{}", i + 1);
+            let conditions = fk
+                .fields()
+                .iter()
+                .zip(fk.ref_fields().iter())
+                .map(|(field, ref_field)| {
+                    format!(
+                        "t0.{} = {}.{}",
+                        quote_ident(field),
+                        alias,
+                        quote_ident(ref_field)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(" AND ");
+            sql.push_str(&format!(
+                "\n  LEFT JOIN {}.{} AS {} ON {}",
+                quote_ident(fk.ref_schema()),
+                quote_ident(fk.ref_table()),
+                alias,
+                conditions
+            ));
+        });
+        sql.push_str("\nLIMIT 100;");
+        sql
+    }
     fn get_foreign_key_commands(&self) -> Vec<Command> {
         let mut cmds = Vec::new();
         cmds.push(Command {
@@ -1546,8 +1645,34 @@ impl<'a> TableDetailComponent<'a> {
                 },
             ]);
         }
+        if self.table_name.is_some() && !self.foreign_keys.is_empty() {
+            cmds.push(Command {
+                name: "Join Query",
+                key: QUERY_KEY,
+            });
+        }
         cmds
     }
+    fn get_reference_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Next Panel",
+                key: TAB_RIGHT_KEY,
+            },
+            Command {
+                name: "Previous Panel",
+                key: TAB_LEFT_KEY,
+            },
+        ]
+    }
     fn get_unique_commands(&self) -> Vec<Command> {
         let mut cmds = Vec::new();
         cmds.push(Command {
@@ -1691,125 +1816,181 @@ impl<'a> TableDetailComponent<'a> {
     fn get_comment_commands(&self) -> Vec<Command> {
         vec![]
     }
+    fn get_changes_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ]
+    }
     fn get_sql_preview_commands(&self) -> Vec<Command> {
-        vec![]
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Copy SQL",
+                key: COPY_SQL_KEY,
+            },
+        ]
     }
     fn draw_indexes<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.indexes
-                .iter()
-                .map(|i| {
-                    RowUI::new(vec![
-                        i.name.clone(),
-                        i.fields()
-                            .iter()
-                            .map(|f| f.to_show_string())
-                            .collect::<Vec<String>>()
-                            .join(","),
-                        i.index_method().map(|s| s.to_string()).unwrap_or_default(),
-                        i.unique().to_string(),
-                        i.concurrent().to_string(),
-                        i.comment.clone().unwrap_or_default(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.indexes.iter().map(|i| {
+            vec![
+                i.name.clone(),
+                i.fields()
+                    .iter()
+                    .map(|f| f.to_show_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+                i.index_method().map(|s| s.to_string()).unwrap_or_default(),
+                i.unique().to_string(),
+                i.concurrent().to_string(),
+                i.scans.map(|s| s.to_string()).unwrap_or_default(),
+                i.size.clone().unwrap_or_default(),
+                i.comment.clone().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "Fields",
             "Index Method",
             "Unique",
             "Concurrent",
+            "Scans",
+            "Size",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
         ])
-        .highlight_style(Style::default().fg(Color::Green));
+        .row_styles(self.indexes.iter().map(|i| {
+            if i.scans == Some(0) {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            }
+        }));
         f.render_stateful_widget(table, r, &mut self.indexes_state);
     }
     fn draw_foreign_keys<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.foreign_keys
-                .iter()
-                .map(|f| {
-                    RowUI::new(vec![
-                        f.name(),
-                        f.field(),
-                        f.ref_schema(),
-                        f.ref_table(),
-                        f.ref_field(),
-                        f.on_delete().unwrap_or(""),
-                        f.on_update().unwrap_or(""),
-                        f.comment().unwrap_or(""),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.foreign_keys.iter().map(|f| {
+            vec![
+                f.name().to_string(),
+                f.fields().join(","),
+                f.ref_schema().to_string(),
+                f.ref_table().to_string(),
+                f.ref_fields().join(","),
+                f.on_delete().unwrap_or("").to_string(),
+                f.on_update().unwrap_or("").to_string(),
+                f.match_kind().unwrap_or("").to_string(),
+                f.get_deferrable_str().to_string(),
+                f.comment().unwrap_or("").to_string(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
-            "Field",
+            "Fields",
             "Ref Schema",
             "Ref Table",
-            "Ref Field",
+            "Ref Fields",
             "On Delete",
             "On Update",
+            "Match",
+            "Deferrable",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-            Constraint::Ratio(1, 8),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.foreign_keys_state);
     }
+    fn draw_references<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let table = DataTable::new(self.references.iter().map(|r| {
+            vec![
+                r.name.clone(),
+                format!("{}.{}", r.schema_name, r.table_name),
+                r.fields.join(","),
+                r.ref_fields.join(","),
+                r.on_delete.clone().unwrap_or_default(),
+                r.on_update.clone().unwrap_or_default(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
+            "Name",
+            "Table",
+            "Fields",
+            "Ref Fields",
+            "On Delete",
+            "On Update",
+        ]);
+        f.render_stateful_widget(table, r, &mut self.references_state);
+    }
     fn draw_triggers<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.triggers
-                .iter()
-                .map(|t| {
-                    RowUI::new(vec![
-                        t.name().to_string(),
-                        t.for_each().unwrap_or("").to_string(),
-                        t.fires().unwrap_or("").to_string(),
-                        if t.insert() { "\u{2705}" } else { "" }.to_string(),
-                        if t.update() { "\u{2705}" } else { "" }.to_string(),
-                        if t.delete() { "\u{2705}" } else { "" }.to_string(),
-                        if t.truncate() { "\u{2705}" } else { "" }.to_string(),
-                        t.update_fields()
-                            .iter()
-                            .filter(|f| f.is_some())
-                            .map(|f| f.as_deref().unwrap().to_string())
-                            .collect::<Vec<String>>()
-                            .join(","),
-                        if t.enable() { "\u{2705}" } else { "" }.to_string(),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.triggers.iter().map(|t| {
+            vec![
+                t.name().to_string(),
+                t.for_each().unwrap_or("").to_string(),
+                t.fires().unwrap_or("").to_string(),
+                if t.insert() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                }
+                .to_string(),
+                if t.update() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                }
+                .to_string(),
+                if t.delete() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                }
+                .to_string(),
+                if t.truncate() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                }
+                .to_string(),
+                t.update_fields()
+                    .iter()
+                    .filter(|f| f.is_some())
+                    .map(|f| f.as_deref().unwrap().to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+                if t.enable() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                }
+                .to_string(),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec![
             "Name",
             "For Each",
             "Fires",
@@ -1819,53 +2000,27 @@ impl<'a> TableDetailComponent<'a> {
             "Truncate",
             "Update of Fields",
             "Enable",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-            Constraint::Ratio(1, 9),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
         f.render_stateful_widget(table, r, &mut self.triggers_state);
     }
     fn draw_checks<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.checks
-                .iter()
-                .map(|c| {
-                    RowUI::new(vec![
-                        c.name(),
-                        c.expression(),
-                        if c.no_inherit() { "\u{2705}" } else { "" },
-                        c.comment().unwrap_or(""),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
-            "Name",
-            "Expression",
-            "No Inherit",
-            "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-            Constraint::Ratio(1, 4),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(self.checks.iter().map(|c| {
+            vec![
+                c.name(),
+                c.expression(),
+                if c.no_inherit() {
+                    &self.display_format.true_text
+                } else {
+                    ""
+                },
+                c.comment().unwrap_or(""),
+            ]
+        }))
+        .display_format(&self.display_format)
+        .header(vec!["Name", "Expression", "No Inherit", "Comment"]);
         f.render_stateful_widget(table, r, &mut self.checks_state);
     }
     fn draw_comment<B>(&self, f: &mut Frame<B>, r: Rect)
@@ -1874,26 +2029,164 @@ impl<'a> TableDetailComponent<'a> {
     {
         f.render_widget(self.comment.widget(), r);
     }
+    fn draw_changes<B>(&mut self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        let mut changes = self.build_changes();
+        if changes.is_empty() {
+            changes.push(Spans::from("No changes."));
+        }
+        self.changes_scroll = self.changes_scroll.min(changes.len() as u16 - 1);
+        f.render_widget(
+            Paragraph::new(changes)
+                .wrap(Wrap { trim: false })
+                .scroll((self.changes_scroll, 0)),
+            r,
+        );
+    }
+    fn build_changes(&self) -> Vec<Spans<'static>> {
+        let schema_name = self.schema_name.as_deref().unwrap_or_default();
+        let table_name = self.table_name.as_deref().unwrap_or(NEW_TABLE_NAME);
+        let mut changes = Vec::new();
+        changes.extend(get_diff_spans(
+            "field",
+            &self.old_fields,
+            &self.fields,
+            |f| *f.id(),
+            |f| f.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "index",
+            &self.old_indexes,
+            &self.indexes,
+            |i| *i.id(),
+            |i| i.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "foreign key",
+            &self.old_foreign_keys,
+            &self.foreign_keys,
+            |k| *k.id(),
+            |k| k.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "unique",
+            &self.old_uniques,
+            &self.uniques,
+            |u| *u.id(),
+            |u| u.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "check",
+            &self.old_checks,
+            &self.checks,
+            |c| *c.id(),
+            |c| c.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "exclude",
+            &self.old_excludes,
+            &self.excludes,
+            |e| *e.id(),
+            |e| e.get_create_ddl(schema_name, table_name).0,
+        ));
+        changes.extend(get_diff_spans(
+            "rule",
+            &self.old_rules,
+            &self.rules,
+            |r| *r.id(),
+            |r| r.get_create_ddl(schema_name, table_name),
+        ));
+        changes.extend(get_diff_spans(
+            "trigger",
+            &self.old_triggers,
+            &self.triggers,
+            |t| *t.id(),
+            |t| t.get_create_ddl(schema_name, table_name).0,
+        ));
+        let comment = self.comment.lines().join("\n");
+        let old_comment = self.old_comment.lines().join("\n");
+        changes.extend(get_value_diff_spans(
+            "comment",
+            Some(old_comment).filter(|c| !c.is_empty()),
+            Some(comment).filter(|c| !c.is_empty()),
+        ));
+        changes
+    }
     fn draw_sql_preview<B>(&mut self, f: &mut Frame<B>, r: Rect)
     where
         B: Backend,
     {
         let sql = self.build_sql(None);
-        self.sql_preview = TextArea::from(sql.lines());
-        f.render_widget(self.sql_preview.widget(), r);
+        let lines = sql.lines().count().max(1);
+        self.sql_preview_scroll = self.sql_preview_scroll.min(lines as u16 - 1);
+        f.render_widget(
+            Paragraph::new(highlight_sql(&sql, &self.syntax_theme))
+                .scroll((self.sql_preview_scroll, 0)),
+            r,
+        );
+        f.render_widget(
+            Scrollbar::new(lines, self.sql_preview_scroll as usize, r.height as usize),
+            r,
+        );
+    }
+    fn push_undo(&mut self) {
+        self.undo_stack.push(Snapshot {
+            fields: self.fields.clone(),
+            indexes: self.indexes.clone(),
+            foreign_keys: self.foreign_keys.clone(),
+            uniques: self.uniques.clone(),
+            checks: self.checks.clone(),
+            excludes: self.excludes.clone(),
+            rules: self.rules.clone(),
+            triggers: self.triggers.clone(),
+        });
+        if self.undo_stack.len() > UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+    }
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo_stack.pop() {
+            self.fields = snapshot.fields;
+            self.indexes = snapshot.indexes;
+            self.foreign_keys = snapshot.foreign_keys;
+            self.uniques = snapshot.uniques;
+            self.checks = snapshot.checks;
+            self.excludes = snapshot.excludes;
+            self.rules = snapshot.rules;
+            self.triggers = snapshot.triggers;
+            for (state, len) in [
+                (&mut self.fields_state, self.fields.len()),
+                (&mut self.indexes_state, self.indexes.len()),
+                (&mut self.foreign_keys_state, self.foreign_keys.len()),
+                (&mut self.uniques_state, self.uniques.len()),
+                (&mut self.checks_state, self.checks.len()),
+                (&mut self.excludes_state, self.excludes.len()),
+                (&mut self.rules_state, self.rules.len()),
+                (&mut self.triggers_state, self.triggers.len()),
+            ] {
+                if state.selected().map_or(false, |i| i >= len) {
+                    state.select(None);
+                }
+            }
+        }
     }
     pub fn clear(&mut self) {
+        self.undo_stack.clear();
         self.conn_id = None;
         self.db_name = None;
         self.schema_name = None;
         self.table_name = None;
         self.panel = PanelKind::Fields;
+        self.changes_scroll = 0;
         self.fields = Vec::new();
         self.old_fields = Vec::new();
         self.indexes = Vec::new();
         self.old_indexes = Vec::new();
         self.foreign_keys = Vec::new();
         self.old_foreign_keys = Vec::new();
+        self.references = Vec::new();
         self.uniques = Vec::new();
         self.old_uniques = Vec::new();
         self.checks = Vec::new();
@@ -1906,10 +2199,11 @@ impl<'a> TableDetailComponent<'a> {
         self.old_triggers = Vec::new();
         self.comment = TextArea::default();
         self.old_comment = TextArea::default();
-        self.sql_preview = TextArea::default();
+        self.sql_preview_scroll = 0;
         self.fields_state = TableState::default();
         self.indexes_state = TableState::default();
         self.foreign_keys_state = TableState::default();
+        self.references_state = TableState::default();
         self.uniques_state = TableState::default();
         self.checks_state = TableState::default();
         self.excludes_state = TableState::default();
@@ -1963,6 +2257,33 @@ impl<'a> TableDetailComponent<'a> {
         }
         ComponentResult::Done
     }
+    fn handle_error_dlg_event(&mut self, key: &Key) -> ComponentResult {
+        if let Some(dlg) = self.error_dlg.as_mut() {
+            match dlg.handle_event(key) {
+                DialogResult::Cancel | DialogResult::Confirm(_) => {
+                    self.error_dlg = None;
+                }
+                _ => (),
+            }
+        }
+        ComponentResult::Done
+    }
+    fn validate_field(&self, field: &Field) -> Option<String> {
+        let name = field.name();
+        if name.trim().is_empty() {
+            Some("Field name can not be empty".to_string())
+        } else if name.trim() != name || name.contains('"') || name.chars().count() > 63 {
+            Some(format!("Field name '{}' is not a valid identifier", name))
+        } else if self
+            .fields
+            .iter()
+            .any(|f| f.id() != field.id() && f.name() == name)
+        {
+            Some(format!("Field '{}' already exists", name))
+        } else {
+            None
+        }
+    }
     fn handle_field_dlg_event(&mut self, key: &Key) -> Result<ComponentResult> {
         if let Some(dlg) = self.field_dlg.as_mut() {
             match dlg.handle_event(key)? {
@@ -1971,7 +2292,15 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(map) => {
                     let field = Self::map_to_pg_field(&map);
-                    match dlg.get_id() {
+                    if let Some(msg) = self.validate_field(&field) {
+                        self.error_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Error,
+                            "Invalid Field",
+                            msg.as_str(),
+                        ));
+                        return Ok(ComponentResult::Done);
+                    }
+                    match self.field_dlg.as_ref().unwrap().get_id() {
                         None => self.fields.push(field),
                         Some(_) => {
                             if let Some(index) = self.fields_state.selected() {
@@ -2204,10 +2533,16 @@ impl<'a> TableDetailComponent<'a> {
             kind: FieldKind::try_from(map.get("type").unwrap().as_deref().unwrap()).unwrap(),
             not_null: not_null == "true",
             key: key == "true",
+            array: map
+                .get("array")
+                .map_or(false, |array| array.as_deref() == Some("true")),
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
             default_value: map
                 .get("default value")
                 .map(|dv| dv.to_owned().unwrap_or_default()),
+            default_expression: map
+                .get("default expression")
+                .map_or(false, |de| de.as_deref() == Some("true")),
             length: {
                 let length = map.get("length");
                 if let Some(length) = length {
@@ -2246,13 +2581,9 @@ impl<'a> TableDetailComponent<'a> {
                 Uuid::new_v4()
             },
             name: map.get("name").unwrap().as_ref().unwrap().to_string(),
-            fields: map
-                .get("fields")
-                .unwrap()
-                .as_ref()
-                .unwrap()
-                .split(',')
-                .map(|f| IndexField::try_from(f).unwrap())
+            fields: split_top_level(map.get("fields").unwrap().as_ref().unwrap(), ',')
+                .iter()
+                .map(|f| IndexField::try_from(f.as_str()).unwrap())
                 .collect(),
             index_method: map
                 .get("index method")
@@ -2261,7 +2592,15 @@ impl<'a> TableDetailComponent<'a> {
                 .map(|m| IndexMethod::try_from(m.as_str()).unwrap()),
             unique: map.get("unique").unwrap().as_ref().unwrap() == "true",
             concurrent: map.get("concurrent").unwrap().as_ref().unwrap() == "true",
+            include: map
+                .get("include")
+                .and_then(|i| i.as_ref())
+                .map(|i| i.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
+            predicate: map.get("where").unwrap().clone(),
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
+            scans: None,
+            size: None,
         }
     }
     fn map_to_foreign_key(map: &HashMap<String, Option<String>>) -> ForeignKey {
@@ -2276,7 +2615,11 @@ impl<'a> TableDetailComponent<'a> {
                 Uuid::new_v4()
             },
             name: map.get("name").unwrap().as_ref().unwrap().to_string(),
-            field: map.get("field").unwrap().as_ref().unwrap().to_string(),
+            fields: map
+                .get("fields")
+                .and_then(|f| f.as_ref())
+                .map(|f| f.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
             ref_schema: map
                 .get("reference schema")
                 .unwrap()
@@ -2289,12 +2632,11 @@ impl<'a> TableDetailComponent<'a> {
                 .as_ref()
                 .unwrap()
                 .to_string(),
-            ref_field: map
-                .get("reference field")
-                .unwrap()
-                .as_ref()
-                .unwrap()
-                .to_string(),
+            ref_fields: map
+                .get("reference fields")
+                .and_then(|f| f.as_ref())
+                .map(|f| f.split(',').map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
             on_delete: map
                 .get("on delete")
                 .unwrap()
@@ -2305,6 +2647,13 @@ impl<'a> TableDetailComponent<'a> {
                 .unwrap()
                 .as_ref()
                 .map(|u| OnUpdateKind::try_from(u.as_str()).unwrap()),
+            match_kind: map
+                .get("match")
+                .unwrap()
+                .as_ref()
+                .map(|m| MatchKind::try_from(m.as_str()).unwrap()),
+            deferrable: map.get("deferrable").unwrap().as_deref() == Some("true"),
+            initially_deferred: map.get("initially deferred").unwrap().as_deref() == Some("true"),
             comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
         }
     }
@@ -2412,6 +2761,7 @@ impl<'a> TableDetailComponent<'a> {
                 .unwrap()
                 .as_ref()
                 .map(|s| s.to_string()),
+            comment: map.get("comment").unwrap().as_ref().map(|s| s.to_string()),
         }
     }
 
@@ -2477,6 +2827,7 @@ impl<'a> TableDetailComponent<'a> {
                     self.conns.clone(),
                     self.pools.clone(),
                     &self.conn_id.unwrap(),
+                    self.db_name.as_deref(),
                     &sql,
                 )
                 .await?;
@@ -2502,18 +2853,22 @@ impl<'a> TableDetailComponent<'a> {
             self.handle_back_event();
         } else if matches!(*key, SAVE_KEY) {
             self.handle_save_event().await?;
+        } else if matches!(*key, UNDO_KEY) {
+            self.undo();
         } else {
-            match self.panel {
-                PanelKind::Fields => self.handle_panel_fields_event(key).await?,
-                PanelKind::Indexes => self.handle_panel_indexes_event(key).await?,
-                PanelKind::ForeignKeys => self.handle_panel_foreign_keys_event(key).await?,
-                PanelKind::Uniques => self.handle_panel_unique_event(key).await?,
-                PanelKind::Excludes => self.handle_panel_exclude_event(key).await?,
-                PanelKind::Rules => self.handle_panel_rule_event(key).await?,
-                PanelKind::Triggers => self.handle_panel_triggers_event(key).await?,
-                PanelKind::Checks => self.handle_panel_checks_event(key).await?,
-                PanelKind::Comment => self.handle_panel_comment_event(key).await?,
-                PanelKind::SQLPreview => self.handle_panel_sql_preview_event(key).await?,
+            return match self.panel {
+                PanelKind::Fields => self.handle_panel_fields_event(key).await,
+                PanelKind::Indexes => self.handle_panel_indexes_event(key).await,
+                PanelKind::ForeignKeys => self.handle_panel_foreign_keys_event(key).await,
+                PanelKind::References => self.handle_panel_references_event(key).await,
+                PanelKind::Uniques => self.handle_panel_unique_event(key).await,
+                PanelKind::Excludes => self.handle_panel_exclude_event(key).await,
+                PanelKind::Rules => self.handle_panel_rule_event(key).await,
+                PanelKind::Triggers => self.handle_panel_triggers_event(key).await,
+                PanelKind::Checks => self.handle_panel_checks_event(key).await,
+                PanelKind::Comment => self.handle_panel_comment_event(key).await,
+                PanelKind::Changes => self.handle_panel_changes_event(key).await,
+                PanelKind::SQLPreview => self.handle_panel_sql_preview_event(key).await,
             };
         }
         Ok(ComponentResult::Done)
@@ -2558,6 +2913,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.fields.is_empty() {
                     if let Some(index) = self.fields_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.fields.swap(index, index - 1);
                         }
                     }
@@ -2567,6 +2923,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.fields.is_empty() {
                     if let Some(index) = self.fields_state.selected() {
                         if index < self.fields.len() - 1 {
+                            self.push_undo();
                             self.fields.swap(index, index + 1);
                         }
                     }
@@ -2646,6 +3003,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.indexes.is_empty() {
                     if let Some(index) = self.indexes_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.indexes.swap(index, index - 1);
                         }
                     }
@@ -2655,6 +3013,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.indexes.is_empty() {
                     if let Some(index) = self.indexes_state.selected() {
                         if index < self.indexes.len() - 1 {
+                            self.push_undo();
                             self.indexes.swap(index, index + 1);
                         }
                     }
@@ -2664,6 +3023,33 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
+    async fn handle_panel_references_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            TAB_RIGHT_KEY => {
+                self.panel = PanelKind::Uniques;
+            }
+            TAB_LEFT_KEY => {
+                self.panel = PanelKind::ForeignKeys;
+            }
+            UP_KEY => {
+                if !self.references.is_empty() {
+                    let index = get_table_up_index(self.references_state.selected());
+                    self.references_state.select(Some(index));
+                }
+            }
+            DOWN_KEY => {
+                if !self.references.is_empty() {
+                    let index = get_table_down_index(
+                        self.references_state.selected(),
+                        self.references.len(),
+                    );
+                    self.references_state.select(Some(index));
+                }
+            }
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
     async fn handle_panel_unique_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             NEW_KEY => {
@@ -2688,7 +3074,7 @@ impl<'a> TableDetailComponent<'a> {
                 self.panel = PanelKind::Checks;
             }
             TAB_LEFT_KEY => {
-                self.panel = PanelKind::ForeignKeys;
+                self.panel = PanelKind::References;
             }
             UP_KEY => {
                 if !self.uniques.is_empty() {
@@ -2707,6 +3093,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.uniques.is_empty() {
                     if let Some(index) = self.uniques_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.uniques.swap(index, index - 1);
                         }
                     }
@@ -2716,6 +3103,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.uniques.is_empty() {
                     if let Some(index) = self.uniques_state.selected() {
                         if index < self.uniques.len() - 1 {
+                            self.push_undo();
                             self.uniques.swap(index, index + 1);
                         }
                     }
@@ -2795,6 +3183,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.excludes.is_empty() {
                     if let Some(index) = self.excludes_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.excludes.swap(index, index - 1);
                         }
                     }
@@ -2804,6 +3193,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.excludes.is_empty() {
                     if let Some(index) = self.excludes_state.selected() {
                         if index < self.excludes.len() - 1 {
+                            self.push_undo();
                             self.excludes.swap(index, index + 1);
                         }
                     }
@@ -2854,6 +3244,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.rules.is_empty() {
                     if let Some(index) = self.rules_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.rules.swap(index, index - 1);
                         }
                     }
@@ -2863,6 +3254,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.rules.is_empty() {
                     if let Some(index) = self.rules_state.selected() {
                         if index < self.rules.len() - 1 {
+                            self.push_undo();
                             self.rules.swap(index, index + 1);
                         }
                     }
@@ -2910,6 +3302,16 @@ impl<'a> TableDetailComponent<'a> {
                     ));
                 }
             }
+            QUERY_KEY => {
+                if self.table_name.is_some() && !self.foreign_keys.is_empty() {
+                    return Ok(ComponentResult::Goto(Goto::QueryDetailPG {
+                        conn_id: self.conn_id.unwrap(),
+                        db_name: self.db_name.clone().unwrap(),
+                        query_name: None,
+                        sql: Some(self.get_join_query()),
+                    }));
+                }
+            }
             DELETE_KEY => {
                 if self.foreign_keys_state.selected().is_some() {
                     self.delete_foreign_key_dlg = Some(ConfirmDialog::new(
@@ -2920,7 +3322,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
             }
             TAB_RIGHT_KEY => {
-                self.panel = PanelKind::Uniques;
+                self.panel = PanelKind::References;
             }
             TAB_LEFT_KEY => {
                 self.panel = PanelKind::Indexes;
@@ -2944,6 +3346,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.foreign_keys.is_empty() {
                     if let Some(index) = self.foreign_keys_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.foreign_keys.swap(index, index - 1);
                         }
                     }
@@ -2953,6 +3356,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.foreign_keys.is_empty() {
                     if let Some(index) = self.foreign_keys_state.selected() {
                         if index < self.foreign_keys.len() - 1 {
+                            self.push_undo();
                             self.foreign_keys.swap(index, index + 1);
                         }
                     }
@@ -3024,6 +3428,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.triggers.is_empty() {
                     if let Some(index) = self.triggers_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.triggers.swap(index, index - 1);
                         }
                     }
@@ -3033,6 +3438,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.triggers.is_empty() {
                     if let Some(index) = self.triggers_state.selected() {
                         if index < self.triggers.len() - 1 {
+                            self.push_undo();
                             self.triggers.swap(index, index + 1);
                         }
                     }
@@ -3085,6 +3491,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.checks.is_empty() {
                     if let Some(index) = self.checks_state.selected() {
                         if index > 0 {
+                            self.push_undo();
                             self.checks.swap(index, index - 1);
                         }
                     }
@@ -3094,6 +3501,7 @@ impl<'a> TableDetailComponent<'a> {
                 if !self.checks.is_empty() {
                     if let Some(index) = self.checks_state.selected() {
                         if index < self.checks.len() - 1 {
+                            self.push_undo();
                             self.checks.swap(index, index + 1);
                         }
                     }
@@ -3106,7 +3514,7 @@ impl<'a> TableDetailComponent<'a> {
     async fn handle_panel_comment_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_LEFT_KEY => self.panel = PanelKind::Triggers,
-            TAB_RIGHT_KEY => self.panel = PanelKind::SQLPreview,
+            TAB_RIGHT_KEY => self.panel = PanelKind::Changes,
             _ => {
                 let key: Input = key.to_owned().into();
                 self.comment.input(key);
@@ -3114,10 +3522,30 @@ impl<'a> TableDetailComponent<'a> {
         }
         Ok(ComponentResult::Done)
     }
-    async fn handle_panel_sql_preview_event(&mut self, key: &Key) -> Result<ComponentResult> {
+    async fn handle_panel_changes_event(&mut self, key: &Key) -> Result<ComponentResult> {
         match *key {
             TAB_LEFT_KEY => self.panel = PanelKind::Comment,
+            TAB_RIGHT_KEY => self.panel = PanelKind::SQLPreview,
+            UP_KEY => self.changes_scroll = self.changes_scroll.saturating_sub(1),
+            DOWN_KEY => self.changes_scroll += 1,
+            _ => (),
+        }
+        Ok(ComponentResult::Done)
+    }
+    async fn handle_panel_sql_preview_event(&mut self, key: &Key) -> Result<ComponentResult> {
+        match *key {
+            TAB_LEFT_KEY => self.panel = PanelKind::Changes,
             TAB_RIGHT_KEY => self.panel = PanelKind::Fields,
+            UP_KEY => self.sql_preview_scroll = self.sql_preview_scroll.saturating_sub(1),
+            DOWN_KEY => self.sql_preview_scroll += 1,
+            COPY_SQL_KEY => {
+                copy_to_clipboard(&self.build_sql(None))?;
+                self.info_dlg = Some(ConfirmDialog::new(
+                    ConfirmKind::Info,
+                    "Copied",
+                    "SQL copied to clipboard",
+                ));
+            }
             _ => (),
         }
         Ok(ComponentResult::Done)
@@ -3134,11 +3562,12 @@ impl<'a> TableDetailComponent<'a> {
                         self.conns.clone(),
                         self.pools.clone(),
                         &self.conn_id.unwrap(),
+                        self.db_name.as_deref(),
                         &sql,
                     )
                     .await?;
                     self.clear();
-                    return Ok(ComponentResult::BackRefresh(MainPanel::TableListMySQL));
+                    return Ok(ComponentResult::BackRefresh(MainPanel::TableListPG));
                 }
                 _ => (),
             }
@@ -3153,6 +3582,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.fields_state.selected() {
+                        self.push_undo();
                         self.fields.remove(index);
                         self.fields_state.select(None);
                         self.delete_field_dlg = None;
@@ -3171,6 +3601,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.indexes_state.selected() {
+                        self.push_undo();
                         self.indexes.remove(index);
                         self.indexes_state.select(None);
                         self.delete_index_dlg = None;
@@ -3189,6 +3620,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.uniques_state.selected() {
+                        self.push_undo();
                         self.uniques.remove(index);
                         self.uniques_state.select(None);
                         self.delete_unique_dlg = None;
@@ -3208,6 +3640,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.excludes_state.selected() {
+                        self.push_undo();
                         self.excludes.remove(index);
                         self.excludes_state.select(None);
                         self.delete_exclude_dlg = None;
@@ -3227,6 +3660,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.rules_state.selected() {
+                        self.push_undo();
                         self.rules.remove(index);
                         self.rules_state.select(None);
                         self.delete_rule_dlg = None;
@@ -3245,6 +3679,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.checks_state.selected() {
+                        self.push_undo();
                         self.checks.remove(index);
                         self.checks_state.select(None);
                         self.delete_check_dlg = None;
@@ -3263,6 +3698,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.triggers_state.selected() {
+                        self.push_undo();
                         self.triggers.remove(index);
                         self.triggers_state.select(None);
                         self.delete_trigger_dlg = None;
@@ -3281,6 +3717,7 @@ impl<'a> TableDetailComponent<'a> {
                 }
                 DialogResult::Confirm(_) => {
                     if let Some(index) = self.foreign_keys_state.selected() {
+                        self.push_undo();
                         self.foreign_keys.remove(index);
                         self.foreign_keys_state.select(None);
                         self.delete_foreign_key_dlg = None;
@@ -3,20 +3,22 @@ use crate::{
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
     dialog::{confirm::Kind as ConfirmKind, pg::RuleDialog, ConfirmDialog, InputDialog},
     event::{config::*, Key},
-    model::pg::{get_pg_role_names, get_pg_view, Connections, Rule, View},
-    pool::{execute_pg_query, execute_pg_query_unprepared, get_pg_pool, PGPools},
-    widget::{Form, FormItem},
+    model::pg::{
+        comment_literal, get_pg_role_names, get_pg_view, quote_ident, Connections, Rule, View,
+    },
+    pool::{execute_pg_query_unprepared, get_pg_pool, PGPools},
+    widget::{DataTable, Form, FormItem},
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState, Tabs},
+    widgets::{Block, BorderType, Borders, TableState, Tabs},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use tui_textarea::{Input, TextArea};
 use uuid::Uuid;
 
@@ -57,13 +59,17 @@ impl<'a> ViewDetailComponent<'a> {
         pools: Rc<RefCell<PGPools>>,
     ) -> Self {
         let mut form = Form::default();
-        form.set_items(vec![FormItem::new_select(
-            "Owner".to_string(),
-            vec![],
-            None,
-            true,
-            false,
-        )]);
+        form.set_items(vec![
+            FormItem::new_select("Owner".to_string(), vec![], None, true, false),
+            FormItem::new_select(
+                "Check Option".to_string(),
+                vec!["LOCAL".to_string(), "CASCADED".to_string()],
+                None,
+                true,
+                false,
+            ),
+            FormItem::new_check("Security Barrier".to_string(), false, false),
+        ]);
         ViewDetailComponent {
             conn_id: None,
             db_name: None,
@@ -120,6 +126,12 @@ impl<'a> ViewDetailComponent<'a> {
             if let FormItem::Select { selected, .. } = self.form.get_item_mut("Owner").unwrap() {
                 *selected = view.owner.clone();
             }
+            if let Some(check_option) = view.check_option.as_deref() {
+                self.form.set_value("Check Option", check_option);
+            }
+            if view.security_barrier {
+                self.form.set_value("Security Barrier", "true");
+            }
 
             self.view = Some(view);
         }
@@ -219,39 +231,24 @@ impl<'a> ViewDetailComponent<'a> {
     where
         B: Backend,
     {
-        let table = Table::new(
-            self.rules
-                .iter()
-                .map(|r| {
-                    RowUI::new(vec![
-                        r.name(),
-                        r.event(),
-                        r.do_instead().unwrap_or(""),
-                        r.where_condition().unwrap_or(""),
-                        r.definition().unwrap_or(""),
-                        r.comment().unwrap_or(""),
-                    ])
-                })
-                .collect::<Vec<RowUI>>(),
-        )
-        .header(RowUI::new(vec![
+        let table = DataTable::new(self.rules.iter().map(|r| {
+            vec![
+                r.name(),
+                r.event(),
+                r.do_instead().unwrap_or(""),
+                r.where_condition().unwrap_or(""),
+                r.definition().unwrap_or(""),
+                r.comment().unwrap_or(""),
+            ]
+        }))
+        .header(vec![
             "Name",
             "Event",
             "Do Instead",
             "Where",
             "Definition",
             "Comment",
-        ]))
-        .block(Block::default())
-        .widths(&[
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-            Constraint::Ratio(1, 6),
-        ])
-        .highlight_style(Style::default().fg(Color::Green));
+        ]);
 
         f.render_stateful_widget(table, r, &mut self.rules_state);
     }
@@ -288,12 +285,12 @@ impl<'a> ViewDetailComponent<'a> {
                 let sql = self.build_sql(None);
                 let sql = sql.trim();
                 if !sql.is_empty() {
-                    execute_pg_query(
+                    execute_pg_query_unprepared(
                         self.conns.clone(),
                         self.pools.clone(),
                         self.conn_id.as_ref().unwrap(),
                         self.db_name.as_deref(),
-                        &self.build_sql(None),
+                        sql,
                     )
                     .await?;
                     let mut view = self.view.as_mut().unwrap();
@@ -301,6 +298,9 @@ impl<'a> ViewDetailComponent<'a> {
                     view.rules = self.rules.clone();
                     view.owner = self.form.get_value("Owner");
                     view.comment = self.comment.lines().join("\n");
+                    view.check_option = self.form.get_value("Check Option");
+                    view.security_barrier =
+                        self.form.get_value("Security Barrier").as_deref() == Some("true");
                     self.info_dlg = Some(ConfirmDialog::new(
                         ConfirmKind::Info,
                         "Success",
@@ -398,10 +398,26 @@ impl<'a> ViewDetailComponent<'a> {
                             self.conns.clone(),
                             self.pools.clone(),
                             &self.conn_id.unwrap(),
+                            self.db_name.as_deref(),
                             sql,
                         )
                         .await?;
+                        let pool = get_pg_pool(
+                            self.conns.clone(),
+                            self.pools.clone(),
+                            self.conn_id.as_ref().unwrap(),
+                            self.db_name.as_deref(),
+                        )
+                        .await?;
+                        self.view = Some(
+                            get_pg_view(&pool, self.schema_name.as_deref().unwrap(), &name).await?,
+                        );
                         self.input_dlg = None;
+                        self.info_dlg = Some(ConfirmDialog::new(
+                            ConfirmKind::Info,
+                            "Success",
+                            "Save Success",
+                        ));
                     }
                 }
                 _ => (),
@@ -506,69 +522,80 @@ impl<'a> ViewDetailComponent<'a> {
         self.rules_state = TableState::default();
         self.form.clear();
     }
-    fn build_create_ddl(&self, view_name: Option<&str>) -> String {
-        let view_name = if let Some(name) = view_name {
-            name
+    fn build_view_ddl(&self, view_name: &str) -> String {
+        let definition = self.definition.lines().join("\n");
+        let definition = definition.trim().trim_end_matches(';');
+        let options = if self.form.get_value("Security Barrier").as_deref() == Some("true") {
+            " WITH (security_barrier=true)"
         } else {
-            "new view"
+            ""
         };
-
-        let view_ddl = format!(
-            "CREATE VIEW \"{}\" AS {}",
-            view_name,
-            self.definition.lines().join("\n")
-        );
-
-        let owner_option = self.form.get_value("Owner");
-
-        let alter_view_ddl = if let Some(owner) = owner_option {
-            format!("ALTER VIEW \"{}\" OWNER TO {}", view_name, owner)
-        } else {
-            String::new()
-        };
-        let rules_ddl = if !self.rules.is_empty() {
-            self.rules
-                .iter()
-                .map(|rule| rule.get_create_ddl(self.schema_name.as_deref().unwrap(), view_name))
-                .collect::<Vec<String>>()
-                .join("\n")
-        } else {
-            String::new()
-        };
-        let comment = self.comment.lines().join("\n");
-        let comment_ddl = if !comment.is_empty() {
-            format!("COMMENT ON {}", comment)
+        let check_option = if let Some(check) = self.form.get_value("Check Option") {
+            format!(" WITH {} CHECK OPTION", check)
         } else {
             String::new()
         };
         format!(
-            "{}\n{}\n{}\n{}",
-            view_ddl, alter_view_ddl, rules_ddl, comment_ddl
+            "CREATE OR REPLACE VIEW {}.{}{} AS {}{}",
+            quote_ident(self.schema_name.as_deref().unwrap()),
+            quote_ident(view_name),
+            options,
+            definition,
+            check_option
         )
     }
+    fn build_create_ddl(&self, view_name: Option<&str>) -> String {
+        let view_name = view_name.unwrap_or("new view");
+        let schema_name = self.schema_name.as_deref().unwrap();
+
+        let mut ddl = vec![self.build_view_ddl(view_name)];
+        if let Some(owner) = self.form.get_value("Owner") {
+            ddl.push(format!(
+                "ALTER VIEW {}.{} OWNER TO {}",
+                quote_ident(schema_name),
+                quote_ident(view_name),
+                owner
+            ));
+        }
+        self.rules
+            .iter()
+            .for_each(|rule| ddl.push(rule.get_create_ddl(schema_name, view_name)));
+        let comment = self.comment.lines().join("\n");
+        if !comment.is_empty() {
+            ddl.push(format!(
+                "COMMENT ON VIEW {}.{} IS {}",
+                quote_ident(schema_name),
+                quote_ident(view_name),
+                comment_literal(Some(&comment))
+            ));
+        }
+        format!("{};", ddl.join(";\n"))
+    }
     fn build_alter_ddl(&self) -> String {
         let mut ddl = Vec::new();
         if let Some(view) = self.view.as_ref() {
-            if view.definition != self.definition.lines().join("\n") {
-                ddl.push(format!(
-                    "DROP VIEW \"{}\".\"{}\"",
-                    self.schema_name.as_deref().unwrap(),
-                    view.name
-                ));
-                ddl.push(self.build_create_ddl(Some(&view.name)));
-            } else {
-                let (mut alter_ddl, mut comment_ddl) = self.build_rule_alter_ddl();
-                ddl.append(&mut alter_ddl);
-                ddl.append(&mut comment_ddl);
-                let mut rules_ddl = self.build_advanced_alter_ddl();
-                ddl.append(&mut rules_ddl);
+            if view.definition != self.definition.lines().join("\n")
+                || view.check_option != self.form.get_value("Check Option")
+                || view.security_barrier
+                    != (self.form.get_value("Security Barrier").as_deref() == Some("true"))
+            {
+                ddl.push(self.build_view_ddl(&view.name));
+            }
+            let (mut alter_ddl, mut comment_ddl) = self.build_rule_alter_ddl();
+            ddl.append(&mut alter_ddl);
+            ddl.append(&mut comment_ddl);
+            let mut rules_ddl = self.build_advanced_alter_ddl();
+            ddl.append(&mut rules_ddl);
 
-                if let Some(comment) = self.build_comment_alter_ddl() {
-                    ddl.push(comment);
-                }
+            if let Some(comment) = self.build_comment_alter_ddl() {
+                ddl.push(comment);
             }
         }
-        ddl.join("\n")
+        if ddl.is_empty() {
+            String::new()
+        } else {
+            format!("{};", ddl.join(";\n"))
+        }
     }
     fn build_rule_alter_ddl(&self) -> (Vec<String>, Vec<String>) {
         let mut ddl = Vec::new();
@@ -618,14 +645,14 @@ impl<'a> ViewDetailComponent<'a> {
         let mut ddl = Vec::new();
         if self.view.as_ref().unwrap().owner != self.form.get_value("Owner") {
             ddl.push(format!(
-                "ALTER VIEW \"{}\".\"{}\" OWNER TO {}",
-                self.schema_name.as_deref().unwrap(),
-                self.view.as_ref().unwrap().name.as_str(),
+                "ALTER VIEW {}.{} OWNER TO {}",
+                quote_ident(self.schema_name.as_deref().unwrap()),
+                quote_ident(self.view.as_ref().unwrap().name.as_str()),
                 if let Some(owner) = self.form.get_value("Owner") {
                     owner
                 } else {
                     "CURRENT_ROLE".to_string()
-                },
+                }
             ));
         }
         ddl
@@ -634,10 +661,10 @@ impl<'a> ViewDetailComponent<'a> {
         let comment = self.comment.lines().join("\n");
         if self.view.as_ref().unwrap().comment != comment {
             Some(format!(
-                "COMMENT ON VIEW \"{}\".\"{}\" IS '{}'",
-                self.schema_name.as_deref().unwrap(),
-                self.view.as_ref().unwrap().name.as_str(),
-                comment,
+                "COMMENT ON VIEW {}.{} IS {}",
+                quote_ident(self.schema_name.as_deref().unwrap()),
+                quote_ident(self.view.as_ref().unwrap().name.as_str()),
+                comment_literal(Some(&comment))
             ))
         } else {
             None
@@ -3,18 +3,19 @@ use crate::{
     component::{get_table_down_index, get_table_up_index, Command, CommandBarComponent},
     dialog::confirm::{ConfirmDialog, Kind as ConfirmKind},
     event::{config::*, Key},
-    model::pg::{get_pg_views, Connections, View},
+    model::pg::{get_pg_views, quote_ident, Connections, View},
     pool::{execute_pg_query, get_pg_pool, PGPools},
+    widget::DataTable,
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row as RowUI, Table, TableState},
+    widgets::{Block, BorderType, Borders, TableState},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use uuid::Uuid;
 
 pub struct ViewListComponent {
@@ -86,16 +87,12 @@ impl ViewListComponent {
                 }),
             r,
         );
-        let table = Table::new(
+        let table = DataTable::new(
             self.views
                 .iter()
-                .map(|view| RowUI::new(vec![view.name.as_str(), view.comment.as_str()]))
-                .collect::<Vec<RowUI>>(),
+                .map(|view| vec![view.name.as_str(), view.comment.as_str()]),
         )
-        .header(RowUI::new(vec!["Name", "Comment"]))
-        .block(Block::default())
-        .widths(&[Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
-        .highlight_style(Style::default().fg(Color::Green));
+        .header(vec!["Name", "Comment"]);
         f.render_stateful_widget(
             table,
             r.inner(&Margin {
@@ -203,7 +200,7 @@ impl ViewListComponent {
                             self.pools.clone(),
                             self.conn_id.as_ref().unwrap(),
                             self.db_name.as_deref(),
-                            &format!("DROP VIEW \"{}\"", view.name),
+                            &format!("DROP VIEW {}", quote_ident(&view.name)),
                         )
                         .await?;
                         self.delete_dlg = None;
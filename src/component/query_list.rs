@@ -7,16 +7,17 @@ use crate::{
         query::{Queries, Query},
         DatabaseKind,
     },
+    widget::DataTable,
 };
 use anyhow::Result;
-use std::{cell::RefCell, rc::Rc};
-use tui::{
+use ratatui::{
     backend::Backend,
-    layout::{Constraint, Margin, Rect},
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Row, Table, TableState},
+    widgets::{Block, BorderType, Borders, TableState},
     Frame,
 };
+use std::{cell::RefCell, rc::Rc};
 use uuid::Uuid;
 
 pub struct QueryListComponent {
@@ -71,43 +72,29 @@ impl QueryListComponent {
             r,
         );
 
-        let data = self
-            .query_list
-            .iter()
-            .map(|q| {
-                Row::new(vec![
-                    q.name.clone(),
-                    q.file_size.to_string(),
-                    q.created_date
-                        .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
-                        .unwrap_or_default(),
-                    q.modified_date
-                        .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
-                        .unwrap_or_default(),
-                    q.access_time
-                        .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
-                        .unwrap_or_default(),
-                ])
-            })
-            .collect::<Vec<Row>>();
+        let data = self.query_list.iter().map(|q| {
+            vec![
+                q.name.clone(),
+                q.file_size.to_string(),
+                q.created_date
+                    .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                q.modified_date
+                    .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                q.access_time
+                    .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+            ]
+        });
 
-        let table = Table::new(data)
-            .header(Row::new(vec![
-                "Name",
-                "File Size",
-                "Created Date",
-                "Modified Date",
-                "Access Time",
-            ]))
-            .block(Block::default())
-            .widths(&[
-                Constraint::Ratio(1, 5),
-                Constraint::Ratio(1, 5),
-                Constraint::Ratio(1, 5),
-                Constraint::Ratio(1, 5),
-                Constraint::Ratio(1, 5),
-            ])
-            .highlight_style(Style::default().fg(Color::Green));
+        let table = DataTable::new(data).header(vec![
+            "Name",
+            "File Size",
+            "Created Date",
+            "Modified Date",
+            "Access Time",
+        ]);
         f.render_stateful_widget(
             table,
             r.inner(&Margin {
@@ -149,6 +136,7 @@ impl QueryListComponent {
                         conn_id: self.conn_id.unwrap(),
                         db_name: self.db_name.clone().unwrap(),
                         query_name: None,
+                        sql: None,
                     }));
                 }
                 DatabaseKind::PostgreSQL => {
@@ -156,6 +144,7 @@ impl QueryListComponent {
                         conn_id: self.conn_id.unwrap(),
                         db_name: self.db_name.clone().unwrap(),
                         query_name: None,
+                        sql: None,
                     }));
                 }
             },
@@ -167,6 +156,7 @@ impl QueryListComponent {
                                 conn_id: self.conn_id.unwrap(),
                                 db_name: self.db_name.clone().unwrap(),
                                 query_name: Some(self.query_list[index].name.to_string()),
+                                sql: None,
                             }));
                         }
                         DatabaseKind::PostgreSQL => {
@@ -174,6 +164,7 @@ impl QueryListComponent {
                                 conn_id: self.conn_id.unwrap(),
                                 db_name: self.db_name.clone().unwrap(),
                                 query_name: Some(self.query_list[index].name.to_string()),
+                                sql: None,
                             }));
                         }
                     }
@@ -0,0 +1,70 @@
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::Paragraph,
+    Frame,
+};
+use std::time::{Duration, Instant};
+
+const MESSAGE_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub enum StatusKind {
+    Info,
+    Warning,
+}
+
+struct StatusMessage {
+    kind: StatusKind,
+    text: String,
+    created_at: Instant,
+}
+
+pub struct StatusLineComponent {
+    message: Option<StatusMessage>,
+}
+
+impl StatusLineComponent {
+    pub fn new() -> StatusLineComponent {
+        StatusLineComponent { message: None }
+    }
+    pub fn draw<B>(&self, f: &mut Frame<B>, r: Rect)
+    where
+        B: Backend,
+    {
+        if let Some(message) = self.message.as_ref() {
+            let color = match message.kind {
+                StatusKind::Info => Color::Green,
+                StatusKind::Warning => Color::Yellow,
+            };
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    message.text.as_str(),
+                    Style::default().fg(color),
+                )),
+                r,
+            );
+        }
+    }
+    pub fn info(&mut self, text: &str) {
+        self.set_message(StatusKind::Info, text);
+    }
+    pub fn warning(&mut self, text: &str) {
+        self.set_message(StatusKind::Warning, text);
+    }
+    fn set_message(&mut self, kind: StatusKind, text: &str) {
+        self.message = Some(StatusMessage {
+            kind,
+            text: text.to_string(),
+            created_at: Instant::now(),
+        });
+    }
+    pub fn handle_tick(&mut self) {
+        if let Some(message) = self.message.as_ref() {
+            if message.created_at.elapsed() >= MESSAGE_TIMEOUT {
+                self.message = None;
+            }
+        }
+    }
+}
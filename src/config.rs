@@ -1,8 +1,13 @@
 use crate::{
     app::APP_DIR,
-    model::{mysql::Connection as MySQLConnection, pg::Connection as PGConnection, Connect},
+    model::{
+        mysql::Connection as MySQLConnection, pg::Connection as PGConnection, Connect,
+        DisplayFormat, DisplayTimezone,
+    },
+    widget::SyntaxTheme,
 };
 use anyhow::{Error, Result};
+use chrono::format::{Item, StrftimeItems};
 use serde::{Deserialize, Serialize};
 use std::{
     fs::{create_dir_all, File, OpenOptions},
@@ -18,6 +23,79 @@ pub struct Config {
     pub version: String,
     pub mysql_connections: Vec<MySQLConnection>,
     pub pg_connections: Vec<PGConnection>,
+    #[serde(default = "default_page_size")]
+    pub default_page_size: usize,
+    #[serde(default = "default_max_rows")]
+    pub max_rows: usize,
+    #[serde(default = "default_display_timezone")]
+    pub display_timezone: String,
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    #[serde(default = "default_time_format")]
+    pub time_format: String,
+    #[serde(default = "default_datetime_format")]
+    pub datetime_format: String,
+    #[serde(default = "default_restore_session")]
+    pub restore_session: bool,
+    #[serde(default = "default_syntax_theme")]
+    pub syntax_theme: String,
+    #[serde(default = "default_auto_pairs")]
+    pub auto_pairs: bool,
+    #[serde(default = "default_tinyint_as_bool")]
+    pub tinyint_as_bool: bool,
+    #[serde(default = "default_null_text")]
+    pub null_text: String,
+    #[serde(default = "default_true_text")]
+    pub true_text: String,
+    #[serde(default = "default_false_text")]
+    pub false_text: String,
+}
+
+fn default_page_size() -> usize {
+    100
+}
+fn default_max_rows() -> usize {
+    1000
+}
+fn default_display_timezone() -> String {
+    String::from("UTC")
+}
+fn default_date_format() -> String {
+    DisplayFormat::default().date_format
+}
+fn default_time_format() -> String {
+    DisplayFormat::default().time_format
+}
+fn default_datetime_format() -> String {
+    DisplayFormat::default().datetime_format
+}
+fn default_restore_session() -> bool {
+    true
+}
+fn default_syntax_theme() -> String {
+    String::from("dark")
+}
+fn default_auto_pairs() -> bool {
+    true
+}
+fn default_tinyint_as_bool() -> bool {
+    true
+}
+fn default_null_text() -> String {
+    DisplayFormat::default().null_text
+}
+fn default_true_text() -> String {
+    DisplayFormat::default().true_text
+}
+fn default_false_text() -> String {
+    DisplayFormat::default().false_text
+}
+fn get_valid_format(format: &str, default: String) -> String {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        default
+    } else {
+        format.to_string()
+    }
 }
 
 impl Config {
@@ -56,6 +134,19 @@ impl Config {
                 version: String::from("0.1"),
                 mysql_connections: Vec::new(),
                 pg_connections: Vec::new(),
+                default_page_size: default_page_size(),
+                max_rows: default_max_rows(),
+                display_timezone: default_display_timezone(),
+                date_format: default_date_format(),
+                time_format: default_time_format(),
+                datetime_format: default_datetime_format(),
+                restore_session: default_restore_session(),
+                syntax_theme: default_syntax_theme(),
+                auto_pairs: default_auto_pairs(),
+                tinyint_as_bool: default_tinyint_as_bool(),
+                null_text: default_null_text(),
+                true_text: default_true_text(),
+                false_text: default_false_text(),
             };
             let json = serde_json::to_string(&config)?;
             write!(file, "{}", json.trim())?;
@@ -81,6 +172,41 @@ impl Config {
     pub fn get_pg_connections(&self) -> &Vec<PGConnection> {
         &self.pg_connections
     }
+    pub fn get_page_size(&self) -> usize {
+        self.default_page_size.min(self.max_rows).max(1)
+    }
+    pub fn get_max_rows(&self) -> usize {
+        self.max_rows
+    }
+    pub fn get_display_format(&self) -> DisplayFormat {
+        let timezone = match self.display_timezone.to_lowercase().as_str() {
+            "utc" => DisplayTimezone::Utc,
+            "local" => DisplayTimezone::Local,
+            offset => offset
+                .parse()
+                .map(DisplayTimezone::Fixed)
+                .unwrap_or_default(),
+        };
+        let default = DisplayFormat::default();
+        DisplayFormat {
+            timezone,
+            date_format: get_valid_format(&self.date_format, default.date_format),
+            time_format: get_valid_format(&self.time_format, default.time_format),
+            datetime_format: get_valid_format(&self.datetime_format, default.datetime_format),
+            offset_format: default.offset_format,
+            tinyint_as_bool: self.tinyint_as_bool,
+            null_text: self.null_text.clone(),
+            true_text: self.true_text.clone(),
+            false_text: self.false_text.clone(),
+        }
+    }
+    pub fn get_syntax_theme(&self) -> SyntaxTheme {
+        match self.syntax_theme.to_lowercase().as_str() {
+            "light" => SyntaxTheme::light(),
+            "none" => SyntaxTheme::plain(),
+            _ => SyntaxTheme::dark(),
+        }
+    }
     pub fn save_mysql_connection(&mut self, conn: &MySQLConnection) -> Result<()> {
         let index = self
             .mysql_connections
@@ -0,0 +1,74 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    widget::{Form, FormItem},
+};
+use anyhow::{Error, Result};
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::cmp::min;
+
+pub struct BulkUpdateDialog<'a> {
+    form: Form<'a>,
+}
+
+impl<'a> BulkUpdateDialog<'a> {
+    pub fn new(table_name: &str, columns: Vec<String>, column: Option<String>) -> Self {
+        let mut form = Form::default();
+        form.set_title(format!("Bulk Update {}", table_name));
+        form.set_items(vec![
+            FormItem::new_select("column".to_string(), columns, column, false, false),
+            FormItem::new_input("value".to_string(), None, true, false, false),
+            FormItem::new_check("null".to_string(), false, false),
+            FormItem::new_input("where".to_string(), None, false, false, false),
+        ]);
+        BulkUpdateDialog { form }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height - 2);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<(String, Option<String>, String)>> {
+        match self.form.handle_event(key)? {
+            DialogResult::Confirm(mut map) => {
+                let column = map.remove("column").flatten().unwrap_or_default();
+                // An empty value sets an empty string, NULL has to be checked explicitly.
+                let value = if map.remove("null").flatten().as_deref() == Some("true") {
+                    None
+                } else {
+                    Some(map.remove("value").flatten().unwrap_or_default())
+                };
+                let condition = map
+                    .remove("where")
+                    .flatten()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string();
+                if condition.is_empty() {
+                    return Err(Error::msg("A WHERE condition is required"));
+                }
+                Ok(DialogResult::Confirm((column, value, condition)))
+            }
+            DialogResult::Cancel => Ok(DialogResult::Cancel),
+            DialogResult::Changed(name, value) => Ok(DialogResult::Changed(name, value)),
+            DialogResult::Done => Ok(DialogResult::Done),
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
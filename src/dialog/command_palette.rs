@@ -0,0 +1,150 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::{config::*, Key, KeyCode, KeyModifier},
+};
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::cmp::min;
+
+pub struct CommandPaletteDialog {
+    commands: Vec<Command>,
+    filter: String,
+    state: ListState,
+}
+
+impl CommandPaletteDialog {
+    pub fn new(commands: Vec<Command>) -> Self {
+        let mut state = ListState::default();
+        if !commands.is_empty() {
+            state.select(Some(0));
+        }
+        CommandPaletteDialog {
+            commands,
+            filter: String::new(),
+            state,
+        }
+    }
+    fn get_filtered_commands(&self) -> Vec<&Command> {
+        let filter = self.filter.to_lowercase();
+        self.commands
+            .iter()
+            .filter(|c| c.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let commands = self.get_filtered_commands();
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 50);
+        let height = min(bounds.height - 2, commands.len() as u16 + 5);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(2)])
+            .split(rect);
+        f.render_widget(
+            Paragraph::new(self.filter.as_str()).block(
+                Block::default()
+                    .title("Commands")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            ),
+            chunks[0],
+        );
+        let items: Vec<ListItem> = commands
+            .iter()
+            .map(|c| ListItem::new(format!("{} [{}]", c.name, c.key)))
+            .collect();
+        f.render_stateful_widget(
+            List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .highlight_style(Style::default().fg(Color::Green)),
+            chunks[1],
+            &mut self.state,
+        );
+    }
+    pub fn handle_event(&mut self, key: &Key) -> DialogResult<Key> {
+        match *key {
+            UP_KEY => {
+                if let Some(i) = self.state.selected() {
+                    self.state.select(Some(i.saturating_sub(1)));
+                }
+            }
+            DOWN_KEY => {
+                let len = self.get_filtered_commands().len();
+                if let Some(i) = self.state.selected() {
+                    self.state.select(Some(min(i + 1, len.saturating_sub(1))));
+                }
+            }
+            CONFIRM_KEY => {
+                if let Some(i) = self.state.selected() {
+                    return DialogResult::Confirm(self.get_filtered_commands()[i].key.clone());
+                }
+            }
+            CANCEL_KEY => {
+                return DialogResult::Cancel;
+            }
+            CLEAR_KEY => {
+                self.filter.pop();
+                self.reset_selected();
+            }
+            Key {
+                code: KeyCode::Char(c),
+                modifier: KeyModifier::None | KeyModifier::Shift,
+            } => {
+                self.filter.push(c);
+                self.reset_selected();
+            }
+            _ => (),
+        }
+        DialogResult::Done
+    }
+    fn reset_selected(&mut self) {
+        if self.get_filtered_commands().is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(0));
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Run",
+                key: CONFIRM_KEY,
+            },
+            Command {
+                name: "Cancel",
+                key: CANCEL_KEY,
+            },
+        ]
+    }
+}
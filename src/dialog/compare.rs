@@ -0,0 +1,78 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, collections::HashMap};
+
+pub struct CompareDialog<'a> {
+    form: Form<'a>,
+}
+
+impl<'a> CompareDialog<'a> {
+    pub fn new(
+        conn_names: Vec<String>,
+        conn_name: &str,
+        db_name: &str,
+        schema_name: Option<&str>,
+        table_name: &str,
+    ) -> Self {
+        let mut form = Form::default();
+        form.set_title(format!("Compare {} With", table_name));
+        let mut items = vec![
+            FormItem::new_select(
+                "connection".to_string(),
+                conn_names,
+                Some(conn_name.to_string()),
+                false,
+                false,
+            ),
+            FormItem::new_input("database".to_string(), Some(db_name), false, false, false),
+        ];
+        if let Some(schema) = schema_name {
+            items.push(FormItem::new_input(
+                "schema".to_string(),
+                Some(schema),
+                false,
+                false,
+                false,
+            ));
+        }
+        items.push(FormItem::new_input(
+            "table".to_string(),
+            Some(table_name),
+            false,
+            false,
+            false,
+        ));
+        form.set_items(items);
+        CompareDialog { form }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height);
+
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
+        self.form.handle_event(key)
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
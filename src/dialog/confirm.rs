@@ -3,7 +3,7 @@ use crate::{
     component::Command,
     event::{config::*, Key},
 };
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -3,13 +3,15 @@ use crate::{
     component::Command,
     event::Key,
     model::{
-        mysql::Connection as MySQLConnection, pg::Connection as PGConnection, Connect, DatabaseKind,
+        mysql::Connection as MySQLConnection, pg::Connection as PGConnection, Connect,
+        ConnectionColor, DatabaseKind,
     },
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 #[derive(Default)]
@@ -110,7 +112,29 @@ impl<'a> ConnectionDialog<'a> {
                 FormItem::new_input(
                     "password".to_string(),
                     Some(conn.get_password()),
+                    true,
+                    false,
+                    false,
+                ),
+                FormItem::new_input(
+                    "password command".to_string(),
+                    conn.get_password_command(),
+                    true,
+                    false,
                     false,
+                ),
+                FormItem::new_input(
+                    "environment".to_string(),
+                    conn.get_environment(),
+                    true,
+                    false,
+                    false,
+                ),
+                FormItem::new_input("group".to_string(), conn.get_group(), true, false, false),
+                FormItem::new_select(
+                    "color".to_string(),
+                    ConnectionColor::iter().map(|c| c.to_string()).collect(),
+                    Some(conn.get_color().to_string()),
                     false,
                     false,
                 ),
@@ -121,7 +145,17 @@ impl<'a> ConnectionDialog<'a> {
                 FormItem::new_input("host".to_string(), None, false, false, false),
                 FormItem::new_input("port".to_string(), None, true, false, false),
                 FormItem::new_input("user".to_string(), None, false, false, false),
-                FormItem::new_input("password".to_string(), None, false, false, false),
+                FormItem::new_input("password".to_string(), None, true, false, false),
+                FormItem::new_input("password command".to_string(), None, true, false, false),
+                FormItem::new_input("environment".to_string(), None, true, false, false),
+                FormItem::new_input("group".to_string(), None, true, false, false),
+                FormItem::new_select(
+                    "color".to_string(),
+                    ConnectionColor::iter().map(|c| c.to_string()).collect(),
+                    Some(ConnectionColor::default().to_string()),
+                    false,
+                    false,
+                ),
             ]
         });
         form
@@ -158,6 +192,13 @@ impl<'a> ConnectionDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_input(
+                    "default schema".to_string(),
+                    conn.get_default_schema(),
+                    true,
+                    false,
+                    false,
+                ),
                 FormItem::new_input(
                     "user".to_string(),
                     Some(conn.get_user()),
@@ -168,7 +209,29 @@ impl<'a> ConnectionDialog<'a> {
                 FormItem::new_input(
                     "password".to_string(),
                     Some(conn.get_password()),
+                    true,
+                    false,
+                    false,
+                ),
+                FormItem::new_input(
+                    "password command".to_string(),
+                    conn.get_password_command(),
+                    true,
+                    false,
                     false,
+                ),
+                FormItem::new_input(
+                    "environment".to_string(),
+                    conn.get_environment(),
+                    true,
+                    false,
+                    false,
+                ),
+                FormItem::new_input("group".to_string(), conn.get_group(), true, false, false),
+                FormItem::new_select(
+                    "color".to_string(),
+                    ConnectionColor::iter().map(|c| c.to_string()).collect(),
+                    Some(conn.get_color().to_string()),
                     false,
                     false,
                 ),
@@ -179,8 +242,19 @@ impl<'a> ConnectionDialog<'a> {
                 FormItem::new_input("host".to_string(), Some("localhost"), false, false, false),
                 FormItem::new_input("port".to_string(), Some("5432"), true, false, false),
                 FormItem::new_input("init db".to_string(), Some("postgres"), true, false, false),
+                FormItem::new_input("default schema".to_string(), None, true, false, false),
                 FormItem::new_input("user".to_string(), Some("postgres"), false, false, false),
-                FormItem::new_input("password".to_string(), None, false, false, false),
+                FormItem::new_input("password".to_string(), None, true, false, false),
+                FormItem::new_input("password command".to_string(), None, true, false, false),
+                FormItem::new_input("environment".to_string(), None, true, false, false),
+                FormItem::new_input("group".to_string(), None, true, false, false),
+                FormItem::new_select(
+                    "color".to_string(),
+                    ConnectionColor::iter().map(|c| c.to_string()).collect(),
+                    Some(ConnectionColor::default().to_string()),
+                    false,
+                    false,
+                ),
             ]
         });
         form
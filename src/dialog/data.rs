@@ -5,6 +5,7 @@ use crate::{
     model::{
         mysql::{get_mysql_field_value, Field as MySQLField},
         pg::{get_pg_field_value, Field as PGField, FieldKind as PGFieldKind},
+        DisplayFormat,
     },
     widget::{Form, FormItem},
 };
@@ -12,7 +13,7 @@ use anyhow::{Error, Result};
 use sqlx::{mysql::MySqlRow, postgres::PgRow};
 use std::cmp::min;
 use std::collections::HashMap;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 
 pub struct DataDialog<'a> {
     form: Form<'a>,
@@ -33,7 +34,7 @@ impl<'a> DataDialog<'a> {
             .iter()
             .map(|field| {
                 let value = if let Some(row) = row {
-                    get_mysql_field_value(field, row)
+                    get_mysql_field_value(field, row, &DisplayFormat::default())
                 } else {
                     None
                 };
@@ -141,7 +142,7 @@ impl<'a> DataDialog<'a> {
             .iter()
             .map(|field| match field.kind() {
                 PGFieldKind::Int2 | PGFieldKind::Int4 | PGFieldKind::Int8 => {
-                    let value = row.map(|row| get_pg_field_value(field, row));
+                    let value = row.and_then(|row| get_pg_field_value(field, row, &DisplayFormat::default()));
 
                     FormItem::new_input(
                         field.name().to_string(),
@@ -152,7 +153,7 @@ impl<'a> DataDialog<'a> {
                     )
                 }
                 _ => {
-                    let value = row.map(|row| get_pg_field_value(field, row));
+                    let value = row.and_then(|row| get_pg_field_value(field, row, &DisplayFormat::default()));
 
                     FormItem::new_input(
                         field.name().to_string(),
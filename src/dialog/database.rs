@@ -15,9 +15,9 @@ use crate::{
 };
 
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use sqlx::Row;
 use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 #[derive(Default)]
@@ -232,6 +232,7 @@ impl<'a> DatabaseDialog<'a> {
                     true,
                     false,
                 ),
+                FormItem::new_input("encoding".to_string(), db.get_encoding(), true, false, true),
                 FormItem::new_input(
                     "collation order".to_string(),
                     db.get_collation(),
@@ -274,6 +275,7 @@ impl<'a> DatabaseDialog<'a> {
             vec![
                 FormItem::new_input("name".to_string(), None, false, false, false),
                 FormItem::new_select("owner".to_string(), roles, None, true, false),
+                FormItem::new_input("encoding".to_string(), None, true, false, false),
                 FormItem::new_input("collation order".to_string(), None, true, false, false),
                 FormItem::new_input("character class".to_string(), None, true, false, false),
                 FormItem::new_select("template".to_string(), dbs, None, true, false),
@@ -286,7 +288,7 @@ impl<'a> DatabaseDialog<'a> {
                     false,
                 ),
                 FormItem::new_check("allow connection".to_string(), true, false),
-                FormItem::new_check("is template".to_string(), true, false),
+                FormItem::new_check("is template".to_string(), false, false),
             ]
         });
         Ok(form)
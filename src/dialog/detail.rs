@@ -3,23 +3,24 @@ use crate::{
     component::Command,
     event::Key,
     model::{
-        mysql::{get_mysql_field_value, Field as MySQLField},
+        mysql::{get_mysql_field_value, Field as MySQLField, DECODE_ERROR},
         pg::{get_pg_field_value, Field as PGField},
+        DisplayFormat,
     },
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use sqlx::{mysql::MySqlRow, postgres::PgRow};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 
 pub struct DetailDialog<'a> {
     form: Form<'a>,
 }
 
 impl<'a> DetailDialog<'a> {
-    pub fn from_map(title: String, map: &HashMap<String, Option<String>>) -> Self {
-        let items: Vec<FormItem<'a>> = map
+    pub fn from_values(title: String, values: &[(String, Option<String>)]) -> Self {
+        let items: Vec<FormItem<'a>> = values
             .iter()
             .map(|(name, value)| {
                 FormItem::new_input(name.to_string(), value.as_deref(), true, true, true)
@@ -31,7 +32,12 @@ impl<'a> DetailDialog<'a> {
 
         DetailDialog { form }
     }
-    pub fn from_mysql_row(title: String, fields: &[MySQLField], row: &MySqlRow) -> Self {
+    pub fn from_mysql_row(
+        title: String,
+        fields: &[MySQLField],
+        row: &MySqlRow,
+        display_format: &DisplayFormat,
+    ) -> Self {
         let mut form = Form::default();
         form.set_title(title);
         form.set_items(
@@ -40,7 +46,9 @@ impl<'a> DetailDialog<'a> {
                 .map(|field| {
                     FormItem::new_input(
                         field.name().to_string(),
-                        get_mysql_field_value(field, row).as_deref(),
+                        get_mysql_field_value(field, row, display_format)
+                            .unwrap_or_else(|_| Some(DECODE_ERROR.to_string()))
+                            .as_deref(),
                         true,
                         true,
                         true,
@@ -50,7 +58,12 @@ impl<'a> DetailDialog<'a> {
         );
         DetailDialog { form }
     }
-    pub fn from_pg_row(title: String, fields: &[PGField], row: &PgRow) -> Self {
+    pub fn from_pg_row(
+        title: String,
+        fields: &[PGField],
+        row: &PgRow,
+        display_format: &DisplayFormat,
+    ) -> Self {
         let mut form = Form::default();
         form.set_title(title);
         form.set_items(
@@ -59,7 +72,7 @@ impl<'a> DetailDialog<'a> {
                 .map(|field| {
                     FormItem::new_input(
                         field.name().to_string(),
-                        Some(&get_pg_field_value(field, row)),
+                        get_pg_field_value(field, row, display_format).as_deref(),
                         true,
                         true,
                         true,
@@ -0,0 +1,87 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    model::export::ExportFormat,
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, collections::HashMap};
+use strum::IntoEnumIterator;
+
+pub struct ExportDialog<'a> {
+    form: Form<'a>,
+}
+
+impl<'a> ExportDialog<'a> {
+    pub fn new(name: &str) -> Self {
+        let path = dirs_next::home_dir()
+            .map(|p| p.join(format!("{}.sql", name)).display().to_string())
+            .unwrap_or_else(|| format!("{}.sql", name));
+        let mut form = Form::default();
+        form.set_title(format!("Export {}", name));
+        form.set_items(vec![
+            FormItem::new_input("file".to_string(), Some(&path), false, false, false),
+            FormItem::new_check("with data".to_string(), false, false),
+            FormItem::new_check("views".to_string(), true, false),
+            FormItem::new_check("triggers".to_string(), true, false),
+        ]);
+        ExportDialog { form }
+    }
+    pub fn new_tables(name: &str) -> Self {
+        let path = dirs_next::home_dir()
+            .map(|p| p.join(format!("{}.sql", name)).display().to_string())
+            .unwrap_or_else(|| format!("{}.sql", name));
+        let mut form = Form::default();
+        form.set_title(format!("Export {}", name));
+        form.set_items(vec![
+            FormItem::new_input("file".to_string(), Some(&path), false, false, false),
+            FormItem::new_check("with data".to_string(), false, false),
+            FormItem::new_check("triggers".to_string(), true, false),
+        ]);
+        ExportDialog { form }
+    }
+    pub fn new_data(name: &str) -> Self {
+        let path = dirs_next::home_dir()
+            .map(|p| p.join(format!("{}.csv", name)).display().to_string())
+            .unwrap_or_else(|| format!("{}.csv", name));
+        let mut form = Form::default();
+        form.set_title(format!("Export {} Data", name));
+        form.set_items(vec![
+            FormItem::new_input("file".to_string(), Some(&path), false, false, false),
+            FormItem::new_select(
+                "format".to_string(),
+                ExportFormat::iter().map(|f| f.to_string()).collect(),
+                Some(ExportFormat::Csv.to_string()),
+                false,
+                false,
+            ),
+        ]);
+        ExportDialog { form }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height);
+
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
+        self.form.handle_event(key)
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::{config::*, Key, KeyCode, KeyModifier},
+    model::{
+        favorite::{Favorite, Favorites},
+        Connect,
+    },
+};
+use anyhow::Result;
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::{cell::RefCell, cmp::min, rc::Rc};
+
+pub struct FavoriteDialog {
+    items: Vec<(Favorite, String)>,
+    filter: String,
+    state: ListState,
+    favorites: Rc<RefCell<Favorites>>,
+}
+
+impl FavoriteDialog {
+    pub fn new(favorites: Rc<RefCell<Favorites>>, conns: &[Box<dyn Connect>]) -> Self {
+        let items = favorites
+            .borrow()
+            .get_favorites()
+            .iter()
+            .filter_map(|favorite| {
+                conns
+                    .iter()
+                    .find(|conn| *conn.get_id() == favorite.conn_id)
+                    .map(|conn| {
+                        (
+                            favorite.clone(),
+                            format!("{}: {}", conn.get_name(), favorite.get_path()),
+                        )
+                    })
+            })
+            .collect::<Vec<(Favorite, String)>>();
+        let mut state = ListState::default();
+        if !items.is_empty() {
+            state.select(Some(0));
+        }
+        FavoriteDialog {
+            items,
+            filter: String::new(),
+            state,
+            favorites,
+        }
+    }
+    fn get_filtered_items(&self) -> Vec<&(Favorite, String)> {
+        let filter = self.filter.to_lowercase();
+        self.items
+            .iter()
+            .filter(|(_, label)| label.to_lowercase().contains(&filter))
+            .collect()
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let items = self.get_filtered_items();
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(bounds.height - 2, items.len() as u16 + 5);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(2)])
+            .split(rect);
+        f.render_widget(
+            Paragraph::new(self.filter.as_str()).block(
+                Block::default()
+                    .title("Favorites")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            ),
+            chunks[0],
+        );
+        let items: Vec<ListItem> = items
+            .iter()
+            .map(|(_, label)| ListItem::new(label.clone()))
+            .collect();
+        f.render_stateful_widget(
+            List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .highlight_style(Style::default().fg(Color::Green)),
+            chunks[1],
+            &mut self.state,
+        );
+    }
+    pub fn handle_event(&mut self, key: &Key) -> Result<DialogResult<Favorite>> {
+        match *key {
+            UP_KEY => {
+                if let Some(i) = self.state.selected() {
+                    self.state.select(Some(i.saturating_sub(1)));
+                }
+            }
+            DOWN_KEY => {
+                let len = self.get_filtered_items().len();
+                if let Some(i) = self.state.selected() {
+                    self.state.select(Some(min(i + 1, len.saturating_sub(1))));
+                }
+            }
+            CONFIRM_KEY => {
+                if let Some(i) = self.state.selected() {
+                    return Ok(DialogResult::Confirm(
+                        self.get_filtered_items()[i].0.clone(),
+                    ));
+                }
+            }
+            DELETE_KEY => {
+                if let Some(i) = self.state.selected() {
+                    let favorite = self.get_filtered_items()[i].0.clone();
+                    self.favorites.borrow_mut().remove(&favorite)?;
+                    self.items.retain(|(f, _)| *f != favorite);
+                    let len = self.get_filtered_items().len();
+                    self.state.select(if len == 0 {
+                        None
+                    } else {
+                        Some(min(i, len - 1))
+                    });
+                }
+            }
+            CANCEL_KEY => {
+                return Ok(DialogResult::Cancel);
+            }
+            CLEAR_KEY => {
+                self.filter.pop();
+                self.reset_selected();
+            }
+            Key {
+                code: KeyCode::Char(c),
+                modifier: KeyModifier::None | KeyModifier::Shift,
+            } => {
+                self.filter.push(c);
+                self.reset_selected();
+            }
+            _ => (),
+        }
+        Ok(DialogResult::Done)
+    }
+    fn reset_selected(&mut self) {
+        if self.get_filtered_items().is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(0));
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        let mut cmds = vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+        ];
+        if self.state.selected().is_some() {
+            cmds.append(&mut vec![
+                Command {
+                    name: "Open Table",
+                    key: CONFIRM_KEY,
+                },
+                Command {
+                    name: "Unpin Table",
+                    key: DELETE_KEY,
+                },
+            ]);
+        }
+        cmds.push(Command {
+            name: "Cancel",
+            key: CANCEL_KEY,
+        });
+        cmds
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    model::{mysql::Field as MySQLField, pg::Field as PGField, DisplayFormat},
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, collections::HashMap};
+
+pub struct InsertDialog<'a> {
+    form: Form<'a>,
+    defaults: HashMap<String, String>,
+    bools: Vec<String>,
+}
+
+impl<'a> InsertDialog<'a> {
+    pub fn from_mysql_fields(
+        title: String,
+        fields: &[MySQLField],
+        skipped: &[String],
+        display_format: &DisplayFormat,
+    ) -> Self {
+        InsertDialog::from_mysql_values(title, fields, skipped, display_format, &HashMap::new())
+    }
+    pub fn from_mysql_values(
+        title: String,
+        fields: &[MySQLField],
+        skipped: &[String],
+        display_format: &DisplayFormat,
+        values: &HashMap<String, Option<String>>,
+    ) -> Self {
+        let fields = fields
+            .iter()
+            .filter(|field| !skipped.iter().any(|s| s == field.name()))
+            .collect::<Vec<&MySQLField>>();
+        let bools = fields
+            .iter()
+            .filter(|field| match field {
+                MySQLField::TinyInt(f) => display_format.tinyint_as_bool && f.is_bool(),
+                _ => false,
+            })
+            .map(|field| field.name().to_string())
+            .collect::<Vec<String>>();
+        let get_value = |field: &MySQLField| {
+            let is_bool = bools.iter().any(|b| b == field.name());
+            let value = match values.get(field.name()) {
+                Some(value) => value.as_deref(),
+                None => field.default_value(),
+            };
+            value.map(|v| match v {
+                "0" if is_bool => String::from("false"),
+                "1" if is_bool => String::from("true"),
+                _ => v.to_string(),
+            })
+        };
+        let items = fields
+            .iter()
+            .map(|field| match field {
+                MySQLField::TinyInt(_) if bools.iter().any(|b| b == field.name()) => {
+                    if field.not_null() {
+                        FormItem::new_check(
+                            field.name().to_string(),
+                            get_value(field).as_deref() == Some("true"),
+                            false,
+                        )
+                    } else {
+                        FormItem::new_select(
+                            field.name().to_string(),
+                            vec![String::from("true"), String::from("false")],
+                            get_value(field),
+                            true,
+                            false,
+                        )
+                    }
+                }
+                MySQLField::Enum(f) => FormItem::new_select(
+                    field.name().to_string(),
+                    f.options.clone(),
+                    get_value(field),
+                    !field.not_null() || field.default_value().is_some(),
+                    false,
+                ),
+                _ => FormItem::new_input(
+                    field.name().to_string(),
+                    get_value(field).as_deref(),
+                    !field.not_null() || field.default_value().is_some(),
+                    !field.not_null(),
+                    false,
+                ),
+            })
+            .collect();
+        let defaults = fields
+            .iter()
+            .filter(|field| !values.contains_key(field.name()))
+            .filter_map(|field| get_value(field).map(|d| (field.name().to_string(), d)))
+            .collect();
+        InsertDialog::new(title, items, defaults, bools)
+    }
+    pub fn from_pg_fields(title: String, fields: &[PGField], skipped: &[String]) -> Self {
+        InsertDialog::from_pg_values(title, fields, skipped, &HashMap::new())
+    }
+    pub fn from_pg_values(
+        title: String,
+        fields: &[PGField],
+        skipped: &[String],
+        values: &HashMap<String, Option<String>>,
+    ) -> Self {
+        let fields = fields
+            .iter()
+            .filter(|field| !skipped.iter().any(|s| s == field.name()))
+            .collect::<Vec<&PGField>>();
+        let items = fields
+            .iter()
+            .map(|field| {
+                let value = match values.get(field.name()) {
+                    Some(value) => value.as_deref(),
+                    None => field.default_value(),
+                };
+                FormItem::new_input(
+                    field.name().to_string(),
+                    value,
+                    !field.not_null() || field.default_value().is_some(),
+                    !field.not_null(),
+                    false,
+                )
+            })
+            .collect();
+        let defaults = fields
+            .iter()
+            .filter(|field| !values.contains_key(field.name()))
+            .filter_map(|field| {
+                field
+                    .default_value()
+                    .map(|d| (field.name().to_string(), d.to_string()))
+            })
+            .collect();
+        InsertDialog::new(title, items, defaults, vec![])
+    }
+    fn new(
+        title: String,
+        items: Vec<FormItem<'a>>,
+        defaults: HashMap<String, String>,
+        bools: Vec<String>,
+    ) -> Self {
+        let mut form = Form::default();
+        form.set_title(title);
+        form.set_items(items);
+        InsertDialog {
+            form,
+            defaults,
+            bools,
+        }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height - 2);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
+        let result = self.form.handle_event(key)?;
+        match result {
+            DialogResult::Confirm(mut map) => {
+                // Columns left at their default preview are omitted so the server evaluates it.
+                map.retain(|name, value| match (self.defaults.get(name), value) {
+                    (Some(default), Some(value)) => value != default && !value.is_empty(),
+                    _ => true,
+                });
+                for name in self.bools.iter() {
+                    if let Some(Some(value)) = map.get_mut(name) {
+                        *value = String::from(if value == "true" { "1" } else { "0" });
+                    }
+                }
+                Ok(DialogResult::Confirm(map))
+            }
+            _ => Ok(result),
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
@@ -1,10 +1,24 @@
+mod bulk_update;
+mod command_palette;
+mod compare;
 pub mod confirm;
 mod connection;
+mod connection_switch;
 pub mod database;
 mod detail;
+mod export;
+mod favorite;
 mod input;
+mod insert;
 pub mod mysql;
+mod param;
 pub mod pg;
 pub mod schema;
+mod search_value;
+mod text;
 
-pub use self::{confirm::*, connection::*, database::*, detail::*, input::*, schema::*};
+pub use self::{
+    bulk_update::*, command_palette::*, compare::*, confirm::*, connection::*,
+    connection_switch::*, database::*, detail::*, export::*, favorite::*, input::*, insert::*,
+    param::*, schema::*, search_value::*, text::*,
+};
@@ -6,8 +6,8 @@ use crate::{
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct CheckDialog<'a> {
@@ -0,0 +1,132 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    model::mysql::{Event, EventIntervalUnit, EventSchedule, EventStatus},
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, collections::HashMap};
+use strum::IntoEnumIterator;
+
+pub struct EventDialog<'a> {
+    name: Option<String>,
+    form: Form<'a>,
+}
+
+impl<'a> EventDialog<'a> {
+    pub fn new(event: Option<&Event>) -> Self {
+        let mut form = Form::default();
+        form.set_title("Edit Event".to_string());
+        form.set_items(vec![
+            FormItem::new_input(
+                "name".to_string(),
+                event.map(|e| e.name()),
+                false,
+                false,
+                false,
+            ),
+            FormItem::new_select(
+                "schedule".to_string(),
+                EventSchedule::iter().map(|s| s.to_string()).collect(),
+                Some(event.map(|e| e.schedule()).unwrap_or("EVERY").to_string()),
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "execute at".to_string(),
+                event.and_then(|e| e.execute_at.as_deref()),
+                true,
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "interval value".to_string(),
+                event.and_then(|e| e.interval_value.as_deref()),
+                true,
+                false,
+                false,
+            ),
+            FormItem::new_select(
+                "interval unit".to_string(),
+                EventIntervalUnit::iter().map(|s| s.to_string()).collect(),
+                event.and_then(|e| e.interval_unit()).map(|u| u.to_string()),
+                true,
+                false,
+            ),
+            FormItem::new_input(
+                "starts".to_string(),
+                event.and_then(|e| e.starts.as_deref()),
+                true,
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "ends".to_string(),
+                event.and_then(|e| e.ends.as_deref()),
+                true,
+                false,
+                false,
+            ),
+            FormItem::new_check(
+                "on completion preserve".to_string(),
+                event.map(|e| e.preserve).unwrap_or(false),
+                false,
+            ),
+            FormItem::new_select(
+                "status".to_string(),
+                EventStatus::iter().map(|s| s.to_string()).collect(),
+                Some(event.map(|e| e.status()).unwrap_or("ENABLE").to_string()),
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "comment".to_string(),
+                event.and_then(|e| e.comment.as_deref()),
+                true,
+                false,
+                false,
+            ),
+            FormItem::new_textarea(
+                "body".to_string(),
+                event.map(|e| e.body.as_str()),
+                false,
+                false,
+                false,
+            ),
+        ]);
+
+        EventDialog {
+            name: event.map(|e| e.name().to_string()),
+            form,
+        }
+    }
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+
+        let height = min(self.form.height(), bounds.height);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
+        self.form.handle_event(key)
+    }
+}
@@ -7,12 +7,12 @@ use crate::{
         Field, FieldKind, FloatField, IntField, SimpleField, TextField, TimeField,
     },
     pool::{fetch_mysql_query, MySQLPools},
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
-use anyhow::Result;
+use anyhow::{Error, Result};
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use sqlx::Row;
 use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct FieldDialog<'a> {
@@ -33,7 +33,7 @@ impl<'a> FieldDialog<'a> {
     ) -> Result<FieldDialog<'a>> {
         let mut form = Form::default();
         form.set_title(format!("{} field", kind.to_string()));
-        let items = match kind {
+        let mut items = match kind {
             FieldKind::BigInt
             | FieldKind::Int
             | FieldKind::Integer
@@ -71,6 +71,7 @@ impl<'a> FieldDialog<'a> {
             | FieldKind::Polygon
             | FieldKind::TinyBlob => Self::create_simple_form(None),
         };
+        items.push(FormItem::new_check("invisible".to_string(), false, false));
 
         form.set_items(items);
         Ok(FieldDialog {
@@ -90,7 +91,7 @@ impl<'a> FieldDialog<'a> {
     ) -> Result<FieldDialog<'a>> {
         let mut form = Form::default();
         form.set_title(format!("{} field", field.kind()));
-        let items = match field {
+        let mut items = match field {
             Field::BigInt(i)
             | Field::Int(i)
             | Field::Integer(i)
@@ -128,6 +129,11 @@ impl<'a> FieldDialog<'a> {
             | Field::Polygon(s)
             | Field::TinyBlob(s) => Self::create_simple_form(Some(s)),
         };
+        items.push(FormItem::new_check(
+            "invisible".to_string(),
+            field.invisible(),
+            false,
+        ));
         form.set_items(items);
         Ok(FieldDialog {
             id: Some(field.id().to_owned()),
@@ -180,6 +186,10 @@ impl<'a> FieldDialog<'a> {
                             &format!("SHOW COLLATION WHERE Charset='{}'", value),
                         )
                         .await?;
+                        let default_collation = rows
+                            .iter()
+                            .find(|row| row.try_get::<String, _>("Default").unwrap() == "Yes")
+                            .map(|row| row.try_get("Collation").unwrap());
                         self.form.set_item(
                             "collation",
                             FormItem::new_select(
@@ -187,7 +197,7 @@ impl<'a> FieldDialog<'a> {
                                 rows.iter()
                                     .map(|row| row.try_get("Collation").unwrap())
                                     .collect(),
-                                None,
+                                default_collation,
                                 true,
                                 false,
                             ),
@@ -208,6 +218,9 @@ impl<'a> FieldDialog<'a> {
                 Ok(DialogResult::Done)
             }
             DialogResult::Confirm(mut map) => {
+                if matches!(self.kind, FieldKind::Decimal | FieldKind::Numeric) {
+                    self.validate_decimal(&map)?;
+                }
                 if let Some(id) = self.id.as_ref() {
                     map.insert("id".to_string(), Some(id.to_string()));
                 }
@@ -217,6 +230,33 @@ impl<'a> FieldDialog<'a> {
             _ => Ok(r),
         }
     }
+    fn validate_decimal(&mut self, map: &HashMap<String, Option<String>>) -> Result<()> {
+        let get_number = |name: &str| {
+            map.get(name)
+                .cloned()
+                .flatten()
+                .filter(|v| !v.is_empty())
+                .map(|v| v.parse::<u32>())
+        };
+        let error = match (get_number("length"), get_number("decimal")) {
+            (Some(Err(_)), _) => Some(("length", "must be a non-negative integer")),
+            (_, Some(Err(_))) => Some(("decimal", "must be a non-negative integer")),
+            (Some(Ok(l)), _) if !(1..=65).contains(&l) => {
+                Some(("length", "must be between 1 and 65"))
+            }
+            (_, Some(Ok(d))) if d > 30 => Some(("decimal", "must not be greater than 30")),
+            (None, Some(Ok(_))) => Some(("length", "is required when decimal is set")),
+            (Some(Ok(l)), Some(Ok(d))) if d > l => {
+                Some(("decimal", "must not be greater than length"))
+            }
+            _ => None,
+        };
+        if let Some((name, error)) = error {
+            self.form.set_error(name, error);
+            return Err(Error::msg(format!("{}: {}", name, error)));
+        }
+        Ok(())
+    }
     fn create_int_form(field: Option<&IntField>) -> Vec<FormItem<'a>> {
         if let Some(f) = field {
             vec![
@@ -224,7 +264,8 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), true, false, false),
+                FormItem::new_input("length".to_string(), f.length(), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -232,6 +273,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_check("auto increment".to_string(), f.auto_increment(), false),
                 FormItem::new_check("unsigned".to_string(), f.unsigned(), false),
                 FormItem::new_check("zerofill".to_string(), f.zerofill(), false),
@@ -242,8 +288,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_check("auto increment".to_string(), false, false),
                 FormItem::new_check("unsigned".to_string(), false, false),
                 FormItem::new_check("zerofill".to_string(), false, false),
@@ -257,7 +305,8 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), Some(f.length()), true, false, false),
+                FormItem::new_input("length".to_string(), Some(f.length()), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -265,6 +314,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
             ]
         } else {
             vec![
@@ -272,8 +326,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
             ]
         }
     }
@@ -319,7 +375,8 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), false, false, false),
+                FormItem::new_input("length".to_string(), f.length(), false, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -327,6 +384,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_select(
                     "character set".to_string(),
                     charsets,
@@ -348,8 +410,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, false, false, false),
+                FormItem::new_input("length".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_select("character set".to_string(), charsets, None, true, false),
                 FormItem::new_select("collation".to_string(), vec![], None, true, false),
             ]
@@ -370,6 +434,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
             ]
         } else {
             vec![
@@ -378,6 +447,7 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
             ]
         }
     }
@@ -388,7 +458,8 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), true, false, false),
+                FormItem::new_input("length".to_string(), f.length(), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -396,6 +467,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_check("on update".to_string(), f.on_update(), false),
             ]
         } else {
@@ -404,8 +480,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_check("on update".to_string(), false, false),
             ]
         }
@@ -417,8 +495,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), true, false, false),
-                FormItem::new_input("decimal".to_string(), f.decimal(), true, false, false),
+                FormItem::new_input("length".to_string(), f.length(), true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("decimal".to_string(), f.decimal(), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -426,6 +506,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_check("unsigned".to_string(), f.unsigned(), false),
                 FormItem::new_check("zerofill".to_string(), f.zerofill(), false),
             ]
@@ -435,9 +520,12 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
-                FormItem::new_input("decimal".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("decimal".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_check("unsigned".to_string(), false, false),
                 FormItem::new_check("zerofill".to_string(), false, false),
             ]
@@ -450,8 +538,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), true, false, false),
-                FormItem::new_input("decimal".to_string(), f.decimal(), true, false, false),
+                FormItem::new_input("length".to_string(), f.length(), true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("decimal".to_string(), f.decimal(), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -459,6 +549,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_check("auto increment".to_string(), f.auto_increment(), false),
                 FormItem::new_check("unsigned".to_string(), f.unsigned(), false),
                 FormItem::new_check("zerofill".to_string(), f.zerofill(), false),
@@ -469,9 +564,12 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
-                FormItem::new_input("decimal".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
+                FormItem::new_input("decimal".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_check("auto increment".to_string(), false, false),
                 FormItem::new_check("unsigned".to_string(), false, false),
                 FormItem::new_check("zerofill".to_string(), false, false),
@@ -527,6 +625,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
                 FormItem::new_select(
                     "character set".to_string(),
                     charsets,
@@ -550,6 +653,7 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_input("comment".to_string(), None, true, false, false),
                 FormItem::new_list("options".to_string(), vec![], false, false),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_select("character set".to_string(), charsets, None, true, false),
                 FormItem::new_select("collation".to_string(), vec![], None, true, false),
             ]
@@ -631,7 +735,8 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
                 FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
-                FormItem::new_input("length".to_string(), f.length(), true, false, false),
+                FormItem::new_input("length".to_string(), f.length(), true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -639,6 +744,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
             ]
         } else {
             vec![
@@ -646,8 +756,10 @@ impl<'a> FieldDialog<'a> {
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
-                FormItem::new_input("length".to_string(), None, true, false, false),
+                FormItem::new_input("length".to_string(), None, true, false, false)
+                    .input_kind(InputKind::Numeric),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
             ]
         }
     }
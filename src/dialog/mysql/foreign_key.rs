@@ -10,9 +10,9 @@ use crate::{
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct ForeignKeyDialog<'a> {
@@ -39,13 +39,13 @@ impl<'a> ForeignKeyDialog<'a> {
         form.set_items(if let Some(f) = foreign_key {
             vec![
                 FormItem::new_input("name".to_string(), Some(f.name()), false, false, false),
-                FormItem::new_select(
-                    "field".to_string(),
+                FormItem::new_multi_select(
+                    "fields".to_string(),
                     fields
                         .iter()
                         .map(|f| f.name().to_string())
                         .collect::<Vec<String>>(),
-                    Some(f.field().to_string()),
+                    f.fields().clone(),
                     false,
                     false,
                 ),
@@ -63,10 +63,10 @@ impl<'a> ForeignKeyDialog<'a> {
                     false,
                     false,
                 ),
-                FormItem::new_select(
-                    "reference field".to_string(),
+                FormItem::new_multi_select(
+                    "reference fields".to_string(),
                     vec![],
-                    Some(f.ref_field().to_string()),
+                    f.ref_fields().clone(),
                     false,
                     false,
                 ),
@@ -88,19 +88,25 @@ impl<'a> ForeignKeyDialog<'a> {
         } else {
             vec![
                 FormItem::new_input("name".to_string(), None, false, false, false),
-                FormItem::new_select(
-                    "field".to_string(),
+                FormItem::new_multi_select(
+                    "fields".to_string(),
                     fields
                         .iter()
                         .map(|f| f.name().to_string())
                         .collect::<Vec<String>>(),
-                    None,
+                    vec![],
                     false,
                     false,
                 ),
                 FormItem::new_select("reference db".to_string(), ref_dbs, None, false, false),
                 FormItem::new_select("reference table".to_string(), vec![], None, false, false),
-                FormItem::new_select("reference field".to_string(), vec![], None, false, false),
+                FormItem::new_multi_select(
+                    "reference fields".to_string(),
+                    vec![],
+                    vec![],
+                    false,
+                    false,
+                ),
                 FormItem::new_select(
                     "on delete".to_string(),
                     OnDeleteKind::iter().map(|s| s.to_string()).collect(),
@@ -167,9 +173,9 @@ impl<'a> ForeignKeyDialog<'a> {
     }
     pub fn set_ref_fields(&mut self, field_names: &[String]) {
         self.form.set_item(
-            "reference field",
+            "reference fields",
             FormItem::new_multi_select(
-                "reference field".to_string(),
+                "reference fields".to_string(),
                 field_names.to_vec(),
                 vec![],
                 false,
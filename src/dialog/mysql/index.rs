@@ -5,17 +5,17 @@ use crate::{
     model::mysql::{
         show_mysql_index_field, Field, Index, IndexKind, IndexMethod, IndexOrder, Version,
     },
-    widget::{ColumnInfo, Form, FormItem},
+    widget::{ColumnInfo, Form, FormItem, InputKind},
 };
 use anyhow::Result;
-use std::{cmp::min, collections::HashMap};
-use strum::IntoEnumIterator;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     widgets::{Clear, ListState},
     Frame,
 };
+use std::{cmp::min, collections::HashMap};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 pub struct IndexDialog<'a> {
@@ -56,9 +56,10 @@ impl<'a> IndexDialog<'a> {
                 match_str: String::new(),
             });
         }
-        form.set_items(if let Some(i) = index {
+        let mut items = if let Some(i) = index {
             vec![
-                FormItem::new_input("name".to_string(), Some(i.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(i.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_table_list(
                     "fields".to_string(),
                     i.fields()
@@ -98,7 +99,8 @@ impl<'a> IndexDialog<'a> {
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_table_list(
                     "fields".to_string(),
                     vec![],
@@ -127,7 +129,15 @@ impl<'a> IndexDialog<'a> {
                 ),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
             ]
-        });
+        };
+        if matches!(ver, Version::Eight) {
+            items.push(FormItem::new_check(
+                "invisible".to_string(),
+                index.map_or(false, |i| i.invisible()),
+                false,
+            ));
+        }
+        form.set_items(items);
 
         IndexDialog {
             id: index.map(|i| i.id().to_owned()),
@@ -1,8 +1,12 @@
 mod check;
+mod event;
 mod field;
 mod foreign_key;
 mod index;
+mod partition;
 mod privilege;
 mod trigger;
 
-pub use self::{check::*, field::*, foreign_key::*, index::*, privilege::*, trigger::*};
+pub use self::{
+    check::*, event::*, field::*, foreign_key::*, index::*, partition::*, privilege::*, trigger::*,
+};
@@ -0,0 +1,103 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    model::mysql::{Partition, PartitionMethod},
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, collections::HashMap};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+pub struct PartitionDialog<'a> {
+    id: Option<Uuid>,
+    form: Form<'a>,
+}
+
+impl<'a> PartitionDialog<'a> {
+    pub fn new(partition: Option<&Partition>, scheme: Option<&Partition>) -> Self {
+        let mut form = Form::default();
+        form.set_title("Edit Partition".to_string());
+        let scheme = partition.or(scheme);
+        form.set_items(vec![
+            FormItem::new_input(
+                "name".to_string(),
+                partition.map(|p| p.name()),
+                false,
+                false,
+                false,
+            ),
+            FormItem::new_select(
+                "method".to_string(),
+                PartitionMethod::iter().map(|m| m.to_string()).collect(),
+                scheme.map(|p| p.method().to_string()),
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "expression".to_string(),
+                scheme.map(|p| p.expression()),
+                false,
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "values".to_string(),
+                partition.map(|p| p.values()),
+                false,
+                false,
+                false,
+            ),
+            FormItem::new_input(
+                "comment".to_string(),
+                partition.and_then(|p| p.comment()),
+                true,
+                false,
+                false,
+            ),
+        ]);
+
+        PartitionDialog {
+            id: partition.map(|p| p.id().to_owned()),
+            form,
+        }
+    }
+    pub fn get_id(&self) -> Option<&Uuid> {
+        self.id.as_ref()
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+
+        let height = min(self.form.height(), bounds.height);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(
+        &mut self,
+        key: &Key,
+    ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
+        let r = self.form.handle_event(key)?;
+        match r {
+            DialogResult::Confirm(mut map) => {
+                if let Some(id) = self.id {
+                    map.insert("id".to_string(), Some(id.to_string()));
+                }
+                Ok(DialogResult::Confirm(map))
+            }
+            _ => Ok(r),
+        }
+    }
+}
@@ -6,9 +6,9 @@ use crate::{
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct TriggerDialog<'a> {
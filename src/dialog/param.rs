@@ -0,0 +1,72 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    widget::{Form, FormItem},
+};
+use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::cmp::min;
+
+pub struct ParamDialog<'a> {
+    form: Form<'a>,
+    names: Vec<String>,
+}
+
+impl<'a> ParamDialog<'a> {
+    pub fn new(prefix: &str, count: usize, values: &[Option<String>]) -> Self {
+        let names = (1..=count)
+            .map(|i| format!("{}{}", prefix, i))
+            .collect::<Vec<String>>();
+        let items = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let value = match values.get(i) {
+                    Some(value) => value.as_deref(),
+                    None => Some(""),
+                };
+                FormItem::new_input(name.clone(), value, true, true, false)
+            })
+            .collect();
+        let mut form = Form::default();
+        // PG parameters are bound as text, so comparing them with other types needs a cast.
+        form.set_title(if prefix == "$" {
+            String::from("Parameters (text, cast as $1::int)")
+        } else {
+            String::from("Parameters")
+        });
+        form.set_items(items);
+        ParamDialog { form, names }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height - 2);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(&mut self, key: &Key) -> Result<DialogResult<Vec<Option<String>>>> {
+        match self.form.handle_event(key)? {
+            DialogResult::Confirm(map) => Ok(DialogResult::Confirm(
+                self.names
+                    .iter()
+                    .map(|name| map.get(name).cloned().flatten())
+                    .collect(),
+            )),
+            DialogResult::Cancel => Ok(DialogResult::Cancel),
+            _ => Ok(DialogResult::Done),
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
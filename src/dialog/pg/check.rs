@@ -3,11 +3,11 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::Check,
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct CheckDialog<'a> {
@@ -21,7 +21,8 @@ impl<'a> CheckDialog<'a> {
         form.set_title("Edit Check".to_string());
         form.set_items(if let Some(c) = check {
             vec![
-                FormItem::new_input("name".to_string(), Some(c.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(c.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_textarea(
                     "expression".to_string(),
                     Some(c.expression()),
@@ -34,7 +35,8 @@ impl<'a> CheckDialog<'a> {
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_textarea("expression".to_string(), None, false, false, false),
                 FormItem::new_check("no inherit".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
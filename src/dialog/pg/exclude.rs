@@ -3,19 +3,19 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::{show_pg_exclude_field, Connections, Exclude, Field, IndexMethod, Schema},
-    pool::{fetch_pg_query, PGPools},
-    widget::{ColumnInfo, Form, FormItem},
+    pool::{fetch_pg_query_with_params, PGPools},
+    widget::{ColumnInfo, Form, FormItem, InputKind},
 };
 use anyhow::Result;
-use sqlx::Row;
-use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
-use strum::IntoEnumIterator;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     widgets::{Clear, ListState},
     Frame,
 };
+use sqlx::Row;
+use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 pub struct ExcludeDialog<'a> {
@@ -37,7 +37,8 @@ impl<'a> ExcludeDialog<'a> {
         let mut form = Form::default();
         form.set_items(if let Some(e) = exclude {
             vec![
-                FormItem::new_input("name".to_string(), Some(e.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(e.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "index method".to_string(),
                     IndexMethod::iter().map(|s| s.to_string()).collect(),
@@ -116,11 +117,12 @@ impl<'a> ExcludeDialog<'a> {
                     show_pg_exclude_field,
                     false,
                 ),
-                FormItem::new_input("comment".to_string(), None, true, false, false),
+                FormItem::new_input("comment".to_string(), e.comment(), true, false, false),
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "index method".to_string(),
                     IndexMethod::iter().map(|s| s.to_string()).collect(),
@@ -226,12 +228,13 @@ impl<'a> ExcludeDialog<'a> {
             DialogResult::Changed(name, selected) => {
                 match name.as_str() {
                     "operator class schema" => {
-                        let operators = fetch_pg_query(
+                        let operators = fetch_pg_query_with_params(
                             self.conns.clone(),
                             self.pools.clone(),
                             &self.conn_id,
                             None,
-                            format!("select distinct opcname from pg_opclass where opcnamespace = '{}'::regnamespace::oid", selected).as_str(),
+                            "select distinct opcname from pg_opclass where opcnamespace = (select oid from pg_namespace where nspname = $1)",
+                            &[selected.as_str()],
                         )
                         .await?;
                         if let FormItem::TableList { columns, .. } =
@@ -250,21 +253,18 @@ impl<'a> ExcludeDialog<'a> {
                         }
                     }
                     "operator schema" => {
-                        let operators = fetch_pg_query(
+                        let operators = fetch_pg_query_with_params(
                             self.conns.clone(),
                             self.pools.clone(),
                             &self.conn_id,
                             None,
-                            format!(
-                                "
+                            "
                                 select
                                     distinct oprname 
                                 from
                                     pg_operator
-                                where oprnamespace = '{}'::regnamespace::oid",
-                                selected
-                            )
-                            .as_str(),
+                                where oprnamespace = (select oid from pg_namespace where nspname = $1)",
+                            &[selected.as_str()],
                         )
                         .await?;
                         if let FormItem::TableList { columns, .. } =
@@ -3,12 +3,12 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::{Field, FieldKind},
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct FieldDialog<'a> {
@@ -32,6 +32,7 @@ impl<'a> FieldDialog<'a> {
                 ),
                 FormItem::new_check("not null".to_string(), f.not_null(), false),
                 FormItem::new_check("key".to_string(), f.key(), false),
+                FormItem::new_check("array".to_string(), f.is_array(), false),
                 FormItem::new_input(
                     "default value".to_string(),
                     f.default_value(),
@@ -39,6 +40,11 @@ impl<'a> FieldDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_check(
+                    "default expression".to_string(),
+                    f.default_expression(),
+                    false,
+                ),
             ];
             match f.kind {
                 FieldKind::BigSerial
@@ -46,7 +52,7 @@ impl<'a> FieldDialog<'a> {
                 | FieldKind::Serial2
                 | FieldKind::Serial8
                 | FieldKind::SmallSerial => {
-                    items.pop();
+                    items.retain(|i| !i.name().starts_with("default"));
                 }
                 FieldKind::VarChar
                 | FieldKind::Char
@@ -57,29 +63,38 @@ impl<'a> FieldDialog<'a> {
                 | FieldKind::TimeTz
                 | FieldKind::VarBit
                 | FieldKind::Bit => {
-                    items.push(FormItem::new_input(
-                        "length".to_string(),
-                        f.length().as_deref(),
-                        true,
-                        false,
-                        false,
-                    ));
+                    items.push(
+                        FormItem::new_input(
+                            "length".to_string(),
+                            f.length().as_deref(),
+                            true,
+                            false,
+                            false,
+                        )
+                        .input_kind(InputKind::Numeric),
+                    );
                 }
                 FieldKind::Decimal | FieldKind::Numeric => {
-                    items.push(FormItem::new_input(
-                        "length".to_string(),
-                        f.length().as_deref(),
-                        true,
-                        false,
-                        false,
-                    ));
-                    items.push(FormItem::new_input(
-                        "decimal".to_string(),
-                        f.decimal().as_deref(),
-                        true,
-                        false,
-                        false,
-                    ));
+                    items.push(
+                        FormItem::new_input(
+                            "length".to_string(),
+                            f.length().as_deref(),
+                            true,
+                            false,
+                            false,
+                        )
+                        .input_kind(InputKind::Numeric),
+                    );
+                    items.push(
+                        FormItem::new_input(
+                            "decimal".to_string(),
+                            f.decimal().as_deref(),
+                            true,
+                            false,
+                            false,
+                        )
+                        .input_kind(InputKind::Numeric),
+                    );
                 }
                 _ => (),
             }
@@ -103,7 +118,9 @@ impl<'a> FieldDialog<'a> {
                 ),
                 FormItem::new_check("not null".to_string(), false, false),
                 FormItem::new_check("key".to_string(), false, false),
+                FormItem::new_check("array".to_string(), false, false),
                 FormItem::new_input("default value".to_string(), None, true, false, false),
+                FormItem::new_check("default expression".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
             ]
         };
@@ -161,6 +178,7 @@ impl<'a> FieldDialog<'a> {
                             ),
                             FormItem::new_check("not null".to_string(), false, false),
                             FormItem::new_check("key".to_string(), false, false),
+                            FormItem::new_check("array".to_string(), false, false),
                             FormItem::new_input(
                                 "default value".to_string(),
                                 None,
@@ -168,6 +186,7 @@ impl<'a> FieldDialog<'a> {
                                 false,
                                 false,
                             ),
+                            FormItem::new_check("default expression".to_string(), false, false),
                         ];
 
                         match kind {
@@ -176,7 +195,7 @@ impl<'a> FieldDialog<'a> {
                             | FieldKind::Serial2
                             | FieldKind::Serial8
                             | FieldKind::SmallSerial => {
-                                items.pop();
+                                items.retain(|i| !i.name().starts_with("default"));
                             }
                             FieldKind::VarChar
                             | FieldKind::Char
@@ -187,29 +206,38 @@ impl<'a> FieldDialog<'a> {
                             | FieldKind::TimeTz
                             | FieldKind::VarBit
                             | FieldKind::Bit => {
-                                items.push(FormItem::new_input(
-                                    "length".to_string(),
-                                    None,
-                                    true,
-                                    false,
-                                    false,
-                                ));
+                                items.push(
+                                    FormItem::new_input(
+                                        "length".to_string(),
+                                        None,
+                                        true,
+                                        false,
+                                        false,
+                                    )
+                                    .input_kind(InputKind::Numeric),
+                                );
                             }
                             FieldKind::Decimal | FieldKind::Numeric => {
-                                items.push(FormItem::new_input(
-                                    "length".to_string(),
-                                    None,
-                                    true,
-                                    false,
-                                    false,
-                                ));
-                                items.push(FormItem::new_input(
-                                    "decimal".to_string(),
-                                    None,
-                                    true,
-                                    false,
-                                    false,
-                                ));
+                                items.push(
+                                    FormItem::new_input(
+                                        "length".to_string(),
+                                        None,
+                                        true,
+                                        false,
+                                        false,
+                                    )
+                                    .input_kind(InputKind::Numeric),
+                                );
+                                items.push(
+                                    FormItem::new_input(
+                                        "decimal".to_string(),
+                                        None,
+                                        true,
+                                        false,
+                                        false,
+                                    )
+                                    .input_kind(InputKind::Numeric),
+                                );
                             }
                             _ => (),
                         }
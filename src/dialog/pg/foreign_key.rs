@@ -2,13 +2,13 @@ use crate::{
     app::DialogResult,
     component::Command,
     event::Key,
-    model::pg::{Field, ForeignKey, OnDeleteKind, OnUpdateKind},
-    widget::{Form, FormItem},
+    model::pg::{Field, ForeignKey, MatchKind, OnDeleteKind, OnUpdateKind},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct ForeignKeyDialog<'a> {
@@ -21,11 +21,12 @@ impl<'a> ForeignKeyDialog<'a> {
         form.set_title("Edit Foreign Key".to_string());
         form.set_items(if let Some(fk) = foreign_key {
             vec![
-                FormItem::new_input("name".to_string(), Some(fk.name()), false, false, false),
-                FormItem::new_select(
-                    "field".to_string(),
+                FormItem::new_input("name".to_string(), Some(fk.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
+                FormItem::new_multi_select(
+                    "fields".to_string(),
                     fields.iter().map(|f| f.name.to_string()).collect(),
-                    Some(fk.field().to_string()),
+                    fk.fields().clone(),
                     false,
                     false,
                 ),
@@ -43,10 +44,10 @@ impl<'a> ForeignKeyDialog<'a> {
                     false,
                     false,
                 ),
-                FormItem::new_select(
-                    "reference field".to_string(),
+                FormItem::new_multi_select(
+                    "reference fields".to_string(),
                     vec![],
-                    Some(fk.ref_field().to_string()),
+                    fk.ref_fields().clone(),
                     false,
                     false,
                 ),
@@ -64,15 +65,29 @@ impl<'a> ForeignKeyDialog<'a> {
                     true,
                     false,
                 ),
+                FormItem::new_select(
+                    "match".to_string(),
+                    MatchKind::iter().map(|s| s.to_string()).collect(),
+                    fk.match_kind().map(|m| m.to_string()),
+                    true,
+                    false,
+                ),
+                FormItem::new_check("deferrable".to_string(), fk.deferrable(), false),
+                FormItem::new_check(
+                    "initially deferred".to_string(),
+                    fk.initially_deferred(),
+                    false,
+                ),
                 FormItem::new_input("comment".to_string(), fk.comment(), true, false, false),
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
-                FormItem::new_select(
-                    "field".to_string(),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
+                FormItem::new_multi_select(
+                    "fields".to_string(),
                     fields.iter().map(|f| f.name.to_string()).collect(),
-                    None,
+                    vec![],
                     false,
                     false,
                 ),
@@ -84,7 +99,13 @@ impl<'a> ForeignKeyDialog<'a> {
                     false,
                 ),
                 FormItem::new_select("reference table".to_string(), vec![], None, false, false),
-                FormItem::new_select("reference field".to_string(), vec![], None, false, false),
+                FormItem::new_multi_select(
+                    "reference fields".to_string(),
+                    vec![],
+                    vec![],
+                    false,
+                    false,
+                ),
                 FormItem::new_select(
                     "on delete".to_string(),
                     OnDeleteKind::iter().map(|s| s.to_string()).collect(),
@@ -99,6 +120,15 @@ impl<'a> ForeignKeyDialog<'a> {
                     true,
                     false,
                 ),
+                FormItem::new_select(
+                    "match".to_string(),
+                    MatchKind::iter().map(|s| s.to_string()).collect(),
+                    None,
+                    true,
+                    false,
+                ),
+                FormItem::new_check("deferrable".to_string(), false, false),
+                FormItem::new_check("initially deferred".to_string(), false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
             ]
         });
@@ -130,11 +160,11 @@ impl<'a> ForeignKeyDialog<'a> {
     }
     pub fn set_ref_fields(&mut self, field_names: Vec<String>) {
         self.form.set_item(
-            "reference field",
-            FormItem::new_select(
-                "reference field".to_string(),
+            "reference fields",
+            FormItem::new_multi_select(
+                "reference fields".to_string(),
                 field_names,
-                None,
+                vec![],
                 false,
                 false,
             ),
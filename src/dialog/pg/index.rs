@@ -2,20 +2,20 @@ use crate::{
     app::DialogResult,
     component::Command,
     event::Key,
-    model::pg::{show_pg_index_field, Connections, Field, Index, IndexMethod, Schema},
-    pool::{fetch_pg_query, PGPools},
-    widget::{ColumnInfo, Form, FormItem},
+    model::pg::{show_pg_index_field, Connections, Field, Index, IndexMethod, IndexOrder, Schema},
+    pool::{fetch_pg_query_with_params, PGPools},
+    widget::{ColumnInfo, Form, FormItem, InputKind},
 };
 use anyhow::Result;
-use sqlx::Row;
-use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
-use strum::IntoEnumIterator;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     widgets::{Clear, ListState},
     Frame,
 };
+use sqlx::Row;
+use std::{cell::RefCell, cmp::min, collections::HashMap, rc::Rc};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 pub struct IndexDialog<'a> {
@@ -39,7 +39,8 @@ impl<'a> IndexDialog<'a> {
         form.set_title("Edit Index".to_string());
         form.set_items(if let Some(i) = index {
             vec![
-                FormItem::new_input("name".to_string(), Some(i.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(i.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_table_list(
                     "fields".to_string(),
                     i.fields()
@@ -65,13 +66,14 @@ impl<'a> IndexDialog<'a> {
                             );
                             v.push(f.sort_order().map(|s| s.to_string()).unwrap_or_default());
                             v.push(f.nulls_order().map(|s| s.to_string()).unwrap_or_default());
+                            v.push(f.expression().map(|s| s.to_string()).unwrap_or_default());
                             v
                         })
                         .collect::<Vec<Vec<String>>>(),
                     vec![
                         ColumnInfo::Select {
                             name: "name".to_string(),
-                            nullable: false,
+                            nullable: true,
                             options: fields.iter().map(|f| f.name().to_string()).collect(),
                             selected: None,
                             state: ListState::default(),
@@ -117,7 +119,7 @@ impl<'a> IndexDialog<'a> {
                         ColumnInfo::Select {
                             name: "sort order".to_string(),
                             nullable: true,
-                            options: vec!["ASC".to_string(), "DESC".to_string()],
+                            options: IndexOrder::iter().map(|o| o.to_string()).collect(),
                             selected: None,
                             state: ListState::default(),
                             is_pop: false,
@@ -132,6 +134,11 @@ impl<'a> IndexDialog<'a> {
                             is_pop: false,
                             match_str: String::new(),
                         },
+                        ColumnInfo::Input {
+                            name: "expression".to_string(),
+                            value: String::default(),
+                            nullable: true,
+                        },
                     ],
                     false,
                     show_pg_index_field,
@@ -148,18 +155,27 @@ impl<'a> IndexDialog<'a> {
                 ),
                 FormItem::new_check("unique".to_string(), i.unique(), true),
                 FormItem::new_check("concurrent".to_string(), i.concurrent(), true),
+                FormItem::new_multi_select(
+                    "include".to_string(),
+                    fields.iter().map(|f| f.name().to_string()).collect(),
+                    i.include().clone(),
+                    true,
+                    true,
+                ),
+                FormItem::new_input("where".to_string(), i.predicate(), true, false, true),
                 FormItem::new_input("comment".to_string(), i.comment(), true, false, false),
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_table_list(
                     "fields".to_string(),
                     vec![],
                     vec![
                         ColumnInfo::Select {
                             name: "name".to_string(),
-                            nullable: false,
+                            nullable: true,
                             options: fields.iter().map(|f| f.name().to_string()).collect(),
                             selected: None,
                             state: ListState::default(),
@@ -205,7 +221,7 @@ impl<'a> IndexDialog<'a> {
                         ColumnInfo::Select {
                             name: "sort order".to_string(),
                             nullable: true,
-                            options: vec!["ASC".to_string(), "DESC".to_string()],
+                            options: IndexOrder::iter().map(|o| o.to_string()).collect(),
                             selected: None,
                             state: ListState::default(),
                             is_pop: false,
@@ -220,6 +236,11 @@ impl<'a> IndexDialog<'a> {
                             is_pop: false,
                             match_str: String::new(),
                         },
+                        ColumnInfo::Input {
+                            name: "expression".to_string(),
+                            value: String::default(),
+                            nullable: true,
+                        },
                     ],
                     false,
                     show_pg_index_field,
@@ -236,6 +257,14 @@ impl<'a> IndexDialog<'a> {
                 ),
                 FormItem::new_check("unique".to_string(), false, false),
                 FormItem::new_check("concurrent".to_string(), false, false),
+                FormItem::new_multi_select(
+                    "include".to_string(),
+                    fields.iter().map(|f| f.name().to_string()).collect(),
+                    vec![],
+                    true,
+                    false,
+                ),
+                FormItem::new_input("where".to_string(), None, true, false, false),
                 FormItem::new_input("comment".to_string(), None, true, false, false),
             ]
         });
@@ -281,12 +310,13 @@ impl<'a> IndexDialog<'a> {
             DialogResult::Changed(name, selected) => {
                 match name.as_str() {
                     "collation schema" => {
-                        let collations = fetch_pg_query(
+                        let collations = fetch_pg_query_with_params(
                             self.conns.clone(),
                             self.pools.clone(),
                             &self.conn_id,
                             None,
-                            format!("select collname from pg_collation where collnamespace = '{}'::regnamespace::oid",selected).as_str() 
+                            "select collname from pg_collation where collnamespace = (select oid from pg_namespace where nspname = $1)",
+                            &[selected.as_str()],
                         )
                         .await?;
                         if let FormItem::TableList { columns, .. } =
@@ -305,12 +335,13 @@ impl<'a> IndexDialog<'a> {
                         }
                     }
                     "operator class schema" => {
-                        let operators = fetch_pg_query(
+                        let operators = fetch_pg_query_with_params(
                             self.conns.clone(),
                             self.pools.clone(),
                             &self.conn_id,
                             None,
-                            format!("select distinct opcname from pg_opclass where opcnamespace = '{}'::regnamespace::oid", selected).as_str(),
+                            "select distinct opcname from pg_opclass where opcnamespace = (select oid from pg_namespace where nspname = $1)",
+                            &[selected.as_str()],
                         )
                         .await?;
                         if let FormItem::TableList { columns, .. } =
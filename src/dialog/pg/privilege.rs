@@ -7,9 +7,9 @@ use crate::{
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cell::RefCell, rc::Rc};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct PrivilegeDialog<'a> {
@@ -6,9 +6,9 @@ use crate::{
     widget::{Form, FormItem},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use sqlx::postgres::types::Oid;
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 
 pub struct RoleMemberDialog<'a> {
     role_id: Option<Oid>,
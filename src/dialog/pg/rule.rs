@@ -3,12 +3,12 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::{DoInstead, EventKind, Rule},
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct RuleDialog<'a> {
@@ -21,7 +21,8 @@ impl<'a> RuleDialog<'a> {
         let mut form = Form::default();
         form.set_items(if let Some(r) = rule {
             vec![
-                FormItem::new_input("name".to_string(), Some(r.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(r.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "event".to_string(),
                     EventKind::iter().map(|s| s.to_string()).collect(),
@@ -55,7 +56,8 @@ impl<'a> RuleDialog<'a> {
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "event".to_string(),
                     EventKind::iter().map(|s| s.to_string()).collect(),
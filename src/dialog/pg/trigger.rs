@@ -3,12 +3,12 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::{Field, FiresKind, ForEachKind, Schema, Trigger},
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
 use strum::IntoEnumIterator;
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct TriggerDialog<'a> {
@@ -22,7 +22,8 @@ impl<'a> TriggerDialog<'a> {
         form.set_title("Edit Trigger".to_string());
         form.set_items(if let Some(f) = trigger {
             vec![
-                FormItem::new_input("name".to_string(), Some(f.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(f.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "for each".to_string(),
                     ForEachKind::iter().map(|s| s.to_string()).collect(),
@@ -74,10 +75,12 @@ impl<'a> TriggerDialog<'a> {
                     false,
                     false,
                 ),
+                FormItem::new_input("comment".to_string(), f.comment(), true, false, false),
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_select(
                     "for each".to_string(),
                     ForEachKind::iter().map(|s| s.to_string()).collect(),
@@ -113,6 +116,7 @@ impl<'a> TriggerDialog<'a> {
                     false,
                 ),
                 FormItem::new_select("function name".to_string(), vec![], None, false, false),
+                FormItem::new_input("comment".to_string(), None, true, false, false),
             ]
         });
 
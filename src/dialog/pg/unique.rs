@@ -3,11 +3,11 @@ use crate::{
     component::Command,
     event::Key,
     model::pg::{Field, Unique},
-    widget::{Form, FormItem},
+    widget::{Form, FormItem, InputKind},
 };
 use anyhow::Result;
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use std::{cmp::min, collections::HashMap};
-use tui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
 use uuid::Uuid;
 
 pub struct UniqueDialog<'a> {
@@ -21,7 +21,8 @@ impl<'a> UniqueDialog<'a> {
         form.set_title("Edit Unique".to_string());
         form.set_items(if let Some(u) = unique {
             vec![
-                FormItem::new_input("name".to_string(), Some(u.name()), false, false, false),
+                FormItem::new_input("name".to_string(), Some(u.name()), false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_multi_select(
                     "fields".to_string(),
                     fields.iter().map(|f| f.name().to_string()).collect(),
@@ -33,7 +34,8 @@ impl<'a> UniqueDialog<'a> {
             ]
         } else {
             vec![
-                FormItem::new_input("name".to_string(), None, false, false, false),
+                FormItem::new_input("name".to_string(), None, false, false, false)
+                    .input_kind(InputKind::Identifier),
                 FormItem::new_multi_select(
                     "fields".to_string(),
                     fields.iter().map(|f| f.name().to_string()).collect(),
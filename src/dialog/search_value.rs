@@ -0,0 +1,70 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::Key,
+    model::SearchColumnKind,
+    widget::{Form, FormItem},
+};
+use anyhow::{Error, Result};
+use ratatui::{backend::Backend, layout::Rect, widgets::Clear, Frame};
+use std::{cmp::min, str::FromStr};
+use strum::IntoEnumIterator;
+
+pub struct SearchValueDialog<'a> {
+    form: Form<'a>,
+}
+
+impl<'a> SearchValueDialog<'a> {
+    pub fn new(schema_name: &str) -> Self {
+        let mut form = Form::default();
+        form.set_title(format!("Search Value in {}", schema_name));
+        form.set_items(vec![
+            FormItem::new_input("value".to_string(), None, false, false, false),
+            FormItem::new_select(
+                "column types".to_string(),
+                SearchColumnKind::iter().map(|k| k.to_string()).collect(),
+                Some(SearchColumnKind::Text.to_string()),
+                false,
+                false,
+            ),
+        ]);
+        SearchValueDialog { form }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 60);
+        let height = min(self.form.height(), bounds.height - 2);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        self.form.draw(f, rect);
+    }
+    pub fn handle_event(&mut self, key: &Key) -> Result<DialogResult<(String, SearchColumnKind)>> {
+        match self.form.handle_event(key)? {
+            DialogResult::Confirm(mut map) => {
+                let value = map.remove("value").flatten().unwrap_or_default();
+                if value.is_empty() {
+                    return Err(Error::msg("A value to search for is required"));
+                }
+                let kind = map
+                    .remove("column types")
+                    .flatten()
+                    .and_then(|k| SearchColumnKind::from_str(&k).ok())
+                    .unwrap_or(SearchColumnKind::Text);
+                Ok(DialogResult::Confirm((value, kind)))
+            }
+            DialogResult::Cancel => Ok(DialogResult::Cancel),
+            DialogResult::Changed(name, value) => Ok(DialogResult::Changed(name, value)),
+            DialogResult::Done => Ok(DialogResult::Done),
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        self.form.get_commands()
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::{config::*, Key},
+};
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use std::cmp::min;
+
+pub struct TextDialog {
+    title: String,
+    text: String,
+    scroll: u16,
+    max_scroll: u16,
+}
+
+impl TextDialog {
+    pub fn new(title: &str, text: &str) -> Self {
+        TextDialog {
+            title: title.to_string(),
+            text: text.to_string(),
+            scroll: 0,
+            max_scroll: 0,
+        }
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = min(bounds.width - 2, 80);
+        let height = min(bounds.height - 2, 20);
+        let left = (bounds.width - width) / 2;
+        let top = (bounds.height - height) / 2;
+        let rect = Rect::new(left, top, width, height);
+        f.render_widget(Clear, rect);
+
+        let line_count: usize = self
+            .text
+            .lines()
+            .map(|l| {
+                (l.chars().count() as f64 / (width - 2) as f64)
+                    .ceil()
+                    .max(1.0) as usize
+            })
+            .sum();
+        self.max_scroll = (line_count as u16).saturating_sub(height - 2);
+        self.scroll = min(self.scroll, self.max_scroll);
+
+        f.render_widget(
+            Paragraph::new(self.text.as_str())
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(Color::Green))
+                        .title(self.title.as_str()),
+                )
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            rect,
+        );
+    }
+    pub fn handle_event(&mut self, key: &Key) -> DialogResult<()> {
+        match *key {
+            UP_KEY => {
+                self.scroll = self.scroll.saturating_sub(1);
+                DialogResult::Done
+            }
+            DOWN_KEY => {
+                if self.scroll < self.max_scroll {
+                    self.scroll += 1;
+                }
+                DialogResult::Done
+            }
+            CANCEL_KEY => DialogResult::Cancel,
+            _ => DialogResult::Done,
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Close",
+                key: CANCEL_KEY,
+            },
+        ]
+    }
+}
@@ -89,6 +89,10 @@ pub const MOVE_UP_KEY: Key = Key {
     code: Code::Up,
     modifier: Mod::Shift,
 };
+pub const UNDO_KEY: Key = Key {
+    code: Code::Char('z'),
+    modifier: Mod::Ctrl,
+};
 pub const MOVE_DOWN_KEY: Key = Key {
     code: Code::Down,
     modifier: Mod::Shift,
@@ -97,6 +101,22 @@ pub const USER_KEY: Key = Key {
     code: Code::Char('u'),
     modifier: Mod::Ctrl,
 };
+pub const PROCESS_KEY: Key = Key {
+    code: Code::Char('g'),
+    modifier: Mod::Ctrl,
+};
+pub const SERVER_INFO_KEY: Key = Key {
+    code: Code::Char('v'),
+    modifier: Mod::Ctrl,
+};
+pub const INTERRUPT_KEY: Key = Key {
+    code: Code::Char('c'),
+    modifier: Mod::Alt,
+};
+pub const MAINTAIN_KEY: Key = Key {
+    code: Code::Char('a'),
+    modifier: Mod::Ctrl,
+};
 pub const QUIT_APP_KEY: Key = Key {
     code: Code::Char('c'),
     modifier: Mod::Ctrl,
@@ -109,3 +129,95 @@ pub const SET_NULL_KEY: Key = Key {
     code: Code::Char('n'),
     modifier: Mod::Ctrl,
 };
+pub const EXPAND_KEY: Key = Key {
+    code: Code::Char('o'),
+    modifier: Mod::Ctrl,
+};
+pub const DISCONNECT_KEY: Key = Key {
+    code: Code::Char('x'),
+    modifier: Mod::Ctrl,
+};
+pub const COMMAND_PALETTE_KEY: Key = Key {
+    code: Code::Char('p'),
+    modifier: Mod::Ctrl,
+};
+pub const QUERY_KEY: Key = Key {
+    code: Code::Char('q'),
+    modifier: Mod::Ctrl,
+};
+pub const COMPARE_KEY: Key = Key {
+    code: Code::Char('k'),
+    modifier: Mod::Ctrl,
+};
+pub const EXPORT_KEY: Key = Key {
+    code: Code::Char('w'),
+    modifier: Mod::Ctrl,
+};
+pub const IMPORT_KEY: Key = Key {
+    code: Code::Char('l'),
+    modifier: Mod::Ctrl,
+};
+pub const COMPLETE_KEY: Key = Key {
+    code: Code::Char(' '),
+    modifier: Mod::Ctrl,
+};
+pub const COMMENT_KEY: Key = Key {
+    code: Code::Char('t'),
+    modifier: Mod::Ctrl,
+};
+pub const COPY_MARKDOWN_KEY: Key = Key {
+    code: Code::Char('m'),
+    modifier: Mod::Alt,
+};
+pub const COPY_WHERE_KEY: Key = Key {
+    code: Code::Char('w'),
+    modifier: Mod::Alt,
+};
+pub const QUERY_ROW_KEY: Key = Key {
+    code: Code::Char('q'),
+    modifier: Mod::Alt,
+};
+pub const CONNECTION_SWITCH_KEY: Key = Key {
+    code: Code::Char('o'),
+    modifier: Mod::Alt,
+};
+pub const FAVORITE_KEY: Key = Key {
+    code: Code::Char('f'),
+    modifier: Mod::Alt,
+};
+pub const PIN_KEY: Key = Key {
+    code: Code::Char('p'),
+    modifier: Mod::Alt,
+};
+pub const COLUMN_INFO_KEY: Key = Key {
+    code: Code::Char('i'),
+    modifier: Mod::Alt,
+};
+pub const COPY_SQL_KEY: Key = Key {
+    code: Code::Char('y'),
+    modifier: Mod::Alt,
+};
+pub const BULK_UPDATE_KEY: Key = Key {
+    code: Code::Char('u'),
+    modifier: Mod::Alt,
+};
+pub const EXPLAIN_KEY: Key = Key {
+    code: Code::Char('e'),
+    modifier: Mod::Alt,
+};
+pub const ANALYZE_KEY: Key = Key {
+    code: Code::Char('a'),
+    modifier: Mod::Alt,
+};
+pub const RELOAD_CONFIG_KEY: Key = Key {
+    code: Code::Char('r'),
+    modifier: Mod::Alt,
+};
+pub const SEARCH_VALUE_KEY: Key = Key {
+    code: Code::Char('s'),
+    modifier: Mod::Alt,
+};
+pub const CLONE_ROW_KEY: Key = Key {
+    code: Code::Char('d'),
+    modifier: Mod::Alt,
+};
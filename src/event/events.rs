@@ -1,5 +1,5 @@
 use crate::event::key::{Code, Key, Modifier};
-use crossterm::event;
+use crossterm::event::{self, MouseEvent};
 use std::{sync::mpsc, thread, time::Duration};
 
 pub struct EventConfig {
@@ -21,6 +21,7 @@ impl Default for EventConfig {
 
 pub enum Event<I> {
     Input(I),
+    Mouse(MouseEvent),
     Tick,
 }
 
@@ -42,10 +43,16 @@ impl Events {
         let event_tx = tx.clone();
         thread::spawn(move || loop {
             if event::poll(config.tick_rate).unwrap() {
-                if let event::Event::Key(key) = event::read().unwrap() {
-                    let key = Key::from(key);
+                match event::read().unwrap() {
+                    event::Event::Key(key) => {
+                        let key = Key::from(key);
 
-                    event_tx.send(Event::Input(key)).unwrap();
+                        event_tx.send(Event::Input(key)).unwrap();
+                    }
+                    event::Event::Mouse(mouse) => {
+                        event_tx.send(Event::Mouse(mouse)).unwrap();
+                    }
+                    _ => (),
                 }
             }
             event_tx.send(Event::Tick).unwrap();
@@ -27,6 +27,7 @@ impl Display for Code {
             Code::Esc => write!(f, "Esc"),
             Code::Backspace => write!(f, "\u{232b}"),
             Code::Tab => write!(f, "Tab"),
+            Code::Char(' ') => write!(f, "Space"),
             Code::Char(c) => write!(f, "{}", c),
             Code::F(n) => write!(f, "F{}", n),
             Code::Unknown => write!(f, ""),
@@ -1,4 +1,6 @@
 mod app;
+mod args;
+mod clipboard;
 mod component;
 mod config;
 mod dialog;
@@ -8,6 +10,7 @@ mod pool;
 mod widget;
 
 use crate::app::App;
+use crate::args::{parse_cli, Cli};
 use crate::config::Config;
 use crate::model::{init_connections, query::Queries};
 use crate::pool::init_pools;
@@ -16,8 +19,20 @@ use std::{cell::RefCell, rc::Rc};
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = parse_cli()?;
     let config = Config::init()?;
     let (mysql_conns, pg_conns) = init_connections(&config);
+    let args = match cli {
+        Cli::Tui(args) => args,
+        Cli::Export(export) => {
+            let count = export.run(&config, mysql_conns, pg_conns).await?;
+            if export.out.is_some() {
+                eprintln!("Exported {} rows", count);
+            }
+            return Ok(());
+        }
+    };
+    let startup = args.get_session(&mysql_conns, &pg_conns)?;
     let (mysql_pools, pg_pools) = init_pools();
     let mysql_conns = Rc::new(RefCell::new(mysql_conns));
     let pg_conns = Rc::new(RefCell::new(pg_conns));
@@ -32,6 +47,7 @@ async fn main() -> Result<()> {
         pg_pools,
         config,
         queries,
+        startup,
     );
     app.start().await?;
     Ok(())
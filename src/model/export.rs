@@ -0,0 +1,286 @@
+use crate::model::{
+    mysql::get_mysql_column_value, pg::get_pg_column_value, DisplayFormat, DisplayTimezone,
+};
+use anyhow::{anyhow, Result};
+use futures_util::TryStreamExt;
+use sqlx::{Column, Executor, MySqlPool, PgPool, Row, Statement, TypeInfo};
+use std::io::Write;
+use strum::{Display, EnumIter, EnumString};
+
+#[derive(Clone, PartialEq, EnumString, EnumIter, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+    Json,
+    Jsonl,
+}
+
+impl ExportFormat {
+    pub fn new_serializer<'w>(&self, writer: Box<dyn Write + 'w>) -> Box<dyn RowSerializer + 'w> {
+        match self {
+            ExportFormat::Csv => Box::new(DelimitedSerializer::new(writer, ',')),
+            ExportFormat::Tsv => Box::new(DelimitedSerializer::new(writer, '\t')),
+            ExportFormat::Json => Box::new(JsonSerializer::new(writer, false)),
+            ExportFormat::Jsonl => Box::new(JsonSerializer::new(writer, true)),
+        }
+    }
+    pub fn get_display_format(&self, display_format: &DisplayFormat) -> DisplayFormat {
+        match self {
+            ExportFormat::Json | ExportFormat::Jsonl => DisplayFormat {
+                timezone: DisplayTimezone::Utc,
+                date_format: String::from("%Y-%m-%d"),
+                time_format: String::from("%H:%M:%S%.f"),
+                datetime_format: String::from("%Y-%m-%dT%H:%M:%S%.f"),
+                offset_format: String::from("%:z"),
+                tinyint_as_bool: false,
+                ..DisplayFormat::default()
+            },
+            _ => display_format.clone(),
+        }
+    }
+}
+
+pub enum ExportValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    Text(String),
+}
+
+impl ExportValue {
+    pub fn new(type_name: &str, value: Option<String>) -> Self {
+        let value = match value {
+            Some(value) => value,
+            None => return ExportValue::Null,
+        };
+        match type_name {
+            "BOOL" | "BOOLEAN" => ExportValue::Bool(value != "0" && value != "false"),
+            "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INT" | "BIGINT" | "TINYINT UNSIGNED"
+            | "SMALLINT UNSIGNED" | "MEDIUMINT UNSIGNED" | "INT UNSIGNED" | "BIGINT UNSIGNED"
+            | "FLOAT" | "DOUBLE" | "DECIMAL" | "INT2" | "INT4" | "INT8" | "FLOAT4" | "FLOAT8"
+            | "NUMERIC" | "OID"
+                if value.parse::<f64>().map_or(false, |n| n.is_finite()) =>
+            {
+                ExportValue::Number(value)
+            }
+            _ => ExportValue::Text(value),
+        }
+    }
+    fn to_text(&self) -> Option<String> {
+        match self {
+            ExportValue::Null => None,
+            ExportValue::Bool(b) => Some(b.to_string()),
+            ExportValue::Number(n) | ExportValue::Text(n) => Some(n.to_string()),
+        }
+    }
+    fn to_json(&self) -> Result<String> {
+        Ok(match self {
+            ExportValue::Null => String::from("null"),
+            ExportValue::Bool(b) => b.to_string(),
+            ExportValue::Number(n) => n.to_string(),
+            ExportValue::Text(t) => serde_json::to_string(t)?,
+        })
+    }
+}
+
+pub trait RowSerializer {
+    fn write_header(&mut self, headers: &[String]) -> Result<()>;
+    fn write_row(&mut self, headers: &[String], values: &[ExportValue]) -> Result<()>;
+    fn finish(&mut self) -> Result<usize>;
+}
+
+pub struct DelimitedSerializer<W: Write> {
+    writer: W,
+    delimiter: char,
+    count: usize,
+}
+
+impl<W: Write> DelimitedSerializer<W> {
+    pub fn new(writer: W, delimiter: char) -> Self {
+        DelimitedSerializer {
+            writer,
+            delimiter,
+            count: 0,
+        }
+    }
+    fn write_line(&mut self, values: Vec<Option<String>>) -> Result<()> {
+        let line = values
+            .into_iter()
+            .map(|v| match self.delimiter {
+                '\t' => v.map_or(String::from("\\N"), |v| escape_tsv(&v)),
+                _ => v.map_or(String::new(), |v| escape_csv(&v, self.delimiter)),
+            })
+            .collect::<Vec<String>>()
+            .join(&self.delimiter.to_string());
+        writeln!(self.writer, "{}", line)?;
+        Ok(())
+    }
+}
+
+impl<W: Write> RowSerializer for DelimitedSerializer<W> {
+    fn write_header(&mut self, headers: &[String]) -> Result<()> {
+        self.write_line(headers.iter().map(|h| Some(h.to_string())).collect())
+    }
+    fn write_row(&mut self, _headers: &[String], values: &[ExportValue]) -> Result<()> {
+        self.write_line(values.iter().map(|v| v.to_text()).collect())?;
+        self.count += 1;
+        Ok(())
+    }
+    fn finish(&mut self) -> Result<usize> {
+        self.writer.flush()?;
+        Ok(self.count)
+    }
+}
+
+pub struct JsonSerializer<W: Write> {
+    writer: W,
+    lines: bool,
+    count: usize,
+}
+
+impl<W: Write> JsonSerializer<W> {
+    pub fn new(writer: W, lines: bool) -> Self {
+        JsonSerializer {
+            writer,
+            lines,
+            count: 0,
+        }
+    }
+}
+
+impl<W: Write> RowSerializer for JsonSerializer<W> {
+    fn write_header(&mut self, _headers: &[String]) -> Result<()> {
+        Ok(())
+    }
+    fn write_row(&mut self, headers: &[String], values: &[ExportValue]) -> Result<()> {
+        let fields = headers
+            .iter()
+            .zip(values)
+            .map(|(h, v)| Ok(format!("{}:{}", serde_json::to_string(h)?, v.to_json()?)))
+            .collect::<Result<Vec<String>>>()?;
+        let object = format!("{{{}}}", fields.join(","));
+        if self.lines {
+            writeln!(self.writer, "{}", object)?;
+        } else {
+            let sep = if self.count == 0 { "[" } else { "," };
+            write!(self.writer, "{}\n  {}", sep, object)?;
+        }
+        self.count += 1;
+        Ok(())
+    }
+    fn finish(&mut self) -> Result<usize> {
+        if !self.lines {
+            writeln!(
+                self.writer,
+                "{}",
+                if self.count == 0 { "[]" } else { "\n]" }
+            )?;
+        }
+        self.writer.flush()?;
+        Ok(self.count)
+    }
+}
+
+fn escape_csv(value: &str, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_tsv(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn get_headers<C: Column>(columns: &[C]) -> Vec<String> {
+    columns.iter().map(|c| c.name().to_string()).collect()
+}
+
+pub async fn export_mysql_query(
+    pool: &MySqlPool,
+    sql: &str,
+    serializer: &mut dyn RowSerializer,
+    display_format: &DisplayFormat,
+) -> Result<usize> {
+    // The header comes from the statement so that an empty result still has one.
+    let headers = get_headers(pool.prepare(sql).await?.columns());
+    serializer.write_header(&headers)?;
+    let mut stream = sqlx::query(sql).fetch(pool);
+    let mut index = 0;
+    while let Some(row) = stream.try_next().await? {
+        index += 1;
+        let values = row
+            .columns()
+            .iter()
+            .map(|c| {
+                let value = get_mysql_column_value(c, &row, display_format).map_err(|e| {
+                    anyhow!(
+                        "Column {} of row {} could not be decoded: {}",
+                        c.name(),
+                        index,
+                        e
+                    )
+                })?;
+                Ok(ExportValue::new(c.type_info().name(), value))
+            })
+            .collect::<Result<Vec<ExportValue>>>()?;
+        serializer.write_row(&headers, &values)?;
+    }
+    serializer.finish()
+}
+
+pub async fn export_pg_query(
+    pool: &PgPool,
+    sql: &str,
+    serializer: &mut dyn RowSerializer,
+    display_format: &DisplayFormat,
+) -> Result<usize> {
+    let headers = get_headers(pool.prepare(sql).await?.columns());
+    serializer.write_header(&headers)?;
+    let mut stream = sqlx::query(sql).fetch(pool);
+    while let Some(row) = stream.try_next().await? {
+        let values = row
+            .columns()
+            .iter()
+            .map(|c| {
+                ExportValue::new(
+                    c.type_info().name(),
+                    get_pg_column_value(c, &row, display_format),
+                )
+            })
+            .collect::<Vec<ExportValue>>();
+        serializer.write_row(&headers, &values)?;
+    }
+    serializer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[test]
+    fn test_json_datetime_is_rfc3339() {
+        let display_format = ExportFormat::Json.get_display_format(&DisplayFormat::default());
+        let datetime = Utc.timestamp_opt(1_700_000_000, 123_000_000).unwrap();
+        let text = display_format.format_datetime(&datetime);
+        assert_eq!(DateTime::parse_from_rfc3339(&text).unwrap(), datetime);
+    }
+
+    #[test]
+    fn test_csv_header_without_rows() {
+        let mut out = Vec::new();
+        let mut serializer = DelimitedSerializer::new(&mut out, ',');
+        serializer
+            .write_header(&[String::from("id"), String::from("name")])
+            .unwrap();
+        assert_eq!(serializer.finish().unwrap(), 0);
+        assert_eq!(String::from_utf8(out).unwrap(), "id,name\n");
+    }
+}
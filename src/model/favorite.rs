@@ -0,0 +1,90 @@
+use crate::{
+    app::APP_DIR,
+    model::{
+        session::{Session, SessionPanel},
+        DatabaseKind,
+    },
+};
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::PathBuf};
+use uuid::Uuid;
+
+const FAVORITE_FILE: &str = "favorites";
+
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub struct Favorite {
+    pub conn_id: Uuid,
+    pub kind: DatabaseKind,
+    pub db_name: String,
+    pub schema_name: Option<String>,
+    pub table_name: String,
+}
+
+impl Favorite {
+    pub fn get_path(&self) -> String {
+        [
+            Some(self.db_name.as_str()),
+            self.schema_name.as_deref(),
+            Some(self.table_name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<&str>>()
+        .join(".")
+    }
+    pub fn to_session(&self) -> Session {
+        Session {
+            conn_id: Some(self.conn_id),
+            kind: Some(self.kind.clone()),
+            db_name: Some(self.db_name.clone()),
+            schema_name: self.schema_name.clone(),
+            table_name: Some(self.table_name.clone()),
+            panel: Some(SessionPanel::DataList),
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct Favorites {
+    favorites: Vec<Favorite>,
+}
+
+impl Favorites {
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(Self::get_file_path()?) {
+            Ok(content) => Ok(serde_json::from_str(&content).unwrap_or_default()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Favorites::default()),
+            Err(e) => Err(Error::new(e)),
+        }
+    }
+    pub fn save(&self) -> Result<()> {
+        fs::write(Self::get_file_path()?, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+    pub fn get_favorites(&self) -> &[Favorite] {
+        &self.favorites
+    }
+    pub fn contains(&self, favorite: &Favorite) -> bool {
+        self.favorites.contains(favorite)
+    }
+    pub fn toggle(&mut self, favorite: Favorite) -> Result<()> {
+        match self.favorites.iter().position(|f| *f == favorite) {
+            Some(index) => {
+                self.favorites.remove(index);
+            }
+            None => self.favorites.push(favorite),
+        }
+        self.save()
+    }
+    pub fn remove(&mut self, favorite: &Favorite) -> Result<()> {
+        self.favorites.retain(|f| f != favorite);
+        self.save()
+    }
+    fn get_file_path() -> Result<PathBuf> {
+        let mut path = dirs_next::home_dir().ok_or(Error::msg("home dir not exists"))?;
+        path.push(APP_DIR);
+        path.push(FAVORITE_FILE);
+        Ok(path)
+    }
+}
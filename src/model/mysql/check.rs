@@ -1,3 +1,4 @@
+use super::quote_ident;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -23,8 +24,8 @@ impl Check {
     }
     pub fn get_create_ddl(&self) -> String {
         format!(
-            "CONSTRAINT `{}` CHECK ({}){}",
-            self.name,
+            "CONSTRAINT {} CHECK ({}){}",
+            quote_ident(&self.name),
             self.expression,
             if self.not_enforced {
                 " NOT ENFORCED"
@@ -37,7 +38,7 @@ impl Check {
         format!("ADD {}", self.get_create_ddl())
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP CHECK `{}`", self.name)
+        format!("DROP CHECK {}", quote_ident(&self.name))
     }
     pub fn get_change_ddl(&self, check: &Check) -> Option<String> {
         if self.not_enforced != check.not_enforced {
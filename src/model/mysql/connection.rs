@@ -1,12 +1,12 @@
 use crate::{
     config::Config,
-    model::{Connect, DatabaseKind},
+    model::{parse_database_url, resolve_password, Connect, ConnectionColor, DatabaseKind},
     pool::test_mysql_connection,
 };
 use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, env, rc::Rc};
 use uuid::Uuid;
 
 pub type Connections = HashMap<Uuid, Connection>;
@@ -19,7 +19,17 @@ pub struct Connection {
     pub port: String,
     pub user: String,
     pub password: String,
+    #[serde(default)]
+    pub password_command: Option<String>,
+    #[serde(default)]
+    pub color: ConnectionColor,
+    #[serde(default)]
+    pub environment: Option<String>,
+    #[serde(default)]
+    pub group: Option<String>,
     pub add_at: DateTime<Utc>,
+    #[serde(skip)]
+    pub ephemeral: bool,
 }
 
 impl Connect for Connection {
@@ -35,6 +45,18 @@ impl Connect for Connection {
     fn get_add_at(&self) -> &DateTime<Utc> {
         &self.add_at
     }
+    fn get_color(&self) -> &ConnectionColor {
+        &self.color
+    }
+    fn get_environment(&self) -> Option<&str> {
+        self.environment.as_deref()
+    }
+    fn get_group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+    fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
 }
 
 impl Connection {
@@ -50,18 +72,22 @@ impl Connection {
     pub fn get_password(&self) -> &str {
         &self.password
     }
-    pub fn get_pool_url(&self, db_name: Option<&str>) -> String {
-        if let Some(db_name) = db_name {
+    pub fn get_password_command(&self) -> Option<&str> {
+        self.password_command.as_deref()
+    }
+    pub fn get_pool_url(&self, db_name: Option<&str>) -> Result<String> {
+        let password = resolve_password(&self.password, self.get_password_command())?;
+        Ok(if let Some(db_name) = db_name {
             format!(
                 "mysql://{}:{}@{}:{}/{}",
-                self.user, self.password, self.host, self.port, db_name
+                self.user, password, self.host, self.port, db_name
             )
         } else {
             format!(
                 "mysql://{}:{}@{}:{}",
-                self.user, self.password, self.host, self.port
+                self.user, password, self.host, self.port
             )
-        }
+        })
     }
 }
 
@@ -81,7 +107,7 @@ pub async fn save_mysql_connection<'a>(
     config: Rc<RefCell<Config>>,
     conn: &Connection,
 ) -> Result<()> {
-    test_mysql_connection(&conn.get_pool_url(None)).await?;
+    test_mysql_connection(&conn.get_pool_url(None)?).await?;
     connections
         .borrow_mut()
         .insert(*conn.get_id(), conn.to_owned());
@@ -96,3 +122,48 @@ pub fn delete_mysql_connection(
     connections.borrow_mut().remove(conn_id);
     config.borrow_mut().delete_mysql_connection(conn_id)
 }
+
+pub fn get_mysql_env_connections() -> Vec<Connection> {
+    let mut conns = Vec::new();
+    if let Some(url) = env::var("DATABASE_URL")
+        .ok()
+        .and_then(|url| parse_database_url(&url))
+        .filter(|url| url.kind == DatabaseKind::MySQL)
+    {
+        conns.push(Connection {
+            id: Uuid::new_v4(),
+            name: String::from("DATABASE_URL"),
+            host: url.host,
+            port: url.port.unwrap_or_else(|| String::from("3306")),
+            user: url.user.unwrap_or_else(|| String::from("root")),
+            password: url.password.unwrap_or_default(),
+            password_command: None,
+            color: ConnectionColor::default(),
+            environment: None,
+            group: None,
+            add_at: Utc::now(),
+            ephemeral: true,
+        });
+    }
+    if let Ok(host) = env::var("MYSQL_HOST") {
+        conns.push(Connection {
+            id: Uuid::new_v4(),
+            name: String::from("MYSQL_HOST"),
+            host,
+            port: env::var("MYSQL_TCP_PORT").unwrap_or_else(|_| String::from("3306")),
+            user: env::var("MYSQL_USER").unwrap_or_else(|_| String::from("root")),
+            password: if env::var("MYSQL_PWD").is_ok() {
+                String::from("${MYSQL_PWD}")
+            } else {
+                String::new()
+            },
+            password_command: None,
+            color: ConnectionColor::default(),
+            environment: None,
+            group: None,
+            add_at: Utc::now(),
+            ephemeral: true,
+        });
+    }
+    conns
+}
@@ -1,5 +1,8 @@
 use crate::{
-    model::{mysql::Connections, DatabaseKind, DB},
+    model::{
+        mysql::{quote_ident, quote_literal, Connections},
+        DatabaseKind, ServerInfo, DB,
+    },
     pool::{fetch_one_mysql, get_mysql_pool, MySQLPools},
 };
 use anyhow::Result;
@@ -38,17 +41,22 @@ impl Database {
     }
     pub fn get_create_ddl(&self) -> String {
         let charset = if let Some(charset) = self.character_set.as_ref() {
-            format!(" CHARACTER SET = '{}'", charset)
+            format!(" CHARACTER SET = {}", quote_literal(charset))
         } else {
             String::new()
         };
 
         let collation = if let Some(collation) = self.collation.as_ref() {
-            format!(" COLLATE = '{}'", collation)
+            format!(" COLLATE = {}", quote_literal(collation))
         } else {
             String::new()
         };
-        format!("CREATE DATABASE {}{}{}", self.name, charset, collation)
+        format!(
+            "CREATE DATABASE {}{}{}",
+            quote_ident(&self.name),
+            charset,
+            collation
+        )
     }
     pub fn get_alter_ddl(&self, old: &Database) -> String {
         let charset = if self.character_set() != old.character_set() {
@@ -70,7 +78,12 @@ impl Database {
             String::new()
         };
         if !charset.is_empty() || !collation.is_empty() {
-            format!("ALTER DATABASE {}{}{}", self.name, charset, collation)
+            format!(
+                "ALTER DATABASE {}{}{}",
+                quote_ident(&self.name),
+                charset,
+                collation
+            )
         } else {
             String::new()
         }
@@ -91,6 +104,26 @@ pub async fn get_mysql_version(
         Ok(Version::Five)
     }
 }
+pub async fn get_mysql_server_info(
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+) -> Result<ServerInfo> {
+    let row = fetch_one_mysql(
+        conns,
+        pools,
+        conn_id,
+        db_name,
+        "SELECT VERSION(), CURRENT_USER(), DATABASE()",
+    )
+    .await?;
+    Ok(ServerInfo {
+        version: row.try_get(0).unwrap(),
+        user: row.try_get(1).unwrap(),
+        database: row.try_get(2).unwrap(),
+    })
+}
 pub async fn get_mysql_database(
     conns: Rc<RefCell<Connections>>,
     pools: Rc<RefCell<MySQLPools>>,
@@ -104,7 +137,8 @@ pub async fn get_mysql_database(
         Some("information_schema"),
     )
     .await?;
-    let db: Database = sqlx::query(format!("SELECT SCHEMA_NAME,DEFAULT_CHARACTER_SET_NAME,DEFAULT_COLLATION_NAME FROM information_schema.schemata WHERE SCHEMA_NAME='{}'", db_name).as_str())
+    let db: Database = sqlx::query("SELECT SCHEMA_NAME,DEFAULT_CHARACTER_SET_NAME,DEFAULT_COLLATION_NAME FROM information_schema.schemata WHERE SCHEMA_NAME = ?")
+        .bind(db_name)
         .map(|r: MySqlRow| {
             let name = r.try_get("SCHEMA_NAME").unwrap();
             let character_set = r.try_get("DEFAULT_CHARACTER_SET_NAME").unwrap();
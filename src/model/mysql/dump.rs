@@ -0,0 +1,146 @@
+use super::{
+    convert_show_column_to_mysql_fields, get_mysql_field_literal, get_mysql_table_names,
+    quote_ident, Trigger, TriggerAction, TriggerTime,
+};
+use crate::model::{sort_tables_by_dependency, DumpOptions};
+use anyhow::{anyhow, Result};
+use sqlx::{MySqlPool, Row};
+use uuid::Uuid;
+
+pub async fn dump_mysql_database(
+    pool: &MySqlPool,
+    db: &str,
+    options: &DumpOptions,
+) -> Result<String> {
+    let deps: Vec<(String, String)> = sqlx::query(
+        "SELECT TABLE_NAME, REFERENCED_TABLE_NAME FROM KEY_COLUMN_USAGE WHERE TABLE_SCHEMA = ? AND REFERENCED_TABLE_SCHEMA = ?",
+    )
+    .bind(db)
+    .bind(db)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| {
+        (
+            r.try_get("TABLE_NAME").unwrap(),
+            r.try_get("REFERENCED_TABLE_NAME").unwrap(),
+        )
+    })
+    .collect();
+    let mut tables = get_mysql_table_names(pool, db).await?;
+    if let Some(only) = options.tables.as_ref() {
+        tables.retain(|t| only.contains(t));
+    }
+    tables.sort();
+    let tables = sort_tables_by_dependency(tables, &deps);
+
+    let mut ddl = vec![
+        format!("-- Dump of database `{}`", db),
+        "SET FOREIGN_KEY_CHECKS = 0;".to_string(),
+        // Timestamps are read back in UTC.
+        "SET time_zone = '+00:00';".to_string(),
+    ];
+    for table in tables.iter() {
+        let create: String = sqlx::query(&format!(
+            "SHOW CREATE TABLE {}.{}",
+            quote_ident(db),
+            quote_ident(table)
+        ))
+        .fetch_one(pool)
+        .await?
+        .try_get(1)?;
+        ddl.push(format!("\n-- Table `{}`\n{};", table, create));
+        if options.with_data {
+            let fields = convert_show_column_to_mysql_fields(
+                sqlx::query(&format!(
+                    "SHOW FULL COLUMNS FROM {}.{}",
+                    quote_ident(db),
+                    quote_ident(table)
+                ))
+                .fetch_all(pool)
+                .await?,
+            );
+            let rows = sqlx::query(&format!(
+                "SELECT * FROM {}.{}",
+                quote_ident(db),
+                quote_ident(table)
+            ))
+            .fetch_all(pool)
+            .await?;
+            for (i, row) in rows.iter().enumerate() {
+                let values: Vec<String> = fields
+                    .iter()
+                    .map(|field| {
+                        let value = get_mysql_field_literal(field, row).map_err(|e| {
+                            anyhow!(
+                                "Column {} of row {} in table {} could not be decoded: {}",
+                                field.name(),
+                                i + 1,
+                                table,
+                                e
+                            )
+                        })?;
+                        Ok(value.unwrap_or_else(|| "NULL".to_string()))
+                    })
+                    .collect::<Result<Vec<String>>>()?;
+                ddl.push(format!(
+                    "INSERT INTO {} VALUES ({});",
+                    quote_ident(table),
+                    values.join(", ")
+                ));
+            }
+        }
+    }
+    if options.views {
+        let views: Vec<String> =
+            sqlx::query("SELECT TABLE_NAME FROM VIEWS WHERE TABLE_SCHEMA = ? ORDER BY TABLE_NAME")
+                .bind(db)
+                .fetch_all(pool)
+                .await?
+                .iter()
+                .map(|r| r.try_get("TABLE_NAME").unwrap())
+                .collect();
+        for view in views.iter() {
+            let create: String = sqlx::query(&format!(
+                "SHOW CREATE VIEW {}.{}",
+                quote_ident(db),
+                quote_ident(view)
+            ))
+            .fetch_one(pool)
+            .await?
+            .try_get(1)?;
+            ddl.push(format!("\n-- View `{}`\n{};", view, create));
+        }
+    }
+    if options.triggers {
+        let triggers = sqlx::query(&format!("SHOW TRIGGERS FROM {}", quote_ident(db)))
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .filter(|row| tables.contains(&row.try_get::<String, _>("Table").unwrap()))
+            .collect::<Vec<_>>();
+        if !triggers.is_empty() {
+            ddl.push("\nDELIMITER ;;".to_string());
+            triggers.iter().for_each(|row| {
+                let table: String = row.try_get("Table").unwrap();
+                let trigger = Trigger {
+                    id: Uuid::new_v4(),
+                    name: row.try_get("Trigger").unwrap(),
+                    time: TriggerTime::try_from(
+                        row.try_get::<String, _>("Timing").unwrap().as_str(),
+                    )
+                    .unwrap(),
+                    action: TriggerAction::try_from(
+                        row.try_get::<String, _>("Event").unwrap().as_str(),
+                    )
+                    .unwrap(),
+                    statement: row.try_get("Statement").unwrap(),
+                };
+                ddl.push(format!("{};", trigger.get_create_ddl(&table)));
+            });
+            ddl.push("DELIMITER ;".to_string());
+        }
+    }
+    ddl.push("\nSET FOREIGN_KEY_CHECKS = 1;".to_string());
+    Ok(ddl.join("\n"))
+}
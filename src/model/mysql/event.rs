@@ -0,0 +1,288 @@
+use super::{quote_ident, quote_literal};
+use anyhow::{Error, Result};
+use sqlx::{MySqlPool, Row};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+
+#[derive(EnumString, EnumIter, Display, IntoStaticStr, Clone, PartialEq)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum EventSchedule {
+    At,
+    Every,
+}
+
+#[derive(EnumString, EnumIter, Display, IntoStaticStr, Clone, PartialEq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventIntervalUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    YearMonth,
+    DayHour,
+    DayMinute,
+    DaySecond,
+    HourMinute,
+    HourSecond,
+    MinuteSecond,
+}
+
+impl EventIntervalUnit {
+    // Compound units take a quoted value such as '1:30' for MINUTE_SECOND.
+    pub fn is_compound(&self) -> bool {
+        matches!(
+            self,
+            EventIntervalUnit::YearMonth
+                | EventIntervalUnit::DayHour
+                | EventIntervalUnit::DayMinute
+                | EventIntervalUnit::DaySecond
+                | EventIntervalUnit::HourMinute
+                | EventIntervalUnit::HourSecond
+                | EventIntervalUnit::MinuteSecond
+        )
+    }
+}
+
+#[derive(EnumString, EnumIter, Display, IntoStaticStr, Clone, PartialEq)]
+pub enum EventStatus {
+    #[strum(serialize = "ENABLE")]
+    Enable,
+    #[strum(serialize = "DISABLE")]
+    Disable,
+    #[strum(serialize = "DISABLE ON SLAVE")]
+    DisableOnSlave,
+}
+
+#[derive(Clone)]
+pub struct Event {
+    pub name: String,
+    pub definer: Option<String>,
+    pub schedule: EventSchedule,
+    pub execute_at: Option<String>,
+    pub interval_value: Option<String>,
+    pub interval_unit: Option<EventIntervalUnit>,
+    pub starts: Option<String>,
+    pub ends: Option<String>,
+    pub preserve: bool,
+    pub status: EventStatus,
+    pub comment: Option<String>,
+    pub body: String,
+}
+
+impl Event {
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+    pub fn schedule(&self) -> &str {
+        self.schedule.clone().into()
+    }
+    pub fn status(&self) -> &str {
+        self.status.clone().into()
+    }
+    pub fn interval_unit(&self) -> Option<&str> {
+        self.interval_unit.clone().map(|u| u.into())
+    }
+    pub fn get_schedule_str(&self) -> String {
+        match self.schedule {
+            EventSchedule::At => {
+                format!(
+                    "AT {}",
+                    quote_literal(self.execute_at.as_deref().unwrap_or_default())
+                )
+            }
+            EventSchedule::Every => {
+                let value = self.interval_value.as_deref().unwrap_or_default();
+                let mut schedule = format!(
+                    "EVERY {} {}",
+                    if self
+                        .interval_unit
+                        .as_ref()
+                        .map_or(false, |u| u.is_compound())
+                    {
+                        quote_literal(value)
+                    } else {
+                        value.to_string()
+                    },
+                    self.interval_unit().unwrap_or_default()
+                );
+                if let Some(starts) = self.starts.as_ref() {
+                    schedule.push_str(&format!(" STARTS {}", quote_literal(starts)));
+                }
+                if let Some(ends) = self.ends.as_ref() {
+                    schedule.push_str(&format!(" ENDS {}", quote_literal(ends)));
+                }
+                schedule
+            }
+        }
+    }
+    fn get_completion_str(&self) -> &str {
+        if self.preserve {
+            "ON COMPLETION PRESERVE"
+        } else {
+            "ON COMPLETION NOT PRESERVE"
+        }
+    }
+    pub fn get_create_ddl(&self) -> String {
+        let mut ddl = format!(
+            "CREATE EVENT {} ON SCHEDULE {} {} {}",
+            quote_ident(&self.name),
+            self.get_schedule_str(),
+            self.get_completion_str(),
+            self.status
+        );
+        if let Some(comment) = self.comment.as_ref() {
+            ddl.push_str(&format!(" COMMENT {}", quote_literal(comment)));
+        }
+        ddl.push_str(&format!(" DO {}", self.body));
+        ddl
+    }
+    pub fn get_drop_ddl(&self) -> String {
+        format!("DROP EVENT IF EXISTS {}", quote_ident(&self.name))
+    }
+    pub fn get_alter_ddl(&self, old_event: &Event) -> String {
+        let mut clauses = Vec::new();
+        if self.get_schedule_str() != old_event.get_schedule_str() {
+            clauses.push(format!("ON SCHEDULE {}", self.get_schedule_str()));
+        }
+        if self.preserve != old_event.preserve {
+            clauses.push(self.get_completion_str().to_string());
+        }
+        if self.name != old_event.name {
+            clauses.push(format!("RENAME TO {}", quote_ident(&self.name)));
+        }
+        if self.status != old_event.status {
+            clauses.push(self.status.to_string());
+        }
+        if self.comment != old_event.comment {
+            clauses.push(format!(
+                "COMMENT {}",
+                quote_literal(self.comment.as_deref().unwrap_or_default())
+            ));
+        }
+        if self.body != old_event.body {
+            clauses.push(format!("DO {}", self.body));
+        }
+        if clauses.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "ALTER EVENT {} {}",
+                quote_ident(&old_event.name),
+                clauses.join(" ")
+            )
+        }
+    }
+    pub fn validate(&self) -> Result<()> {
+        match self.schedule {
+            EventSchedule::At => {
+                if self.execute_at.is_none() {
+                    return Err(Error::msg("Please input execute at"));
+                }
+            }
+            EventSchedule::Every => {
+                let value = match self.interval_value.as_deref() {
+                    Some(value) => value,
+                    None => return Err(Error::msg("Please input interval value")),
+                };
+                let unit = match self.interval_unit.as_ref() {
+                    Some(unit) => unit,
+                    None => return Err(Error::msg("Please select interval unit")),
+                };
+                if !unit.is_compound()
+                    && (value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()))
+                {
+                    return Err(Error::msg("Interval value must be a number"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub async fn get_mysql_events(pool: &MySqlPool, db: &str) -> Result<Vec<Event>> {
+    let events: Vec<Event> = sqlx::query(
+        "SELECT EVENT_NAME, DEFINER, EVENT_TYPE, CAST(EXECUTE_AT AS CHAR) AS EXECUTE_AT, INTERVAL_VALUE, INTERVAL_FIELD, CAST(STARTS AS CHAR) AS STARTS, CAST(ENDS AS CHAR) AS ENDS, ON_COMPLETION, STATUS, EVENT_COMMENT, EVENT_DEFINITION FROM EVENTS WHERE EVENT_SCHEMA = ? ORDER BY EVENT_NAME",
+    )
+    .bind(db)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| {
+        let event_type: String = r.try_get("EVENT_TYPE").unwrap();
+        let status: String = r.try_get("STATUS").unwrap();
+        let on_completion: String = r.try_get("ON_COMPLETION").unwrap();
+        let comment: String = r.try_get("EVENT_COMMENT").unwrap();
+        Event {
+            name: r.try_get("EVENT_NAME").unwrap(),
+            definer: r.try_get("DEFINER").unwrap(),
+            schedule: if event_type == "ONE TIME" {
+                EventSchedule::At
+            } else {
+                EventSchedule::Every
+            },
+            execute_at: r.try_get("EXECUTE_AT").unwrap(),
+            interval_value: r.try_get("INTERVAL_VALUE").unwrap(),
+            interval_unit: r
+                .try_get::<Option<String>, _>("INTERVAL_FIELD")
+                .unwrap()
+                .and_then(|f| EventIntervalUnit::try_from(f.as_str()).ok()),
+            starts: r.try_get("STARTS").unwrap(),
+            ends: r.try_get("ENDS").unwrap(),
+            preserve: on_completion == "PRESERVE",
+            status: match status.as_str() {
+                "ENABLED" => EventStatus::Enable,
+                "DISABLED" => EventStatus::Disable,
+                _ => EventStatus::DisableOnSlave,
+            },
+            comment: if comment.is_empty() {
+                None
+            } else {
+                Some(comment)
+            },
+            body: r.try_get("EVENT_DEFINITION").unwrap(),
+        }
+    })
+    .collect();
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn every(value: &str, unit: EventIntervalUnit) -> Event {
+        Event {
+            name: String::from("cleanup"),
+            definer: None,
+            schedule: EventSchedule::Every,
+            execute_at: None,
+            interval_value: Some(value.to_string()),
+            interval_unit: Some(unit),
+            starts: None,
+            ends: None,
+            preserve: false,
+            status: EventStatus::Enable,
+            comment: None,
+            body: String::from("DELETE FROM logs"),
+        }
+    }
+
+    #[test]
+    fn test_every_interval_value() {
+        assert_eq!(
+            every("5", EventIntervalUnit::Minute).get_schedule_str(),
+            "EVERY 5 MINUTE"
+        );
+        assert_eq!(
+            every("1:30", EventIntervalUnit::MinuteSecond).get_schedule_str(),
+            "EVERY '1:30' MINUTE_SECOND"
+        );
+        assert!(every("5", EventIntervalUnit::Minute).validate().is_ok());
+        assert!(every("1 DAY; DROP TABLE t", EventIntervalUnit::Day)
+            .validate()
+            .is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::{comment, default_value, length, not_null};
+use super::{comment, default_value, invisible, length, not_null, quote_ident};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,8 +8,10 @@ pub struct BinaryField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: String,
     pub default_value: Option<String>,
+    pub default_expression: bool,
 }
 
 impl BinaryField {
@@ -28,7 +30,9 @@ impl BinaryField {
             key,
             length: length.to_string(),
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
         }
     }
     pub fn id(&self) -> &Uuid {
@@ -43,6 +47,9 @@ impl BinaryField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn not_null(&self) -> bool {
         self.not_null
     }
@@ -55,27 +62,33 @@ impl BinaryField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             length(Some(self.length())),
             not_null(self.not_null),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), false, self.default_expression()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &BinaryField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
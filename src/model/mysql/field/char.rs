@@ -1,4 +1,7 @@
-use super::{character_set, collation, comment, default_value, length, not_null};
+use super::{
+    character_set, collation, comment, default_value, get_charset_from_collation, invisible,
+    length, not_null, quote_ident,
+};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -8,8 +11,10 @@ pub struct CharField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub character_set: Option<String>,
     pub collation: Option<String>,
 }
@@ -23,8 +28,7 @@ impl CharField {
         default_value: Option<&str>,
         collation: &str,
     ) -> Self {
-        let underline_index = collation.find('_').unwrap();
-        let charset = &collation[..underline_index];
+        let charset = get_charset_from_collation(collation);
         CharField {
             id: Uuid::new_v4(),
             name: name.to_string(),
@@ -32,7 +36,9 @@ impl CharField {
             key,
             length: Some(length.to_string()),
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
             character_set: Some(charset.to_string()),
             collation: Some(collation.to_string()),
         }
@@ -46,6 +52,9 @@ impl CharField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -67,31 +76,37 @@ impl CharField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             length(self.length()),
             character_set(self.character_set()),
             collation(self.collation()),
             not_null(self.not_null),
-            default_value(self.default_value(), true),
-            comment(self.comment()),
+            default_value(self.default_value(), true, self.default_expression()),
+            invisible(self.invisible()),
+            comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &CharField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.character_set != self.character_set
             || old.collation != self.collation
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
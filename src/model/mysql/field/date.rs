@@ -1,4 +1,4 @@
-use super::{comment, default_value, not_null};
+use super::{comment, default_value, invisible, not_null, quote_ident};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,7 +8,9 @@ pub struct DateField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub default_value: Option<String>,
+    pub default_expression: bool,
 }
 impl DateField {
     pub fn new(
@@ -24,7 +26,9 @@ impl DateField {
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
         }
     }
     pub fn id(&self) -> &Uuid {
@@ -36,6 +40,9 @@ impl DateField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -48,25 +55,31 @@ impl DateField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             not_null(self.not_null()),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), true, self.default_expression()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &DateField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
@@ -1,4 +1,4 @@
-use super::{comment, default_value, length, not_null, on_update};
+use super::{comment, default_value, invisible, length, not_null, on_update, quote_ident};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,8 +8,10 @@ pub struct DateTimeField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub on_update: bool,
 }
 impl DateTimeField {
@@ -28,8 +30,10 @@ impl DateTimeField {
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             length: length.map(|s| s.to_string()),
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
             on_update: extra.contains("on update"),
         }
     }
@@ -42,6 +46,9 @@ impl DateTimeField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -64,29 +71,35 @@ impl DateTimeField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             length(self.length()),
             not_null(self.not_null()),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), true, self.default_expression()),
             on_update(self.on_update(), self.length()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &DateTimeField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.on_update != self.on_update
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
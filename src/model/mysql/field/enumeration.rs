@@ -1,4 +1,7 @@
-use super::{character_set, collation, comment, default_value, not_null};
+use super::{
+    character_set, collation, comment, default_value, get_charset_from_collation, invisible,
+    not_null, quote_ident, quote_literal,
+};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,8 +11,10 @@ pub struct EnumField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub options: Vec<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub character_set: Option<String>,
     pub collation: Option<String>,
 }
@@ -24,8 +29,7 @@ impl EnumField {
         comment: Option<&str>,
         default_value: Option<&str>,
     ) -> Self {
-        let underline_index = collation.find('_').unwrap();
-        let charset = &collation[..underline_index];
+        let charset = get_charset_from_collation(collation);
 
         EnumField {
             id: Uuid::new_v4(),
@@ -33,8 +37,10 @@ impl EnumField {
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             options: options.split(',').map(|s| s.replace('\'', "")).collect(),
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
             character_set: Some(charset.to_string()),
             collation: Some(collation.to_string()),
         }
@@ -48,6 +54,9 @@ impl EnumField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -69,35 +78,41 @@ impl EnumField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}({}){}{}{}{}{}",
-            self.name,
+            "{} {}({}){}{}{}{}{}{}",
+            quote_ident(&self.name),
             kind,
             self.options
                 .iter()
-                .map(|s| format!("'{}'", s))
+                .map(|s| quote_literal(s))
                 .collect::<Vec<String>>()
                 .join(","),
             character_set(self.character_set()),
             collation(self.collation()),
             not_null(self.not_null()),
-            default_value(self.default_value(), true),
+            default_value(self.default_value(), true, self.default_expression()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &EnumField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.options != self.options
             || old.character_set != self.character_set
             || old.collation != self.collation
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
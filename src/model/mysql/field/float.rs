@@ -1,4 +1,7 @@
-use super::{auto_increment, comment, default_value, length_decimal, not_null, unsigned, zerofill};
+use super::{
+    auto_increment, comment, default_value, invisible, length_decimal, not_null, quote_ident,
+    unsigned, zerofill,
+};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,9 +11,11 @@ pub struct FloatField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: Option<String>,
     pub decimal: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub auto_increment: bool,
     pub unsigned: bool,
     pub zerofill: bool,
@@ -37,7 +42,9 @@ impl FloatField {
             length: length.map(|l| l.to_string()),
             decimal: decimal.map(|d| d.to_string()),
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
             auto_increment: extra.contains("auto_increment"),
             unsigned,
             zerofill,
@@ -52,6 +59,9 @@ impl FloatField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -83,23 +93,28 @@ impl FloatField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}{}{}{}",
-            self.name,
+            "{} {}{}{}{}{}{}{}{}{}",
+            quote_ident(&self.name),
             kind,
             length_decimal(self.length(), self.decimal()),
             unsigned(self.unsigned()),
             zerofill(self.zerofill()),
             not_null(self.not_null()),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), false, self.default_expression()),
             auto_increment(self.auto_increment()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &FloatField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.decimal != self.decimal
@@ -107,10 +122,11 @@ impl FloatField {
             || old.unsigned != self.unsigned
             || old.zerofill != self.zerofill
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
@@ -1,4 +1,7 @@
-use super::{auto_increment, comment, default_value, length, not_null, unsigned, zerofill};
+use super::{
+    auto_increment, comment, default_value, invisible, length, not_null, quote_ident, unsigned,
+    zerofill,
+};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,8 +11,10 @@ pub struct IntField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub auto_increment: bool,
     pub unsigned: bool,
     pub zerofill: bool,
@@ -33,8 +38,10 @@ impl IntField {
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             length: length.map(|s| s.to_string()),
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
             auto_increment: extra.contains("auto_increment"),
             unsigned,
             zerofill,
@@ -55,9 +62,15 @@ impl IntField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn length(&self) -> Option<&str> {
         self.length.as_deref()
     }
@@ -70,6 +83,9 @@ impl IntField {
     pub fn zerofill(&self) -> bool {
         self.zerofill
     }
+    pub fn is_bool(&self) -> bool {
+        self.length.as_deref() == Some("1") && !self.zerofill
+    }
     pub fn extra(&self) -> Option<&str> {
         if self.auto_increment {
             Some("AUTO_INCREMENT")
@@ -79,31 +95,34 @@ impl IntField {
     }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             length(self.length()),
             unsigned(self.unsigned()),
             zerofill(self.zerofill()),
             not_null(self.not_null()),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), false, self.default_expression()),
             auto_increment(self.auto_increment()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &IntField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.auto_increment != self.auto_increment
             || old.unsigned != self.unsigned
             || old.zerofill != self.zerofill
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
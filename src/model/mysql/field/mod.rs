@@ -1,5 +1,7 @@
-use anyhow::Result;
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use super::{geometry_to_wkt, quote_ident, quote_literal};
+use crate::model::{format_decimal, DisplayFormat};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use regex::Regex;
 use sqlx::{
     mysql::{MySqlColumn, MySqlRow},
@@ -472,6 +474,36 @@ impl Field {
             Field::Year(d) => d.extra(),
         }
     }
+    pub fn auto_increment(&self) -> bool {
+        match self {
+            Field::BigInt(i)
+            | Field::Int(i)
+            | Field::Integer(i)
+            | Field::MediumInt(i)
+            | Field::SmallInt(i)
+            | Field::TinyInt(i) => i.auto_increment(),
+            Field::Double(f) | Field::Float(f) | Field::Real(f) => f.auto_increment(),
+            _ => false,
+        }
+    }
+    // Floats, JSON and geometry have no exact equality, so rows can't be matched on them.
+    pub fn is_comparable(&self) -> bool {
+        !matches!(
+            self,
+            Field::Float(_)
+                | Field::Double(_)
+                | Field::Real(_)
+                | Field::Json(_)
+                | Field::Geometry(_)
+                | Field::GeometryCollection(_)
+                | Field::LineString(_)
+                | Field::MultiLineString(_)
+                | Field::MultiPoint(_)
+                | Field::MultiPolygon(_)
+                | Field::Point(_)
+                | Field::Polygon(_)
+        )
+    }
     pub fn comment(&self) -> Option<&str> {
         match self {
             Field::BigInt(i) => i.comment(),
@@ -516,6 +548,107 @@ impl Field {
             Field::Year(d) => d.comment(),
         }
     }
+    pub fn collation(&self) -> Option<&str> {
+        match self {
+            Field::Char(c) | Field::VarChar(c) => c.collation(),
+            Field::LongText(t) | Field::MediumText(t) | Field::Text(t) | Field::TinyText(t) => {
+                t.collation()
+            }
+            Field::Enum(e) | Field::Set(e) => e.collation(),
+            _ => None,
+        }
+    }
+    pub fn invisible(&self) -> bool {
+        match self {
+            Field::BigInt(i)
+            | Field::Int(i)
+            | Field::Integer(i)
+            | Field::MediumInt(i)
+            | Field::SmallInt(i)
+            | Field::TinyInt(i) => i.invisible(),
+            Field::Binary(b) | Field::Bit(b) | Field::VarBinary(b) => b.invisible(),
+            Field::Char(c) | Field::VarChar(c) => c.invisible(),
+            Field::Date(d) | Field::Year(d) => d.invisible(),
+            Field::DateTime(d) | Field::Timestamp(d) => d.invisible(),
+            Field::Decimal(d) | Field::Numeric(d) => d.invisible(),
+            Field::Double(f) | Field::Float(f) | Field::Real(f) => f.invisible(),
+            Field::Enum(e) | Field::Set(e) => e.invisible(),
+            Field::LongText(t) | Field::MediumText(t) | Field::Text(t) | Field::TinyText(t) => {
+                t.invisible()
+            }
+            Field::Time(t) => t.invisible(),
+            Field::Blob(s)
+            | Field::Geometry(s)
+            | Field::GeometryCollection(s)
+            | Field::Json(s)
+            | Field::LineString(s)
+            | Field::LongBlob(s)
+            | Field::MediumBlob(s)
+            | Field::MultiLineString(s)
+            | Field::MultiPoint(s)
+            | Field::MultiPolygon(s)
+            | Field::Point(s)
+            | Field::Polygon(s)
+            | Field::TinyBlob(s) => s.invisible(),
+        }
+    }
+    pub fn set_default_expression(&mut self, default_expression: bool) {
+        match self {
+            Field::BigInt(i)
+            | Field::Int(i)
+            | Field::Integer(i)
+            | Field::MediumInt(i)
+            | Field::SmallInt(i)
+            | Field::TinyInt(i) => i.default_expression = default_expression,
+            Field::Binary(b) | Field::Bit(b) | Field::VarBinary(b) => {
+                b.default_expression = default_expression
+            }
+            Field::Char(c) | Field::VarChar(c) => c.default_expression = default_expression,
+            Field::Date(d) | Field::Year(d) => d.default_expression = default_expression,
+            Field::DateTime(d) | Field::Timestamp(d) => d.default_expression = default_expression,
+            Field::Decimal(d) | Field::Numeric(d) => d.default_expression = default_expression,
+            Field::Double(f) | Field::Float(f) | Field::Real(f) => {
+                f.default_expression = default_expression
+            }
+            Field::Enum(e) | Field::Set(e) => e.default_expression = default_expression,
+            Field::Time(t) => t.default_expression = default_expression,
+            _ => (),
+        }
+    }
+    pub fn set_invisible(&mut self, invisible: bool) {
+        match self {
+            Field::BigInt(i)
+            | Field::Int(i)
+            | Field::Integer(i)
+            | Field::MediumInt(i)
+            | Field::SmallInt(i)
+            | Field::TinyInt(i) => i.invisible = invisible,
+            Field::Binary(b) | Field::Bit(b) | Field::VarBinary(b) => b.invisible = invisible,
+            Field::Char(c) | Field::VarChar(c) => c.invisible = invisible,
+            Field::Date(d) | Field::Year(d) => d.invisible = invisible,
+            Field::DateTime(d) | Field::Timestamp(d) => d.invisible = invisible,
+            Field::Decimal(d) | Field::Numeric(d) => d.invisible = invisible,
+            Field::Double(f) | Field::Float(f) | Field::Real(f) => f.invisible = invisible,
+            Field::Enum(e) | Field::Set(e) => e.invisible = invisible,
+            Field::LongText(t) | Field::MediumText(t) | Field::Text(t) | Field::TinyText(t) => {
+                t.invisible = invisible
+            }
+            Field::Time(t) => t.invisible = invisible,
+            Field::Blob(s)
+            | Field::Geometry(s)
+            | Field::GeometryCollection(s)
+            | Field::Json(s)
+            | Field::LineString(s)
+            | Field::LongBlob(s)
+            | Field::MediumBlob(s)
+            | Field::MultiLineString(s)
+            | Field::MultiPoint(s)
+            | Field::MultiPolygon(s)
+            | Field::Point(s)
+            | Field::Polygon(s)
+            | Field::TinyBlob(s) => s.invisible = invisible,
+        }
+    }
     pub fn get_create_str(&self) -> String {
         match self {
             Field::BigInt(i) => i.get_create_str(FieldKind::BigInt.to_string()),
@@ -848,18 +981,53 @@ impl Field {
         format!("ADD {}", self.get_create_str())
     }
     pub fn get_drop_str(&self) -> String {
-        format!("DROP COLUMN `{}`", self.name())
+        format!("DROP COLUMN {}", quote_ident(self.name()))
     }
 }
 
 pub async fn get_mysql_field_names(pool: &MySqlPool, table: &str) -> Result<Vec<String>> {
-    let fields: Vec<String> = sqlx::query(format!("show columns from {}", table).as_str())
+    let fields: Vec<String> =
+        sqlx::query(format!("show columns from {}", quote_ident(table)).as_str())
+            .fetch_all(pool)
+            .await?
+            .iter()
+            .map(|t| t.try_get("Field").unwrap())
+            .collect();
+    Ok(fields)
+}
+
+pub async fn get_mysql_fields(pool: &MySqlPool, table: &str) -> Result<Vec<Field>> {
+    let fields = sqlx::query(format!("SHOW FULL COLUMNS FROM {}", quote_ident(table)).as_str())
         .fetch_all(pool)
-        .await?
+        .await?;
+    Ok(convert_show_column_to_mysql_fields(fields))
+}
+pub fn get_mysql_fields_compare_ddl(table: &str, source: &[Field], target: &[Field]) -> String {
+    let mut ddl = Vec::new();
+    source.iter().for_each(|field| {
+        if let Some(old) = target.iter().find(|f| f.name() == field.name()) {
+            if old.kind_str() != field.kind_str() {
+                ddl.push(format!(
+                    "CHANGE COLUMN {} {}",
+                    quote_ident(old.name()),
+                    field.get_create_str()
+                ));
+            } else if let Some(str) = field.get_change_str(old) {
+                ddl.push(str);
+            }
+        } else {
+            ddl.push(field.get_add_str());
+        }
+    });
+    target
         .iter()
-        .map(|t| t.try_get("Field").unwrap())
-        .collect();
-    Ok(fields)
+        .filter(|f| !source.iter().any(|s| s.name() == f.name()))
+        .for_each(|f| ddl.push(f.get_drop_str()));
+    if ddl.is_empty() {
+        String::new()
+    } else {
+        format!("ALTER TABLE {}\n{};", quote_ident(table), ddl.join(",\n"))
+    }
 }
 
 pub fn unsigned(unsigned: bool) -> String {
@@ -884,11 +1052,17 @@ pub fn zerofill(zerofill: bool) -> String {
     )
 }
 
-pub fn default_value(default_value: Option<&str>, quote: bool) -> String {
+pub fn default_value(default_value: Option<&str>, quote: bool, expression: bool) -> String {
     if let Some(d) = default_value {
         if !d.is_empty() {
-            if quote {
-                format!(" DEFAULT '{}'", d)
+            if expression {
+                if d.starts_with('(') || is_current_timestamp(d) {
+                    format!(" DEFAULT {}", d)
+                } else {
+                    format!(" DEFAULT ({})", d)
+                }
+            } else if quote {
+                format!(" DEFAULT {}", quote_literal(d))
             } else {
                 format!(" DEFAULT {}", d)
             }
@@ -900,6 +1074,11 @@ pub fn default_value(default_value: Option<&str>, quote: bool) -> String {
     }
 }
 
+fn is_current_timestamp(value: &str) -> bool {
+    Regex::new(r"(?i)^(current_timestamp|now|localtime|localtimestamp)(\(\d*\))?$")
+        .unwrap()
+        .is_match(value)
+}
 pub fn length(length: Option<&str>) -> String {
     let l = length.unwrap();
     if !l.is_empty() {
@@ -947,6 +1126,10 @@ pub fn collation(collation: Option<&str>) -> String {
     }
 }
 
+pub fn get_charset_from_collation(collation: &str) -> &str {
+    collation.split('_').next().unwrap_or(collation)
+}
+
 pub fn not_null(not_null: bool) -> String {
     format!("{}", if not_null { " NOT NULL" } else { " NULL" })
 }
@@ -975,26 +1158,46 @@ pub fn on_update(on_update: bool, length: Option<&str>) -> String {
     }
 }
 
+pub fn invisible(invisible: bool) -> String {
+    if invisible {
+        String::from(" /*!80023 INVISIBLE */")
+    } else {
+        String::from("")
+    }
+}
+
 pub fn comment(comment: Option<&str>) -> String {
     let c = comment.unwrap();
     if !c.is_empty() {
-        format!(" COMMENT '{}'", c)
+        format!(" COMMENT {}", quote_literal(c))
     } else {
         String::from("")
     }
 }
 
-pub fn get_mysql_field_value(field: &Field, row: &MySqlRow) -> Option<String> {
+pub const DECODE_ERROR: &str = "<decode error>";
+
+pub fn get_mysql_field_value(
+    field: &Field,
+    row: &MySqlRow,
+    display_format: &DisplayFormat,
+) -> Result<Option<String>> {
     let col_name = field.name();
 
-    fn get_value<'r, U>(name: &str, row: &'r MySqlRow) -> Option<String>
+    fn get_value<'r, U>(name: &str, row: &'r MySqlRow) -> Result<Option<String>>
     where
         U: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
     {
-        let i: Option<U> = row.try_get(name).unwrap();
-        i.map(|i| i.to_string())
+        match row.try_get::<Option<U>, _>(name) {
+            Ok(i) => Ok(i.map(|i| i.to_string())),
+            Err(_) => get_raw_value(name, row),
+        }
     }
-    fn get_numeric<'r, I, U>(name: &str, is_unsigned: bool, row: &'r MySqlRow) -> Option<String>
+    fn get_numeric<'r, I, U>(
+        name: &str,
+        is_unsigned: bool,
+        row: &'r MySqlRow,
+    ) -> Result<Option<String>>
     where
         I: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
         U: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
@@ -1013,10 +1216,23 @@ pub fn get_mysql_field_value(field: &Field, row: &MySqlRow) -> Option<String> {
         | Field::Blob(_)
         | Field::TinyBlob(_)
         | Field::MediumBlob(_)
-        | Field::LongBlob(_) => {
-            let i: Option<Vec<u8>> = row.try_get(col_name).unwrap();
-            i.map(|i| String::from_utf8(i).unwrap_or_default())
-        }
+        | Field::LongBlob(_) => get_raw_value(col_name, row),
+        Field::Bit(_) => get_bit_value(col_name, row),
+        Field::Geometry(_)
+        | Field::GeometryCollection(_)
+        | Field::LineString(_)
+        | Field::MultiLineString(_)
+        | Field::MultiPoint(_)
+        | Field::MultiPolygon(_)
+        | Field::Point(_)
+        | Field::Polygon(_) => get_geometry_value(col_name, row),
+        Field::TinyInt(field) if display_format.tinyint_as_bool && field.is_bool() => Ok(
+            get_numeric::<i8, u8>(col_name, field.unsigned, row)?.map(|v| match v.as_str() {
+                "0" => String::from("false"),
+                "1" => String::from("true"),
+                _ => v,
+            }),
+        ),
         Field::TinyInt(field) => get_numeric::<i8, u8>(col_name, field.unsigned, row),
         Field::SmallInt(field) => get_numeric::<i16, u16>(col_name, field.unsigned, row),
         Field::MediumInt(field) => get_numeric::<i32, u32>(col_name, field.unsigned, row),
@@ -1024,7 +1240,14 @@ pub fn get_mysql_field_value(field: &Field, row: &MySqlRow) -> Option<String> {
             get_numeric::<i32, u32>(col_name, field.unsigned, row)
         }
         Field::BigInt(field) => get_numeric::<i64, u64>(col_name, field.unsigned, row),
-        Field::Numeric(_) | Field::Decimal(_) => get_value::<BigDecimal>(col_name, row),
+        Field::Numeric(field) | Field::Decimal(field) => {
+            match row.try_get::<Option<BigDecimal>, _>(col_name) {
+                Ok(d) => Ok(d.map(|d| {
+                    format_decimal(&d, field.decimal().and_then(|s| s.parse::<i64>().ok()))
+                })),
+                Err(_) => get_raw_value(col_name, row),
+            }
+        }
         Field::Float(_) => get_value::<f32>(col_name, row),
         Field::Double(_) => get_value::<f64>(col_name, row),
         Field::Real(_) => get_value::<f64>(col_name, row),
@@ -1035,35 +1258,223 @@ pub fn get_mysql_field_value(field: &Field, row: &MySqlRow) -> Option<String> {
             get_value::<String>(col_name, row)
         }
         Field::Year(_) => get_value::<u16>(col_name, row),
-        Field::Date(_) => {
-            let d: Option<NaiveDate> = row.try_get(field.name()).unwrap();
-            d.map(|d| d.format("%Y-%m-%d").to_string())
+        Field::Date(_) => match row.try_get::<Option<NaiveDate>, _>(field.name()) {
+            Ok(d) => Ok(d.map(|d| display_format.format_date(&d))),
+            Err(_) => get_temporal_value(field.name(), row, "0000-00-00"),
+        },
+        Field::Time(_) => match row.try_get::<Option<NaiveTime>, _>(field.name()) {
+            Ok(d) => Ok(d.map(|d| display_format.format_time(&d))),
+            Err(_) => get_temporal_value(field.name(), row, "00:00:00"),
+        },
+        Field::DateTime(field) => match row.try_get::<Option<NaiveDateTime>, _>(field.name()) {
+            Ok(d) => Ok(d.map(|d| display_format.format_naive_datetime(&d))),
+            Err(_) => get_temporal_value(field.name(), row, "0000-00-00 00:00:00"),
+        },
+        Field::Timestamp(field) => match row.try_get::<Option<DateTime<Utc>>, _>(field.name()) {
+            Ok(d) => Ok(d.map(|d| display_format.format_datetime(&d))),
+            Err(_) => get_temporal_value(field.name(), row, "0000-00-00 00:00:00"),
+        },
+    }
+}
+
+// Stored value as MySQL reads it back in a literal, at full precision and without display
+// formatting. Binary, bit and geometry values have no such text form and are rejected.
+pub fn get_mysql_field_text(field: &Field, row: &MySqlRow) -> Result<Option<String>> {
+    let col_name = field.name();
+
+    fn get_text<'r, U>(name: &str, row: &'r MySqlRow) -> Result<Option<String>>
+    where
+        U: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
+    {
+        Ok(row.try_get::<Option<U>, _>(name)?.map(|i| i.to_string()))
+    }
+    fn get_numeric<'r, I, U>(
+        name: &str,
+        is_unsigned: bool,
+        row: &'r MySqlRow,
+    ) -> Result<Option<String>>
+    where
+        I: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
+        U: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
+    {
+        if is_unsigned {
+            get_text::<U>(name, row)
+        } else {
+            get_text::<I>(name, row)
         }
+    }
+    fn get_temporal<T>(
+        name: &str,
+        row: &MySqlRow,
+        zero: &str,
+        format: impl Fn(&T) -> String,
+    ) -> Result<Option<String>>
+    where
+        T: for<'r> sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
+    {
+        if let Ok(d) = row.try_get::<Option<T>, _>(name) {
+            return Ok(d.map(|d| format(&d)));
+        }
+        let bytes: Option<Vec<u8>> = row.try_get_unchecked(name)?;
+        match bytes {
+            None => Ok(None),
+            Some(b) if b.first().map_or(false, |c| c.is_ascii_digit()) => {
+                Ok(Some(String::from_utf8_lossy(&b).to_string()))
+            }
+            Some(b) if b.len() <= 1 => Ok(Some(zero.to_string())),
+            Some(_) => Err(anyhow!("Column {} could not be decoded", name)),
+        }
+    }
+
+    match field {
+        Field::VarChar(_)
+        | Field::Char(_)
+        | Field::Enum(_)
+        | Field::Set(_)
+        | Field::Text(_)
+        | Field::TinyText(_)
+        | Field::MediumText(_)
+        | Field::LongText(_) => get_text::<String>(col_name, row),
+        Field::TinyInt(field) => get_numeric::<i8, u8>(col_name, field.unsigned, row),
+        Field::SmallInt(field) => get_numeric::<i16, u16>(col_name, field.unsigned, row),
+        Field::MediumInt(field) => get_numeric::<i32, u32>(col_name, field.unsigned, row),
+        Field::Int(field) | Field::Integer(field) => {
+            get_numeric::<i32, u32>(col_name, field.unsigned, row)
+        }
+        Field::BigInt(field) => get_numeric::<i64, u64>(col_name, field.unsigned, row),
+        Field::Numeric(_) | Field::Decimal(_) => get_text::<BigDecimal>(col_name, row),
+        Field::Float(_) => get_text::<f32>(col_name, row),
+        Field::Double(_) | Field::Real(_) => get_text::<f64>(col_name, row),
+        Field::Json(_) => get_text::<JsonValue>(col_name, row),
+        Field::Year(_) => get_text::<u16>(col_name, row),
+        Field::Date(_) => get_temporal::<NaiveDate>(col_name, row, "0000-00-00", |d| {
+            d.format("%Y-%m-%d").to_string()
+        }),
+        // TIME is a duration: it can be negative or past a day, which NaiveTime can't hold.
         Field::Time(_) => {
-            let d: Option<NaiveTime> = row.try_get(field.name()).unwrap();
-            d.map(|d| d.format("%H:%M:%S").to_string())
+            let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+            match bytes {
+                None => Ok(None),
+                Some(b)
+                    if b.first()
+                        .map_or(false, |c| c.is_ascii_digit() || *c == b'-') =>
+                {
+                    Ok(Some(String::from_utf8_lossy(&b).to_string()))
+                }
+                Some(b) => format_mysql_time(&b)
+                    .map(Some)
+                    .ok_or_else(|| anyhow!("Column {} could not be decoded", col_name)),
+            }
         }
-        Field::DateTime(field) | Field::Timestamp(field) => {
-            let d: Option<DateTime<Utc>> = row.try_get(field.name()).unwrap();
-            d.map(|d| d.format("%Y-%m-%d %H:%M:%S").to_string())
+        Field::DateTime(_) => {
+            get_temporal::<NaiveDateTime>(col_name, row, "0000-00-00 00:00:00", |d| {
+                d.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+            })
+        }
+        // The session runs in UTC, so the decoded value reads back as-is.
+        Field::Timestamp(_) => {
+            get_temporal::<DateTime<Utc>>(col_name, row, "0000-00-00 00:00:00", |d| {
+                d.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+            })
+        }
+        Field::Binary(_)
+        | Field::VarBinary(_)
+        | Field::Blob(_)
+        | Field::TinyBlob(_)
+        | Field::MediumBlob(_)
+        | Field::LongBlob(_)
+        | Field::Bit(_)
+        | Field::Geometry(_)
+        | Field::GeometryCollection(_)
+        | Field::LineString(_)
+        | Field::MultiLineString(_)
+        | Field::MultiPoint(_)
+        | Field::MultiPolygon(_)
+        | Field::Point(_)
+        | Field::Polygon(_) => Err(anyhow!("Column {} has no text form", col_name)),
+    }
+}
+
+// SQL literal of the stored value at full precision, for row conditions and dumps.
+pub fn get_mysql_field_literal(field: &Field, row: &MySqlRow) -> Result<Option<String>> {
+    let col_name = field.name();
+    match field {
+        Field::VarChar(_)
+        | Field::Char(_)
+        | Field::Enum(_)
+        | Field::Set(_)
+        | Field::Text(_)
+        | Field::TinyText(_)
+        | Field::MediumText(_)
+        | Field::LongText(_) => {
+            let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+            Ok(bytes.map(|b| match String::from_utf8(b) {
+                Ok(s) => quote_literal(&s),
+                Err(e) => hex_literal(e.as_bytes()),
+            }))
+        }
+        // Geometry is sent in MySQL's internal format, which the column accepts back as-is.
+        Field::Binary(_)
+        | Field::VarBinary(_)
+        | Field::Blob(_)
+        | Field::TinyBlob(_)
+        | Field::MediumBlob(_)
+        | Field::LongBlob(_)
+        | Field::Geometry(_)
+        | Field::GeometryCollection(_)
+        | Field::LineString(_)
+        | Field::MultiLineString(_)
+        | Field::MultiPoint(_)
+        | Field::MultiPolygon(_)
+        | Field::Point(_)
+        | Field::Polygon(_) => {
+            let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+            Ok(bytes.map(|b| hex_literal(&b)))
         }
-        _ => None,
+        Field::Bit(_) => get_bit_value(col_name, row),
+        // Numbers stay unquoted: comparing a decimal with a string goes through a double.
+        Field::TinyInt(_)
+        | Field::SmallInt(_)
+        | Field::MediumInt(_)
+        | Field::Int(_)
+        | Field::Integer(_)
+        | Field::BigInt(_)
+        | Field::Numeric(_)
+        | Field::Decimal(_)
+        | Field::Float(_)
+        | Field::Double(_)
+        | Field::Real(_)
+        | Field::Year(_) => get_mysql_field_text(field, row),
+        _ => Ok(get_mysql_field_text(field, row)?.map(|v| quote_literal(&v))),
     }
 }
 
-pub fn get_mysql_column_value(column: &MySqlColumn, row: &MySqlRow) -> Option<String> {
+pub fn get_mysql_column_value(
+    column: &MySqlColumn,
+    row: &MySqlRow,
+    display_format: &DisplayFormat,
+) -> Result<Option<String>> {
     let col_name = column.name();
-    fn get_value<'r, T>(col_name: &str, row: &'r MySqlRow) -> Option<String>
+    fn get_value<'r, T>(col_name: &str, row: &'r MySqlRow) -> Result<Option<String>>
     where
         T: std::fmt::Display + sqlx::Decode<'r, MySql> + sqlx::Type<MySql>,
     {
-        let i: Option<T> = row.try_get(col_name).unwrap();
-        i.map(|i| i.to_string())
+        match row.try_get::<Option<T>, _>(col_name) {
+            Ok(i) => Ok(i.map(|i| i.to_string())),
+            Err(_) => get_raw_value(col_name, row),
+        }
     }
 
     match column.type_info().name() {
         "VARCHAR" | "CHAR" => get_value::<String>(col_name, row),
-        "TINYINT" => get_value::<i8>(col_name, row),
+        "BOOLEAN" if display_format.tinyint_as_bool => {
+            Ok(get_value::<i8>(col_name, row)?.map(|v| match v.as_str() {
+                "0" => String::from("false"),
+                "1" => String::from("true"),
+                _ => v,
+            }))
+        }
+        "BOOLEAN" | "TINYINT" => get_value::<i8>(col_name, row),
         "TINYINT UNSIGNED" => get_value::<u8>(col_name, row),
         "SMALLINT" => get_value::<i16>(col_name, row),
         "SMALLINT UNSIGNED" => get_value::<u16>(col_name, row),
@@ -1078,25 +1489,91 @@ pub fn get_mysql_column_value(column: &MySqlColumn, row: &MySqlRow) -> Option<St
         "DOUBLE" => get_value::<f64>(col_name, row),
         "REAL" => get_value::<f64>(col_name, row),
         "JSON" => get_value::<JsonValue>(col_name, row),
-        "BIT" => None,
+        "BIT" => get_bit_value(col_name, row),
+        "GEOMETRY" => get_geometry_value(col_name, row),
         "ENUM" => get_value::<String>(col_name, row),
         "SET" => get_value::<String>(col_name, row),
         "TEXT" | "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" => get_value::<String>(col_name, row),
         "YEAR" => get_value::<u16>(col_name, row),
-        "DATE" => {
-            let d: Option<NaiveDate> = row.try_get(col_name).unwrap();
-            d.map(|d| d.format("%Y-%m-%d").to_string())
-        }
-        "TIME" => {
-            let d: Option<NaiveTime> = row.try_get(col_name).unwrap();
-            d.map(|d| d.format("%H:%M:%S").to_string())
-        }
-        "DATETIME" | "TIMESTAMP" => {
-            let d: Option<DateTime<Utc>> = row.try_get(col_name).unwrap();
-            d.map(|d| d.format("%Y-%m-%d %H:%M:%S").to_string())
-        }
-        _ => None,
+        "DATE" => match row.try_get::<Option<NaiveDate>, _>(col_name) {
+            Ok(d) => Ok(d.map(|d| display_format.format_date(&d))),
+            Err(_) => get_temporal_value(col_name, row, "0000-00-00"),
+        },
+        "TIME" => match row.try_get::<Option<NaiveTime>, _>(col_name) {
+            Ok(d) => Ok(d.map(|d| display_format.format_time(&d))),
+            Err(_) => get_temporal_value(col_name, row, "00:00:00"),
+        },
+        "DATETIME" => match row.try_get::<Option<NaiveDateTime>, _>(col_name) {
+            Ok(d) => Ok(d.map(|d| display_format.format_naive_datetime(&d))),
+            Err(_) => get_temporal_value(col_name, row, "0000-00-00 00:00:00"),
+        },
+        "TIMESTAMP" => match row.try_get::<Option<DateTime<Utc>>, _>(col_name) {
+            Ok(d) => Ok(d.map(|d| display_format.format_datetime(&d))),
+            Err(_) => get_temporal_value(col_name, row, "0000-00-00 00:00:00"),
+        },
+        "NULL" => Ok(None),
+        _ => get_raw_value(col_name, row),
+    }
+}
+fn get_raw_value(col_name: &str, row: &MySqlRow) -> Result<Option<String>> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+    Ok(bytes.map(|b| String::from_utf8(b).unwrap_or_else(|e| to_hex(e.as_bytes()))))
+}
+// Zero dates can't be decoded into chrono types: the text protocol returns them as-is and the
+// binary protocol sends an empty value.
+fn get_temporal_value(col_name: &str, row: &MySqlRow, zero: &str) -> Result<Option<String>> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+    Ok(bytes.map(|b| match b.first() {
+        Some(c) if c.is_ascii_digit() || *c == b'-' => String::from_utf8_lossy(&b).to_string(),
+        _ if b.len() <= 1 => zero.to_string(),
+        _ => to_hex(&b),
+    }))
+}
+fn get_bit_value(col_name: &str, row: &MySqlRow) -> Result<Option<String>> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+    Ok(bytes.map(|b| {
+        let bits = b.iter().fold(0u64, |n, b| n << 8 | *b as u64);
+        format!("b'{:b}'", bits)
+    }))
+}
+fn get_geometry_value(col_name: &str, row: &MySqlRow) -> Result<Option<String>> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name)?;
+    Ok(bytes.map(|b| geometry_to_wkt(&b).unwrap_or_else(|| to_hex(&b))))
+}
+fn format_mysql_time(bytes: &[u8]) -> Option<String> {
+    // length, sign, days (4 bytes), hours, minutes, seconds and optional microseconds
+    if bytes.len() <= 1 {
+        return Some("00:00:00".to_string());
+    }
+    if bytes.len() < 9 {
+        return None;
+    }
+    let days = u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]);
+    let mut time = format!(
+        "{}{:02}:{:02}:{:02}",
+        if bytes[1] == 1 { "-" } else { "" },
+        days * 24 + bytes[6] as u32,
+        bytes[7],
+        bytes[8]
+    );
+    if bytes.len() >= 13 {
+        time.push_str(&format!(
+            ".{:06}",
+            u32::from_le_bytes([bytes[9], bytes[10], bytes[11], bytes[12]])
+        ));
     }
+    Some(time)
+}
+fn hex_literal(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::from("X'"), |s, b| s + &format!("{:02X}", b))
+        + "'"
+}
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::from("0x"), |s, b| s + &format!("{:02X}", b))
 }
 pub fn convert_show_column_to_mysql_fields(fields: Vec<MySqlRow>) -> Vec<Field> {
     fields
@@ -1119,7 +1596,7 @@ pub fn convert_show_column_to_mysql_fields(fields: Vec<MySqlRow>) -> Vec<Field>
             let collation: Option<String> = r.try_get("Collation").unwrap();
             let comment: Option<String> = r.try_get("Comment").unwrap();
 
-            match FieldKind::try_from(caps.name("kind").unwrap().as_str()).unwrap() {
+            let mut field = match FieldKind::try_from(caps.name("kind").unwrap().as_str()).unwrap() {
                 FieldKind::BigInt => Field::BigInt(IntField::new(
                     name.as_str(),
                     not_null,
@@ -1414,7 +1891,60 @@ pub fn convert_show_column_to_mysql_fields(fields: Vec<MySqlRow>) -> Vec<Field>
                     comment.as_deref(),
                     default.as_deref(),
                 )),
-            }
+            };
+            field.set_invisible(extra.contains("INVISIBLE"));
+            field.set_default_expression(
+                extra.contains("DEFAULT_GENERATED")
+                    || default.as_deref().map_or(false, is_current_timestamp),
+            );
+            field
         })
         .collect::<Vec<Field>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_value_escapes_literals() {
+        assert_eq!(
+            default_value(Some("O'Brien"), true, false),
+            " DEFAULT 'O''Brien'"
+        );
+        assert_eq!(
+            default_value(Some(r"C:\temp"), true, false),
+            r" DEFAULT 'C:\\temp'"
+        );
+        assert_eq!(default_value(Some("0"), false, false), " DEFAULT 0");
+        assert_eq!(default_value(Some(""), true, false), "");
+        assert_eq!(default_value(None, true, false), "");
+    }
+
+    #[test]
+    fn test_comment_escapes_literals() {
+        assert_eq!(comment(Some("owner's name")), " COMMENT 'owner''s name'");
+        assert_eq!(comment(Some(r"a\b")), r" COMMENT 'a\\b'");
+        assert_eq!(comment(Some("")), "");
+    }
+
+    #[test]
+    fn test_hex_literal() {
+        assert_eq!(hex_literal(&[0xde, 0xad, 0x01]), "X'DEAD01'");
+        assert_eq!(hex_literal(&[]), "X''");
+    }
+
+    #[test]
+    fn test_format_mysql_time() {
+        assert_eq!(format_mysql_time(&[0]).as_deref(), Some("00:00:00"));
+        assert_eq!(
+            format_mysql_time(&[8, 1, 1, 0, 0, 0, 2, 3, 4]).as_deref(),
+            Some("-26:03:04")
+        );
+        assert_eq!(
+            format_mysql_time(&[12, 0, 0, 0, 0, 0, 10, 0, 5, 0x40, 0xe2, 0x01, 0]).as_deref(),
+            Some("10:00:05.123456")
+        );
+        assert_eq!(format_mysql_time(&[8, 0, 0]), None);
+    }
+}
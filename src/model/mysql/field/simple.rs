@@ -1,4 +1,4 @@
-use super::{comment, not_null};
+use super::{comment, invisible, not_null, quote_ident};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,6 +8,7 @@ pub struct SimpleField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
 }
 impl SimpleField {
     pub fn new(name: &str, not_null: bool, key: bool, comment: Option<&str>) -> Self {
@@ -17,6 +18,7 @@ impl SimpleField {
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
         }
     }
     pub fn id(&self) -> &Uuid {
@@ -40,20 +42,28 @@ impl SimpleField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}",
-            self.name,
+            "{} {}{}{}{}",
+            quote_ident(&self.name),
             kind,
             not_null(self.not_null()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &SimpleField) -> Option<String> {
-        if old.name != self.name || old.not_null != self.not_null || old.comment != self.comment {
+        if old.name != self.name
+            || old.not_null != self.not_null
+            || old.comment != self.comment
+            || old.invisible != self.invisible
+        {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
@@ -1,4 +1,6 @@
-use super::{character_set, collation, comment, not_null};
+use super::{
+    character_set, collation, comment, get_charset_from_collation, invisible, not_null, quote_ident,
+};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -8,6 +10,7 @@ pub struct TextField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub character_set: Option<String>,
     pub collation: Option<String>,
 }
@@ -19,14 +22,14 @@ impl TextField {
         collation: &str,
         comment: Option<&str>,
     ) -> Self {
-        let underline_index = collation.find('_').unwrap();
-        let charset = &collation[..underline_index];
+        let charset = get_charset_from_collation(collation);
         TextField {
             id: Uuid::new_v4(),
             name: name.to_string(),
             not_null,
             key,
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             character_set: Some(charset.to_string()),
             collation: Some(collation.to_string()),
         }
@@ -58,15 +61,19 @@ impl TextField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
 
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             character_set(self.character_set()),
             collation(self.collation()),
             not_null(self.not_null()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
@@ -74,12 +81,13 @@ impl TextField {
         if old.name != self.name
             || old.not_null != self.not_null
             || old.comment != self.comment
+            || old.invisible != self.invisible
             || old.character_set != self.character_set
             || old.collation != self.collation
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
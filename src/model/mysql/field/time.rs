@@ -1,4 +1,4 @@
-use super::{comment, default_value, length, not_null};
+use super::{comment, default_value, invisible, length, not_null, quote_ident};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -8,8 +8,10 @@ pub struct TimeField {
     pub not_null: bool,
     pub key: bool,
     pub comment: Option<String>,
+    pub invisible: bool,
     pub length: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
 }
 
 impl TimeField {
@@ -28,7 +30,9 @@ impl TimeField {
             key,
             length: length.map(|l| l.to_string()),
             comment: comment.map(|s| s.to_string()),
+            invisible: false,
             default_value: default_value.map(|s| s.to_string()),
+            default_expression: false,
         }
     }
     pub fn id(&self) -> &Uuid {
@@ -40,6 +44,9 @@ impl TimeField {
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn key(&self) -> bool {
         self.key
     }
@@ -55,27 +62,33 @@ impl TimeField {
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn invisible(&self) -> bool {
+        self.invisible
+    }
     pub fn get_create_str(&self, kind: String) -> String {
         format!(
-            "`{}` {}{}{}{}{}",
-            self.name(),
+            "{} {}{}{}{}{}{}",
+            quote_ident(self.name()),
             kind,
             length(self.length()),
             not_null(self.not_null()),
-            default_value(self.default_value(), false),
+            default_value(self.default_value(), true, self.default_expression()),
+            invisible(self.invisible()),
             comment(self.comment())
         )
     }
     pub fn get_change_str(&self, kind: String, old: &TimeField) -> Option<String> {
         if old.name != self.name
             || old.default_value != self.default_value
+            || old.default_expression != self.default_expression
             || old.not_null != self.not_null
             || old.length != self.length
             || old.comment != self.comment
+            || old.invisible != self.invisible
         {
             Some(format!(
-                "CHANGE COLUMN `{}` {}",
-                old.name,
+                "CHANGE COLUMN {} {}",
+                quote_ident(&old.name),
                 self.get_create_str(kind)
             ))
         } else {
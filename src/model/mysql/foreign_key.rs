@@ -1,3 +1,5 @@
+use super::quote_ident;
+use crate::model::Reference;
 use sqlx::{mysql::MySqlRow, Row};
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 use uuid::Uuid;
@@ -32,10 +34,10 @@ pub enum OnUpdateKind {
 pub struct ForeignKey {
     pub id: Uuid,
     pub name: String,
-    pub field: String,
+    pub fields: Vec<String>,
     pub ref_db: String,
     pub ref_table: String,
-    pub ref_field: String,
+    pub ref_fields: Vec<String>,
     pub on_delete: Option<OnDeleteKind>,
     pub on_update: Option<OnUpdateKind>,
 }
@@ -46,8 +48,8 @@ impl ForeignKey {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
-    pub fn field(&self) -> &str {
-        self.field.as_str()
+    pub fn fields(&self) -> &Vec<String> {
+        &self.fields
     }
     pub fn ref_db(&self) -> &str {
         self.ref_db.as_str()
@@ -55,8 +57,8 @@ impl ForeignKey {
     pub fn ref_table(&self) -> &str {
         self.ref_table.as_str()
     }
-    pub fn ref_field(&self) -> &str {
-        self.ref_field.as_str()
+    pub fn ref_fields(&self) -> &Vec<String> {
+        &self.ref_fields
     }
     pub fn on_delete(&self) -> Option<&str> {
         self.on_delete.clone().map(|s| s.into())
@@ -66,28 +68,32 @@ impl ForeignKey {
     }
     pub fn get_create_ddl(&self) -> String {
         let mut sql = format!(
-            "CONSTRAINT `{}` FOREIGN KEY (`{}`) REFERENCES `{}`.`{}` (`{}`)",
-            self.name, self.field, self.ref_db, self.ref_table, self.ref_field,
+            "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}.{} ({})",
+            quote_ident(&self.name),
+            get_column_list(&self.fields),
+            quote_ident(&self.ref_db),
+            quote_ident(&self.ref_table),
+            get_column_list(&self.ref_fields)
         );
-        if let Some(update) = self.on_update.as_ref() {
-            sql = format!("{} ON DELETE {}", sql, update);
-        }
         if let Some(delete) = self.on_delete.as_ref() {
-            sql = format!("{} ON UPDATE {}", sql, delete);
+            sql = format!("{} ON DELETE {}", sql, delete);
+        }
+        if let Some(update) = self.on_update.as_ref() {
+            sql = format!("{} ON UPDATE {}", sql, update);
         }
         sql
     }
 
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP FOREIGN KEY `{}`", self.name)
+        format!("DROP FOREIGN KEY {}", quote_ident(&self.name))
     }
     pub fn get_alter_ddl(&self, old_fk: &ForeignKey) -> Vec<String> {
         let mut ddl = Vec::new();
         if self.name != old_fk.name
-            || self.field != old_fk.field
+            || self.fields != old_fk.fields
             || self.ref_db != old_fk.ref_db
             || self.ref_table != old_fk.ref_table
-            || self.ref_field != old_fk.ref_field
+            || self.ref_fields != old_fk.ref_fields
             || self.on_delete != old_fk.on_delete
             || self.on_update != old_fk.on_update
         {
@@ -100,17 +106,70 @@ impl ForeignKey {
         format!("ADD {}", self.get_create_ddl())
     }
 }
+fn get_column_list(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| quote_ident(c))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
 pub fn convert_show_fk_to_mysql_fk(rows: Vec<MySqlRow>) -> Vec<ForeignKey> {
-    rows.iter()
-        .map(|row| ForeignKey {
-            id: Uuid::new_v4(),
-            name: row.try_get("CONSTRAINT_NAME").unwrap(),
-            field: row.try_get("COLUMN_NAME").unwrap(),
-            ref_db: row.try_get("REFERENCED_TABLE_SCHEMA").unwrap(),
-            ref_table: row.try_get("REFERENCED_TABLE_NAME").unwrap(),
-            ref_field: row.try_get("REFERENCED_COLUMN_NAME").unwrap(),
-            on_update: None,
-            on_delete: None,
-        })
-        .collect()
+    let mut foreign_keys: Vec<ForeignKey> = Vec::new();
+    rows.iter().for_each(|row| {
+        let name: String = row.try_get("CONSTRAINT_NAME").unwrap();
+        let field: String = row.try_get("COLUMN_NAME").unwrap();
+        let ref_field: String = row.try_get("REFERENCED_COLUMN_NAME").unwrap();
+        if let Some(fk) = foreign_keys.iter_mut().find(|fk| fk.name == name) {
+            fk.fields.push(field);
+            fk.ref_fields.push(ref_field);
+        } else {
+            foreign_keys.push(ForeignKey {
+                id: Uuid::new_v4(),
+                name,
+                fields: vec![field],
+                ref_db: row.try_get("REFERENCED_TABLE_SCHEMA").unwrap(),
+                ref_table: row.try_get("REFERENCED_TABLE_NAME").unwrap(),
+                ref_fields: vec![ref_field],
+                on_update: row
+                    .try_get::<Option<String>, _>("UPDATE_RULE")
+                    .ok()
+                    .flatten()
+                    .and_then(|u| OnUpdateKind::try_from(u.as_str()).ok()),
+                on_delete: row
+                    .try_get::<Option<String>, _>("DELETE_RULE")
+                    .ok()
+                    .flatten()
+                    .and_then(|d| OnDeleteKind::try_from(d.as_str()).ok()),
+            });
+        }
+    });
+    foreign_keys
+}
+pub fn convert_show_reference_to_mysql_references(rows: Vec<MySqlRow>) -> Vec<Reference> {
+    let mut references: Vec<Reference> = Vec::new();
+    rows.iter().for_each(|row| {
+        let name: String = row.try_get("CONSTRAINT_NAME").unwrap();
+        let schema_name: String = row.try_get("TABLE_SCHEMA").unwrap();
+        let table_name: String = row.try_get("TABLE_NAME").unwrap();
+        let field: String = row.try_get("COLUMN_NAME").unwrap();
+        let ref_field: String = row.try_get("REFERENCED_COLUMN_NAME").unwrap();
+        if let Some(r) = references
+            .iter_mut()
+            .find(|r| r.name == name && r.schema_name == schema_name && r.table_name == table_name)
+        {
+            r.fields.push(field);
+            r.ref_fields.push(ref_field);
+        } else {
+            references.push(Reference {
+                name,
+                schema_name,
+                table_name,
+                fields: vec![field],
+                ref_fields: vec![ref_field],
+                on_update: row.try_get("UPDATE_RULE").ok().flatten(),
+                on_delete: row.try_get("DELETE_RULE").ok().flatten(),
+            });
+        }
+    });
+    references
 }
@@ -0,0 +1,83 @@
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes = self.bytes.get(self.pos..self.pos + N)?.try_into().ok()?;
+        self.pos += N;
+        Some(bytes)
+    }
+    fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.read::<4>()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+    fn read_f64(&mut self) -> Option<f64> {
+        let bytes = self.read::<8>()?;
+        Some(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+    fn read_point(&mut self) -> Option<String> {
+        let x = self.read_f64()?;
+        let y = self.read_f64()?;
+        Some(format!("{} {}", x, y))
+    }
+    fn read_points(&mut self) -> Option<String> {
+        let count = self.read_u32()?;
+        let points = (0..count)
+            .map(|_| self.read_point())
+            .collect::<Option<Vec<String>>>()?;
+        Some(format!("({})", points.join(",")))
+    }
+    fn read_rings(&mut self) -> Option<String> {
+        let count = self.read_u32()?;
+        let rings = (0..count)
+            .map(|_| self.read_points())
+            .collect::<Option<Vec<String>>>()?;
+        Some(format!("({})", rings.join(",")))
+    }
+    fn read_members(&mut self, name: &str) -> Option<String> {
+        let count = self.read_u32()?;
+        let members = (0..count)
+            .map(|_| {
+                let wkt = self.read_geometry()?;
+                Some(wkt.strip_prefix(name).unwrap_or(&wkt).to_string())
+            })
+            .collect::<Option<Vec<String>>>()?;
+        Some(format!("({})", members.join(",")))
+    }
+    fn read_geometry(&mut self) -> Option<String> {
+        self.little_endian = self.read::<1>()?[0] == 1;
+        match self.read_u32()? {
+            1 => Some(format!("POINT({})", self.read_point()?)),
+            2 => Some(format!("LINESTRING{}", self.read_points()?)),
+            3 => Some(format!("POLYGON{}", self.read_rings()?)),
+            4 => Some(format!("MULTIPOINT{}", self.read_members("POINT")?)),
+            5 => Some(format!(
+                "MULTILINESTRING{}",
+                self.read_members("LINESTRING")?
+            )),
+            6 => Some(format!("MULTIPOLYGON{}", self.read_members("POLYGON")?)),
+            7 => Some(format!("GEOMETRYCOLLECTION{}", self.read_members("")?)),
+            _ => None,
+        }
+    }
+}
+
+pub fn geometry_to_wkt(bytes: &[u8]) -> Option<String> {
+    WkbReader {
+        bytes: bytes.get(4..)?,
+        pos: 0,
+        little_endian: true,
+    }
+    .read_geometry()
+}
@@ -0,0 +1,42 @@
+pub fn quote_ident(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+pub fn value_literal(value: Option<&str>) -> String {
+    match value {
+        Some(v) => quote_literal(v),
+        None => String::from("NULL"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_ident() {
+        assert_eq!(quote_ident("users"), "`users`");
+        assert_eq!(quote_ident("my table"), "`my table`");
+        assert_eq!(quote_ident("a`b"), "`a``b`");
+        assert_eq!(quote_ident("it's"), "`it's`");
+        assert_eq!(quote_ident(""), "``");
+    }
+
+    #[test]
+    fn test_quote_literal() {
+        assert_eq!(quote_literal("O'Brien"), "'O''Brien'");
+        assert_eq!(quote_literal(r"C:\temp"), r"'C:\\temp'");
+        assert_eq!(quote_literal(r"\'"), r"'\\'''");
+        assert_eq!(quote_literal("a`b"), "'a`b'");
+        assert_eq!(quote_literal(""), "''");
+    }
+
+    #[test]
+    fn test_value_literal() {
+        assert_eq!(value_literal(Some("it's")), "'it''s'");
+        assert_eq!(value_literal(Some("")), "''");
+        assert_eq!(value_literal(None), "NULL");
+    }
+}
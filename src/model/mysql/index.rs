@@ -1,3 +1,4 @@
+use super::{quote_ident, quote_literal};
 use itertools::Itertools;
 use sqlx::{mysql::MySqlRow, Row};
 use std::fmt;
@@ -20,7 +21,7 @@ pub struct IndexField {
 
 impl fmt::Display for IndexField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut str = format!("`{}`", self.name);
+        let mut str = quote_ident(&self.name);
         if let Some(sub_part) = self.sub_part.as_ref() {
             str = format!("{}({})", str, sub_part);
         }
@@ -39,12 +40,8 @@ impl TryFrom<&str> for IndexField {
 
         Ok(IndexField {
             name: arr.first().unwrap().to_string(),
-            sub_part: arr.get(1).unwrap().to_owned().parse::<i64>().ok(),
-            order: if let Some(order) = arr.get(2) {
-                IndexOrder::try_from(*order).ok()
-            } else {
-                None
-            },
+            sub_part: arr.get(1).and_then(|s| s.parse::<i64>().ok()),
+            order: arr.get(2).and_then(|o| IndexOrder::try_from(*o).ok()),
         })
     }
 }
@@ -73,6 +70,8 @@ pub struct Index {
     pub kind: IndexKind,
     pub method: Option<IndexMethod>,
     pub comment: Option<String>,
+    pub invisible: bool,
+    pub cardinality: Option<u64>,
 }
 impl Index {
     pub fn id(&self) -> &Uuid {
@@ -91,64 +90,70 @@ impl Index {
         self.method.as_ref()
     }
     pub fn comment(&self) -> Option<&str> {
-        self.comment.as_deref()
+        self.comment.as_deref().filter(|c| !c.is_empty())
+    }
+    pub fn invisible(&self) -> bool {
+        self.invisible
     }
     pub fn get_create_ddl(&self) -> String {
         let fields: Vec<String> = self.fields.iter().map(|s| s.to_string()).collect();
         let mut sql = match self.kind {
             IndexKind::FullText => {
                 format!(
-                    "FULLTEXT INDEX `{}`({}){}",
-                    self.name,
+                    "FULLTEXT INDEX {}({}){}",
+                    quote_ident(&self.name),
                     fields.join(","),
                     if let Some(m) = self.method() {
                         format!(" USING {}", m)
                     } else {
                         "".to_string()
-                    },
+                    }
                 )
             }
             IndexKind::Normal => {
                 format!(
-                    "INDEX `{}`({}){}",
-                    self.name,
+                    "INDEX {}({}){}",
+                    quote_ident(&self.name),
                     fields.join(","),
                     if let Some(m) = self.method() {
                         format!(" USING {}", m)
                     } else {
                         "".to_string()
-                    },
+                    }
                 )
             }
             IndexKind::Spatial => format!(
-                "SPATIAL INDEX `{}`({}) USING {}",
-                self.name,
+                "SPATIAL INDEX {}({}){}",
+                quote_ident(&self.name),
                 fields.join(","),
                 if let Some(m) = self.method() {
                     format!(" USING {}", m)
                 } else {
                     "".to_string()
-                },
+                }
             ),
             IndexKind::Unique => format!(
-                "UNIQUE INDEX `{}`({}){}",
-                self.name,
+                "UNIQUE INDEX {}({}){}",
+                quote_ident(&self.name),
                 fields.join(","),
                 if let Some(m) = self.method() {
                     format!(" USING {}", m)
                 } else {
                     "".to_string()
-                },
+                }
             ),
         };
 
-        if let Some(comment) = self.comment().as_ref() {
-            sql = format!("{} COMMENT '{}'", sql, comment);
+        if let Some(comment) = self.comment() {
+            sql = format!("{} COMMENT {}", sql, quote_literal(comment));
+        }
+        if self.invisible {
+            sql = format!("{} /*!80000 INVISIBLE */", sql);
         }
         sql
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP INDEX `{}`", self.name)
+        format!("DROP INDEX {}", quote_ident(&self.name))
     }
     pub fn get_add_ddl(&self) -> String {
         format!("ADD {}", self.get_create_ddl())
@@ -156,17 +161,31 @@ impl Index {
     pub fn get_alter_ddl(&self, old: &Index) -> Vec<String> {
         let mut ddl = Vec::new();
         if old.name != self.name {
-            ddl.push(format!("RENAME INDEX `{}` TO `{}`", old.name, self.name))
+            ddl.push(format!(
+                "RENAME INDEX {} TO {}",
+                quote_ident(&old.name),
+                quote_ident(&self.name)
+            ))
         }
         if old.fields != self.fields
             || old.kind != self.kind
             || old.method != self.method
-            || old.comment != self.comment
+            || old.comment() != self.comment()
         {
             if old.name == self.name {
-                ddl.push(format!("DROP INDEX `{}`", self.name));
+                ddl.push(format!("DROP INDEX {}", quote_ident(&self.name)));
             }
             ddl.push(self.get_add_ddl());
+        } else if old.invisible != self.invisible {
+            ddl.push(format!(
+                "ALTER INDEX {} {}",
+                quote_ident(&self.name),
+                if self.invisible {
+                    "INVISIBLE"
+                } else {
+                    "VISIBLE"
+                }
+            ));
         }
         ddl
     }
@@ -198,7 +217,16 @@ pub fn convert_show_index_to_mysql_indexes(fields: Vec<MySqlRow>) -> Vec<Index>
                     };
                     IndexField {
                         name: f.try_get("Column_name").unwrap(),
-                        sub_part: f.try_get("Sub_part").unwrap(),
+                        sub_part: f
+                            .try_get::<Option<i64>, _>("Sub_part")
+                            .ok()
+                            .flatten()
+                            .or_else(|| {
+                                f.try_get::<Option<u64>, _>("Sub_part")
+                                    .ok()
+                                    .flatten()
+                                    .map(|s| s as i64)
+                            }),
                         order,
                     }
                 })
@@ -218,6 +246,17 @@ pub fn convert_show_index_to_mysql_indexes(fields: Vec<MySqlRow>) -> Vec<Index>
             } else {
                 IndexKind::Normal
             };
+            let cardinality = fields
+                .iter()
+                .filter(|f| f.try_get::<String, _>("Key_name").unwrap() == name)
+                .filter_map(|f| {
+                    f.try_get::<Option<i64>, _>("Cardinality")
+                        .ok()
+                        .flatten()
+                        .map(|c| c as u64)
+                        .or_else(|| f.try_get::<Option<u64>, _>("Cardinality").ok().flatten())
+                })
+                .max();
             Index {
                 id: Uuid::new_v4(),
                 name,
@@ -225,6 +264,10 @@ pub fn convert_show_index_to_mysql_indexes(fields: Vec<MySqlRow>) -> Vec<Index>
                 method: IndexMethod::try_from(index_type.as_str()).ok(),
                 kind,
                 comment: row.try_get::<Option<String>, _>("Index_comment").unwrap(),
+                invisible: row
+                    .try_get::<String, _>("Visible")
+                    .map_or(false, |v| v == "NO"),
+                cardinality,
             }
         })
         .collect()
@@ -232,10 +275,9 @@ pub fn convert_show_index_to_mysql_indexes(fields: Vec<MySqlRow>) -> Vec<Index>
 pub fn show_mysql_index_field(row: &[String]) -> String {
     let mut str = String::from(&row[0]);
     if !row[1].is_empty() {
-        str.push(' ');
-        str.push_str(&row[1]);
+        str = format!("{}({})", str, row[1]);
     }
-    if let Some(order) = row.get(2) {
+    if let Some(order) = row.get(2).filter(|o| !o.is_empty()) {
         str.push(' ');
         str.push_str(order);
     }
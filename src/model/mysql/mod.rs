@@ -2,16 +2,24 @@ mod check;
 mod column;
 mod connection;
 mod database;
+mod dump;
+mod event;
 mod field;
 mod foreign_key;
+mod geometry;
+mod ident;
 mod index;
+mod partition;
 mod privilege;
+mod process;
+mod routine;
 mod table;
 mod trigger;
 mod user;
 mod view;
 
 pub use self::{
-    check::*, column::*, connection::*, database::*, field::*, foreign_key::*, index::*,
-    privilege::*, table::*, trigger::*, user::*, view::*,
+    check::*, column::*, connection::*, database::*, dump::*, event::*, field::*, foreign_key::*,
+    geometry::*, ident::*, index::*, partition::*, privilege::*, process::*, routine::*, table::*,
+    trigger::*, user::*, view::*,
 };
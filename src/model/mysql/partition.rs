@@ -0,0 +1,103 @@
+use super::{quote_ident, quote_literal};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+use uuid::Uuid;
+
+#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone, PartialEq)]
+pub enum PartitionMethod {
+    #[strum(serialize = "RANGE")]
+    Range,
+    #[strum(serialize = "RANGE COLUMNS")]
+    RangeColumns,
+    #[strum(serialize = "LIST")]
+    List,
+    #[strum(serialize = "LIST COLUMNS")]
+    ListColumns,
+}
+
+#[derive(Clone)]
+pub struct Partition {
+    pub id: Uuid,
+    pub name: String,
+    pub method: PartitionMethod,
+    pub expression: String,
+    pub values: String,
+    pub comment: Option<String>,
+}
+
+impl Partition {
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+    pub fn method(&self) -> &str {
+        self.method.clone().into()
+    }
+    pub fn expression(&self) -> &str {
+        self.expression.as_str()
+    }
+    pub fn values(&self) -> &str {
+        self.values.as_str()
+    }
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    pub fn get_create_ddl(&self) -> String {
+        let mut ddl = match self.method {
+            PartitionMethod::Range | PartitionMethod::RangeColumns => format!(
+                "PARTITION {} VALUES LESS THAN ({})",
+                quote_ident(&self.name),
+                self.values
+            ),
+            PartitionMethod::List | PartitionMethod::ListColumns => {
+                format!(
+                    "PARTITION {} VALUES IN ({})",
+                    quote_ident(&self.name),
+                    self.values
+                )
+            }
+        };
+        if let Some(comment) = self.comment.as_ref() {
+            ddl.push_str(&format!(" COMMENT = {}", quote_literal(comment)));
+        }
+        ddl
+    }
+    pub fn get_add_ddl(&self) -> String {
+        format!("ADD PARTITION ({})", self.get_create_ddl())
+    }
+    pub fn get_alter_ddl(&self, old_partition: &Partition) -> Option<String> {
+        if self.name != old_partition.name
+            || self.values != old_partition.values
+            || self.comment != old_partition.comment
+        {
+            Some(format!(
+                "REORGANIZE PARTITION {} INTO ({})",
+                quote_ident(&old_partition.name),
+                self.get_create_ddl()
+            ))
+        } else {
+            None
+        }
+    }
+    pub fn is_same_scheme(&self, partition: &Partition) -> bool {
+        self.method == partition.method && self.expression == partition.expression
+    }
+}
+
+pub fn get_partition_by_ddl(partitions: &[Partition]) -> String {
+    if let Some(first) = partitions.first() {
+        format!(
+            "PARTITION BY {} ({}) (\n{}\n)",
+            first.method,
+            first.expression,
+            partitions
+                .iter()
+                .map(|p| p.get_create_ddl())
+                .collect::<Vec<String>>()
+                .join(",\n")
+        )
+    } else {
+        String::new()
+    }
+}
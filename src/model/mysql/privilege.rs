@@ -1,3 +1,4 @@
+use super::quote_ident;
 use anyhow::Result;
 use regex::Regex;
 use sqlx::{MySqlPool, Row};
@@ -63,12 +64,12 @@ impl Privilege {
         }
 
         format!(
-            "REVOKE {} ON TABLE `{}`.`{}` FROM `{}`@`{}`",
+            "REVOKE {} ON TABLE {}.{} FROM {}@{}",
             privs.join(","),
-            self.db,
-            self.name,
-            user_name,
-            user_host,
+            quote_ident(&self.db),
+            quote_ident(&self.name),
+            quote_ident(user_name),
+            quote_ident(user_host)
         )
     }
     pub fn get_grant_ddl(&self, user_name: &str, user_host: &str) -> String {
@@ -110,12 +111,12 @@ impl Privilege {
             actions.push("Update");
         }
         format!(
-            "GRANT {} ON `{}`.`{}` TO `{}`@`{}`",
+            "GRANT {} ON {}.{} TO {}@{}",
             actions.join(","),
-            self.db,
-            self.name,
-            user_name,
-            user_host
+            quote_ident(&self.db),
+            quote_ident(&self.name),
+            quote_ident(user_name),
+            quote_ident(user_host)
         )
     }
     pub fn get_alter_ddl(&self, old: &Privilege, user_name: &str, user_host: &str) -> Vec<String> {
@@ -210,22 +211,22 @@ impl Privilege {
 
         if !grant_actions.is_empty() {
             ddl.push(format!(
-                "GRANT {} ON `{}`.`{}` TO `{}`@`{}`",
+                "GRANT {} ON {}.{} TO {}@{}",
                 grant_actions.join(","),
-                self.db,
-                self.name,
-                user_name,
-                user_host
+                quote_ident(&self.db),
+                quote_ident(&self.name),
+                quote_ident(user_name),
+                quote_ident(user_host)
             ))
         }
         if !revoke_actions.is_empty() {
             ddl.push(format!(
-                "REVOKE {} ON `{}`.`{}` FROM `{}`@`{}`",
+                "REVOKE {} ON {}.{} FROM {}@{}",
                 revoke_actions.join(","),
-                self.db,
-                self.name,
-                user_name,
-                user_host
+                quote_ident(&self.db),
+                quote_ident(&self.name),
+                quote_ident(user_name),
+                quote_ident(user_host)
             ))
         }
         ddl
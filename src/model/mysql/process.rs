@@ -0,0 +1,41 @@
+use anyhow::Result;
+use sqlx::{MySqlPool, Row};
+
+#[derive(Clone)]
+pub struct Process {
+    pub id: u64,
+    pub user: String,
+    pub host: String,
+    pub db: Option<String>,
+    pub command: String,
+    pub time: i64,
+    pub state: Option<String>,
+    pub info: Option<String>,
+}
+
+impl Process {
+    pub fn get_kill_ddl(&self) -> String {
+        format!("KILL {}", self.id)
+    }
+}
+
+pub async fn get_mysql_processes(pool: &MySqlPool) -> Result<Vec<Process>> {
+    let processes = sqlx::query(
+        "SELECT CAST(ID AS UNSIGNED) AS ID, USER, HOST, DB, COMMAND, CAST(TIME AS SIGNED) AS TIME, STATE, INFO FROM information_schema.PROCESSLIST ORDER BY ID",
+    )
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| Process {
+        id: r.try_get("ID").unwrap(),
+        user: r.try_get("USER").unwrap(),
+        host: r.try_get("HOST").unwrap(),
+        db: r.try_get("DB").unwrap(),
+        command: r.try_get("COMMAND").unwrap(),
+        time: r.try_get("TIME").unwrap(),
+        state: r.try_get("STATE").unwrap(),
+        info: r.try_get("INFO").unwrap(),
+    })
+    .collect();
+    Ok(processes)
+}
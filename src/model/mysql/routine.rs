@@ -0,0 +1,37 @@
+use super::quote_ident;
+use anyhow::Result;
+use sqlx::{MySqlPool, Row};
+
+#[derive(Clone)]
+pub struct Routine {
+    pub name: String,
+    pub kind: String,
+    pub data_type: Option<String>,
+    pub definer: Option<String>,
+    pub comment: Option<String>,
+}
+
+impl Routine {
+    pub fn get_drop_ddl(&self) -> String {
+        format!("DROP {} IF EXISTS {}", self.kind, quote_ident(&self.name))
+    }
+}
+
+pub async fn get_mysql_routines(pool: &MySqlPool, db: &str) -> Result<Vec<Routine>> {
+    let routines: Vec<Routine> = sqlx::query(
+        "SELECT ROUTINE_NAME, ROUTINE_TYPE, DTD_IDENTIFIER, DEFINER, ROUTINE_COMMENT FROM ROUTINES WHERE ROUTINE_SCHEMA = ? ORDER BY ROUTINE_NAME",
+    )
+    .bind(db)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| Routine {
+        name: r.try_get("ROUTINE_NAME").unwrap(),
+        kind: r.try_get("ROUTINE_TYPE").unwrap(),
+        data_type: r.try_get("DTD_IDENTIFIER").unwrap(),
+        definer: r.try_get("DEFINER").unwrap(),
+        comment: r.try_get("ROUTINE_COMMENT").unwrap(),
+    })
+    .collect();
+    Ok(routines)
+}
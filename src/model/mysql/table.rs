@@ -1,9 +1,13 @@
 use crate::{
-    model::mysql::Connections,
-    pool::{execute_mysql_query, MySQLPools},
+    model::{
+        mysql::{quote_ident, quote_literal, Connections},
+        SearchColumnKind, SearchMatch,
+    },
+    pool::{execute_mysql_query, fetch_mysql_query, MySQLPools},
 };
-use anyhow::Result;
+use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use sqlx::{MySqlPool, Row};
 use std::{cell::RefCell, rc::Rc};
 use strum::{Display, EnumIter, EnumString};
@@ -43,6 +47,93 @@ pub enum RowFormat {
     Redundant,
 }
 
+#[derive(Clone, Copy, Display, EnumIter, EnumString)]
+pub enum TableMaintenance {
+    #[strum(serialize = "Analyze Table")]
+    Analyze,
+    #[strum(serialize = "Optimize Table")]
+    Optimize,
+}
+
+impl TableMaintenance {
+    pub fn get_ddl(&self, table_name: &str) -> String {
+        match self {
+            TableMaintenance::Analyze => format!("ANALYZE TABLE {}", quote_ident(table_name)),
+            TableMaintenance::Optimize => format!("OPTIMIZE TABLE {}", quote_ident(table_name)),
+        }
+    }
+    pub fn get_confirm_msg(&self, table_name: &str) -> String {
+        match self {
+            TableMaintenance::Analyze => {
+                format!("Are you sure to analyze table {}?", table_name)
+            }
+            TableMaintenance::Optimize => format!(
+                "OPTIMIZE TABLE rebuilds the table and may lock it until done. Are you sure to optimize table {}?",
+                table_name
+            ),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableOptions {
+    pub engine: Option<String>,
+    pub charset: Option<String>,
+    pub collation: Option<String>,
+    pub min_rows: Option<String>,
+    pub max_rows: Option<String>,
+    pub avg_row_length: Option<String>,
+    pub key_block_size: Option<String>,
+    pub tablespace: Option<String>,
+    pub encryption: Option<String>,
+    pub comment: Option<String>,
+}
+
+pub fn parse_mysql_table_options(def: &str) -> Option<TableOptions> {
+    let line = def.lines().rev().find(|l| l.starts_with(')'))?;
+    let get = |pattern: &str| {
+        Regex::new(pattern)
+            .unwrap()
+            .captures(line)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().to_string())
+    };
+    Some(TableOptions {
+        engine: get(r"\bENGINE=(\w+)"),
+        charset: get(r"\bCHARSET=(\w+)"),
+        collation: get(r"\bCOLLATE=(\w+)"),
+        min_rows: get(r"\bMIN_ROWS=(\d+)"),
+        max_rows: get(r"\bMAX_ROWS=(\d+)"),
+        avg_row_length: get(r"\bAVG_ROW_LENGTH=(\d+)"),
+        key_block_size: get(r"\bKEY_BLOCK_SIZE=(\d+)"),
+        tablespace: get(r"\bTABLESPACE `((?:[^`]|``)+)`").map(|t| t.replace("``", "`")),
+        encryption: get(r"\bENCRYPTION='([YNyn])'").map(|e| e.to_uppercase()),
+        comment: get(r"\bCOMMENT='((?:[^'\\]|''|\\.)*)'").map(|c| unescape_literal(&c)),
+    })
+}
+fn unescape_literal(value: &str) -> String {
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('r') => result.push('\r'),
+                Some('t') => result.push('\t'),
+                Some('0') => result.push('\0'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            },
+            '\'' => {
+                chars.next();
+                result.push('\'');
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
 pub async fn get_mysql_tables(pool: &MySqlPool, db: &str) -> Result<Vec<Table>> {
     let tbs: Vec<Table> =
         sqlx::query("SELECT * FROM TABLES WHERE TABLE_TYPE = 'BASE TABLE' AND TABLE_SCHEMA = ?")
@@ -74,6 +165,126 @@ pub async fn get_mysql_table_names(pool: &MySqlPool, db: &str) -> Result<Vec<Str
     .collect();
     Ok(tb_names)
 }
+pub async fn get_mysql_referencing_tables(
+    pool: &MySqlPool,
+    db: &str,
+    tables: &[String],
+) -> Result<Vec<String>> {
+    let mut names: Vec<String> = sqlx::query(
+        "SELECT DISTINCT TABLE_SCHEMA, TABLE_NAME, REFERENCED_TABLE_NAME FROM KEY_COLUMN_USAGE WHERE REFERENCED_TABLE_SCHEMA = ?",
+    )
+    .bind(db)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .filter_map(|r| {
+        let schema_name: String = r.try_get("TABLE_SCHEMA").unwrap();
+        let table_name: String = r.try_get("TABLE_NAME").unwrap();
+        let ref_table_name: String = r.try_get("REFERENCED_TABLE_NAME").unwrap();
+        if tables.contains(&ref_table_name)
+            && !(schema_name == db && tables.contains(&table_name))
+        {
+            Some(format!("{}.{}", schema_name, table_name))
+        } else {
+            None
+        }
+    })
+    .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+pub async fn search_mysql_value(
+    pool: &MySqlPool,
+    db: &str,
+    tables: &[String],
+    value: &str,
+    kind: &SearchColumnKind,
+) -> Result<Vec<SearchMatch>> {
+    let is_number = value.trim().parse::<f64>().is_ok();
+    if *kind == SearchColumnKind::Numeric && !is_number {
+        return Err(Error::msg(format!("{} is not a number", value)));
+    }
+    let columns: Vec<(String, String)> = sqlx::query(
+        "SELECT TABLE_NAME, COLUMN_NAME, DATA_TYPE FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? ORDER BY TABLE_NAME, ORDINAL_POSITION",
+    )
+    .bind(db)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .filter_map(|r| {
+        let table_name: String = r.try_get("TABLE_NAME").unwrap();
+        let column_name: String = r.try_get("COLUMN_NAME").unwrap();
+        let data_type: String = r.try_get("DATA_TYPE").unwrap();
+        let is_text = matches!(
+            data_type.to_lowercase().as_str(),
+            "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set"
+        );
+        let is_numeric = is_number
+            && matches!(
+                data_type.to_lowercase().as_str(),
+                "tinyint"
+                    | "smallint"
+                    | "mediumint"
+                    | "int"
+                    | "bigint"
+                    | "decimal"
+                    | "float"
+                    | "double"
+            );
+        let included = match kind {
+            SearchColumnKind::Text => is_text,
+            SearchColumnKind::Numeric => is_numeric,
+            SearchColumnKind::All => is_text || is_numeric,
+        };
+        if included && tables.contains(&table_name) {
+            Some((table_name, column_name))
+        } else {
+            None
+        }
+    })
+    .collect();
+
+    let literal = quote_literal(value);
+    let mut matches = Vec::new();
+    for table_name in tables.iter() {
+        let column_names: Vec<&String> = columns
+            .iter()
+            .filter(|(t, _)| t == table_name)
+            .map(|(_, c)| c)
+            .collect();
+        if column_names.is_empty() {
+            continue;
+        }
+        let sql = format!(
+            "SELECT {} FROM {}.{}",
+            column_names
+                .iter()
+                .map(|c| format!(
+                    "COUNT(CASE WHEN {} = {} THEN 1 END)",
+                    quote_ident(c),
+                    literal
+                ))
+                .collect::<Vec<String>>()
+                .join(", "),
+            quote_ident(db),
+            quote_ident(table_name)
+        );
+        let row = sqlx::query(&sql).fetch_one(pool).await?;
+        for (i, column_name) in column_names.iter().enumerate() {
+            let count: i64 = row.try_get(i)?;
+            if count > 0 {
+                matches.push(SearchMatch {
+                    table_name: table_name.clone(),
+                    column_name: column_name.to_string(),
+                    count,
+                });
+            }
+        }
+    }
+    Ok(matches)
+}
 
 pub async fn execute_mysql_table(
     conns: Rc<RefCell<Connections>>,
@@ -85,3 +296,31 @@ pub async fn execute_mysql_table(
     execute_mysql_query(conns, pools, conn_id, Some(db_name), sql).await?;
     Ok(())
 }
+pub async fn execute_mysql_table_maintenance(
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db_name: &str,
+    table_name: &str,
+    maintenance: TableMaintenance,
+) -> Result<String> {
+    let rows = fetch_mysql_query(
+        conns,
+        pools,
+        conn_id,
+        Some(db_name),
+        &maintenance.get_ddl(table_name),
+    )
+    .await?;
+    let msgs = rows
+        .iter()
+        .map(|r| {
+            format!(
+                "{}: {}",
+                r.try_get::<String, _>("Msg_type").unwrap_or_default(),
+                r.try_get::<String, _>("Msg_text").unwrap_or_default()
+            )
+        })
+        .collect::<Vec<String>>();
+    Ok(msgs.join("\n"))
+}
@@ -1,3 +1,4 @@
+use super::quote_ident;
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 use uuid::Uuid;
 
@@ -43,12 +44,16 @@ impl Trigger {
     }
     pub fn get_create_ddl(&self, table_name: &str) -> String {
         format!(
-            "CREATE TRIGGER `{}` {} {} ON `{}` FOR EACH ROW {};",
-            self.name, self.time, self.action, table_name, self.statement
+            "CREATE TRIGGER {} {} {} ON {} FOR EACH ROW {};",
+            quote_ident(&self.name),
+            self.time,
+            self.action,
+            quote_ident(table_name),
+            self.statement
         )
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP TRIGGER `{}`;", self.name)
+        format!("DROP TRIGGER {};", quote_ident(&self.name))
     }
     pub fn get_alter_ddl(&self, old_trigger: &Trigger, table_name: &str) -> Vec<String> {
         let mut ddl = Vec::new();
@@ -1,3 +1,4 @@
+use super::quote_ident;
 use anyhow::Result;
 use sqlx::{mysql::MySqlRow, MySqlPool, Row};
 
@@ -95,15 +96,21 @@ impl UserMember {
             if self.granted {
                 if let (Some(user_name), Some(user_host)) = (self.user_name(), self.user_host()) {
                     Some(format!(
-                        "GRANT `{}`@`{}` TO `{}`@`{}`",
-                        name, host, user_name, user_host
+                        "GRANT {}@{} TO {}@{}",
+                        quote_ident(name),
+                        quote_ident(host),
+                        quote_ident(user_name),
+                        quote_ident(user_host)
                     ))
                 } else if let (Some(user_name), Some(user_host)) =
                     (self.member_name(), self.member_host())
                 {
                     Some(format!(
-                        "GRANT `{}`@`{}` TO `{}`@`{}`",
-                        user_name, user_host, name, host
+                        "GRANT {}@{} TO {}@{}",
+                        quote_ident(user_name),
+                        quote_ident(user_host),
+                        quote_ident(name),
+                        quote_ident(host)
                     ))
                 } else {
                     None
@@ -111,15 +118,21 @@ impl UserMember {
             } else if let (Some(user_name), Some(user_host)) = (self.user_name(), self.user_host())
             {
                 Some(format!(
-                    "REVOKE `{}`@`{}` FROM `{}`@`{}`",
-                    name, host, user_name, user_host
+                    "REVOKE {}@{} FROM {}@{}",
+                    quote_ident(name),
+                    quote_ident(host),
+                    quote_ident(user_name),
+                    quote_ident(user_host)
                 ))
             } else if let (Some(user_name), Some(user_host)) =
                 (self.member_name(), self.member_host())
             {
                 Some(format!(
-                    "REVOKE `{}`@`{}` FROM `{}`@`{}`",
-                    user_name, user_host, name, host
+                    "REVOKE {}@{} FROM {}@{}",
+                    quote_ident(user_name),
+                    quote_ident(user_host),
+                    quote_ident(name),
+                    quote_ident(host)
                 ))
             } else {
                 None
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use sqlx::{PgPool, Row};
+
+pub const LONG_RUNNING_SECONDS: f64 = 60.0;
+
+#[derive(Clone)]
+pub struct Activity {
+    pub pid: i32,
+    pub user: Option<String>,
+    pub database: Option<String>,
+    pub client: Option<String>,
+    pub state: Option<String>,
+    pub wait_event: Option<String>,
+    pub duration: Option<f64>,
+    pub query: Option<String>,
+}
+
+impl Activity {
+    pub fn get_cancel_ddl(&self) -> String {
+        format!("SELECT pg_cancel_backend({})", self.pid)
+    }
+    pub fn get_terminate_ddl(&self) -> String {
+        format!("SELECT pg_terminate_backend({})", self.pid)
+    }
+    pub fn is_idle_in_transaction(&self) -> bool {
+        self.state
+            .as_deref()
+            .map_or(false, |s| s.starts_with("idle in transaction"))
+    }
+    pub fn is_long_running(&self) -> bool {
+        self.state.as_deref() == Some("active")
+            && self.duration.map_or(false, |d| d >= LONG_RUNNING_SECONDS)
+    }
+    pub fn duration_str(&self) -> Option<String> {
+        self.duration.map(|d| {
+            let secs = d as u64;
+            if secs >= 3600 {
+                format!("{}h {}m {}s", secs / 3600, secs % 3600 / 60, secs % 60)
+            } else if secs >= 60 {
+                format!("{}m {}s", secs / 60, secs % 60)
+            } else {
+                format!("{:.1}s", d)
+            }
+        })
+    }
+}
+
+pub async fn get_pg_activities(pool: &PgPool) -> Result<Vec<Activity>> {
+    let activities = sqlx::query(
+        r#"
+        SELECT
+            pid,
+            usename::text AS usename,
+            datname::text AS datname,
+            host(client_addr) AS client,
+            state,
+            CASE WHEN wait_event IS NULL THEN NULL
+                ELSE wait_event_type || ':' || wait_event END AS wait_event,
+            EXTRACT(EPOCH FROM (now() - COALESCE(
+                CASE WHEN state = 'active' THEN query_start END,
+                xact_start,
+                state_change
+            )))::float8 AS duration,
+            query
+        FROM
+            pg_catalog.pg_stat_activity
+        WHERE
+            pid <> pg_backend_pid()
+        ORDER BY duration DESC NULLS LAST, pid
+        "#,
+    )
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| Activity {
+        pid: r.try_get("pid").unwrap(),
+        user: r.try_get("usename").unwrap(),
+        database: r.try_get("datname").unwrap(),
+        client: r.try_get("client").unwrap(),
+        state: r.try_get("state").unwrap(),
+        wait_event: r.try_get("wait_event").unwrap(),
+        duration: r.try_get("duration").unwrap(),
+        query: r.try_get("query").unwrap(),
+    })
+    .collect();
+    Ok(activities)
+}
@@ -1,3 +1,4 @@
+use super::{comment_literal, quote_ident, quote_literal};
 use sqlx::{postgres::PgRow, Row};
 use uuid::Uuid;
 
@@ -29,18 +30,18 @@ impl Check {
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         (
             format!(
-                r#"CONSTRAINT "{}" CHECK ({}){}"#,
-                self.name,
+                "CONSTRAINT {} CHECK ({}){}",
+                quote_ident(&self.name),
                 self.expression,
                 if self.no_inherit() { " NO INHERIT" } else { "" }
             ),
             self.comment().map(|c| {
                 format!(
-                    r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}';"#,
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    c
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    quote_literal(c)
                 )
             }),
         )
@@ -50,7 +51,7 @@ impl Check {
         (format!("ADD {}", check_ddl), comment_ddl)
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!(r#"DROP CONSTRAINT "{}""#, self.name)
+        format!("DROP CONSTRAINT {}", quote_ident(&self.name))
     }
     pub fn get_alter_ddl(
         &self,
@@ -60,11 +61,11 @@ impl Check {
     ) -> (Vec<String>, Option<String>) {
         let comment = if old.comment() != self.comment() {
             Some(format!(
-                r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}'"#,
-                self.name,
-                schema_name,
-                table_name,
-                self.comment().unwrap_or("")
+                "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                quote_ident(&self.name),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                comment_literal(self.comment())
             ))
         } else {
             None
@@ -76,7 +77,9 @@ impl Check {
         if other.name != self.name {
             ddl.push(format!(
                 "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
-                table_name, other.name, self.name
+                quote_ident(table_name),
+                quote_ident(&other.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
@@ -1,12 +1,12 @@
 use crate::{
     config::Config,
-    model::{Connect, DatabaseKind},
+    model::{parse_database_url, resolve_password, Connect, ConnectionColor, DatabaseKind},
     pool::test_pg_connection,
 };
 use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, env, rc::Rc};
 use uuid::Uuid;
 
 pub type Connections = HashMap<Uuid, Connection>;
@@ -18,9 +18,21 @@ pub struct Connection {
     pub host: String,
     pub port: String,
     pub init_db: Option<String>,
+    #[serde(default)]
+    pub default_schema: Option<String>,
     pub user: String,
     pub password: String,
+    #[serde(default)]
+    pub password_command: Option<String>,
+    #[serde(default)]
+    pub color: ConnectionColor,
+    #[serde(default)]
+    pub environment: Option<String>,
+    #[serde(default)]
+    pub group: Option<String>,
     pub add_at: DateTime<Utc>,
+    #[serde(skip)]
+    pub ephemeral: bool,
 }
 
 impl Connect for Connection {
@@ -36,6 +48,18 @@ impl Connect for Connection {
     fn get_add_at(&self) -> &DateTime<Utc> {
         &self.add_at
     }
+    fn get_color(&self) -> &ConnectionColor {
+        &self.color
+    }
+    fn get_environment(&self) -> Option<&str> {
+        self.environment.as_deref()
+    }
+    fn get_group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+    fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
 }
 
 impl Connection {
@@ -51,26 +75,33 @@ impl Connection {
     pub fn get_password(&self) -> &str {
         &self.password
     }
+    pub fn get_password_command(&self) -> Option<&str> {
+        self.password_command.as_deref()
+    }
     pub fn get_init_db(&self) -> Option<&str> {
         self.init_db.as_deref()
     }
-    pub fn get_pool_url(&self, db_name: Option<&str>) -> String {
-        if let Some(db_name) = db_name {
+    pub fn get_default_schema(&self) -> Option<&str> {
+        self.default_schema.as_deref()
+    }
+    pub fn get_pool_url(&self, db_name: Option<&str>) -> Result<String> {
+        let password = resolve_password(&self.password, self.get_password_command())?;
+        Ok(if let Some(db_name) = db_name {
             format!(
                 "postgresql://{}:{}@{}:{}/{}",
-                self.user, self.password, self.host, self.port, db_name
+                self.user, password, self.host, self.port, db_name
             )
         } else if let Some(db_name) = self.init_db.as_deref() {
             format!(
                 "postgresql://{}:{}@{}:{}/{}",
-                self.user, self.password, self.host, self.port, db_name
+                self.user, password, self.host, self.port, db_name
             )
         } else {
             format!(
                 "postgresql://{}:{}@{}:{}",
-                self.user, self.password, self.host, self.port,
+                self.user, password, self.host, self.port,
             )
-        }
+        })
     }
 }
 
@@ -90,7 +121,7 @@ pub async fn save_pg_connection<'a>(
     config: Rc<RefCell<Config>>,
     conn: &Connection,
 ) -> Result<()> {
-    test_pg_connection(&conn.get_pool_url(conn.get_init_db())).await?;
+    test_pg_connection(&conn.get_pool_url(conn.get_init_db())?).await?;
     connections
         .borrow_mut()
         .insert(*conn.get_id(), conn.to_owned());
@@ -105,3 +136,52 @@ pub fn delete_pg_connection(
     connections.borrow_mut().remove(conn_id);
     config.borrow_mut().delete_pg_connection(conn_id)
 }
+
+pub fn get_pg_env_connections() -> Vec<Connection> {
+    let mut conns = Vec::new();
+    if let Some(url) = env::var("DATABASE_URL")
+        .ok()
+        .and_then(|url| parse_database_url(&url))
+        .filter(|url| url.kind == DatabaseKind::PostgreSQL)
+    {
+        conns.push(Connection {
+            id: Uuid::new_v4(),
+            name: String::from("DATABASE_URL"),
+            host: url.host,
+            port: url.port.unwrap_or_else(|| String::from("5432")),
+            init_db: url.db_name,
+            default_schema: None,
+            user: url.user.unwrap_or_else(|| String::from("postgres")),
+            password: url.password.unwrap_or_default(),
+            password_command: None,
+            color: ConnectionColor::default(),
+            environment: None,
+            group: None,
+            add_at: Utc::now(),
+            ephemeral: true,
+        });
+    }
+    if let Ok(host) = env::var("PGHOST") {
+        conns.push(Connection {
+            id: Uuid::new_v4(),
+            name: String::from("PGHOST"),
+            host,
+            port: env::var("PGPORT").unwrap_or_else(|_| String::from("5432")),
+            init_db: env::var("PGDATABASE").ok(),
+            default_schema: None,
+            user: env::var("PGUSER").unwrap_or_else(|_| String::from("postgres")),
+            password: if env::var("PGPASSWORD").is_ok() {
+                String::from("${PGPASSWORD}")
+            } else {
+                String::new()
+            },
+            password_command: None,
+            color: ConnectionColor::default(),
+            environment: None,
+            group: None,
+            add_at: Utc::now(),
+            ephemeral: true,
+        });
+    }
+    conns
+}
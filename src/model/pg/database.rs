@@ -1,5 +1,6 @@
+use super::{quote_ident, quote_literal};
 use crate::{
-    model::{pg::Connections, DatabaseKind, DB},
+    model::{pg::Connections, DatabaseKind, ServerInfo, DB},
     pool::{get_pg_pool, PGPools},
 };
 use anyhow::Result;
@@ -11,6 +12,7 @@ use uuid::Uuid;
 pub struct Database {
     pub name: String,
     pub owner: Option<String>,
+    pub encoding: Option<String>,
     pub collation_order: Option<String>,
     pub character_class: Option<String>,
     pub template: Option<String>,
@@ -32,6 +34,9 @@ impl Database {
     pub fn get_character_set(&self) -> Option<&str> {
         self.character_class.as_deref()
     }
+    pub fn get_encoding(&self) -> Option<&str> {
+        self.encoding.as_deref()
+    }
     pub fn get_collation(&self) -> Option<&str> {
         self.collation_order.as_deref()
     }
@@ -54,18 +59,21 @@ impl Database {
         self.is_template
     }
     pub fn get_create_ddl(&self) -> String {
-        let mut sql = format!("CREATE DATABASE \"{}\"", self.name);
+        let mut sql = format!("CREATE DATABASE {}", quote_ident(&self.name));
         if let Some(owner) = self.owner.as_ref() {
             sql.push_str(&format!(" OWNER = {}", owner));
         }
         if let Some(tpl) = self.template.as_ref() {
             sql.push_str(&format!(" TEMPLATE = {}", tpl));
         }
+        if let Some(encoding) = self.encoding.as_ref() {
+            sql.push_str(&format!(" ENCODING = {}", quote_literal(encoding)));
+        }
         if let Some(collate) = self.collation_order.as_ref() {
-            sql.push_str(&format!(" LC_COLLATE = '{}'", collate));
+            sql.push_str(&format!(" LC_COLLATE = {}", quote_literal(collate)));
         }
         if let Some(tp) = self.character_class.as_ref() {
-            sql.push_str(&format!(" LC_TYPE = '{}'", tp));
+            sql.push_str(&format!(" LC_TYPE = {}", quote_literal(tp)));
         }
         if let Some(tb_spc) = self.tablespace.as_ref() {
             sql.push_str(&format!(" TABLESPACE = {}", tb_spc));
@@ -81,23 +89,31 @@ impl Database {
         sql
     }
     pub fn get_rename_ddl(&self, old_name: &str) -> String {
-        format!("ALTER DATABASE {} RENAME TO {}", old_name, self.name)
+        format!(
+            "ALTER DATABASE {} RENAME TO {}",
+            quote_ident(old_name),
+            quote_ident(&self.name)
+        )
     }
     pub fn get_alter_owner_ddl(&self) -> String {
         format!(
             "ALTER DATABASE {} OWNER TO {}",
-            self.name,
+            quote_ident(&self.name),
             if let Some(owner) = self.owner.as_deref() {
-                owner
+                quote_ident(owner)
             } else {
-                "CURRENT_USER"
+                "CURRENT_USER".to_string()
             }
         )
     }
     pub fn get_alter_tablespace_ddl(&self) -> Option<String> {
-        self.tablespace
-            .as_ref()
-            .map(|ts| format!("ALTER DATABASE {} SET TABLESPACE {}", self.name, ts,))
+        self.tablespace.as_ref().map(|ts| {
+            format!(
+                "ALTER DATABASE {} SET TABLESPACE {}",
+                quote_ident(&self.name),
+                quote_ident(ts)
+            )
+        })
     }
     pub fn get_alter_options_ddl(
         &self,
@@ -105,7 +121,7 @@ impl Database {
         conn_limit: Option<&str>,
         is_template: bool,
     ) -> String {
-        let mut sql = format!("ALTER DATABASE {}", self.name);
+        let mut sql = format!("ALTER DATABASE {}", quote_ident(&self.name));
         if self.get_allow_conn() != allow_conn {
             sql = format!(
                 "{} allow_connections {}",
@@ -145,11 +161,11 @@ pub async fn get_pg_database(
 ) -> Result<Database> {
     let pool = get_pg_pool(conns.clone(), pools.clone(), conn_id, None).await?;
     let db: Database = sqlx::query(
-        format!(
-            r#"
+        r#"
        SELECT 
             d.datname,
             r.rolname,
+            pg_catalog.pg_encoding_to_char(d.encoding) AS encoding,
             d.datctype,
             d.datcollate,
             t.spcname,
@@ -161,18 +177,17 @@ pub async fn get_pg_database(
             join pg_catalog.pg_roles r on d.datdba = r.oid
             join pg_catalog.pg_tablespace t on d.dattablespace = t.oid
        WHERE 
-            d.datname = '{}'
+            d.datname = $1
         "#,
-            db_name
-        )
-        .as_str(),
     )
+    .bind(db_name)
     .map(|r: PgRow| {
         let conn_limit: i32 = r.try_get("datconnlimit").unwrap();
 
         Database {
             name: r.try_get("datname").unwrap(),
             owner: r.try_get("rolname").unwrap(),
+            encoding: r.try_get("encoding").unwrap(),
             collation_order: r.try_get("datctype").unwrap(),
             character_class: r.try_get("datcollate").unwrap(),
             template: None,
@@ -194,6 +209,7 @@ pub async fn get_pg_databases(pool: &PgPool) -> Result<Vec<Database>> {
         SELECT
             d.datname,
             r.rolname,
+            pg_catalog.pg_encoding_to_char(d.encoding) AS encoding,
             d.datctype,
             d.datcollate,
             t.spcname,
@@ -217,6 +233,7 @@ pub async fn get_pg_databases(pool: &PgPool) -> Result<Vec<Database>> {
         Database {
             name: r.try_get("datname").unwrap(),
             owner: r.try_get("rolname").unwrap(),
+            encoding: r.try_get("encoding").unwrap(),
             collation_order: r.try_get("datctype").unwrap(),
             character_class: r.try_get("datcollate").unwrap(),
             template: None,
@@ -247,3 +264,24 @@ pub async fn get_pg_db_names(pool: &PgPool) -> Result<Vec<String>> {
 
     Ok(dbs)
 }
+pub async fn get_pg_server_info(
+    conns: Rc<RefCell<Connections>>,
+    pools: Rc<RefCell<PGPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+) -> Result<ServerInfo> {
+    let pool = get_pg_pool(conns, pools, conn_id, db_name).await?;
+    let version: String = sqlx::query("SHOW server_version")
+        .fetch_one(&pool)
+        .await?
+        .try_get(0)
+        .unwrap();
+    let row = sqlx::query("SELECT current_user::text, current_database()::text")
+        .fetch_one(&pool)
+        .await?;
+    Ok(ServerInfo {
+        version,
+        user: row.try_get(0).unwrap(),
+        database: row.try_get(1).unwrap(),
+    })
+}
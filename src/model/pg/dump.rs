@@ -0,0 +1,163 @@
+use super::{
+    get_pg_field_literal, get_pg_fields, get_pg_table_names, get_pg_views, quote_ident,
+    quote_literal,
+};
+use crate::model::{sort_tables_by_dependency, DumpOptions};
+use anyhow::{anyhow, Result};
+use sqlx::{PgPool, Row};
+
+pub async fn dump_pg_schema(pool: &PgPool, schema: &str, options: &DumpOptions) -> Result<String> {
+    let deps: Vec<(String, String)> = sqlx::query(
+        "SELECT cl.relname::text AS table_name, rcl.relname::text AS ref_name
+        FROM pg_constraint c
+        JOIN pg_class cl ON cl.oid = c.conrelid
+        JOIN pg_class rcl ON rcl.oid = c.confrelid
+        WHERE c.contype = 'f' AND cl.relnamespace = $1::regnamespace AND rcl.relnamespace = $1::regnamespace",
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| {
+        (
+            r.try_get("table_name").unwrap(),
+            r.try_get("ref_name").unwrap(),
+        )
+    })
+    .collect();
+    let mut tables = get_pg_table_names(pool, schema).await?;
+    if let Some(only) = options.tables.as_ref() {
+        tables.retain(|t| only.contains(t));
+    }
+    tables.sort();
+    let tables = sort_tables_by_dependency(tables, &deps);
+
+    let mut ddl = vec![format!("-- Dump of schema \"{}\"", schema)];
+    let mut triggers_ddl = Vec::new();
+    for table in tables.iter() {
+        let regclass = format!("{}.{}", quote_ident(schema), quote_ident(table));
+        let fields = get_pg_fields(pool, schema, table).await?;
+        let mut table_ddl = Vec::new();
+        let mut comments_ddl = Vec::new();
+        fields.iter().for_each(|f| {
+            let (field_ddl, comment_ddl) = f.get_create_ddl(schema, table);
+            table_ddl.push(field_ddl);
+            if let Some(c) = comment_ddl {
+                comments_ddl.push(c);
+            }
+        });
+        let keys: Vec<String> = fields
+            .iter()
+            .filter(|f| f.key())
+            .map(|f| quote_ident(f.name()))
+            .collect();
+        if !keys.is_empty() {
+            table_ddl.push(format!(" PRIMARY KEY ({})", keys.join(",")));
+        }
+        sqlx::query(
+            "SELECT conname::text AS name, pg_get_constraintdef(oid) AS def
+            FROM pg_constraint
+            WHERE conrelid = $1::regclass AND contype IN ('f', 'u', 'c', 'x')
+            ORDER BY contype, conname",
+        )
+        .bind(&regclass)
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .for_each(|r| {
+            table_ddl.push(format!(
+                " CONSTRAINT {} {}",
+                quote_ident(&r.try_get::<String, _>("name").unwrap()),
+                r.try_get::<String, _>("def").unwrap()
+            ))
+        });
+        ddl.push(format!(
+            "\n-- Table {}\nCREATE TABLE {} (\n{}\n);",
+            regclass,
+            regclass,
+            table_ddl.join(",\n")
+        ));
+
+        sqlx::query(
+            "SELECT pg_get_indexdef(indexrelid) AS def
+            FROM pg_index
+            WHERE indrelid = $1::regclass
+            AND indexrelid NOT IN (SELECT conindid FROM pg_constraint WHERE conrelid = $1::regclass)",
+        )
+        .bind(&regclass)
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .for_each(|r| ddl.push(format!("{};", r.try_get::<String, _>("def").unwrap())));
+
+        let comment: Option<String> =
+            sqlx::query("SELECT obj_description($1::regclass) AS comment")
+                .bind(&regclass)
+                .fetch_one(pool)
+                .await?
+                .try_get("comment")?;
+        if let Some(c) = comment {
+            ddl.push(format!(
+                "COMMENT ON TABLE {} IS {};",
+                regclass,
+                quote_literal(&c)
+            ));
+        }
+        ddl.append(&mut comments_ddl);
+
+        if options.with_data {
+            let rows = sqlx::query(&format!("SELECT * FROM {}", regclass))
+                .fetch_all(pool)
+                .await?;
+            for (i, row) in rows.iter().enumerate() {
+                let values: Vec<String> = fields
+                    .iter()
+                    .map(|field| {
+                        let value = get_pg_field_literal(field, row).map_err(|e| {
+                            anyhow!(
+                                "Column {} of row {} in table {} could not be decoded: {}",
+                                field.name(),
+                                i + 1,
+                                table,
+                                e
+                            )
+                        })?;
+                        Ok(value.unwrap_or_else(|| "NULL".to_string()))
+                    })
+                    .collect::<Result<Vec<String>>>()?;
+                ddl.push(format!(
+                    "INSERT INTO {} VALUES ({});",
+                    regclass,
+                    values.join(", ")
+                ));
+            }
+        }
+        if options.triggers {
+            sqlx::query(
+                "SELECT pg_get_triggerdef(oid) AS def FROM pg_trigger WHERE tgrelid = $1::regclass AND NOT tgisinternal ORDER BY tgname",
+            )
+            .bind(&regclass)
+            .fetch_all(pool)
+            .await?
+            .iter()
+            .for_each(|r| triggers_ddl.push(format!("{};", r.try_get::<String, _>("def").unwrap())));
+        }
+    }
+    if options.views {
+        get_pg_views(pool, schema).await?.iter().for_each(|view| {
+            ddl.push(format!(
+                "\n-- View \"{}\".\"{}\"\nCREATE OR REPLACE VIEW {}.{} AS\n{};",
+                schema,
+                view.name,
+                quote_ident(schema),
+                quote_ident(&view.name),
+                view.definition.trim_end().trim_end_matches(';')
+            ));
+        });
+    }
+    if !triggers_ddl.is_empty() {
+        ddl.push(String::new());
+        ddl.append(&mut triggers_ddl);
+    }
+    Ok(ddl.join("\n"))
+}
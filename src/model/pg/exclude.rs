@@ -1,4 +1,4 @@
-use super::index::IndexMethod;
+use super::{comment_literal, index::IndexMethod, quote_ident, quote_literal};
 use sqlx::{postgres::PgRow, Row};
 use std::fmt;
 use uuid::Uuid;
@@ -33,8 +33,8 @@ impl Exclude {
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         (
             format!(
-                r#"CONSTRAINT "{}" EXCLUDE ({})"#,
-                self.name(),
+                "CONSTRAINT {} EXCLUDE ({})",
+                quote_ident(self.name()),
                 self.element()
                     .iter()
                     .map(|e| e.get_create_ddl())
@@ -43,11 +43,11 @@ impl Exclude {
             ),
             self.comment().map(|c| {
                 format!(
-                    r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}';"#,
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    c
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    quote_literal(c)
                 )
             }),
         )
@@ -57,7 +57,7 @@ impl Exclude {
         (format!("ADD {}", exclude_ddl), comment_ddl)
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP CONSTRAINT {}", self.name)
+        format!("DROP CONSTRAINT {}", quote_ident(&self.name))
     }
     pub fn get_alter_ddl(
         &self,
@@ -67,11 +67,11 @@ impl Exclude {
     ) -> (Vec<String>, Option<String>) {
         let comment = if old.comment() != self.comment() {
             Some(format!(
-                r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}'"#,
-                self.name(),
-                schema_name,
-                table_name,
-                self.comment().unwrap_or(""),
+                "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                quote_ident(self.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                comment_literal(self.comment())
             ))
         } else {
             None
@@ -83,7 +83,9 @@ impl Exclude {
         if other.name != self.name {
             ddl.push(format!(
                 "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
-                table_name, other.name, self.name
+                quote_ident(table_name),
+                quote_ident(&other.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
@@ -163,8 +165,8 @@ impl ExcludeElement {
             String::from("")
         };
         format!(
-            "\"{}\"{}{}{}{} ",
-            self.element(),
+            "{}{}{}{}{} ",
+            quote_ident(self.element()),
             operator_class,
             self.order().unwrap_or(""),
             self.nulls_order().unwrap_or(""),
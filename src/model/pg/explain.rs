@@ -0,0 +1,112 @@
+use anyhow::{Error, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+pub struct PlanNode {
+    pub label: String,
+    pub depth: usize,
+    pub startup_cost: f64,
+    pub total_cost: f64,
+    pub plan_rows: f64,
+    pub actual_rows: Option<f64>,
+    pub actual_time: Option<f64>,
+    pub loops: Option<f64>,
+    pub self_cost: f64,
+}
+
+pub struct Plan {
+    pub nodes: Vec<PlanNode>,
+    pub planning_time: Option<f64>,
+    pub execution_time: Option<f64>,
+}
+
+impl Plan {
+    pub fn has_children(&self, index: usize) -> bool {
+        self.nodes
+            .get(index + 1)
+            .map_or(false, |n| n.depth > self.nodes[index].depth)
+    }
+    pub fn get_visible_nodes(&self, collapsed: &HashSet<usize>) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut hidden_depth: Option<usize> = None;
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(depth) = hidden_depth {
+                if node.depth > depth {
+                    continue;
+                }
+                hidden_depth = None;
+            }
+            visible.push(i);
+            if collapsed.contains(&i) {
+                hidden_depth = Some(node.depth);
+            }
+        }
+        visible
+    }
+    pub fn get_most_expensive_node(&self) -> Option<usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.self_cost.total_cmp(&b.self_cost))
+            .map(|(i, _)| i)
+    }
+}
+
+pub fn parse_pg_plan(value: &Value) -> Result<Plan> {
+    let root = value
+        .get(0)
+        .ok_or_else(|| Error::msg("Empty EXPLAIN output"))?;
+    let plan = root
+        .get("Plan")
+        .ok_or_else(|| Error::msg("EXPLAIN output has no plan"))?;
+    let mut nodes = Vec::new();
+    add_plan_node(plan, 0, &mut nodes);
+    Ok(Plan {
+        nodes,
+        planning_time: root.get("Planning Time").and_then(|v| v.as_f64()),
+        execution_time: root.get("Execution Time").and_then(|v| v.as_f64()),
+    })
+}
+fn add_plan_node(plan: &Value, depth: usize, nodes: &mut Vec<PlanNode>) {
+    let get_str = |key: &str| plan.get(key).and_then(|v| v.as_str());
+    let get_f64 = |key: &str| plan.get(key).and_then(|v| v.as_f64());
+
+    let mut label = get_str("Node Type").unwrap_or("Unknown").to_string();
+    if let Some(join_type) = get_str("Join Type").filter(|j| *j != "Inner") {
+        label.push_str(&format!(" ({})", join_type));
+    }
+    if let Some(index_name) = get_str("Index Name") {
+        label.push_str(&format!(" using {}", index_name));
+    }
+    if let Some(relation_name) = get_str("Relation Name") {
+        label.push_str(&format!(" on {}", relation_name));
+        if let Some(alias) = get_str("Alias").filter(|a| *a != relation_name) {
+            label.push_str(&format!(" {}", alias));
+        }
+    }
+
+    let total_cost = get_f64("Total Cost").unwrap_or_default();
+    let children = plan
+        .get("Plans")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let children_cost = children
+        .iter()
+        .filter_map(|c| c.get("Total Cost").and_then(|v| v.as_f64()))
+        .sum::<f64>();
+    nodes.push(PlanNode {
+        label,
+        depth,
+        startup_cost: get_f64("Startup Cost").unwrap_or_default(),
+        total_cost,
+        plan_rows: get_f64("Plan Rows").unwrap_or_default(),
+        actual_rows: get_f64("Actual Rows"),
+        actual_time: get_f64("Actual Total Time"),
+        loops: get_f64("Actual Loops"),
+        self_cost: (total_cost - children_cost).max(0.0),
+    });
+    for child in children.iter() {
+        add_plan_node(child, depth + 1, nodes);
+    }
+}
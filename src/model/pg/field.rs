@@ -1,11 +1,18 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use super::{comment_literal, quote_ident, quote_literal};
+use crate::model::{format_decimal, DisplayFormat};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use regex::Regex;
 use sqlx::{
-    postgres::{PgColumn, PgPool, PgRow},
+    postgres::{
+        types::{Oid, PgInterval, PgMoney, PgTimeTz},
+        PgColumn, PgHasArrayType, PgPool, PgRow,
+    },
+    types::{mac_address::MacAddress, BigDecimal, BitVec, JsonValue, Uuid as SqlxUuid},
     Column, Postgres, Row, TypeInfo,
 };
+use std::net::IpAddr;
 use strum::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
-use time::{Date, Time};
 use uuid::Uuid;
 
 #[derive(Default, EnumIter, Display, AsRefStr, EnumString, Clone, IntoStaticStr, PartialEq, Eq)]
@@ -64,8 +71,10 @@ pub struct Field {
     pub kind: FieldKind,
     pub not_null: bool,
     pub key: bool,
+    pub array: bool,
     pub comment: Option<String>,
     pub default_value: Option<String>,
+    pub default_expression: bool,
     pub length: Option<i32>,
     pub decimal: Option<i32>,
 }
@@ -86,12 +95,40 @@ impl Field {
     pub fn key(&self) -> bool {
         self.key
     }
+    pub fn is_array(&self) -> bool {
+        self.array
+    }
+    // Rows can only be matched by equality on scalar columns with an exact text form.
+    pub fn is_comparable(&self) -> bool {
+        !self.array
+            && !matches!(
+                self.kind,
+                FieldKind::Float4
+                    | FieldKind::Float8
+                    | FieldKind::Json
+                    | FieldKind::Jsonb
+                    | FieldKind::Money
+                    | FieldKind::Xml
+                    | FieldKind::TsQuery
+                    | FieldKind::TsVector
+                    | FieldKind::Box
+                    | FieldKind::Circle
+                    | FieldKind::Line
+                    | FieldKind::Lseg
+                    | FieldKind::Path
+                    | FieldKind::Point
+                    | FieldKind::Polygon
+            )
+    }
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
     pub fn default_value(&self) -> Option<&str> {
         self.default_value.as_deref()
     }
+    pub fn default_expression(&self) -> bool {
+        self.default_expression
+    }
     pub fn length(&self) -> Option<String> {
         self.length.map(|l| l.to_string())
     }
@@ -99,7 +136,7 @@ impl Field {
         self.decimal.map(|d| d.to_string())
     }
     fn get_kind_ddl(&self) -> String {
-        match self.kind {
+        let kind = match self.kind {
             FieldKind::VarChar
             | FieldKind::Char
             | FieldKind::Interval
@@ -127,48 +164,64 @@ impl Field {
                 }
             }
             _ => self.kind.to_string(),
+        };
+        if self.array {
+            format!("{}[]", kind)
+        } else {
+            kind
         }
     }
-    pub fn get_default_value_ddl(&self) -> String {
-        if let Some(de_val) = self.default_value() {
-            if !de_val.is_empty() {
-                let val = match self.kind {
-                    FieldKind::VarChar
-                    | FieldKind::Char
-                    | FieldKind::Time
-                    | FieldKind::Timestamp
-                    | FieldKind::TimestampTz
-                    | FieldKind::TimeTz
-                    | FieldKind::VarBit
-                    | FieldKind::Bit => {
-                        format!("'{}'", de_val)
-                    }
-                    _ => String::from(de_val),
-                };
-                format!(r#" DEFAULT {}"#, val)
-            } else {
-                String::from("")
+    fn get_default_value(&self) -> Option<String> {
+        let de_val = self.default_value().filter(|d| !d.is_empty())?;
+        if self.default_expression {
+            return Some(String::from(de_val));
+        }
+        let val = match self.kind {
+            FieldKind::BigSerial
+            | FieldKind::Bool
+            | FieldKind::Decimal
+            | FieldKind::Float4
+            | FieldKind::Float8
+            | FieldKind::Int2
+            | FieldKind::Int4
+            | FieldKind::Int8
+            | FieldKind::Numeric
+            | FieldKind::Serial
+            | FieldKind::Serial2
+            | FieldKind::Serial4
+            | FieldKind::Serial8
+            | FieldKind::SmallSerial
+                if !self.array =>
+            {
+                String::from(de_val)
             }
+            _ => quote_literal(de_val),
+        };
+        Some(val)
+    }
+    pub fn get_default_value_ddl(&self) -> String {
+        if let Some(val) = self.get_default_value() {
+            format!(r#" DEFAULT {}"#, val)
         } else {
             String::from("")
         }
     }
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         let field_ddl = format!(
-            r#" "{}" {} {}{}"#,
-            self.name,
+            " {} {} {}{}",
+            quote_ident(&self.name),
             self.get_kind_ddl(),
             if self.not_null { "NOT NULL" } else { "" },
-            self.get_default_value_ddl(),
+            self.get_default_value_ddl()
         );
 
         let comment_ddl = if let Some(comment) = self.comment() {
             Some(format!(
-                "COMMENT ON COLUMN \"{}\".\"{}\".\"{}\" IS '{}';",
-                schema_name,
-                table_name,
-                self.name(),
-                comment
+                "COMMENT ON COLUMN {}.{}.{} IS {};",
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_ident(self.name()),
+                quote_literal(comment)
             ))
         } else {
             None
@@ -176,7 +229,7 @@ impl Field {
         (field_ddl, comment_ddl)
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP COLUMN {}", self.name)
+        format!("DROP COLUMN {}", quote_ident(&self.name))
     }
     pub fn get_add_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         let (field_ddl, comment_ddl) = self.get_create_ddl(schema_name, table_name);
@@ -189,34 +242,47 @@ impl Field {
         table_name: &str,
     ) -> (Vec<String>, Option<String>) {
         let mut ddl = Vec::new();
-        if old.kind != self.kind || old.length != self.length || old.decimal != self.decimal {
+        if old.kind != self.kind
+            || old.array != self.array
+            || old.length != self.length
+            || old.decimal != self.decimal
+        {
             ddl.push(format!(
-                "ALTER COLUMN \"{}\" TYPE {}",
-                self.name,
+                "ALTER COLUMN {} TYPE {}",
+                quote_ident(&self.name),
                 self.get_kind_ddl()
             ));
         }
-        if old.default_value != self.default_value {
-            if let Some(dv) = self.default_value() {
-                ddl.push(format!("ALTER COLUMN \"{}\" SET DEFAULT {}", self.name, dv));
+        if old.default_value != self.default_value
+            || old.default_expression != self.default_expression
+        {
+            if let Some(dv) = self.get_default_value() {
+                ddl.push(format!(
+                    "ALTER COLUMN {} SET DEFAULT {}",
+                    quote_ident(&self.name),
+                    dv
+                ));
             } else {
-                ddl.push(format!("ALTER COLUMN \"{}\" DROP DEFAULT", self.name));
+                ddl.push(format!(
+                    "ALTER COLUMN {} DROP DEFAULT",
+                    quote_ident(&self.name)
+                ));
             }
         }
         if old.not_null != self.not_null {
             ddl.push(format!(
-                "ALTER COLUMN \"{}\" {} NOT NULL",
-                self.name,
+                "ALTER COLUMN {} {} NOT NULL",
+                quote_ident(&self.name),
                 if self.not_null { "SET" } else { "DROP" }
             ));
         }
         let comment_ddl = if old.comment != self.comment {
             Some(format!(
-                "COMMENT ON COLUMN \"{}\".\"{}\".\"{}\" IS '{}';",
-                schema_name,
-                table_name,
-                self.name(),
-                self.comment().unwrap_or("")
+                "COMMENT ON COLUMN {}.{}.{} IS {};",
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_ident(self.name()),
+                comment_literal(self.comment())
             ))
         } else {
             None
@@ -227,8 +293,11 @@ impl Field {
         let mut ddl = Vec::new();
         if old.name != self.name {
             ddl.push(format!(
-                "ALTER TABLE \"{}\".\"{}\" RENAME COLUMN {} TO {};",
-                schema_name, table_name, old.name, self.name
+                "ALTER TABLE {}.{} RENAME COLUMN {} TO {};",
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_ident(&old.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
@@ -236,7 +305,9 @@ impl Field {
 }
 
 pub async fn get_pg_field_names(pool: &PgPool, schema: &str, table: &str) -> Result<Vec<String>> {
-    let fields: Vec<String> = sqlx::query(format!("select column_name from information_schema.columns where table_schema = '{}' and table_name = '{}'",schema, table).as_str())
+    let fields: Vec<String> = sqlx::query("select column_name from information_schema.columns where table_schema = $1 and table_name = $2")
+        .bind(schema)
+        .bind(table)
         .fetch_all(pool)
         .await?
         .iter()
@@ -244,21 +315,121 @@ pub async fn get_pg_field_names(pool: &PgPool, schema: &str, table: &str) -> Res
         .collect();
     Ok(fields)
 }
-pub fn get_pg_field_value(field: &Field, row: &PgRow) -> String {
-    fn get_value<'r, T>(field: &Field, row: &'r PgRow) -> String
+pub async fn get_pg_visible_field_names(pool: &PgPool, table: &str) -> Result<Vec<String>> {
+    let fields: Vec<String> = sqlx::query(
+        "select column_name from information_schema.columns where table_schema = ANY(current_schemas(false)) and table_name = $1 order by ordinal_position",
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|t| t.try_get("column_name").unwrap())
+    .collect();
+    Ok(fields)
+}
+pub async fn get_pg_fields(pool: &PgPool, schema: &str, table: &str) -> Result<Vec<Field>> {
+    let fields = sqlx::query(
+        "SELECT
+                col_description((table_schema||'.'||table_name)::regclass::oid, ordinal_position) as comment,
+                *
+            FROM
+                information_schema.columns
+            WHERE
+                table_schema = $1 AND table_name = $2
+            ORDER BY ordinal_position ASC",
+    )
+    .bind(schema)
+    .bind(table)
+    .fetch_all(pool)
+    .await?;
+    let keys: Vec<String> = sqlx::query(
+        "SELECT
+                a.attname
+            FROM
+                pg_index i
+            JOIN pg_attribute a
+                ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+            WHERE i.indrelid = $1::regclass AND i.indisprimary",
+    )
+    .bind(format!("{}.{}", quote_ident(schema), quote_ident(table)))
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|k| k.try_get("attname").unwrap())
+    .collect();
+    Ok(convert_show_column_to_pg_fields(fields, keys))
+}
+pub fn get_pg_fields_compare_ddl(
+    schema: &str,
+    table: &str,
+    source: &[Field],
+    target: &[Field],
+) -> String {
+    let mut alter_table_ddl = Vec::new();
+    let mut comments_ddl = Vec::new();
+    target
+        .iter()
+        .filter(|f| !source.iter().any(|s| s.name() == f.name()))
+        .for_each(|f| alter_table_ddl.push(f.get_drop_ddl()));
+    source.iter().for_each(|field| {
+        let (mut field_ddl, comment_ddl) =
+            if let Some(old) = target.iter().find(|f| f.name() == field.name()) {
+                field.get_alter_ddl(old, schema, table)
+            } else {
+                let (field_ddl, comment_ddl) = field.get_add_ddl(schema, table);
+                (vec![field_ddl], comment_ddl)
+            };
+        alter_table_ddl.append(&mut field_ddl);
+        if let Some(comment) = comment_ddl {
+            comments_ddl.push(comment);
+        }
+    });
+    let mut ddl = Vec::new();
+    if !alter_table_ddl.is_empty() {
+        ddl.push(format!(
+            "ALTER TABLE {}.{}",
+            quote_ident(schema),
+            quote_ident(table)
+        ));
+        ddl.push(format!("{};", alter_table_ddl.join(",\n")));
+    }
+    ddl.append(&mut comments_ddl);
+    ddl.join("\n")
+}
+pub fn get_pg_field_value(
+    field: &Field,
+    row: &PgRow,
+    display_format: &DisplayFormat,
+) -> Option<String> {
+    fn get_value<'r, T>(field: &Field, row: &'r PgRow) -> Option<String>
     where
         T: std::fmt::Display + sqlx::Decode<'r, Postgres> + sqlx::Type<Postgres>,
     {
         let col_name = field.name();
         if !field.not_null() {
             let i: Option<T> = row.try_get(col_name).unwrap();
-            i.map(|i| i.to_string()).unwrap_or_default()
+            i.map(|i| i.to_string())
         } else {
             let i: T = row.try_get(col_name).unwrap();
-            i.to_string()
+            Some(i.to_string())
         }
     }
 
+    if field.is_array() {
+        let col_name = field.name();
+        return match field.kind() {
+            FieldKind::VarChar | FieldKind::Char | FieldKind::Text => {
+                get_array_value::<String>(col_name, row)
+            }
+            FieldKind::Int2 => get_array_value::<i16>(col_name, row),
+            FieldKind::Int4 => get_array_value::<i32>(col_name, row),
+            FieldKind::Int8 => get_array_value::<i64>(col_name, row),
+            FieldKind::Float4 => get_array_value::<f32>(col_name, row),
+            FieldKind::Float8 => get_array_value::<f64>(col_name, row),
+            FieldKind::Bool => get_array_value::<bool>(col_name, row),
+            kind => Some(format!("{}[]", kind)),
+        };
+    }
     match field.kind() {
         FieldKind::VarChar | FieldKind::Char | FieldKind::Text => get_value::<String>(field, row),
         FieldKind::Int2 | FieldKind::Serial2 | FieldKind::SmallSerial => {
@@ -266,58 +437,437 @@ pub fn get_pg_field_value(field: &Field, row: &PgRow) -> String {
         }
         FieldKind::Int4 | FieldKind::Serial4 | FieldKind::Serial => get_value::<i32>(field, row),
         FieldKind::Int8 | FieldKind::Serial8 | FieldKind::BigSerial => get_value::<i64>(field, row),
-        FieldKind::Numeric | FieldKind::Decimal => get_value::<f32>(field, row),
+        FieldKind::Numeric | FieldKind::Decimal => {
+            let d: Option<BigDecimal> = row.try_get(field.name()).unwrap();
+            d.map(|d| format_decimal(&d, field.decimal.map(|s| s as i64)))
+        }
         FieldKind::Float4 => get_value::<f32>(field, row),
         FieldKind::Float8 | FieldKind::Money => get_value::<f64>(field, row),
-        FieldKind::Bit | FieldKind::VarBit => "Bit".to_string(),
-        FieldKind::Json | FieldKind::Jsonb => "Json".to_string(),
-        FieldKind::Point => "Point".to_string(),
-        FieldKind::Polygon => "Polygon".to_string(),
-        FieldKind::Time | FieldKind::TimeTz => get_value::<Time>(field, row),
-        FieldKind::Timestamp | FieldKind::TimestampTz => get_value::<DateTime<Utc>>(field, row),
+        FieldKind::Bit | FieldKind::VarBit => Some("Bit".to_string()),
+        FieldKind::Json | FieldKind::Jsonb => Some("Json".to_string()),
+        FieldKind::Point => Some("Point".to_string()),
+        FieldKind::Polygon => Some("Polygon".to_string()),
+        FieldKind::Time => {
+            let t: Option<NaiveTime> = row.try_get(field.name()).unwrap();
+            t.map(|t| display_format.format_time(&t))
+        }
+        FieldKind::TimeTz => {
+            let t: Option<PgTimeTz<NaiveTime, FixedOffset>> = row.try_get(field.name()).unwrap();
+            t.map(|t| format!("{}{}", display_format.format_time(&t.time), t.offset))
+        }
+        FieldKind::Timestamp => {
+            let d: Option<NaiveDateTime> = row.try_get(field.name()).unwrap();
+            d.map(|d| display_format.format_naive_datetime(&d))
+        }
+        FieldKind::TimestampTz => {
+            let d: Option<DateTime<Utc>> = row.try_get(field.name()).unwrap();
+            d.map(|d| display_format.format_datetime(&d))
+        }
         FieldKind::Bool => get_value::<bool>(field, row),
-        FieldKind::Box => "Box".to_string(),
-        FieldKind::Bytea => "Bytea".to_string(),
-        FieldKind::Cidr | FieldKind::Inet | FieldKind::Macaddr => get_value::<String>(field, row),
-        FieldKind::Date => get_value::<Date>(field, row),
-        FieldKind::Circle => "Circle".to_string(),
-        FieldKind::Interval => "Interval".to_string(),
-        FieldKind::Line => "Line".to_string(),
-        FieldKind::Lseg => "Lseg".to_string(),
-        FieldKind::Path => "Path".to_string(),
-        FieldKind::TsQuery => "TsQuery".to_string(),
-        FieldKind::TsVector => "TsVector".to_string(),
-        FieldKind::Uuid => "Uuid".to_string(),
-        FieldKind::Xml => "Xml".to_string(),
+        FieldKind::Box => Some("Box".to_string()),
+        FieldKind::Bytea => Some("Bytea".to_string()),
+        FieldKind::Cidr | FieldKind::Inet => get_inet_value(field.name(), row),
+        FieldKind::Macaddr => {
+            let mac: Option<MacAddress> = row.try_get(field.name()).unwrap();
+            mac.map(|m| format_macaddr(&m))
+        }
+        FieldKind::Date => {
+            let d: Option<NaiveDate> = row.try_get(field.name()).unwrap();
+            d.map(|d| display_format.format_date(&d))
+        }
+        FieldKind::Circle => Some("Circle".to_string()),
+        FieldKind::Interval => get_interval_value(field.name(), row),
+        FieldKind::Line => Some("Line".to_string()),
+        FieldKind::Lseg => Some("Lseg".to_string()),
+        FieldKind::Path => Some("Path".to_string()),
+        FieldKind::TsQuery => Some("TsQuery".to_string()),
+        FieldKind::TsVector => Some("TsVector".to_string()),
+        FieldKind::Uuid => get_value::<SqlxUuid>(field, row),
+        FieldKind::Xml => Some("Xml".to_string()),
+    }
+}
+
+// Stored value in PostgreSQL's text form at full precision, without display formatting. Text
+// search types and arrays of anything but numbers, strings and booleans are rejected.
+pub fn get_pg_field_text(field: &Field, row: &PgRow) -> Result<Option<String>> {
+    fn get_text<'r, T>(
+        field: &Field,
+        row: &'r PgRow,
+        format: impl Fn(T) -> String,
+    ) -> Result<Option<String>>
+    where
+        T: sqlx::Decode<'r, Postgres> + sqlx::Type<Postgres>,
+    {
+        let value: Option<T> = row.try_get(field.name())?;
+        Ok(value.map(format))
+    }
+
+    fn get_array<T>(field: &Field, row: &PgRow) -> Result<Option<String>>
+    where
+        T: std::fmt::Display
+            + for<'r> sqlx::Decode<'r, Postgres>
+            + sqlx::Type<Postgres>
+            + PgHasArrayType,
+    {
+        let items: Option<Vec<Option<T>>> = row.try_get(field.name())?;
+        Ok(items.map(|items| format_array(&items)))
+    }
+
+    if field.is_array() {
+        return match field.kind() {
+            FieldKind::VarChar | FieldKind::Char | FieldKind::Text => {
+                get_array::<String>(field, row)
+            }
+            FieldKind::Int2 => get_array::<i16>(field, row),
+            FieldKind::Int4 => get_array::<i32>(field, row),
+            FieldKind::Int8 => get_array::<i64>(field, row),
+            FieldKind::Numeric | FieldKind::Decimal => get_array::<BigDecimal>(field, row),
+            FieldKind::Float4 => get_array::<f32>(field, row),
+            FieldKind::Float8 => get_array::<f64>(field, row),
+            FieldKind::Bool => get_array::<bool>(field, row),
+            _ => Err(anyhow!("Column {} has no text form", field.name())),
+        };
+    }
+    match field.kind() {
+        FieldKind::VarChar | FieldKind::Char | FieldKind::Text => {
+            get_text::<String>(field, row, |v| v)
+        }
+        FieldKind::Xml => Ok(row.try_get_unchecked::<Option<String>, _>(field.name())?),
+        FieldKind::Int2 | FieldKind::Serial2 | FieldKind::SmallSerial => {
+            get_text::<i16>(field, row, |v| v.to_string())
+        }
+        FieldKind::Int4 | FieldKind::Serial4 | FieldKind::Serial => {
+            get_text::<i32>(field, row, |v| v.to_string())
+        }
+        FieldKind::Int8 | FieldKind::Serial8 | FieldKind::BigSerial => {
+            get_text::<i64>(field, row, |v| v.to_string())
+        }
+        FieldKind::Numeric | FieldKind::Decimal => {
+            get_text::<BigDecimal>(field, row, |v| v.to_string())
+        }
+        FieldKind::Bool => get_text::<bool>(field, row, |v| v.to_string()),
+        FieldKind::Bit | FieldKind::VarBit => get_text::<BitVec>(field, row, |v| {
+            v.iter().map(|b| if b { '1' } else { '0' }).collect()
+        }),
+        FieldKind::Bytea => get_text::<Vec<u8>>(field, row, |v| {
+            v.iter()
+                .fold(String::from("\\x"), |s, b| s + &format!("{:02x}", b))
+        }),
+        FieldKind::Date => get_text::<NaiveDate>(field, row, |v| v.format("%Y-%m-%d").to_string()),
+        FieldKind::Time => {
+            get_text::<NaiveTime>(field, row, |v| v.format("%H:%M:%S%.f").to_string())
+        }
+        FieldKind::TimeTz => get_text::<PgTimeTz<NaiveTime, FixedOffset>>(field, row, |v| {
+            format!("{}{}", v.time.format("%H:%M:%S%.f"), v.offset)
+        }),
+        FieldKind::Timestamp => {
+            get_text::<NaiveDateTime>(field, row, |v| v.format("%Y-%m-%d %H:%M:%S%.f").to_string())
+        }
+        FieldKind::TimestampTz => get_text::<DateTime<Utc>>(field, row, |v| {
+            v.format("%Y-%m-%d %H:%M:%S%.f+00:00").to_string()
+        }),
+        FieldKind::Uuid => get_text::<SqlxUuid>(field, row, |v| v.to_string()),
+        FieldKind::Macaddr => get_text::<MacAddress>(field, row, |v| format_macaddr(&v)),
+        FieldKind::Interval => get_text::<PgInterval>(field, row, |v| format_interval(&v)),
+        FieldKind::Cidr | FieldKind::Inet => {
+            let bytes: Option<Vec<u8>> = row.try_get_unchecked(field.name())?;
+            match bytes {
+                Some(b) => format_inet(&b)
+                    .map(Some)
+                    .ok_or_else(|| anyhow!("Column {} could not be decoded", field.name())),
+                None => Ok(None),
+            }
+        }
+        FieldKind::Float4 => get_text::<f32>(field, row, |v| v.to_string()),
+        FieldKind::Float8 => get_text::<f64>(field, row, |v| v.to_string()),
+        FieldKind::Json | FieldKind::Jsonb => get_text::<JsonValue>(field, row, |v| v.to_string()),
+        FieldKind::Money => get_text::<PgMoney>(field, row, |v| v.to_bigdecimal(2).to_string()),
+        FieldKind::Box
+        | FieldKind::Circle
+        | FieldKind::Line
+        | FieldKind::Lseg
+        | FieldKind::Path
+        | FieldKind::Point
+        | FieldKind::Polygon => {
+            let bytes: Option<Vec<u8>> = row.try_get_unchecked(field.name())?;
+            match bytes {
+                Some(b) => format_geometry(field.kind(), &b)
+                    .map(Some)
+                    .ok_or_else(|| anyhow!("Column {} could not be decoded", field.name())),
+                None => Ok(None),
+            }
+        }
+        FieldKind::TsQuery | FieldKind::TsVector => {
+            Err(anyhow!("Column {} has no text form", field.name()))
+        }
     }
 }
 
-pub fn get_pg_column_value(column: &PgColumn, row: &PgRow) -> String {
+// SQL literal of the stored value, for matching the row in a WHERE clause. Floats and json have
+// no exact equality on their text form and are rejected as well.
+// SQL literal of the stored value at full precision, for row conditions and dumps.
+pub fn get_pg_field_literal(field: &Field, row: &PgRow) -> Result<Option<String>> {
+    let text = get_pg_field_text(field, row)?;
+    Ok(text.map(|v| match field.kind() {
+        FieldKind::Bytea if !field.is_array() => format!("{}::bytea", quote_literal(&v)),
+        FieldKind::Bit | FieldKind::VarBit if !field.is_array() => format!("B'{}'", v),
+        _ => quote_literal(&v),
+    }))
+}
+
+pub fn get_pg_column_value(
+    column: &PgColumn,
+    row: &PgRow,
+    display_format: &DisplayFormat,
+) -> Option<String> {
     let col_name = column.name();
-    fn get_numeric<'r, T>(col_name: &str, row: &'r PgRow) -> String
+    fn get_value<'r, T>(col_name: &str, row: &'r PgRow) -> Option<String>
     where
-        T: Default + std::fmt::Display + sqlx::Decode<'r, Postgres> + sqlx::Type<Postgres>,
+        T: std::fmt::Display + sqlx::Decode<'r, Postgres> + sqlx::Type<Postgres>,
     {
-        let i: Option<T> = row.try_get(col_name).unwrap();
-        i.unwrap_or_default().to_string()
+        match row.try_get::<Option<T>, _>(col_name) {
+            Ok(i) => i.map(|i| i.to_string()),
+            Err(_) => get_raw_value(col_name, row),
+        }
     }
 
     match column.type_info().name() {
-        "VARCHAR" | "CHAR" | "TEXT" => {
-            let value: Option<String> = row.try_get(col_name).unwrap();
-            value.unwrap_or_default()
-        }
-        "INT2" => get_numeric::<i16>(col_name, row),
-        "INT4" => get_numeric::<i32>(col_name, row),
-        "INT8" => get_numeric::<i64>(col_name, row),
-        "NUMERIC" | "DECIMAL" | "FLOAT4" => get_numeric::<f32>(col_name, row),
-        "FLOAT8" => get_numeric::<f64>(col_name, row),
-        "DATE" => "Date".to_string(),
-        "TIME" => "Time".to_string(),
-        _ => {
-            println!("{}", column.type_info().name());
-            String::new()
+        "VARCHAR" | "CHAR" | "BPCHAR" | "TEXT" | "NAME" => get_value::<String>(col_name, row),
+        "BOOL" => get_value::<bool>(col_name, row),
+        "INT2" => get_value::<i16>(col_name, row),
+        "INT4" => get_value::<i32>(col_name, row),
+        "INT8" => get_value::<i64>(col_name, row),
+        "OID" => {
+            let i: Option<Oid> = row.try_get(col_name).ok()?;
+            i.map(|i| i.0.to_string())
+        }
+        "NUMERIC" | "DECIMAL" => get_value::<BigDecimal>(col_name, row),
+        "MONEY" => {
+            let i: Option<PgMoney> = row.try_get(col_name).ok()?;
+            i.map(|i| i.to_bigdecimal(2).to_string())
+        }
+        "FLOAT4" => get_value::<f32>(col_name, row),
+        "FLOAT8" => get_value::<f64>(col_name, row),
+        "UUID" => get_value::<SqlxUuid>(col_name, row),
+        "JSON" | "JSONB" => get_value::<JsonValue>(col_name, row),
+        "INET" | "CIDR" => get_inet_value(col_name, row),
+        "MACADDR" => {
+            let mac: Option<MacAddress> = row.try_get(col_name).ok()?;
+            mac.map(|m| format_macaddr(&m))
+        }
+        "DATE" => {
+            let d: Option<NaiveDate> = row.try_get(col_name).ok()?;
+            d.map(|d| display_format.format_date(&d))
+        }
+        "TIME" => {
+            let t: Option<NaiveTime> = row.try_get(col_name).ok()?;
+            t.map(|t| display_format.format_time(&t))
         }
+        "TIMETZ" => {
+            let t: Option<PgTimeTz<NaiveTime, FixedOffset>> = row.try_get(col_name).ok()?;
+            t.map(|t| format!("{}{}", display_format.format_time(&t.time), t.offset))
+        }
+        "TIMESTAMP" => {
+            let d: Option<NaiveDateTime> = row.try_get(col_name).ok()?;
+            d.map(|d| display_format.format_naive_datetime(&d))
+        }
+        "TIMESTAMPTZ" => {
+            let d: Option<DateTime<Utc>> = row.try_get(col_name).ok()?;
+            d.map(|d| display_format.format_datetime(&d))
+        }
+        "INTERVAL" => get_interval_value(col_name, row),
+        "BIT" | "VARBIT" => {
+            let b: Option<BitVec> = row.try_get(col_name).ok()?;
+            b.map(|b| b.iter().map(|bit| if bit { '1' } else { '0' }).collect())
+        }
+        "BYTEA" => {
+            let b: Option<Vec<u8>> = row.try_get(col_name).ok()?;
+            b.map(|b| to_hex(&b))
+        }
+        "VARCHAR[]" | "CHAR[]" | "BPCHAR[]" | "TEXT[]" | "NAME[]" => {
+            get_array_value::<String>(col_name, row)
+        }
+        "BOOL[]" => get_array_value::<bool>(col_name, row),
+        "INT2[]" => get_array_value::<i16>(col_name, row),
+        "INT4[]" => get_array_value::<i32>(col_name, row),
+        "INT8[]" => get_array_value::<i64>(col_name, row),
+        "NUMERIC[]" => get_array_value::<BigDecimal>(col_name, row),
+        "FLOAT4[]" => get_array_value::<f32>(col_name, row),
+        "FLOAT8[]" => get_array_value::<f64>(col_name, row),
+        "UUID[]" => get_array_value::<SqlxUuid>(col_name, row),
+        "VOID" | "UNKNOWN" => None,
+        _ => get_raw_value(col_name, row),
+    }
+}
+fn get_array_value<T>(col_name: &str, row: &PgRow) -> Option<String>
+where
+    T: std::fmt::Display
+        + for<'r> sqlx::Decode<'r, Postgres>
+        + sqlx::Type<Postgres>
+        + PgHasArrayType,
+{
+    let items: Option<Vec<Option<T>>> = row.try_get(col_name).ok()?;
+    items.map(|items| format_array(&items))
+}
+fn format_array<T: std::fmt::Display>(items: &[Option<T>]) -> String {
+    let items = items
+        .iter()
+        .map(|i| match i {
+            Some(i) => get_array_item(&i.to_string()),
+            None => String::from("NULL"),
+        })
+        .collect::<Vec<String>>();
+    format!("{{{}}}", items.join(","))
+}
+fn get_array_item(item: &str) -> String {
+    if item.is_empty()
+        || item.eq_ignore_ascii_case("NULL")
+        || item
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '{' | '}' | ',' | '"' | '\\'))
+    {
+        format!("\"{}\"", item.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        item.to_string()
+    }
+}
+// Geometric values arrive in binary form as big-endian float8 coordinates.
+fn format_geometry(kind: &FieldKind, bytes: &[u8]) -> Option<String> {
+    fn floats(bytes: &[u8]) -> Option<Vec<f64>> {
+        let chunks = bytes.chunks_exact(8);
+        if !chunks.remainder().is_empty() {
+            return None;
+        }
+        Some(
+            chunks
+                .map(|c| f64::from_be_bytes(<[u8; 8]>::try_from(c).unwrap()))
+                .collect(),
+        )
+    }
+    fn points(v: &[f64]) -> String {
+        v.chunks(2)
+            .map(|p| format!("({},{})", p[0], p[1]))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+    fn point_list(bytes: &[u8]) -> Option<String> {
+        let count = u32::from_be_bytes(<[u8; 4]>::try_from(bytes.get(..4)?).ok()?) as usize;
+        let v = floats(&bytes[4..])?;
+        if v.len() != count * 2 {
+            return None;
+        }
+        Some(points(&v))
+    }
+
+    match kind {
+        FieldKind::Point => floats(bytes).filter(|v| v.len() == 2).map(|v| points(&v)),
+        FieldKind::Box => floats(bytes).filter(|v| v.len() == 4).map(|v| points(&v)),
+        FieldKind::Lseg => floats(bytes)
+            .filter(|v| v.len() == 4)
+            .map(|v| format!("[{}]", points(&v))),
+        FieldKind::Line => floats(bytes)
+            .filter(|v| v.len() == 3)
+            .map(|v| format!("{{{},{},{}}}", v[0], v[1], v[2])),
+        FieldKind::Circle => floats(bytes)
+            .filter(|v| v.len() == 3)
+            .map(|v| format!("<({},{}),{}>", v[0], v[1], v[2])),
+        FieldKind::Path => {
+            let p = point_list(bytes.get(1..)?)?;
+            if bytes[0] == 1 {
+                Some(format!("({})", p))
+            } else {
+                Some(format!("[{}]", p))
+            }
+        }
+        FieldKind::Polygon => point_list(bytes).map(|p| format!("({})", p)),
+        _ => None,
+    }
+}
+fn get_raw_value(col_name: &str, row: &PgRow) -> Option<String> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name).ok()?;
+    bytes.map(|b| String::from_utf8(b).unwrap_or_else(|e| to_hex(e.as_bytes())))
+}
+fn get_inet_value(col_name: &str, row: &PgRow) -> Option<String> {
+    let bytes: Option<Vec<u8>> = row.try_get_unchecked(col_name).ok()?;
+    bytes.and_then(|b| format_inet(&b))
+}
+fn format_inet(bytes: &[u8]) -> Option<String> {
+    let (addr, bits, is_cidr): (IpAddr, u8, bool) = match bytes {
+        [2, bits, is_cidr, 4, addr @ ..] => {
+            (<[u8; 4]>::try_from(addr).ok()?.into(), *bits, *is_cidr == 1)
+        }
+        [3, bits, is_cidr, 16, addr @ ..] => (
+            <[u8; 16]>::try_from(addr).ok()?.into(),
+            *bits,
+            *is_cidr == 1,
+        ),
+        _ => return None,
+    };
+    let max_bits = if addr.is_ipv4() { 32 } else { 128 };
+    if is_cidr || bits != max_bits {
+        Some(format!("{}/{}", addr, bits))
+    } else {
+        Some(addr.to_string())
+    }
+}
+fn format_macaddr(mac: &MacAddress) -> String {
+    mac.to_string().to_lowercase()
+}
+fn get_interval_value(col_name: &str, row: &PgRow) -> Option<String> {
+    let interval: Option<PgInterval> = row.try_get(col_name).ok()?;
+    interval.map(|i| format_interval(&i))
+}
+fn format_interval(interval: &PgInterval) -> String {
+    let mut parts = Vec::new();
+    let (years, months) = (interval.months / 12, interval.months % 12);
+    let unit =
+        |value: i32, name: &str| format!("{} {}{}", value, name, if value == 1 { "" } else { "s" });
+    if years != 0 {
+        parts.push(unit(years, "year"));
+    }
+    if months != 0 {
+        parts.push(unit(months, "mon"));
+    }
+    if interval.days != 0 {
+        parts.push(unit(interval.days, "day"));
+    }
+    if interval.microseconds != 0 || parts.is_empty() {
+        let micros = interval.microseconds.abs();
+        let secs = micros / 1_000_000;
+        let mut time = format!(
+            "{}{:02}:{:02}:{:02}",
+            if interval.microseconds < 0 { "-" } else { "" },
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        if micros % 1_000_000 != 0 {
+            time.push_str(format!(".{:06}", micros % 1_000_000).trim_end_matches('0'));
+        }
+        parts.push(time);
+    }
+    parts.join(" ")
+}
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::from("\\x"), |s, b| s + &format!("{:02x}", b))
+}
+
+fn parse_default_value(column_default: Option<String>) -> (Option<String>, bool) {
+    let default = match column_default {
+        Some(default) => default,
+        None => return (None, false),
+    };
+    let literal = Regex::new(r"^'((?:[^']|'')*)'(?:::[\w\s]+(?:\[\])?)?$").unwrap();
+    if let Some(caps) = literal.captures(&default) {
+        return (Some(caps[1].replace("''", "'")), false);
+    }
+    let constant = Regex::new(r"^(-?\d+(\.\d+)?|true|false)$").unwrap();
+    if constant.is_match(&default) {
+        (Some(default), false)
+    } else {
+        (Some(default), true)
     }
 }
 
@@ -328,6 +878,10 @@ pub fn convert_show_column_to_pg_fields(fields: Vec<PgRow>, key_names: Vec<Strin
             let name: String = r.try_get("column_name").unwrap();
             let key = key_names.contains(&name);
             let udt_name = r.try_get::<&str, _>("udt_name").unwrap();
+            let (udt_name, array) = match udt_name.strip_prefix('_') {
+                Some(name) => (name, true),
+                None => (udt_name, false),
+            };
 
             let kind = if let Ok(k) = FieldKind::try_from(udt_name) {
                 k
@@ -351,6 +905,8 @@ pub fn convert_show_column_to_pg_fields(fields: Vec<PgRow>, key_names: Vec<Strin
                 FieldKind::Decimal | FieldKind::Numeric => r.try_get("numeric_scale").unwrap(),
                 _ => None,
             };
+            let (default_value, default_expression) =
+                parse_default_value(r.try_get("column_default").unwrap());
             Field {
                 id: Uuid::new_v4(),
                 name,
@@ -361,11 +917,117 @@ pub fn convert_show_column_to_pg_fields(fields: Vec<PgRow>, key_names: Vec<Strin
                     true
                 },
                 key,
+                array,
                 comment: r.try_get("comment").unwrap(),
-                default_value: r.try_get("column_default").unwrap(),
+                default_value,
+                default_expression,
                 length,
                 decimal,
             }
         })
         .collect::<Vec<Field>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_ddl_escapes_literals() {
+        let field = Field {
+            id: Uuid::new_v4(),
+            name: String::from("name"),
+            kind: FieldKind::VarChar,
+            not_null: false,
+            key: false,
+            array: false,
+            comment: Some(String::from("owner's name")),
+            default_value: Some(String::from("O'Brien")),
+            default_expression: false,
+            length: Some(20),
+            decimal: None,
+        };
+        let (field_ddl, comment_ddl) = field.get_create_ddl("public", "users");
+        assert!(field_ddl.ends_with(" DEFAULT 'O''Brien'"));
+        assert_eq!(
+            comment_ddl.as_deref(),
+            Some(r#"COMMENT ON COLUMN "public"."users"."name" IS 'owner''s name';"#)
+        );
+    }
+
+    #[test]
+    fn test_format_inet() {
+        let v6 = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let cases: Vec<(Vec<u8>, &str)> = vec![
+            (vec![2, 32, 0, 4, 192, 168, 0, 1], "192.168.0.1"),
+            (vec![2, 24, 0, 4, 192, 168, 0, 1], "192.168.0.1/24"),
+            (vec![2, 24, 1, 4, 10, 0, 0, 0], "10.0.0.0/24"),
+            (vec![2, 32, 1, 4, 10, 0, 0, 1], "10.0.0.1/32"),
+            ([&[3, 128, 0, 16][..], &v6].concat(), "2001:db8::1"),
+            (
+                [&[3, 64, 1, 16][..], &v6[..8], &[0; 8]].concat(),
+                "2001:db8::/64",
+            ),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(format_inet(&bytes).as_deref(), Some(expected));
+        }
+        assert_eq!(format_inet(&[2, 32, 0, 3, 10, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_format_interval() {
+        let cases = [
+            ((0, 0, 0), "00:00:00"),
+            ((14, 3, 0), "1 year 2 mons 3 days"),
+            ((24, 1, 0), "2 years 1 day"),
+            ((0, 0, 3_723_000_000), "01:02:03"),
+            ((0, -1, -1_500_000), "-1 days -00:00:01.5"),
+            ((1, 0, 1), "1 mon 00:00:00.000001"),
+        ];
+        for ((months, days, microseconds), expected) in cases {
+            let interval = PgInterval {
+                months,
+                days,
+                microseconds,
+            };
+            assert_eq!(format_interval(&interval), expected);
+        }
+    }
+
+    #[test]
+    fn test_format_macaddr() {
+        let mac = MacAddress::new([0x08, 0x00, 0x2b, 0x01, 0x02, 0xab]);
+        assert_eq!(format_macaddr(&mac), "08:00:2b:01:02:ab");
+    }
+
+    #[test]
+    fn test_format_uuid() {
+        let uuid = SqlxUuid::parse_str("A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11").unwrap();
+        assert_eq!(uuid.to_string(), "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11");
+    }
+
+    #[test]
+    fn test_format_geometry() {
+        let floats = |v: &[f64]| v.iter().flat_map(|f| f.to_be_bytes()).collect::<Vec<u8>>();
+        assert_eq!(
+            format_geometry(&FieldKind::Point, &floats(&[1.5, -2.0])).as_deref(),
+            Some("(1.5,-2)")
+        );
+        assert_eq!(
+            format_geometry(&FieldKind::Circle, &floats(&[0.0, 1.0, 2.5])).as_deref(),
+            Some("<(0,1),2.5>")
+        );
+        let mut path = vec![0, 0, 0, 0, 2];
+        path.extend(floats(&[0.0, 0.0, 1.0, 1.0]));
+        assert_eq!(
+            format_geometry(&FieldKind::Path, &path).as_deref(),
+            Some("[(0,0),(1,1)]")
+        );
+        assert_eq!(
+            format_geometry(&FieldKind::Polygon, &path[1..]).as_deref(),
+            Some("((0,0),(1,1))")
+        );
+        assert_eq!(format_geometry(&FieldKind::Point, &[0; 9]), None);
+    }
+}
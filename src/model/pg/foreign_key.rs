@@ -1,39 +1,60 @@
+use super::{comment_literal, quote_ident, quote_literal};
+use crate::model::Reference;
 use sqlx::{postgres::PgRow, Row};
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 use uuid::Uuid;
 
 use regex::Regex;
 
-#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone)]
+const FOREIGN_KEY_DEF_PATTERN: &str = r"FOREIGN KEY \((?P<fields>[^)]+)\) REFERENCES (?P<ref_table>[^(]+)\((?P<ref_fields>[^)]+)\)(?: MATCH (?P<match>FULL|PARTIAL|SIMPLE))?(?: ON UPDATE (?P<on_update>CASCADE|RESTRICT|NO ACTION|SET NULL|SET DEFAULT))?(?: ON DELETE (?P<on_delete>CASCADE|RESTRICT|NO ACTION|SET NULL|SET DEFAULT))?(?P<deferrable> DEFERRABLE)?(?P<deferred> INITIALLY DEFERRED)?";
+
+#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone, PartialEq)]
 #[strum(serialize_all = "UPPERCASE")]
 pub enum OnDeleteKind {
     Cascade,
+    #[strum(serialize = "SET NULL")]
     SetNull,
+    #[strum(serialize = "NO ACTION")]
     NoAction,
     Restrict,
+    #[strum(serialize = "SET DEFAULT")]
     SetDefault,
 }
 
-#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone)]
+#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone, PartialEq)]
 #[strum(serialize_all = "UPPERCASE")]
 pub enum OnUpdateKind {
     Cascade,
+    #[strum(serialize = "NO ACTION")]
     NoAction,
     Restrict,
+    #[strum(serialize = "SET NULL")]
     SetNull,
+    #[strum(serialize = "SET DEFAULT")]
     SetDefault,
 }
 
+#[derive(EnumIter, EnumString, Display, IntoStaticStr, Clone, PartialEq)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum MatchKind {
+    Full,
+    Partial,
+    Simple,
+}
+
 #[derive(Clone)]
 pub struct ForeignKey {
     pub id: Uuid,
     pub name: String,
-    pub field: String,
+    pub fields: Vec<String>,
     pub ref_schema: String,
     pub ref_table: String,
-    pub ref_field: String,
+    pub ref_fields: Vec<String>,
     pub on_delete: Option<OnDeleteKind>,
     pub on_update: Option<OnUpdateKind>,
+    pub match_kind: Option<MatchKind>,
+    pub deferrable: bool,
+    pub initially_deferred: bool,
     pub comment: Option<String>,
 }
 
@@ -44,8 +65,8 @@ impl ForeignKey {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
-    pub fn field(&self) -> &str {
-        &self.field
+    pub fn fields(&self) -> &Vec<String> {
+        &self.fields
     }
     pub fn ref_schema(&self) -> &str {
         self.ref_schema.as_str()
@@ -53,8 +74,8 @@ impl ForeignKey {
     pub fn ref_table(&self) -> &str {
         self.ref_table.as_str()
     }
-    pub fn ref_field(&self) -> &str {
-        &self.ref_field
+    pub fn ref_fields(&self) -> &Vec<String> {
+        &self.ref_fields
     }
     pub fn on_delete(&self) -> Option<&str> {
         self.on_delete.as_ref().map(|s| s.into())
@@ -62,18 +83,41 @@ impl ForeignKey {
     pub fn on_update(&self) -> Option<&str> {
         self.on_update.as_ref().map(|s| s.into())
     }
+    pub fn match_kind(&self) -> Option<&str> {
+        self.match_kind.as_ref().map(|s| s.into())
+    }
+    pub fn deferrable(&self) -> bool {
+        self.deferrable
+    }
+    pub fn initially_deferred(&self) -> bool {
+        self.initially_deferred
+    }
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
+    pub fn get_deferrable_str(&self) -> &str {
+        if !self.deferrable {
+            ""
+        } else if self.initially_deferred {
+            "DEFERRABLE INITIALLY DEFERRED"
+        } else {
+            "DEFERRABLE"
+        }
+    }
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         (
             format!(
-                "CONSTRAINT \"{}\" FOREIGN KEY (\"{}\") REFERENCES \"{}\".\"{}\" (\"{}\"){}{}",
-                self.name(),
-                self.field(),
-                self.ref_schema(),
-                self.ref_table(),
-                self.ref_field(),
+                "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}.{} ({}){}{}{}{}",
+                quote_ident(self.name()),
+                get_column_list(&self.fields),
+                quote_ident(self.ref_schema()),
+                quote_ident(self.ref_table()),
+                get_column_list(&self.ref_fields),
+                if let Some(m) = self.match_kind() {
+                    format!(" MATCH {}", m)
+                } else {
+                    String::from("")
+                },
                 if let Some(d) = self.on_delete() {
                     format!(" ON DELETE {}", d)
                 } else {
@@ -84,14 +128,19 @@ impl ForeignKey {
                 } else {
                     String::from("")
                 },
+                if self.deferrable {
+                    format!(" {}", self.get_deferrable_str())
+                } else {
+                    String::from("")
+                }
             ),
             self.comment().map(|c| {
                 format!(
-                    "COMMENT ON CONSTRAINT \"{}\" ON \"{}\".\"{}\" IS '{}';",
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    c
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    quote_literal(c)
                 )
             }),
         )
@@ -101,7 +150,7 @@ impl ForeignKey {
         (format!("ADD {}", fk_ddl), comment_ddl)
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP CONSTRAINT \"{}\"", self.name)
+        format!("DROP CONSTRAINT {}", quote_ident(&self.name))
     }
     pub fn get_alter_ddl(
         &self,
@@ -109,15 +158,29 @@ impl ForeignKey {
         schema_name: &str,
         table_name: &str,
     ) -> (Vec<String>, Option<String>) {
+        let mut ddl = Vec::new();
+        if old.fields != self.fields
+            || old.ref_schema != self.ref_schema
+            || old.ref_table != self.ref_table
+            || old.ref_fields != self.ref_fields
+            || old.on_delete != self.on_delete
+            || old.on_update != self.on_update
+            || old.match_kind != self.match_kind
+            || old.deferrable != self.deferrable
+            || old.initially_deferred != self.initially_deferred
+        {
+            ddl.push(self.get_drop_ddl());
+            ddl.push(self.get_add_ddl(schema_name, table_name).0);
+        }
         (
-            vec![],
+            ddl,
             if old.comment != self.comment {
                 Some(format!(
-                    r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}'"#,
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    self.comment().unwrap_or("")
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    comment_literal(self.comment())
                 ))
             } else {
                 None
@@ -129,40 +192,77 @@ impl ForeignKey {
         if other.name != self.name {
             ddl.push(format!(
                 "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
-                table_name, other.name, self.name,
+                quote_ident(table_name),
+                quote_ident(&other.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
     }
 }
+fn get_column_list(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| quote_ident(c))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+fn split_column_list(columns: &str) -> Vec<String> {
+    columns
+        .split(',')
+        .map(|c| c.trim().trim_matches('"').to_string())
+        .collect()
+}
 pub fn convert_show_fk_to_pg_fk(schema_name: &str, rows: Vec<PgRow>) -> Vec<ForeignKey> {
+    let reg = Regex::new(FOREIGN_KEY_DEF_PATTERN).unwrap();
     rows.iter()
         .map(|row| {
             let def: String = row.try_get("def").unwrap();
-            let reg = Regex::new(
-                r"FOREIGN\sKEY\s\((?P<field>\w+)\)\sREFERENCES\s(?P<ref_table>\w+)\((?P<ref_field>\w+)\)(\sON\sUPDATE\s(?P<on_update>CASCADE|RESTRICT|SET NULL|SET DEFAULT))?(\sON\sDELETE\s(?P<on_delete>CASCADE|RESTRICT|SET NULL|SET DEFAULT))?",
-            )
-            .unwrap();
             let caps = reg.captures(def.as_str()).unwrap();
-            let field = caps.name("field").unwrap().as_str();
-            let ref_schema = schema_name.to_string();
-            let ref_table =caps.name("ref_table").unwrap().as_str(); 
-            let ref_field =caps.name("ref_field").unwrap().as_str(); 
-            let on_update = caps.name("on_update").map(|s| 
-                s.as_str().chars().filter(|c| !c.is_whitespace()).collect::<String>());
-            let on_delete = caps.name("on_delete").map(|s|s.as_str().chars().filter(|c| !c.is_whitespace()).collect::<String>());
+            let ref_table = caps.name("ref_table").unwrap().as_str().trim();
+            let (ref_schema, ref_table) = match ref_table.split_once('.') {
+                Some((s, t)) => (s.trim_matches('"'), t.trim_matches('"')),
+                None => (schema_name, ref_table.trim_matches('"')),
+            };
 
             ForeignKey {
                 id: Uuid::new_v4(),
                 name: row.try_get("foreign_key").unwrap(),
-                field: field.to_string(),
+                fields: split_column_list(caps.name("fields").unwrap().as_str()),
                 ref_schema: ref_schema.to_string(),
                 ref_table: ref_table.to_string(),
-                ref_field: ref_field.to_string(),
-                on_delete: on_delete.map(|d| OnDeleteKind::try_from(d.as_str()).unwrap()),
-                on_update: on_update.map(|u| OnUpdateKind::try_from(u.as_str()).unwrap()),
+                ref_fields: split_column_list(caps.name("ref_fields").unwrap().as_str()),
+                on_delete: caps
+                    .name("on_delete")
+                    .and_then(|d| OnDeleteKind::try_from(d.as_str()).ok()),
+                on_update: caps
+                    .name("on_update")
+                    .and_then(|u| OnUpdateKind::try_from(u.as_str()).ok()),
+                match_kind: caps
+                    .name("match")
+                    .and_then(|m| MatchKind::try_from(m.as_str()).ok()),
+                deferrable: caps.name("deferrable").is_some(),
+                initially_deferred: caps.name("deferred").is_some(),
                 comment: row.try_get("comment").unwrap(),
             }
         })
         .collect()
 }
+pub fn convert_show_reference_to_pg_references(rows: Vec<PgRow>) -> Vec<Reference> {
+    let reg = Regex::new(FOREIGN_KEY_DEF_PATTERN).unwrap();
+    rows.iter()
+        .map(|row| {
+            let def: String = row.try_get("def").unwrap();
+            let caps = reg.captures(def.as_str()).unwrap();
+            Reference {
+                name: row.try_get("name").unwrap(),
+                schema_name: row.try_get("schema_name").unwrap(),
+                table_name: row.try_get("table_name").unwrap(),
+                fields: split_column_list(caps.name("fields").unwrap().as_str()),
+                ref_fields: split_column_list(caps.name("ref_fields").unwrap().as_str()),
+                on_update: caps.name("on_update").map(|u| u.as_str().to_string()),
+                on_delete: caps.name("on_delete").map(|d| d.as_str().to_string()),
+            }
+        })
+        .collect()
+}
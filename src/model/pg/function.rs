@@ -0,0 +1,87 @@
+use super::quote_ident;
+use anyhow::Result;
+use sqlx::{PgPool, Row};
+
+#[derive(Clone)]
+pub struct Function {
+    pub oid: i64,
+    pub name: String,
+    pub kind: String,
+    pub arguments: String,
+    pub result: Option<String>,
+    pub language: String,
+    pub owner: String,
+    pub comment: Option<String>,
+}
+
+impl Function {
+    pub fn get_drop_ddl(&self, schema_name: &str) -> String {
+        format!(
+            "DROP {} {}.{}({})",
+            self.kind,
+            quote_ident(schema_name),
+            quote_ident(&self.name),
+            self.arguments
+        )
+    }
+}
+
+const FUNCTIONS_SQL: &str = r#"
+    SELECT
+        p.oid::int8 AS oid,
+        p.proname,
+        CASE p.prokind WHEN 'p' THEN 'PROCEDURE' ELSE 'FUNCTION' END AS kind,
+        pg_catalog.pg_get_function_identity_arguments(p.oid) AS arguments,
+        pg_catalog.pg_get_function_result(p.oid) AS result,
+        l.lanname,
+        r.rolname,
+        obj_description(p.oid, 'pg_proc') AS comment
+    FROM
+        pg_catalog.pg_proc p
+        JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+        JOIN pg_catalog.pg_language l ON l.oid = p.prolang
+        JOIN pg_catalog.pg_roles r ON r.oid = p.proowner
+    WHERE
+        n.nspname = $1 AND p.prokind IN ('f', 'p')
+"#;
+
+fn convert_row_to_pg_function(row: &sqlx::postgres::PgRow) -> Function {
+    Function {
+        oid: row.try_get("oid").unwrap(),
+        name: row.try_get("proname").unwrap(),
+        kind: row.try_get("kind").unwrap(),
+        arguments: row.try_get("arguments").unwrap(),
+        result: row.try_get("result").unwrap(),
+        language: row.try_get("lanname").unwrap(),
+        owner: row.try_get("rolname").unwrap(),
+        comment: row.try_get("comment").unwrap(),
+    }
+}
+
+pub async fn get_pg_functions(pool: &PgPool, schema_name: &str) -> Result<Vec<Function>> {
+    let functions = sqlx::query(&format!("{} ORDER BY p.proname", FUNCTIONS_SQL))
+        .bind(schema_name)
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(convert_row_to_pg_function)
+        .collect();
+    Ok(functions)
+}
+
+pub async fn get_pg_function(pool: &PgPool, schema_name: &str, oid: i64) -> Result<Function> {
+    let row = sqlx::query(&format!("{} AND p.oid = $2::oid", FUNCTIONS_SQL))
+        .bind(schema_name)
+        .bind(oid)
+        .fetch_one(pool)
+        .await?;
+    Ok(convert_row_to_pg_function(&row))
+}
+
+pub async fn get_pg_function_definition(pool: &PgPool, oid: i64) -> Result<String> {
+    let row = sqlx::query("SELECT pg_catalog.pg_get_functiondef($1::oid) AS definition")
+        .bind(oid)
+        .fetch_one(pool)
+        .await?;
+    Ok(row.try_get("definition")?)
+}
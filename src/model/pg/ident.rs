@@ -0,0 +1,52 @@
+pub fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+pub fn value_literal(value: Option<&str>) -> String {
+    match value {
+        Some(v) => quote_literal(v),
+        None => String::from("NULL"),
+    }
+}
+pub fn comment_literal(comment: Option<&str>) -> String {
+    value_literal(comment.filter(|c| !c.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_ident() {
+        assert_eq!(quote_ident("users"), r#""users""#);
+        assert_eq!(quote_ident("My Table"), r#""My Table""#);
+        assert_eq!(quote_ident(r#"a"b"#), r#""a""b""#);
+        assert_eq!(quote_ident("it's"), r#""it's""#);
+        assert_eq!(quote_ident(""), r#""""#);
+    }
+
+    #[test]
+    fn test_quote_literal() {
+        assert_eq!(quote_literal("O'Brien"), "'O''Brien'");
+        assert_eq!(quote_literal("''"), "''''''");
+        assert_eq!(quote_literal(r"C:\temp"), r"'C:\temp'");
+        assert_eq!(quote_literal(r#"say "hi""#), r#"'say "hi"'"#);
+        assert_eq!(quote_literal(""), "''");
+    }
+
+    #[test]
+    fn test_value_literal() {
+        assert_eq!(value_literal(Some("it's")), "'it''s'");
+        assert_eq!(value_literal(Some("")), "''");
+        assert_eq!(value_literal(None), "NULL");
+    }
+
+    #[test]
+    fn test_comment_literal() {
+        assert_eq!(comment_literal(Some("owner's table")), "'owner''s table'");
+        assert_eq!(comment_literal(Some("")), "NULL");
+        assert_eq!(comment_literal(None), "NULL");
+    }
+}
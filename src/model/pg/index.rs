@@ -1,3 +1,4 @@
+use super::{comment_literal, quote_ident, quote_literal};
 use regex::Regex;
 use sqlx::{postgres::PgRow, Row};
 use std::fmt;
@@ -20,9 +21,10 @@ pub enum IndexKind {
     Unique,
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct IndexField {
     pub name: String,
+    pub expression: Option<String>,
     pub collation_schema: Option<String>,
     pub collation: Option<String>,
     pub operator_class_schema: Option<String>,
@@ -35,6 +37,9 @@ impl IndexField {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+    pub fn expression(&self) -> Option<&str> {
+        self.expression.as_deref()
+    }
     pub fn collation_schema(&self) -> Option<&str> {
         self.collation_schema.as_deref()
     }
@@ -54,61 +59,70 @@ impl IndexField {
         self.nulls_order.as_deref()
     }
     pub fn to_show_string(&self) -> String {
-        let mut str = String::from(self.name());
-        if let Some(cs) = self.collation_schema() {
-            str = format!("{} {}", str, cs);
-        }
-        if let Some(c) = self.collation() {
-            str = format!("{} {}", str, c);
-        }
-        if let Some(ocs) = self.operator_class_schema() {
-            str = format!("{} \"{}\"", str, ocs);
-        }
-        if let Some(oc) = self.operator_class() {
-            str = format!("{} \"{}\"", str, oc);
-        }
-        if let Some(sort) = self.sort_order() {
-            str = format!("{} {}", str, sort);
-        }
-        if let Some(null) = self.nulls_order() {
-            str = format!("{} NULLS {}", str, null);
-        }
-        str
+        self.get_create_ddl().trim_start().to_string()
+    }
+    fn get_create_ddl(&self) -> String {
+        let element = if let Some(e) = self.expression() {
+            format!("({})", e)
+        } else {
+            quote_ident(&self.name)
+        };
+        let collate = if let Some(c) = self.collation() {
+            if let Some(s) = self.collation_schema() {
+                format!(" COLLATE {}.{}", quote_ident(s), quote_ident(c))
+            } else {
+                format!(" COLLATE {}", quote_ident(c))
+            }
+        } else {
+            String::from("")
+        };
+        let opclass = if let Some(c) = self.operator_class() {
+            if let Some(s) = self.operator_class_schema() {
+                format!(" {}.{}", quote_ident(s), quote_ident(c))
+            } else {
+                format!(" {}", quote_ident(c))
+            }
+        } else {
+            String::from("")
+        };
+        format!(
+            "  {}{}{}{}{}",
+            element,
+            collate,
+            opclass,
+            if let Some(s) = self.sort_order() {
+                format!(" {}", s)
+            } else {
+                String::from("")
+            },
+            if let Some(n) = self.nulls_order() {
+                format!(" {}", n)
+            } else {
+                String::from("")
+            }
+        )
     }
 }
 
 pub fn show_pg_index_field(row: &[String]) -> String {
-    let mut str = String::from(&row[0]);
-    if !row[1].is_empty() {
-        str = format!("{} {}", str, row[1]);
-    }
-    if !&row[2].is_empty() {
-        str = format!("{} {}", str, row[2]);
-    }
-    if !&row[3].is_empty() {
-        str = format!("{} \"{}\"", str, row[3]);
-    }
-    if !&row[4].is_empty() {
-        str = format!("{} \"{}\"", str, row[4]);
-    }
-    if !&row[5].is_empty() {
-        str = format!("{} {}", str, row[5]);
-    }
-    if !&row[6].is_empty() {
-        str = format!("{} NULLS {}", str, row[6]);
-    }
-    str
+    IndexField::try_from(row.join(":").as_str())
+        .map(|f| f.to_show_string())
+        .unwrap_or_default()
 }
 
 impl fmt::Display for IndexField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}:{}:{}:{}",
+            "{}:{}:{}:{}:{}:{}:{}:{}",
             self.name,
             self.collation_schema.as_deref().unwrap_or(""),
             self.collation.as_deref().unwrap_or(""),
-            self.operator_class_schema.as_deref().unwrap_or("")
+            self.operator_class_schema.as_deref().unwrap_or(""),
+            self.operator_class.as_deref().unwrap_or(""),
+            self.sort_order.as_deref().unwrap_or(""),
+            self.nulls_order.as_deref().unwrap_or(""),
+            self.expression.as_deref().unwrap_or("")
         )
     }
 }
@@ -117,44 +131,22 @@ impl TryFrom<&str> for IndexField {
     type Error = &'static str;
 
     fn try_from(s: &str) -> Result<IndexField, Self::Error> {
-        let arr: Vec<&str> = s.split(':').collect();
+        let arr: Vec<&str> = s.splitn(8, ':').collect();
+        let get = |i: usize| arr.get(i).filter(|s| !s.is_empty()).map(|s| s.to_string());
 
         Ok(IndexField {
             name: arr[0].to_string(),
-            collation_schema: if arr[1].is_empty() {
-                None
-            } else {
-                Some(arr[1].to_string())
-            },
-            collation: if arr[2].is_empty() {
-                None
-            } else {
-                Some(arr[2].to_string())
-            },
-            operator_class_schema: if arr[3].is_empty() {
-                None
-            } else {
-                Some(arr[3].to_string())
-            },
-            operator_class: if arr[4].is_empty() {
-                None
-            } else {
-                Some(arr[4].to_string())
-            },
-            sort_order: if arr[5].is_empty() {
-                None
-            } else {
-                Some(arr[5].to_string())
-            },
-            nulls_order: if arr[6].is_empty() {
-                None
-            } else {
-                Some(arr[6].to_string())
-            },
+            expression: get(7),
+            collation_schema: get(1),
+            collation: get(2),
+            operator_class_schema: get(3),
+            operator_class: get(4),
+            sort_order: get(5),
+            nulls_order: get(6),
         })
     }
 }
-#[derive(Display, EnumIter, EnumString, AsRefStr, IntoStaticStr, Clone)]
+#[derive(Display, EnumIter, EnumString, AsRefStr, IntoStaticStr, Clone, PartialEq)]
 #[strum(serialize_all = "lowercase")]
 pub enum IndexMethod {
     Btree,
@@ -173,7 +165,11 @@ pub struct Index {
     pub index_method: Option<IndexMethod>,
     pub unique: bool,
     pub concurrent: bool,
+    pub include: Vec<String>,
+    pub predicate: Option<String>,
     pub comment: Option<String>,
+    pub scans: Option<i64>,
+    pub size: Option<String>,
 }
 
 impl Index {
@@ -195,17 +191,23 @@ impl Index {
     pub fn concurrent(&self) -> bool {
         self.concurrent
     }
+    pub fn include(&self) -> &Vec<String> {
+        &self.include
+    }
+    pub fn predicate(&self) -> Option<&str> {
+        self.predicate.as_deref()
+    }
     pub fn comment(&self) -> Option<&str> {
         self.comment.as_deref()
     }
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         let index_ddl = format!(
-            "CREATE {}INDEX{} \"{}\" ON \"{}\".\"{}\" {}(\n{}\n);",
+            "CREATE {}INDEX{} {} ON {}.{} {}(\n{}\n){}{};",
             if self.unique { "UNIQUE " } else { "" },
-            if self.concurrent { " CONCURRENT" } else { "" },
-            self.name,
-            schema_name,
-            table_name,
+            if self.concurrent { " CONCURRENTLY" } else { "" },
+            quote_ident(&self.name),
+            quote_ident(schema_name),
+            quote_ident(table_name),
             if let Some(m) = self.index_method() {
                 format!("USING {} ", m)
             } else {
@@ -213,49 +215,26 @@ impl Index {
             },
             self.fields
                 .iter()
-                .map(|f| {
-                    let collate = if let (Some(s), Some(c)) =
-                        (f.collation_schema.as_deref(), f.collation.as_deref())
-                    {
-                        format!(" COLLATE \"{}\".\"{}\"", s, c)
-                    } else {
-                        String::from("")
-                    };
-                    let opclass = if let (Some(s), Some(c)) = (
-                        f.operator_class_schema.as_deref(),
-                        f.operator_class.as_deref(),
-                    ) {
-                        format!(" \"{}\".\"{}\"", s, c)
-                    } else {
-                        String::from("")
-                    };
-
-                    format!(
-                        "  {}{}{}{}{}",
-                        f.name,
-                        collate,
-                        opclass,
-                        if let Some(s) = f.sort_order.as_deref() {
-                            format!(" {}", s)
-                        } else {
-                            String::from("")
-                        },
-                        if let Some(n) = f.nulls_order.as_deref() {
-                            format!(" {}", n)
-                        } else {
-                            String::from("")
-                        }
-                    )
-                })
+                .map(|f| f.get_create_ddl())
                 .collect::<Vec<String>>()
-                .join(",")
+                .join(",\n"),
+            if self.include.is_empty() {
+                String::from("")
+            } else {
+                format!(" INCLUDE ({})", self.include.join(", "))
+            },
+            if let Some(p) = self.predicate() {
+                format!(" WHERE {}", p)
+            } else {
+                String::from("")
+            }
         );
         let comment_ddl = self.comment().map(|comment| {
             format!(
-                "COMMENT ON INDEX \"{}\".\"{}\" IS '{}';",
-                schema_name,
-                self.name(),
-                comment
+                "COMMENT ON INDEX {}.{} IS {};",
+                quote_ident(schema_name),
+                quote_ident(self.name()),
+                quote_literal(comment)
             )
         });
         (index_ddl, comment_ddl)
@@ -267,56 +246,212 @@ impl Index {
         let mut ddl = Vec::new();
         if old.name != self.name {
             ddl.push(format!(
-                "ALTER INDEX \"{}\" RENAME TO \"{}\"",
-                old.name, self.name
+                "ALTER INDEX {} RENAME TO {}",
+                quote_ident(&old.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP INDEX {}", self.name)
+        format!("DROP INDEX {};", quote_ident(&self.name))
     }
-    pub fn get_alter_ddl(&self, old: &Index, schema_name: &str) -> (Vec<String>, Option<String>) {
+    pub fn get_alter_ddl(
+        &self,
+        old: &Index,
+        schema_name: &str,
+        table_name: &str,
+    ) -> (Vec<String>, Option<String>) {
+        let mut ddl = Vec::new();
+        if old.fields != self.fields
+            || old.index_method != self.index_method
+            || old.unique != self.unique
+            || old.include != self.include
+            || old.predicate != self.predicate
+        {
+            ddl.push(self.get_drop_ddl());
+            ddl.push(self.get_create_ddl(schema_name, table_name).0);
+        }
         let comment_ddl = if old.comment() != self.comment() {
             Some(format!(
-                "COMMENT ON INDEX \"{}\".\"{}\" IS '{}';",
-                schema_name,
-                self.name(),
-                self.comment().unwrap_or("")
+                "COMMENT ON INDEX {}.{} IS {};",
+                quote_ident(schema_name),
+                quote_ident(self.name()),
+                comment_literal(self.comment())
             ))
         } else {
             None
         };
-        (vec![], comment_ddl)
+        (ddl, comment_ddl)
+    }
+}
+fn find_closing_paren(s: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_quote: Option<char> = None;
+    for (i, c) in s.char_indices().skip_while(|(i, _)| *i < open) {
+        match in_quote {
+            Some(q) if c == q => in_quote = None,
+            Some(_) => (),
+            None => match c {
+                '\'' | '"' => in_quote = Some(c),
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => (),
+            },
+        }
     }
+    None
+}
+pub fn split_top_level(s: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut in_quote: Option<char> = None;
+    let mut current = String::new();
+    for c in s.chars() {
+        match in_quote {
+            Some(q) => {
+                if c == q {
+                    in_quote = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' => in_quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ if c == sep && depth == 0 => {
+                    if !current.trim().is_empty() {
+                        parts.push(current.trim().to_string());
+                    }
+                    current = String::new();
+                    continue;
+                }
+                _ => (),
+            },
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+fn split_qualified_name(name: &str) -> (Option<String>, String) {
+    let parts = split_top_level(name, '.')
+        .into_iter()
+        .map(|p| p.trim_matches('"').to_string())
+        .collect::<Vec<String>>();
+    match parts.as_slice() {
+        [schema, name] => (Some(schema.to_string()), name.to_string()),
+        _ => (None, name.trim_matches('"').to_string()),
+    }
+}
+fn parse_index_field(element: &str) -> IndexField {
+    let tokens = split_top_level(element, ' ');
+    let mut field = IndexField {
+        name: String::new(),
+        expression: None,
+        collation_schema: None,
+        collation: None,
+        operator_class_schema: None,
+        operator_class: None,
+        sort_order: None,
+        nulls_order: None,
+    };
+    let mut tokens = tokens.into_iter();
+    if let Some(first) = tokens.next() {
+        let is_column = first
+            .trim_matches('"')
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+            || (first.starts_with('"') && first.ends_with('"'));
+        if is_column {
+            field.name = first.trim_matches('"').to_string();
+        } else if first.starts_with('(') && first.ends_with(')') {
+            field.expression = Some(first[1..first.len() - 1].to_string());
+        } else {
+            field.expression = Some(first);
+        }
+    }
+    while let Some(token) = tokens.next() {
+        match token.to_uppercase().as_str() {
+            "COLLATE" => {
+                if let Some(c) = tokens.next() {
+                    let (schema, name) = split_qualified_name(&c);
+                    field.collation_schema = schema;
+                    field.collation = Some(name);
+                }
+            }
+            "ASC" | "DESC" => field.sort_order = Some(token.to_uppercase()),
+            "NULLS" => {
+                if let Some(n) = tokens.next() {
+                    field.nulls_order = Some(format!("NULLS {}", n.to_uppercase()));
+                }
+            }
+            _ => {
+                let (schema, name) = split_qualified_name(&token);
+                field.operator_class_schema = schema;
+                field.operator_class = Some(name);
+            }
+        }
+    }
+    field
 }
 pub fn convert_show_index_to_pg_indexes(rows: Vec<PgRow>) -> Vec<Index> {
+    let create_regex = Regex::new(
+        r"^CREATE\s(?P<u>UNIQUE\s)?INDEX\s(?P<c>CONCURRENTLY\s)?.+?\sUSING\s(?P<m>\w+)\s\(",
+    )
+    .unwrap();
     rows.iter()
         .map(|row| {
-            let def = row.try_get::<String, _>("indexdef").unwrap().replace("\"","");
-            let create_regex = Regex::new(r"CREATE\s(?P<u>UNIQUE\s)?INDEX\s(?P<c>CONCURRENTLY\s)?(?:\w+)\sON\s(?:\w+).(?:\w+)\sUSING\s(?P<m>btree|hash|gist|spgist|gin|brin)\s\((?P<index>.+)\)")
-                .unwrap();
-            let create_captures = create_regex.captures(def.as_str()).unwrap();
-            let fields_regex = Regex::new(r"(?P<name>\w+)\s?(COLLATE\s(?P<collate>\w+))?\s?(?P<sort>DESC)?\s?(NULLS\s(?P<null>FIRST|LAST))?").unwrap();
-            let fields = fields_regex.captures_iter(create_captures.name("index").unwrap().as_str()).map(|cap| IndexField
-                {
-                   name: cap.name("name").unwrap().as_str().to_string(), 
-                   collation_schema: None,
-                   collation: None,
-                   operator_class: None,
-                   operator_class_schema: None,
-                   sort_order: cap.name("sort").map(|s| s.as_str().to_string()) ,
-                   nulls_order: cap.name("null").map(|s| s.as_str().to_string()),
-
-                }).collect::<Vec<IndexField>>();
+            let def = row.try_get::<String, _>("indexdef").unwrap();
+            let captures = create_regex.captures(def.as_str());
+            let open = captures
+                .as_ref()
+                .map(|c| c.get(0).unwrap().end() - 1)
+                .unwrap_or(def.len());
+            let close = find_closing_paren(&def, open).unwrap_or(def.len());
+            let fields = def
+                .get(open + 1..close)
+                .map(|elements| {
+                    split_top_level(elements, ',')
+                        .iter()
+                        .map(|e| parse_index_field(e))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let mut rest = def.get(close + 1..).unwrap_or("").trim_start();
+            let mut include = Vec::new();
+            if let Some(r) = rest.strip_prefix("INCLUDE ") {
+                let close = find_closing_paren(r, 0).unwrap_or(r.len());
+                include = split_top_level(r.get(1..close).unwrap_or(""), ',')
+                    .iter()
+                    .map(|c| c.trim_matches('"').to_string())
+                    .collect();
+                rest = r.get(close + 1..).unwrap_or("").trim_start();
+            }
+            let predicate = rest
+                .find("WHERE ")
+                .map(|i| rest[i + 6..].trim().to_string());
             Index {
                 id: Uuid::new_v4(),
                 name: row.try_get("indexname").unwrap(),
-                fields ,
-                index_method: create_captures.name("m").map(|method| IndexMethod::try_from(method.as_str()).unwrap()),
-                unique: create_captures.name("u").is_some(),
-                concurrent: create_captures.name("c").is_some(),
+                fields,
+                index_method: captures
+                    .as_ref()
+                    .and_then(|c| c.name("m"))
+                    .and_then(|m| IndexMethod::try_from(m.as_str()).ok()),
+                unique: captures.as_ref().and_then(|c| c.name("u")).is_some(),
+                concurrent: captures.as_ref().and_then(|c| c.name("c")).is_some(),
+                include,
+                predicate,
                 comment: row.try_get("comment").unwrap(),
+                scans: row.try_get("idx_scan").unwrap_or_default(),
+                size: row.try_get("index_size").unwrap_or_default(),
             }
         })
         .collect::<Vec<Index>>()
@@ -1,14 +1,20 @@
+mod activity;
 mod check;
 mod connection;
 mod database;
+mod dump;
 mod exclude;
+mod explain;
 mod field;
 mod foreign_key;
+mod function;
+mod ident;
 mod index;
 mod privilege;
 mod role;
 mod rule;
 mod schema;
+mod sequence;
 mod table;
 mod table_space;
 mod trigger;
@@ -16,7 +22,7 @@ mod unique;
 mod view;
 
 pub use self::{
-    check::*, connection::*, database::*, exclude::*, field::*, foreign_key::*, index::*,
-    privilege::*, role::*, rule::*, schema::*, table::*, table_space::*, trigger::*, unique::*,
-    view::*,
+    activity::*, check::*, connection::*, database::*, dump::*, exclude::*, explain::*, field::*,
+    foreign_key::*, function::*, ident::*, index::*, privilege::*, role::*, rule::*, schema::*,
+    sequence::*, table::*, table_space::*, trigger::*, unique::*, view::*,
 };
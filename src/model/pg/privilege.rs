@@ -1,3 +1,4 @@
+use super::quote_ident;
 use anyhow::Result;
 use itertools::Itertools;
 use sqlx::{PgPool, Row};
@@ -21,8 +22,11 @@ pub struct Privilege {
 impl Privilege {
     pub fn get_revoke_all_ddl(&self, role_name: &str) -> String {
         format!(
-            "REVOKE ALL PRIVILEGE ON \"{}\".\"{}\".\"{}\" FROM \"{}\"",
-            self.db, self.schema, self.name, role_name
+            "REVOKE ALL PRIVILEGE ON {}.{}.{} FROM {}",
+            quote_ident(&self.db),
+            quote_ident(&self.schema),
+            quote_ident(&self.name),
+            quote_ident(role_name)
         )
     }
     pub fn get_grant_ddl(&self, role_name: &str) -> String {
@@ -49,12 +53,12 @@ impl Privilege {
             actions.push("UPDATE");
         }
         format!(
-            "GRANT {} ON \"{}\".\"{}\".\"{}\" TO \"{}\"",
+            "GRANT {} ON {}.{}.{} TO {}",
             actions.join(","),
-            self.db,
-            self.schema,
-            self.name,
-            role_name,
+            quote_ident(&self.db),
+            quote_ident(&self.schema),
+            quote_ident(&self.name),
+            quote_ident(role_name)
         )
     }
     pub fn get_alter_ddl(&self, old: &Privilege, role_name: &str) -> Vec<String> {
@@ -114,22 +118,22 @@ impl Privilege {
 
         if !grant_actions.is_empty() {
             ddl.push(format!(
-                "GRANT {} ON \"{}\".\"{}\".\"{}\" TO \"{}\"",
+                "GRANT {} ON {}.{}.{} TO {}",
                 grant_actions.join(","),
-                self.db,
-                self.schema,
-                self.name,
-                role_name,
+                quote_ident(&self.db),
+                quote_ident(&self.schema),
+                quote_ident(&self.name),
+                quote_ident(role_name)
             ))
         }
         if !revoke_actions.is_empty() {
             ddl.push(format!(
-                "REVOKE {} ON \"{}\".\"{}\".\"{}\" TO \"{}\"",
+                "REVOKE {} ON {}.{}.{} TO {}",
                 grant_actions.join(","),
-                self.db,
-                self.schema,
-                self.name,
-                role_name,
+                quote_ident(&self.db),
+                quote_ident(&self.schema),
+                quote_ident(&self.name),
+                quote_ident(role_name)
             ))
         }
         ddl
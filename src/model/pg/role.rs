@@ -1,3 +1,4 @@
+use super::quote_ident;
 use anyhow::Result;
 use chrono::{DateTime, Local};
 use sqlx::{postgres::types::Oid, PgPool, Row};
@@ -85,20 +86,29 @@ impl RoleMember {
 
         if self.admin_option && !old_rm.admin_option {
             Some(format!(
-                r#"GRANT "{}" TO "{}" WITH ADMIN OPTION"#,
-                left, right,
+                "GRANT {} TO {} WITH ADMIN OPTION",
+                quote_ident(left),
+                quote_ident(right)
             ))
         } else if !self.admin_option && old_rm.admin_option {
             Some(format!(
-                r#"REVOKE ADMIN OPTION FOR "{}" FROM "{}""#,
-                self.role_name.as_deref().unwrap(),
-                self.member_name.as_deref().unwrap()
+                "REVOKE ADMIN OPTION FOR {} FROM {}",
+                quote_ident(self.role_name.as_deref().unwrap()),
+                quote_ident(self.member_name.as_deref().unwrap())
             ))
         } else {
             if self.granted && !old_rm.granted {
-                Some(format!(r#"GRANT "{}" TO "{}""#, left, right,))
+                Some(format!(
+                    "GRANT {} TO {}",
+                    quote_ident(left),
+                    quote_ident(right)
+                ))
             } else if !self.granted && old_rm.granted {
-                Some(format!(r#"REVOKE "{}" FROM "{}""#, left, right,))
+                Some(format!(
+                    "REVOKE {} FROM {}",
+                    quote_ident(left),
+                    quote_ident(right)
+                ))
             } else {
                 None
             }
@@ -1,3 +1,4 @@
+use super::{comment_literal, quote_ident};
 use sqlx::{postgres::PgRow, Row};
 use strum::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
 use uuid::Uuid;
@@ -59,10 +60,10 @@ impl Rule {
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> String {
         format!(
             "CREATE RULE {} AS ON {} TO {}.{}{} DO {}{}",
-            self.name(),
+            quote_ident(self.name()),
             self.event(),
-            schema_name,
-            table_name,
+            quote_ident(schema_name),
+            quote_ident(table_name),
             if let Some(w) = self.where_condition() {
                 w
             } else {
@@ -84,7 +85,11 @@ impl Rule {
         self.get_create_ddl(schema_name, table_name)
     }
     pub fn get_drop_ddl(&self, table_name: &str) -> String {
-        format!("DROP RULE \"{}\" ON \"{}\"", self.name, table_name)
+        format!(
+            "DROP RULE {} ON {}",
+            quote_ident(&self.name),
+            quote_ident(table_name)
+        )
     }
     pub fn get_alter_ddl(
         &self,
@@ -95,20 +100,20 @@ impl Rule {
         let mut ddl = Vec::new();
         if old.name() != self.name() {
             ddl.push(format!(
-                "ALTER RULE \"{}\" ON \"{}\".\"{}\" RENAME TO \"{}\"",
-                old.name(),
-                schema_name,
-                table_name,
-                self.name()
+                "ALTER RULE {} ON {}.{} RENAME TO {}",
+                quote_ident(old.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_ident(self.name())
             ));
         }
         let comment = if old.comment() != self.comment() {
             Some(format!(
-                "COMMENT ON RULE \"{}\" ON \"{}\".\"{}\" IS '{}'",
-                self.name(),
-                schema_name,
-                table_name,
-                self.comment().unwrap_or(""),
+                "COMMENT ON RULE {} ON {}.{} IS {};",
+                quote_ident(self.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                comment_literal(self.comment())
             ))
         } else {
             None
@@ -1,5 +1,5 @@
 use crate::{
-    model::pg::Connections,
+    model::pg::{quote_ident, Connections},
     pool::{get_pg_pool, PGPools},
 };
 use anyhow::Result;
@@ -21,23 +21,31 @@ impl Schema {
     }
     pub fn get_create_ddl(&self) -> String {
         let owner = if let Some(owner) = self.owner.as_ref() {
-            format!(" AUTHORIZATION {}", owner)
+            format!(" AUTHORIZATION {}", quote_ident(owner))
         } else {
             String::new()
         };
 
-        format!("CREATE SCHEMA {}{}", self.name, owner)
+        format!("CREATE SCHEMA {}{}", quote_ident(&self.name), owner)
     }
     pub fn get_rename_ddl(&self, old_name: &str) -> String {
-        format!("ALTER SCHEMA {} RENAME TO {}", old_name, self.name)
+        format!(
+            "ALTER SCHEMA {} RENAME TO {}",
+            quote_ident(old_name),
+            quote_ident(&self.name)
+        )
     }
     pub fn get_alter_owner_ddl(&self) -> String {
         let owner = if let Some(owner) = self.owner() {
-            owner
+            quote_ident(owner)
         } else {
-            "CURRENT_USER"
+            "CURRENT_USER".to_string()
         };
-        format!("ALTER SCHEMA {} OWNER TO {}", self.name, owner)
+        format!(
+            "ALTER SCHEMA {} OWNER TO {}",
+            quote_ident(&self.name),
+            owner
+        )
     }
 }
 pub async fn get_all_pg_schemas(
@@ -0,0 +1,78 @@
+use super::quote_ident;
+use anyhow::Result;
+use sqlx::{PgPool, Row};
+
+#[derive(Clone)]
+pub struct Sequence {
+    pub name: String,
+    pub owner: String,
+    pub data_type: String,
+    pub last_value: Option<i64>,
+    pub start_value: i64,
+    pub increment_by: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub cycle: bool,
+    pub owned_by: Option<String>,
+}
+
+impl Sequence {
+    pub fn get_restart_ddl(&self, schema_name: &str, value: Option<i64>) -> String {
+        format!(
+            "ALTER SEQUENCE {}.{} RESTART{}",
+            quote_ident(schema_name),
+            quote_ident(&self.name),
+            value.map_or(String::new(), |v| format!(" WITH {}", v))
+        )
+    }
+}
+
+pub async fn get_pg_sequences(pool: &PgPool, schema_name: &str) -> Result<Vec<Sequence>> {
+    let sequences = sqlx::query(
+        r#"
+        SELECT
+            s.sequencename,
+            s.sequenceowner,
+            s.data_type::text AS data_type,
+            s.last_value,
+            s.start_value,
+            s.increment_by,
+            s.min_value,
+            s.max_value,
+            s.cycle,
+            t.relname || '.' || a.attname AS owned_by
+        FROM
+            pg_catalog.pg_sequences s
+            JOIN pg_catalog.pg_namespace n ON n.nspname = s.schemaname
+            JOIN pg_catalog.pg_class c ON c.relname = s.sequencename AND c.relnamespace = n.oid
+            LEFT JOIN pg_catalog.pg_depend d ON d.objid = c.oid
+                AND d.classid = 'pg_class'::regclass
+                AND d.refclassid = 'pg_class'::regclass
+                AND d.deptype IN ('a', 'i')
+            LEFT JOIN pg_catalog.pg_class t ON t.oid = d.refobjid
+            LEFT JOIN pg_catalog.pg_attribute a ON a.attrelid = d.refobjid
+                AND a.attnum = d.refobjsubid
+        WHERE
+            s.schemaname = $1
+        ORDER BY s.sequencename
+        "#,
+    )
+    .bind(schema_name)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|r| Sequence {
+        name: r.try_get("sequencename").unwrap(),
+        owner: r.try_get("sequenceowner").unwrap(),
+        data_type: r.try_get("data_type").unwrap(),
+        last_value: r.try_get("last_value").unwrap(),
+        start_value: r.try_get("start_value").unwrap(),
+        increment_by: r.try_get("increment_by").unwrap(),
+        min_value: r.try_get("min_value").unwrap(),
+        max_value: r.try_get("max_value").unwrap(),
+        cycle: r.try_get("cycle").unwrap(),
+        owned_by: r.try_get("owned_by").unwrap(),
+    })
+    .collect();
+    Ok(sequences)
+}
@@ -1,4 +1,6 @@
-use anyhow::Result;
+use super::{quote_ident, quote_literal};
+use crate::model::{SearchColumnKind, SearchMatch};
+use anyhow::{Error, Result};
 use sqlx::{PgPool, Row};
 use strum::{Display, EnumIter, EnumString};
 
@@ -24,6 +26,44 @@ pub enum RowFormat {
     Redundant,
 }
 
+#[derive(Clone, Copy, Display, EnumIter, EnumString)]
+pub enum TableMaintenance {
+    #[strum(serialize = "Analyze")]
+    Analyze,
+    #[strum(serialize = "Vacuum")]
+    Vacuum,
+    #[strum(serialize = "Vacuum Full")]
+    VacuumFull,
+    #[strum(serialize = "Reindex")]
+    Reindex,
+}
+
+impl TableMaintenance {
+    pub fn get_ddl(&self, schema_name: &str, table_name: &str) -> String {
+        let name = format!("{}.{}", quote_ident(schema_name), quote_ident(table_name));
+        match self {
+            TableMaintenance::Analyze => format!("ANALYZE {}", name),
+            TableMaintenance::Vacuum => format!("VACUUM {}", name),
+            TableMaintenance::VacuumFull => format!("VACUUM FULL {}", name),
+            TableMaintenance::Reindex => format!("REINDEX TABLE {}", name),
+        }
+    }
+    pub fn get_confirm_msg(&self, table_name: &str) -> String {
+        match self {
+            TableMaintenance::Analyze => format!("Are you sure to analyze table {}?", table_name),
+            TableMaintenance::Vacuum => format!("Are you sure to vacuum table {}?", table_name),
+            TableMaintenance::VacuumFull => format!(
+                "VACUUM FULL rewrites the table and holds an exclusive lock until done. Are you sure to vacuum table {}?",
+                table_name
+            ),
+            TableMaintenance::Reindex => format!(
+                "REINDEX blocks writes to the table until done. Are you sure to reindex table {}?",
+                table_name
+            ),
+        }
+    }
+}
+
 pub async fn get_pg_tables(pool: &PgPool, schema_name: &str) -> Result<Vec<Table>> {
     let tbs: Vec<Table> = sqlx::query("select * from pg_catalog.pg_tables where schemaname = $1")
         .bind(schema_name)
@@ -53,3 +93,151 @@ pub async fn get_pg_table_names(pool: &PgPool, schema_name: &str) -> Result<Vec<
             .collect();
     Ok(names)
 }
+pub async fn get_pg_visible_table_names(pool: &PgPool) -> Result<Vec<String>> {
+    let names: Vec<String> = sqlx::query(
+        "select tablename from pg_catalog.pg_tables where schemaname = ANY(current_schemas(false))",
+    )
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|t| t.try_get("tablename").unwrap())
+    .collect();
+    Ok(names)
+}
+pub async fn get_pg_referencing_tables(
+    pool: &PgPool,
+    schema_name: &str,
+    tables: &[String],
+) -> Result<Vec<String>> {
+    let mut names: Vec<String> = sqlx::query(
+        r"
+        SELECT DISTINCT
+            ns.nspname::text AS schema_name,
+            cls.relname::text AS table_name,
+            ref_cls.relname::text AS ref_table_name
+        FROM pg_constraint AS con
+        JOIN pg_class AS cls ON cls.oid = con.conrelid
+        JOIN pg_namespace AS ns ON ns.oid = cls.relnamespace
+        JOIN pg_class AS ref_cls ON ref_cls.oid = con.confrelid
+        JOIN pg_namespace AS ref_ns ON ref_ns.oid = ref_cls.relnamespace
+        WHERE con.contype = 'f' AND ref_ns.nspname = $1
+        ",
+    )
+    .bind(schema_name)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .filter_map(|r| {
+        let table_schema_name: String = r.try_get("schema_name").unwrap();
+        let table_name: String = r.try_get("table_name").unwrap();
+        let ref_table_name: String = r.try_get("ref_table_name").unwrap();
+        if tables.contains(&ref_table_name)
+            && !(table_schema_name == schema_name && tables.contains(&table_name))
+        {
+            Some(format!("{}.{}", table_schema_name, table_name))
+        } else {
+            None
+        }
+    })
+    .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+pub async fn search_pg_value(
+    pool: &PgPool,
+    schema_name: &str,
+    tables: &[String],
+    value: &str,
+    kind: &SearchColumnKind,
+) -> Result<Vec<SearchMatch>> {
+    let is_number = value.trim().parse::<f64>().is_ok();
+    if *kind == SearchColumnKind::Numeric && !is_number {
+        return Err(Error::msg(format!("{} is not a number", value)));
+    }
+    let columns: Vec<(String, String, bool)> = sqlx::query(
+        r"
+        SELECT table_name::text, column_name::text, data_type::text
+        FROM information_schema.columns
+        WHERE table_schema = $1
+        ORDER BY table_name, ordinal_position
+        ",
+    )
+    .bind(schema_name)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .filter_map(|r| {
+        let table_name: String = r.try_get("table_name").unwrap();
+        let column_name: String = r.try_get("column_name").unwrap();
+        let data_type: String = r.try_get("data_type").unwrap();
+        let is_text = matches!(
+            data_type.as_str(),
+            "character" | "character varying" | "text" | "uuid"
+        );
+        let is_numeric = is_number
+            && matches!(
+                data_type.as_str(),
+                "smallint" | "integer" | "bigint" | "numeric" | "real" | "double precision"
+            );
+        let included = match kind {
+            SearchColumnKind::Text => is_text,
+            SearchColumnKind::Numeric => is_numeric,
+            SearchColumnKind::All => is_text || is_numeric,
+        };
+        if included && tables.contains(&table_name) {
+            Some((table_name, column_name, is_numeric))
+        } else {
+            None
+        }
+    })
+    .collect();
+
+    let literal = quote_literal(value);
+    let mut matches = Vec::new();
+    for table_name in tables.iter() {
+        let table_columns: Vec<(&String, bool)> = columns
+            .iter()
+            .filter(|(t, _, _)| t == table_name)
+            .map(|(_, c, n)| (c, *n))
+            .collect();
+        if table_columns.is_empty() {
+            continue;
+        }
+        let sql = format!(
+            "SELECT {} FROM {}.{}",
+            table_columns
+                .iter()
+                .map(|(c, is_numeric)| if *is_numeric {
+                    format!(
+                        "COUNT(CASE WHEN {} = {}::numeric THEN 1 END)",
+                        quote_ident(c),
+                        literal
+                    )
+                } else {
+                    format!(
+                        "COUNT(CASE WHEN {}::text = {} THEN 1 END)",
+                        quote_ident(c),
+                        literal
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+            quote_ident(schema_name),
+            quote_ident(table_name)
+        );
+        let row = sqlx::query(&sql).fetch_one(pool).await?;
+        for (i, (column_name, _)) in table_columns.iter().enumerate() {
+            let count: i64 = row.try_get(i)?;
+            if count > 0 {
+                matches.push(SearchMatch {
+                    table_name: table_name.clone(),
+                    column_name: column_name.to_string(),
+                    count,
+                });
+            }
+        }
+    }
+    Ok(matches)
+}
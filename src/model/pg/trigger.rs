@@ -1,3 +1,4 @@
+use super::{comment_literal, quote_ident, quote_literal};
 use sqlx::{postgres::PgRow, Row};
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 use uuid::Uuid;
@@ -45,6 +46,7 @@ pub struct Trigger {
     pub fn_schema: String,
     pub fn_name: String,
     pub fn_arg: Option<String>,
+    pub comment: Option<String>,
 }
 
 impl Trigger {
@@ -90,7 +92,10 @@ impl Trigger {
     pub fn fn_arg(&self) -> Option<&str> {
         self.fn_arg.as_deref()
     }
-    pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> String {
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         let mut action = Vec::new();
         if self.insert {
             action.push(String::from("INSERT"));
@@ -102,7 +107,7 @@ impl Trigger {
                     self.update_fields
                         .iter()
                         .filter(|f| f.is_some())
-                        .map(|f| format!(r#""{}""#, f.as_deref().unwrap()))
+                        .map(|f| quote_ident(f.as_deref().unwrap()))
                         .collect::<Vec<String>>()
                         .join(","),
                 ));
@@ -116,17 +121,17 @@ impl Trigger {
         if self.truncate {
             action.push(String::from("TRUNCATE"));
         }
-        format!(
-            r#"CREATE TRIGGER "{}"{}{}ON "{}"."{}" {}{} EXECUTE PROCEDURE {}.{} {};"#,
-            self.name,
+        let trigger_ddl = format!(
+            "CREATE TRIGGER {}{}{}ON {}.{} {}{} EXECUTE PROCEDURE {}.{} {};",
+            quote_ident(&self.name),
             self.fires().unwrap_or(" "),
             if !action.is_empty() {
                 action.join("OR")
             } else {
                 String::new()
             },
-            schema_name,
-            table_name,
+            quote_ident(schema_name),
+            quote_ident(table_name),
             self.for_each().unwrap_or(""),
             self.where_condition().unwrap_or(""),
             self.fn_schema,
@@ -136,13 +141,27 @@ impl Trigger {
             } else {
                 String::from("")
             }
-        )
-    }
-    pub fn get_add_ddl(&self, schema_name: &str, table_name: &str) -> String {
+        );
+        let comment_ddl = self.comment().map(|c| {
+            format!(
+                "COMMENT ON TRIGGER {} ON {}.{} IS {};",
+                quote_ident(self.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_literal(c)
+            )
+        });
+        (trigger_ddl, comment_ddl)
+    }
+    pub fn get_add_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         self.get_create_ddl(schema_name, table_name)
     }
     pub fn get_drop_ddl(&self, table_name: &str) -> String {
-        format!("DROP TRIGGER {} ON {}", self.name, table_name)
+        format!(
+            "DROP TRIGGER {} ON {}",
+            quote_ident(&self.name),
+            quote_ident(table_name)
+        )
     }
     pub fn get_alter_ddl(
         &self,
@@ -153,15 +172,25 @@ impl Trigger {
         let mut ddl = Vec::new();
         if old.name() != self.name() {
             ddl.push(format!(
-                "ALTER TRIGGER \"{}\" ON \"{}\".\"{}\" RENAME TO \"{}\"",
-                old.name(),
-                schema_name,
-                table_name,
-                self.name()
+                "ALTER TRIGGER {} ON {}.{} RENAME TO {}",
+                quote_ident(old.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                quote_ident(self.name())
             ));
         }
-
-        (ddl, None)
+        let comment = if old.comment() != self.comment() {
+            Some(format!(
+                "COMMENT ON TRIGGER {} ON {}.{} IS {};",
+                quote_ident(self.name()),
+                quote_ident(schema_name),
+                quote_ident(table_name),
+                comment_literal(self.comment())
+            ))
+        } else {
+            None
+        };
+        (ddl, comment)
     }
 }
 pub fn convert_row_to_pg_trigger(rows: &Vec<PgRow>) -> Vec<Trigger> {
@@ -195,6 +224,7 @@ pub fn convert_row_to_pg_trigger(rows: &Vec<PgRow>) -> Vec<Trigger> {
                     .try_get::<Option<Vec<u8>>, _>("tgargs")
                     .unwrap()
                     .map(|v| String::from_utf8(v).unwrap()),
+                comment: row.try_get("comment").unwrap(),
             }
         })
         .collect()
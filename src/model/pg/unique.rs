@@ -1,3 +1,4 @@
+use super::{comment_literal, quote_ident, quote_literal};
 use sqlx::{postgres::PgRow, Row};
 use uuid::Uuid;
 
@@ -25,21 +26,21 @@ impl Unique {
     pub fn get_create_ddl(&self, schema_name: &str, table_name: &str) -> (String, Option<String>) {
         (
             format!(
-                "CONSTRAINT \"{}\" UNIQUE ({})",
-                self.name(),
+                "CONSTRAINT {} UNIQUE ({})",
+                quote_ident(self.name()),
                 self.fields()
                     .iter()
-                    .map(|f| format!("\"{}\"", f))
+                    .map(|f| quote_ident(f))
                     .collect::<Vec<String>>()
                     .join(",")
             ),
             self.comment().map(|c| {
                 format!(
-                    "COMMENT ON CONSTRAINT \"{}\" ON \"{}\".\"{}\" IS '{}';",
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    c
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    quote_literal(c)
                 )
             }),
         )
@@ -49,7 +50,7 @@ impl Unique {
         (format!("ADD {}", un_ddl), comment_ddl)
     }
     pub fn get_drop_ddl(&self) -> String {
-        format!("DROP CONSTRAINT \"{}\"", self.name())
+        format!("DROP CONSTRAINT {}", quote_ident(self.name()))
     }
     pub fn get_alter_ddl(
         &self,
@@ -61,11 +62,11 @@ impl Unique {
             vec![],
             if old.comment != self.comment {
                 Some(format!(
-                    r#"COMMENT ON CONSTRAINT "{}" ON "{}"."{}" IS '{}'"#,
-                    self.name(),
-                    schema_name,
-                    table_name,
-                    self.comment().unwrap_or("")
+                    "COMMENT ON CONSTRAINT {} ON {}.{} IS {};",
+                    quote_ident(self.name()),
+                    quote_ident(schema_name),
+                    quote_ident(table_name),
+                    comment_literal(self.comment())
                 ))
             } else {
                 None
@@ -76,8 +77,10 @@ impl Unique {
         let mut ddl = Vec::new();
         if other.name != self.name {
             ddl.push(format!(
-                "ALTER TABLE {} CONSTRAINT {} TO {}",
-                table_name, other.name, self.name
+                "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
+                quote_ident(table_name),
+                quote_ident(&other.name),
+                quote_ident(&self.name)
             ));
         }
         ddl
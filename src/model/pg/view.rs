@@ -10,6 +10,8 @@ pub struct View {
     pub owner: Option<String>,
     pub definition: String,
     pub comment: String,
+    pub check_option: Option<String>,
+    pub security_barrier: bool,
 }
 
 #[derive(Clone, EnumIter, Display)]
@@ -18,24 +20,40 @@ pub enum CheckOption {
     Local,
 }
 
+fn get_view_option<'a>(options: &'a [String], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|o| {
+        o.split_once('=')
+            .filter(|(k, _)| *k == name)
+            .map(|(_, v)| v)
+    })
+}
+
 pub async fn get_pg_views(pool: &PgPool, schema_name: &str) -> Result<Vec<View>> {
-    let views: Vec<View> = sqlx::query("SELECT * ,obj_description((schemaname||'.'||viewname)::regclass::oid) as comment FROM pg_views WHERE schemaname = $1")
+    let views: Vec<View> = sqlx::query("SELECT * ,obj_description((schemaname||'.'||viewname)::regclass::oid) as comment, (SELECT reloptions FROM pg_class WHERE oid = (schemaname||'.'||viewname)::regclass::oid) as options FROM pg_views WHERE schemaname = $1")
         .bind(schema_name)
         .fetch_all(pool)
         .await?
         .iter()
-        .map(|v| View {
-            name: v.try_get("viewname").unwrap(),
-            rules: Vec::new(),
-            owner: v.try_get("viewowner").unwrap(),
-            definition: v.try_get("definition").unwrap(),
-            comment:  v.try_get("comment").unwrap_or_default(),
+        .map(|v| {
+            let options: Vec<String> = v
+                .try_get::<Option<Vec<String>>, _>("options")
+                .unwrap()
+                .unwrap_or_default();
+            View {
+                name: v.try_get("viewname").unwrap(),
+                rules: Vec::new(),
+                owner: v.try_get("viewowner").unwrap(),
+                definition: v.try_get("definition").unwrap(),
+                comment: v.try_get("comment").unwrap_or_default(),
+                check_option: get_view_option(&options, "check_option").map(|o| o.to_uppercase()),
+                security_barrier: get_view_option(&options, "security_barrier") == Some("true"),
+            }
         })
         .collect();
     Ok(views)
 }
 pub async fn get_pg_view(pool: &PgPool, schema_name: &str, view_name: &str) -> Result<View> {
-    let row = sqlx::query("SELECT *, obj_description((schemaname||'.'||viewname)::regclass::oid) as comment FROM pg_views WHERE schemaname = $1 AND viewname=$2")
+    let row = sqlx::query("SELECT *, obj_description((schemaname||'.'||viewname)::regclass::oid) as comment, (SELECT reloptions FROM pg_class WHERE oid = (schemaname||'.'||viewname)::regclass::oid) as options FROM pg_views WHERE schemaname = $1 AND viewname=$2")
         .bind(schema_name)
         .bind(view_name)
         .fetch_one(pool)
@@ -47,6 +65,10 @@ pub async fn get_pg_view(pool: &PgPool, schema_name: &str, view_name: &str) -> R
         .await?;
 
     let rules = convert_row_to_pg_rule(rule_rows);
+    let options: Vec<String> = row
+        .try_get::<Option<Vec<String>>, _>("options")
+        .unwrap()
+        .unwrap_or_default();
 
     Ok(View {
         name: row.try_get("viewname").unwrap(),
@@ -54,5 +76,7 @@ pub async fn get_pg_view(pool: &PgPool, schema_name: &str, view_name: &str) -> R
         rules,
         definition: row.try_get("definition").unwrap(),
         comment: row.try_get("comment").unwrap_or_default(),
+        check_option: get_view_option(&options, "check_option").map(|o| o.to_uppercase()),
+        security_barrier: get_view_option(&options, "security_barrier") == Some("true"),
     })
 }
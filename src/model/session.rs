@@ -0,0 +1,44 @@
+use crate::{app::APP_DIR, model::DatabaseKind};
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::PathBuf};
+use uuid::Uuid;
+
+const SESSION_FILE: &str = "session";
+
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+pub enum SessionPanel {
+    TableList,
+    DataList,
+    TableDetail,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct Session {
+    pub conn_id: Option<Uuid>,
+    pub kind: Option<DatabaseKind>,
+    pub db_name: Option<String>,
+    pub schema_name: Option<String>,
+    pub table_name: Option<String>,
+    pub panel: Option<SessionPanel>,
+}
+
+impl Session {
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(Self::get_file_path()?) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Session::default()),
+            Err(e) => Err(Error::new(e)),
+        }
+    }
+    pub fn save(&self) -> Result<()> {
+        fs::write(Self::get_file_path()?, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+    fn get_file_path() -> Result<PathBuf> {
+        let mut path = dirs_next::home_dir().ok_or(Error::msg("home dir not exists"))?;
+        path.push(APP_DIR);
+        path.push(SESSION_FILE);
+        Ok(path)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::app::APP_DIR;
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, io::ErrorKind, path::PathBuf};
+use uuid::Uuid;
+
+const TABLE_VIEW_FILE: &str = "table_views";
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct TableView {
+    pub page: usize,
+    pub column: usize,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct TableViews {
+    views: HashMap<String, TableView>,
+}
+
+impl TableViews {
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(Self::get_file_path()?) {
+            Ok(content) => Ok(serde_json::from_str(&content).unwrap_or_default()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(TableViews::default()),
+            Err(e) => Err(Error::new(e)),
+        }
+    }
+    pub fn save(&self) -> Result<()> {
+        fs::write(Self::get_file_path()?, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+    pub fn get(
+        &self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: Option<&str>,
+        table_name: &str,
+    ) -> Option<&TableView> {
+        self.views
+            .get(&Self::get_key(conn_id, db_name, schema_name, table_name))
+    }
+    pub fn set(
+        &mut self,
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: Option<&str>,
+        table_name: &str,
+        view: TableView,
+    ) {
+        self.views.insert(
+            Self::get_key(conn_id, db_name, schema_name, table_name),
+            view,
+        );
+    }
+    fn get_key(
+        conn_id: &Uuid,
+        db_name: &str,
+        schema_name: Option<&str>,
+        table_name: &str,
+    ) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            conn_id,
+            db_name,
+            schema_name.unwrap_or_default(),
+            table_name
+        )
+    }
+    fn get_file_path() -> Result<PathBuf> {
+        let mut path = dirs_next::home_dir().ok_or(Error::msg("home dir not exists"))?;
+        path.push(APP_DIR);
+        path.push(TABLE_VIEW_FILE);
+        Ok(path)
+    }
+}
@@ -1,14 +1,20 @@
 use crate::model::{
+    is_query_statement,
     mysql::{get_mysql_connection, Connections as MySQLConnections},
     pg::{get_pg_connection, Connections as PGConnections},
 };
 use anyhow::{Error, Result};
+use futures_util::{Stream, TryStreamExt};
 use sqlx::{
-    mysql::MySqlPoolOptions, postgres::PgPoolOptions, Connection as Conn, Executor,
-    MySqlConnection, MySqlPool, PgConnection, PgPool,
+    mysql::MySqlPoolOptions, pool::PoolConnection, postgres::PgPoolOptions, Connection as Conn,
+    Executor, MySql, MySqlConnection, MySqlPool, PgConnection, PgPool, Postgres,
 };
 use sqlx::{mysql::MySqlRow, postgres::PgRow};
 use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+use tokio::{
+    sync::mpsc::{error::TryRecvError, unbounded_channel, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
 use uuid::Uuid;
 
 pub type MySQLPools = HashMap<(Uuid, Option<String>), MySqlPool>;
@@ -27,10 +33,11 @@ pub async fn get_mysql_pool(
     let conn = get_mysql_connection(conns.clone(), conn_id)?;
     let key = (*conn_id, db.map(|d| d.to_string()));
     if !pools.borrow().contains_key(&key) {
-        let uri = conn.get_pool_url(db);
+        let uri = conn.get_pool_url(db)?;
         let pool = MySqlPoolOptions::new()
             .max_connections(10)
             .acquire_timeout(Duration::from_secs(2))
+            .test_before_acquire(true)
             .connect(&uri)
             .await?;
         pools.borrow_mut().insert(key.clone(), pool);
@@ -41,6 +48,20 @@ pub async fn get_mysql_pool(
         .cloned()
         .ok_or_else(|| Error::msg("can not get mysql connection"))
 }
+pub async fn close_mysql_pools(pools: Rc<RefCell<MySQLPools>>, conn_id: &Uuid) {
+    let keys: Vec<(Uuid, Option<String>)> = pools
+        .borrow()
+        .keys()
+        .filter(|(id, _)| id == conn_id)
+        .cloned()
+        .collect();
+    for key in keys {
+        let pool = pools.borrow_mut().remove(&key);
+        if let Some(pool) = pool {
+            pool.close().await;
+        }
+    }
+}
 pub async fn close_mysql_pool(
     conns: Rc<RefCell<MySQLConnections>>,
     pools: Rc<RefCell<MySQLPools>>,
@@ -69,7 +90,8 @@ pub async fn get_pg_pool(
         let pool = PgPoolOptions::new()
             .max_connections(10)
             .acquire_timeout(Duration::from_secs(2))
-            .connect(&conn.get_pool_url(db))
+            .test_before_acquire(true)
+            .connect(&conn.get_pool_url(db)?)
             .await?;
         pools.borrow_mut().insert(key.clone(), pool);
     }
@@ -79,6 +101,20 @@ pub async fn get_pg_pool(
         .cloned()
         .ok_or_else(|| Error::msg("cannot get pg connection"))
 }
+pub async fn close_pg_pools(pools: Rc<RefCell<PGPools>>, conn_id: &Uuid) {
+    let keys: Vec<(Uuid, Option<String>)> = pools
+        .borrow()
+        .keys()
+        .filter(|(id, _)| id == conn_id)
+        .cloned()
+        .collect();
+    for key in keys {
+        let pool = pools.borrow_mut().remove(&key);
+        if let Some(pool) = pool {
+            pool.close().await;
+        }
+    }
+}
 pub async fn close_pg_pool(
     conns: Rc<RefCell<PGConnections>>,
     pools: Rc<RefCell<PGPools>>,
@@ -104,6 +140,52 @@ pub async fn test_pg_connection(uri: &str) -> Result<()> {
     PgConnection::connect(uri).await?;
     Ok(())
 }
+fn is_connection_error(e: &sqlx::Error) -> bool {
+    matches!(
+        e,
+        sqlx::Error::Io(_)
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::WorkerCrashed
+    )
+}
+async fn acquire_mysql_connection(
+    conns: Rc<RefCell<MySQLConnections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db: Option<&str>,
+) -> Result<PoolConnection<MySql>> {
+    let pool = get_mysql_pool(conns.clone(), pools.clone(), conn_id, db).await?;
+    match pool.acquire().await {
+        Err(e) if is_connection_error(&e) => {
+            pools
+                .borrow_mut()
+                .remove(&(*conn_id, db.map(|d| d.to_string())));
+            let pool = get_mysql_pool(conns, pools, conn_id, db).await?;
+            Ok(pool.acquire().await?)
+        }
+        result => Ok(result?),
+    }
+}
+async fn acquire_pg_connection(
+    conns: Rc<RefCell<PGConnections>>,
+    pools: Rc<RefCell<PGPools>>,
+    conn_id: &Uuid,
+    db: Option<&str>,
+) -> Result<PoolConnection<Postgres>> {
+    let pool = get_pg_pool(conns.clone(), pools.clone(), conn_id, db).await?;
+    match pool.acquire().await {
+        Err(e) if is_connection_error(&e) => {
+            pools
+                .borrow_mut()
+                .remove(&(*conn_id, db.map(|d| d.to_string())));
+            let pool = get_pg_pool(conns, pools, conn_id, db).await?;
+            Ok(pool.acquire().await?)
+        }
+        result => Ok(result?),
+    }
+}
+
 pub async fn execute_mysql_query(
     conns: Rc<RefCell<MySQLConnections>>,
     pools: Rc<RefCell<MySQLPools>>,
@@ -111,8 +193,8 @@ pub async fn execute_mysql_query(
     db: Option<&str>,
     sql: &str,
 ) -> Result<()> {
-    let pool = get_mysql_pool(conns, pools, conn_id, db).await?;
-    sqlx::query(sql).execute(&pool).await?;
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db).await?;
+    sqlx::query(sql).execute(&mut conn).await?;
     Ok(())
 }
 
@@ -123,8 +205,25 @@ pub async fn execute_mysql_query_unprepared(
     db: Option<&str>,
     sql: &str,
 ) -> Result<()> {
-    let pool = get_mysql_pool(conns, pools, conn_id, db).await?;
-    pool.execute(sql).await?;
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db).await?;
+    conn.execute(sql).await?;
+    Ok(())
+}
+
+// Foreign key checks are a session setting, so they are turned off and back on around the
+// statement on the same connection, also when the statement fails.
+pub async fn execute_mysql_query_without_fk_checks(
+    conns: Rc<RefCell<MySQLConnections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db: Option<&str>,
+    sql: &str,
+) -> Result<()> {
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db).await?;
+    conn.execute("SET FOREIGN_KEY_CHECKS = 0").await?;
+    let result = conn.execute(sql).await;
+    conn.execute("SET FOREIGN_KEY_CHECKS = 1").await?;
+    result?;
     Ok(())
 }
 
@@ -135,8 +234,8 @@ pub async fn execute_pg_query(
     db: Option<&str>,
     sql: &str,
 ) -> Result<()> {
-    let pool = get_pg_pool(conns, pools, conn_id, db).await?;
-    sqlx::query(sql).execute(&pool).await?;
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db).await?;
+    sqlx::query(sql).execute(&mut conn).await?;
     Ok(())
 }
 
@@ -144,10 +243,11 @@ pub async fn execute_pg_query_unprepared(
     conns: Rc<RefCell<PGConnections>>,
     pools: Rc<RefCell<PGPools>>,
     conn_id: &Uuid,
+    db: Option<&str>,
     sql: &str,
 ) -> Result<()> {
-    let pool = get_pg_pool(conns, pools, conn_id, None).await?;
-    pool.execute(sql).await?;
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db).await?;
+    conn.execute(sql).await?;
     Ok(())
 }
 
@@ -158,12 +258,24 @@ pub async fn fetch_mysql_query(
     db_name: Option<&str>,
     sql: &str,
 ) -> Result<Vec<MySqlRow>> {
-    let pool = get_mysql_pool(conns.clone(), pools, conn_id, db_name).await?;
-    let result = sqlx::query(sql).fetch_all(&pool).await;
-    match result {
-        Ok(v) => Ok(v),
-        Err(e) => Err(Error::from(e)),
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db_name).await?;
+    Ok(sqlx::query(sql).fetch_all(&mut conn).await?)
+}
+
+pub async fn fetch_mysql_query_with_params(
+    conns: Rc<RefCell<MySQLConnections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+    sql: &str,
+    params: &[&str],
+) -> Result<Vec<MySqlRow>> {
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db_name).await?;
+    let mut query = sqlx::query(sql);
+    for param in params.iter() {
+        query = query.bind(*param);
     }
+    Ok(query.fetch_all(&mut conn).await?)
 }
 
 pub async fn fetch_one_mysql(
@@ -173,12 +285,8 @@ pub async fn fetch_one_mysql(
     db_name: Option<&str>,
     sql: &str,
 ) -> Result<MySqlRow> {
-    let pool = get_mysql_pool(conns.clone(), pools, conn_id, db_name).await?;
-    let result = sqlx::query(sql).fetch_one(&pool).await;
-    match result {
-        Ok(v) => Ok(v),
-        Err(e) => Err(Error::from(e)),
-    }
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db_name).await?;
+    Ok(sqlx::query(sql).fetch_one(&mut conn).await?)
 }
 
 pub async fn fetch_one_pg(
@@ -188,12 +296,8 @@ pub async fn fetch_one_pg(
     db_name: Option<&str>,
     sql: &str,
 ) -> Result<Option<PgRow>> {
-    let pool = get_pg_pool(conns.clone(), pools, conn_id, db_name).await?;
-    let result = sqlx::query(sql).fetch_optional(&pool).await;
-    match result {
-        Ok(v) => Ok(v),
-        Err(e) => Err(Error::from(e)),
-    }
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db_name).await?;
+    Ok(sqlx::query(sql).fetch_optional(&mut conn).await?)
 }
 
 pub async fn fetch_pg_query(
@@ -203,10 +307,208 @@ pub async fn fetch_pg_query(
     db_name: Option<&str>,
     sql: &str,
 ) -> Result<Vec<PgRow>> {
-    let pool = get_pg_pool(conns.clone(), pools.clone(), conn_id, db_name).await?;
-    let result = sqlx::query(sql).fetch_all(&pool).await;
-    match result {
-        Ok(v) => Ok(v),
-        Err(e) => Err(Error::from(e)),
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db_name).await?;
+    Ok(sqlx::query(sql).fetch_all(&mut conn).await?)
+}
+
+pub struct QueryPage<R> {
+    pub rows: Vec<R>,
+    pub rows_affected: Option<u64>,
+    pub has_more: bool,
+}
+
+pub struct QueryCursor<R> {
+    requests: UnboundedSender<usize>,
+    pages: UnboundedReceiver<Result<QueryPage<R>>>,
+    task: JoinHandle<()>,
+    pending: bool,
+}
+
+impl<R> QueryCursor<R> {
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+    pub fn fetch_more(&mut self, count: usize) {
+        if !self.pending && self.requests.send(count).is_ok() {
+            self.pending = true;
+        }
+    }
+    pub fn try_next_page(&mut self) -> Option<Result<QueryPage<R>>> {
+        if !self.pending {
+            return None;
+        }
+        match self.pages.try_recv() {
+            Ok(page) => {
+                self.pending = false;
+                Some(page)
+            }
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                self.pending = false;
+                Some(Err(Error::msg("query task stopped unexpectedly")))
+            }
+        }
+    }
+}
+
+impl<R> Drop for QueryCursor<R> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn stream_pages<R, S>(
+    mut stream: S,
+    mut requests: UnboundedReceiver<usize>,
+    pages: &UnboundedSender<Result<QueryPage<R>>>,
+) -> Result<()>
+where
+    S: Stream<Item = Result<R, sqlx::Error>> + Unpin,
+{
+    let mut next = None;
+    let mut started = false;
+    while let Some(count) = requests.recv().await {
+        if !started {
+            next = stream.try_next().await?;
+            started = true;
+        }
+        let mut rows = Vec::new();
+        while rows.len() < count {
+            match next.take() {
+                Some(row) => {
+                    rows.push(row);
+                    next = stream.try_next().await?;
+                }
+                None => break,
+            }
+        }
+        let has_more = next.is_some();
+        let page = QueryPage {
+            rows,
+            rows_affected: None,
+            has_more,
+        };
+        if pages.send(Ok(page)).is_err() || !has_more {
+            break;
+        }
+    }
+    Ok(())
+}
+
+pub async fn fetch_pg_query_with_params(
+    conns: Rc<RefCell<PGConnections>>,
+    pools: Rc<RefCell<PGPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+    sql: &str,
+    params: &[&str],
+) -> Result<Vec<PgRow>> {
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db_name).await?;
+    let mut query = sqlx::query(sql);
+    for param in params.iter() {
+        query = query.bind(*param);
     }
+    Ok(query.fetch_all(&mut conn).await?)
+}
+
+pub async fn spawn_mysql_query(
+    conns: Rc<RefCell<MySQLConnections>>,
+    pools: Rc<RefCell<MySQLPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+    sql: &str,
+    params: &[Option<String>],
+    page_size: usize,
+) -> Result<QueryCursor<MySqlRow>> {
+    let mut conn = acquire_mysql_connection(conns, pools, conn_id, db_name).await?;
+    let sql = sql.to_string();
+    let params = params.to_vec();
+    let (request_tx, mut request_rx) = unbounded_channel();
+    let (page_tx, page_rx) = unbounded_channel();
+    let task = tokio::spawn(async move {
+        let result = async {
+            let mut query = sqlx::query(&sql);
+            for param in params.iter() {
+                query = query.bind(param);
+            }
+            if !is_query_statement(&sql) {
+                let result = if params.is_empty() {
+                    conn.execute(sql.as_str()).await?
+                } else {
+                    query.execute(&mut conn).await?
+                };
+                request_rx.recv().await;
+                let _ = page_tx.send(Ok(QueryPage {
+                    rows: Vec::new(),
+                    rows_affected: Some(result.rows_affected()),
+                    has_more: false,
+                }));
+                return Ok(());
+            }
+            stream_pages(query.fetch(&mut conn), request_rx, &page_tx).await
+        }
+        .await;
+        if let Err(e) = result {
+            let _ = page_tx.send(Err(e));
+        }
+    });
+    let mut cursor = QueryCursor {
+        requests: request_tx,
+        pages: page_rx,
+        task,
+        pending: false,
+    };
+    cursor.fetch_more(page_size);
+    Ok(cursor)
+}
+
+pub async fn spawn_pg_query(
+    conns: Rc<RefCell<PGConnections>>,
+    pools: Rc<RefCell<PGPools>>,
+    conn_id: &Uuid,
+    db_name: Option<&str>,
+    sql: &str,
+    params: &[Option<String>],
+    page_size: usize,
+) -> Result<QueryCursor<PgRow>> {
+    let mut conn = acquire_pg_connection(conns, pools, conn_id, db_name).await?;
+    let sql = sql.to_string();
+    let params = params.to_vec();
+    let (request_tx, mut request_rx) = unbounded_channel();
+    let (page_tx, page_rx) = unbounded_channel();
+    let task = tokio::spawn(async move {
+        let result = async {
+            let mut query = sqlx::query(&sql);
+            for param in params.iter() {
+                query = query.bind(param);
+            }
+            if !is_query_statement(&sql) {
+                let result = if params.is_empty() {
+                    conn.execute(sql.as_str()).await?
+                } else {
+                    query.execute(&mut conn).await?
+                };
+                request_rx.recv().await;
+                let _ = page_tx.send(Ok(QueryPage {
+                    rows: Vec::new(),
+                    rows_affected: Some(result.rows_affected()),
+                    has_more: false,
+                }));
+                return Ok(());
+            }
+            stream_pages(query.fetch(&mut conn), request_rx, &page_tx).await
+        }
+        .await;
+        if let Err(e) = result {
+            let _ = page_tx.send(Err(e));
+        }
+    });
+    let mut cursor = QueryCursor {
+        requests: request_tx,
+        pages: page_rx,
+        task,
+        pending: false,
+    };
+    cursor.fetch_more(page_size);
+    Ok(cursor)
 }
@@ -0,0 +1,52 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+pub struct ConnectionAccent<'a> {
+    color: Option<Color>,
+    label: Option<&'a str>,
+}
+
+impl<'a> ConnectionAccent<'a> {
+    pub fn new(color: Option<Color>, label: Option<&'a str>) -> Self {
+        ConnectionAccent { color, label }
+    }
+}
+
+impl<'a> Widget for ConnectionAccent<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+        if let Some(color) = self.color {
+            for x in area.left()..area.right() {
+                buf.get_mut(x, area.top()).set_fg(color);
+                buf.get_mut(x, area.bottom() - 1).set_fg(color);
+            }
+            for y in area.top()..area.bottom() {
+                buf.get_mut(area.left(), y).set_fg(color);
+                buf.get_mut(area.right() - 1, y).set_fg(color);
+            }
+        }
+        if let Some(label) = self.label {
+            let label = format!(" {} ", label);
+            let width = label.chars().count() as u16;
+            if width + 4 > area.width {
+                return;
+            }
+            let style = match self.color {
+                Some(color) => Style::default().fg(Color::Black).bg(color),
+                None => Style::default().add_modifier(Modifier::REVERSED),
+            };
+            buf.set_string(
+                area.right() - 2 - width,
+                area.top(),
+                label,
+                style.add_modifier(Modifier::BOLD),
+            );
+        }
+    }
+}
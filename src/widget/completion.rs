@@ -0,0 +1,164 @@
+use crate::{
+    app::DialogResult,
+    component::Command,
+    event::{config::*, Key},
+};
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+use regex::Regex;
+use std::cmp::min;
+use tui_textarea::TextArea;
+
+const MAX_ITEMS: u16 = 8;
+
+pub struct Completion {
+    items: Vec<String>,
+    prefix: String,
+    state: ListState,
+}
+
+impl Completion {
+    pub fn new(candidates: &[String], prefix: &str) -> Option<Self> {
+        let lower = prefix.to_lowercase();
+        let mut items = candidates
+            .iter()
+            .filter(|c| c.to_lowercase().starts_with(&lower) && c.len() > prefix.len())
+            .cloned()
+            .collect::<Vec<String>>();
+        items.sort_by_key(|i| i.to_lowercase());
+        items.dedup();
+        if items.is_empty() {
+            return None;
+        }
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Some(Completion {
+            items,
+            prefix: prefix.to_string(),
+            state,
+        })
+    }
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_str()
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, x: u16, y: u16)
+    where
+        B: Backend,
+    {
+        let bounds = f.size();
+        let width = self
+            .items
+            .iter()
+            .map(|i| i.chars().count() as u16 + 2)
+            .max()
+            .unwrap_or_default()
+            .clamp(12, 40)
+            .min(bounds.width);
+        let height = min(self.items.len() as u16, MAX_ITEMS) + 2;
+        let left = min(x, bounds.width - width);
+        let top = if y + 1 + height <= bounds.height {
+            y + 1
+        } else {
+            y.saturating_sub(height)
+        };
+        let rect = Rect::new(left, top, width, height.min(bounds.height));
+
+        f.render_widget(Clear, rect);
+        f.render_stateful_widget(
+            List::new(
+                self.items
+                    .iter()
+                    .map(|i| ListItem::new(i.as_str()))
+                    .collect::<Vec<ListItem>>(),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(Style::default().fg(Color::Green)),
+            rect,
+            &mut self.state,
+        );
+    }
+    pub fn handle_event(&mut self, key: &Key) -> DialogResult<String> {
+        match *key {
+            UP_KEY => {
+                let index = self.state.selected().unwrap_or_default();
+                self.state.select(Some(index.saturating_sub(1)));
+                DialogResult::Done
+            }
+            DOWN_KEY => {
+                let index = self.state.selected().map_or(0, |i| i + 1);
+                self.state
+                    .select(Some(min(index, self.items.len().saturating_sub(1))));
+                DialogResult::Done
+            }
+            CONFIRM_KEY | SWITCH_KEY => match self.state.selected() {
+                Some(i) => DialogResult::Confirm(self.items[i].clone()),
+                None => DialogResult::Cancel,
+            },
+            _ => DialogResult::Cancel,
+        }
+    }
+    pub fn get_commands(&self) -> Vec<Command> {
+        vec![
+            Command {
+                name: "Up",
+                key: UP_KEY,
+            },
+            Command {
+                name: "Down",
+                key: DOWN_KEY,
+            },
+            Command {
+                name: "Complete",
+                key: CONFIRM_KEY,
+            },
+            Command {
+                name: "Close",
+                key: CANCEL_KEY,
+            },
+        ]
+    }
+}
+pub fn get_completion_context(textarea: &TextArea) -> (Option<String>, String) {
+    let (row, col) = textarea.cursor();
+    let line = textarea.lines()[row]
+        .chars()
+        .take(col)
+        .collect::<Vec<char>>();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_' || *c == '$';
+    let start = line.len() - line.iter().rev().take_while(|c| is_word(c)).count();
+    let prefix = line[start..].iter().collect::<String>();
+    if start > 0 && line[start - 1] == '.' {
+        let before = &line[..start - 1];
+        let qualifier_start = before.len() - before.iter().rev().take_while(|c| is_word(c)).count();
+        let qualifier = before[qualifier_start..].iter().collect::<String>();
+        if !qualifier.is_empty() {
+            return (Some(qualifier), prefix);
+        }
+    }
+    (None, prefix)
+}
+pub fn resolve_table_alias(sql: &str, alias: &str, tables: &[String]) -> Option<String> {
+    if let Some(t) = tables.iter().find(|t| t.eq_ignore_ascii_case(alias)) {
+        return Some(t.to_string());
+    }
+    let regex = Regex::new(&format!(
+        r#"(?i)[`"]?([\w$]+)[`"]?\s+(?:AS\s+)?{}\b"#,
+        regex::escape(alias)
+    ))
+    .ok()?;
+    let table = regex
+        .captures_iter(sql)
+        .filter_map(|c| c.get(1))
+        .find_map(|m| tables.iter().find(|t| t.eq_ignore_ascii_case(m.as_str())))
+        .cloned();
+    table
+}
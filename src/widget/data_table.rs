@@ -0,0 +1,170 @@
+use crate::{model::DisplayFormat, widget::Scrollbar};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Cell, Row, StatefulWidget, Table, TableState, Widget},
+};
+
+pub const MAX_COLUMN_WIDTH: u16 = 40;
+
+pub trait TableValue {
+    fn into_value(self) -> Option<String>;
+}
+
+impl TableValue for String {
+    fn into_value(self) -> Option<String> {
+        Some(self)
+    }
+}
+
+impl TableValue for &str {
+    fn into_value(self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl TableValue for &String {
+    fn into_value(self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+impl TableValue for Option<String> {
+    fn into_value(self) -> Option<String> {
+        self
+    }
+}
+
+impl TableValue for Option<&str> {
+    fn into_value(self) -> Option<String> {
+        self.map(|s| s.to_string())
+    }
+}
+
+pub struct DataTable<'a> {
+    headers: Vec<&'a str>,
+    rows: Vec<Vec<Option<String>>>,
+    row_styles: Vec<Style>,
+    null_text: String,
+}
+
+impl<'a> DataTable<'a> {
+    pub fn new<R, V>(rows: impl IntoIterator<Item = R>) -> Self
+    where
+        R: IntoIterator<Item = V>,
+        V: TableValue,
+    {
+        DataTable {
+            headers: Vec::new(),
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|v| v.into_value()).collect())
+                .collect(),
+            row_styles: Vec::new(),
+            null_text: DisplayFormat::default().null_text,
+        }
+    }
+    pub fn header(mut self, headers: impl IntoIterator<Item = &'a str>) -> Self {
+        self.headers = headers.into_iter().collect();
+        self
+    }
+    pub fn row_styles(mut self, styles: impl IntoIterator<Item = Style>) -> Self {
+        self.row_styles = styles.into_iter().collect();
+        self
+    }
+    pub fn display_format(mut self, display_format: &DisplayFormat) -> Self {
+        self.null_text = display_format.null_text.clone();
+        self
+    }
+}
+
+impl<'a> StatefulWidget for DataTable<'a> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut TableState) {
+        let header_height = if self.headers.is_empty() { 0 } else { 1 };
+        let widths =
+            get_column_widths(&self.headers, &self.rows, &self.null_text, MAX_COLUMN_WIDTH);
+        let total = widths.iter().map(|w| *w as u32).sum::<u32>().max(1);
+        let constraints = widths
+            .iter()
+            .map(|w| Constraint::Ratio(*w as u32, total))
+            .collect::<Vec<Constraint>>();
+        let len = self.rows.len();
+
+        let mut table = Table::new(
+            self.rows
+                .iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    Row::new(
+                        row.iter()
+                            .map(|value| get_value_cell(value.as_deref(), &self.null_text))
+                            .collect::<Vec<Cell>>(),
+                    )
+                    .style(self.row_styles.get(i).copied().unwrap_or_default())
+                })
+                .collect::<Vec<Row>>(),
+        )
+        .widths(&constraints)
+        .highlight_style(Style::default().fg(Color::Green));
+        if !self.headers.is_empty() {
+            table = table.header(Row::new(self.headers.clone()));
+        }
+        StatefulWidget::render(table, area, buf, state);
+
+        Scrollbar::new(
+            len,
+            state.offset(),
+            area.height.saturating_sub(header_height) as usize,
+        )
+        .render(
+            Rect::new(
+                area.right().saturating_sub(1),
+                area.y + header_height,
+                1,
+                area.height.saturating_sub(header_height),
+            ),
+            buf,
+        );
+    }
+}
+
+pub fn get_column_widths<H: AsRef<str>>(
+    headers: &[H],
+    values: &[Vec<Option<String>>],
+    null_text: &str,
+    max_width: u16,
+) -> Vec<u16> {
+    let columns = headers
+        .len()
+        .max(values.iter().map(|row| row.len()).max().unwrap_or_default());
+    (0..columns)
+        .map(|i| {
+            let width = values
+                .iter()
+                .map(|row| {
+                    row.get(i)
+                        .and_then(|v| v.as_ref())
+                        .map_or(null_text.chars().count(), |v| v.chars().count())
+                })
+                .fold(
+                    headers.get(i).map_or(0, |h| h.as_ref().chars().count()),
+                    usize::max,
+                );
+            (width as u16).clamp(4, max_width)
+        })
+        .collect()
+}
+pub fn get_value_cell<'a>(value: Option<&'a str>, null_text: &'a str) -> Cell<'a> {
+    if let Some(value) = value {
+        Cell::from(value)
+    } else {
+        Cell::from(null_text).style(
+            Style::default()
+                .add_modifier(Modifier::DIM)
+                .add_modifier(Modifier::ITALIC),
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use tui_textarea::{CursorMove, TextArea};
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('\'', '\''), ('"', '"'), ('`', '`')];
+
+fn get_char_around(textarea: &TextArea) -> (Option<char>, Option<char>) {
+    let (row, col) = textarea.cursor();
+    let line = textarea.lines()[row].chars().collect::<Vec<char>>();
+    let prev = if col > 0 { line.get(col - 1) } else { None };
+    (prev.copied(), line.get(col).copied())
+}
+
+pub fn insert_pair_char(textarea: &mut TextArea, c: char) -> bool {
+    let (prev, next) = get_char_around(textarea);
+    if next == Some(c) && PAIRS.iter().any(|(_, close)| *close == c) {
+        textarea.move_cursor(CursorMove::Forward);
+        return true;
+    }
+    let close = match PAIRS.iter().find(|(open, _)| *open == c) {
+        Some((_, close)) => *close,
+        None => return false,
+    };
+    let is_word = |c: Option<char>| c.map_or(false, |c| c.is_alphanumeric() || c == '_');
+    if is_word(next) || (c != '(' && is_word(prev)) {
+        return false;
+    }
+    textarea.insert_char(c);
+    textarea.insert_char(close);
+    textarea.move_cursor(CursorMove::Back);
+    true
+}
+
+pub fn delete_pair_char(textarea: &mut TextArea) -> bool {
+    match get_char_around(textarea) {
+        (Some(prev), Some(next)) if PAIRS.contains(&(prev, next)) => {
+            textarea.delete_next_char();
+            textarea.delete_char();
+            true
+        }
+        _ => false,
+    }
+}
+
+// Comments out the lines from `anchor` to the cursor line, or uncomments them when every
+// non-blank line in the range already starts with `--`.
+pub fn toggle_line_comment(textarea: &mut TextArea, anchor: Option<usize>) {
+    let (row, col) = textarea.cursor();
+    let (first, last) = match anchor {
+        Some(anchor) => (anchor.min(row), anchor.max(row)),
+        None => (row, row),
+    };
+    let lines = textarea.lines()[first..=last]
+        .iter()
+        .map(|l| l.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+    let get_indent = |line: &[char]| line.iter().take_while(|c| c.is_whitespace()).count();
+    let mut targets = (0..lines.len())
+        .filter(|i| get_indent(&lines[*i]) < lines[*i].len())
+        .collect::<Vec<usize>>();
+    if targets.is_empty() {
+        targets = (0..lines.len()).collect();
+    }
+    let uncomment = targets
+        .iter()
+        .all(|i| lines[*i][get_indent(&lines[*i])..].starts_with(&['-', '-']));
+    let mut cursor_col = col;
+    for i in targets {
+        let indent = get_indent(&lines[i]);
+        let rest = &lines[i][indent..];
+        textarea.move_cursor(CursorMove::Jump((first + i) as u16, indent as u16));
+        if uncomment {
+            let len = if rest.starts_with(&['-', '-', ' ']) {
+                3
+            } else {
+                2
+            };
+            for _ in 0..len {
+                textarea.delete_next_char();
+            }
+            if first + i == row && col > indent {
+                cursor_col = col.saturating_sub(len).max(indent);
+            }
+        } else {
+            textarea.insert_str("-- ");
+            if first + i == row && col >= indent {
+                cursor_col = col + 3;
+            }
+        }
+    }
+    textarea.move_cursor(CursorMove::Jump(row as u16, cursor_col as u16));
+}
@@ -2,16 +2,18 @@ use crate::{
     app::DialogResult,
     component::Command,
     event::{config::*, Key},
-    widget::{DialogState, FormItem, FormItemResult},
+    widget::{DialogState, FormItem, FormItemResult, InputKind},
 };
 use anyhow::{Error, Result};
-use std::collections::HashMap;
-use tui::{
+use ratatui::{
     backend::Backend,
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    widgets::{Block, BorderType, Borders},
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Widget},
     Frame,
 };
+use std::collections::HashMap;
 
 const MARGIN_VERTICAL: u16 = 1;
 const MARGIN_HORIZONTAL: u16 = 1;
@@ -22,6 +24,7 @@ pub struct Form<'a> {
     focus: usize,
     offset: usize,
     items: Vec<FormItem<'a>>,
+    errors: HashMap<String, String>,
 }
 
 impl<'a> Form<'a> {
@@ -51,6 +54,25 @@ impl<'a> Form<'a> {
             self.items.splice(index..index + 1, [item]);
         }
     }
+    pub fn set_error(&mut self, name: &str, error: &str) {
+        self.errors.insert(name.to_string(), error.to_string());
+    }
+    pub fn clear_error(&mut self, name: &str) {
+        self.errors.remove(name);
+    }
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+    pub fn get_errors(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                self.errors
+                    .get(item.name())
+                    .map(|e| format!("{}: {}", item.name(), e))
+            })
+            .collect()
+    }
     pub fn set_value(&mut self, name: &str, value: &str) {
         let index = self.items.iter().position(|i| i.name() == name);
         if let Some(index) = index {
@@ -92,6 +114,9 @@ impl<'a> Form<'a> {
             .enumerate()
             .for_each(|(i, item)| {
                 item.draw(f, chunks[i], self.focus - self.offset == i);
+                if let Some(error) = self.errors.get(item.name()) {
+                    f.render_widget(ErrorBorder(error), chunks[i]);
+                }
             });
         self.items
             .iter_mut()
@@ -177,7 +202,11 @@ impl<'a> Form<'a> {
         &mut self,
         key: &Key,
     ) -> Result<DialogResult<HashMap<String, Option<String>>>> {
-        match self.items[self.focus].handle_event(key)? {
+        let result = self.items[self.focus].handle_event(key)?;
+        if !matches!(result, FormItemResult::UnHandled) {
+            self.errors.remove(self.items[self.focus].name());
+        }
+        match result {
             FormItemResult::Changed(name, changed) => Ok(DialogResult::Changed(name, changed)),
             FormItemResult::UnHandled => match *key {
                 UP_KEY => {
@@ -187,7 +216,7 @@ impl<'a> Form<'a> {
                     Ok(DialogResult::Done)
                 }
                 DOWN_KEY => {
-                    if self.focus < self.items.len() - 1 {
+                    if self.focus + 1 < self.items.len() {
                         self.focus += 1;
                     }
                     Ok(DialogResult::Done)
@@ -195,6 +224,9 @@ impl<'a> Form<'a> {
                 CANCEL_KEY => Ok(DialogResult::Cancel),
                 SAVE_KEY => {
                     self.validate_input()?;
+                    if let Some(error) = self.get_errors().first() {
+                        return Err(Error::msg(error.to_string()));
+                    }
                     Ok(DialogResult::Confirm(self.get_data()))
                 }
                 _ => Ok(DialogResult::Done),
@@ -206,81 +238,13 @@ impl<'a> Form<'a> {
         self.items.iter_mut().for_each(|item| item.clear());
         self.focus = 0;
         self.offset = 0;
+        self.errors.clear();
     }
-    pub fn validate_input(&self) -> Result<()> {
+    pub fn validate_input(&mut self) -> Result<()> {
         for item in self.items.iter() {
-            match &item {
-                FormItem::Input {
-                    name,
-                    nullable,
-                    can_null,
-                    is_null,
-                    input,
-                    ..
-                } => {
-                    if !*nullable {
-                        if (*can_null && *is_null) || (!*can_null && input.is_empty()) {
-                            return Err(Error::msg(format!("Please input {}", name)));
-                        }
-                    }
-                }
-                FormItem::TextArea {
-                    name,
-                    nullable,
-                    textarea,
-                    ..
-                } => {
-                    if !nullable && textarea.is_empty() {
-                        return Err(Error::msg(format!("Please input {}", name)));
-                    }
-                }
-                FormItem::Select {
-                    name,
-                    selected,
-                    nullable,
-                    ..
-                } => {
-                    if !*nullable && selected.is_none() {
-                        return Err(Error::msg(format!("Please select {}", name)));
-                    }
-                }
-                FormItem::MultiSelect {
-                    name,
-                    selected,
-                    nullable,
-                    ..
-                } => {
-                    if !nullable && selected.is_empty() {
-                        return Err(Error::msg(format!("Please select {}", name)));
-                    }
-                }
-                FormItem::List {
-                    name,
-                    nullable,
-                    items,
-                    ..
-                } => {
-                    if !nullable && items.is_empty() {
-                        return Err(Error::msg(format!(
-                            "Please add at least one item in {}",
-                            name
-                        )));
-                    }
-                }
-                FormItem::TableList {
-                    name,
-                    nullable,
-                    rows,
-                    ..
-                } => {
-                    if !nullable && rows.is_empty() {
-                        return Err(Error::msg(format!(
-                            "Please add at least one item in {}",
-                            name
-                        )));
-                    }
-                }
-                FormItem::Check { .. } => (),
+            if let Err(e) = validate_item(item) {
+                self.errors.insert(item.name().to_string(), e.to_string());
+                return Err(e);
             }
         }
         Ok(())
@@ -363,7 +327,7 @@ impl<'a> Form<'a> {
             height += item.height();
             end += 1;
         }
-        let focus = focus.min(self.items.len() - 1);
+        let focus = focus.min(self.items.len().saturating_sub(1));
         while focus >= end {
             height = height.saturating_add(self.items[end].height());
             end += 1;
@@ -383,3 +347,114 @@ impl<'a> Form<'a> {
         (start, end)
     }
 }
+
+fn validate_item(item: &FormItem) -> Result<()> {
+    match item {
+        FormItem::Input {
+            name,
+            nullable,
+            can_null,
+            is_null,
+            input,
+            kind,
+            ..
+        } => {
+            if !*nullable && ((*can_null && *is_null) || (!*can_null && input.is_empty())) {
+                return Err(Error::msg(format!("Please input {}", name)));
+            }
+            if *kind == InputKind::Numeric
+                && !(*can_null && *is_null)
+                && input
+                    .lines()
+                    .iter()
+                    .any(|l| !l.chars().all(|c| c.is_ascii_digit()))
+            {
+                return Err(Error::msg(format!("{} must be a number", name)));
+            }
+        }
+        FormItem::TextArea {
+            name,
+            nullable,
+            textarea,
+            ..
+        } => {
+            if !nullable && textarea.is_empty() {
+                return Err(Error::msg(format!("Please input {}", name)));
+            }
+        }
+        FormItem::Select {
+            name,
+            selected,
+            nullable,
+            ..
+        } => {
+            if !*nullable && selected.is_none() {
+                return Err(Error::msg(format!("Please select {}", name)));
+            }
+        }
+        FormItem::MultiSelect {
+            name,
+            selected,
+            nullable,
+            ..
+        } => {
+            if !nullable && selected.is_empty() {
+                return Err(Error::msg(format!("Please select {}", name)));
+            }
+        }
+        FormItem::List {
+            name,
+            nullable,
+            items,
+            ..
+        } => {
+            if !nullable && items.is_empty() {
+                return Err(Error::msg(format!(
+                    "Please add at least one item in {}",
+                    name
+                )));
+            }
+        }
+        FormItem::TableList {
+            name,
+            nullable,
+            rows,
+            ..
+        } => {
+            if !nullable && rows.is_empty() {
+                return Err(Error::msg(format!(
+                    "Please add at least one item in {}",
+                    name
+                )));
+            }
+        }
+        FormItem::Check { .. } => (),
+    }
+    Ok(())
+}
+
+struct ErrorBorder<'b>(&'b str);
+
+impl<'b> Widget for ErrorBorder<'b> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+        let style = Style::default().fg(Color::Red);
+        for x in area.left()..area.right() {
+            buf.get_mut(x, area.top()).set_fg(Color::Red);
+            buf.get_mut(x, area.bottom() - 1).set_fg(Color::Red);
+        }
+        for y in area.top()..area.bottom() {
+            buf.get_mut(area.left(), y).set_fg(Color::Red);
+            buf.get_mut(area.right() - 1, y).set_fg(Color::Red);
+        }
+        buf.set_stringn(
+            area.x + 1,
+            area.bottom() - 1,
+            self.0,
+            area.width.saturating_sub(2) as usize,
+            style,
+        );
+    }
+}
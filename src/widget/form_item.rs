@@ -4,8 +4,7 @@ use crate::{
     widget::select::{draw_select, handle_select_event},
 };
 use anyhow::{Error, Result};
-use std::cmp::min;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Style},
@@ -16,6 +15,7 @@ use tui::{
     },
     Frame,
 };
+use std::cmp::min;
 use tui_textarea::{CursorMove, Input, TextArea};
 
 pub enum FormItemResult {
@@ -164,6 +164,23 @@ pub enum DialogState {
 
 type ShowFn = fn(&[String]) -> String;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Freeform,
+    Numeric,
+    Identifier,
+}
+
+impl InputKind {
+    fn accept(&self, c: char) -> bool {
+        match self {
+            InputKind::Freeform => true,
+            InputKind::Numeric => c.is_ascii_digit(),
+            InputKind::Identifier => c.is_alphanumeric() || c == '_' || c == '$',
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum FormItem<'a> {
     Select {
@@ -193,6 +210,7 @@ pub enum FormItem<'a> {
         is_null: bool,
         can_null: bool,
         readonly: bool,
+        kind: InputKind,
     },
     TextArea {
         name: String,
@@ -258,7 +276,14 @@ impl<'a> FormItem<'a> {
             is_null: can_null && content.is_none(),
             can_null,
             readonly,
+            kind: InputKind::Freeform,
+        }
+    }
+    pub fn input_kind(mut self, input_kind: InputKind) -> FormItem<'a> {
+        if let FormItem::Input { kind, .. } = &mut self {
+            *kind = input_kind;
         }
+        self
     }
     pub fn new_textarea(
         name: String,
@@ -1173,6 +1198,7 @@ impl<'a> FormItem<'a> {
                 readonly,
                 can_null,
                 is_null,
+                kind,
                 ..
             } => match key {
                 Key {
@@ -1201,6 +1227,10 @@ impl<'a> FormItem<'a> {
                     }
                     FormItemResult::Handled
                 }
+                Key {
+                    code: KeyCode::Char(c),
+                    modifier: KeyModifier::None | KeyModifier::Shift,
+                } if !*readonly && !kind.accept(*c) => FormItemResult::Handled,
                 _ => {
                     if !*readonly {
                         let key: Input = key.to_owned().into();
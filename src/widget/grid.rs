@@ -0,0 +1,249 @@
+use crate::{
+    component::get_clicked_row,
+    event::{config::*, Key},
+    model::DisplayFormat,
+    widget::{get_column_widths, get_value_cell, Scrollbar, MAX_COLUMN_WIDTH},
+};
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Cell, Row, Table, TableState},
+    Frame,
+};
+
+#[derive(Default)]
+pub struct Grid {
+    headers: Vec<String>,
+    bools: Vec<bool>,
+    values: Vec<Vec<Option<String>>>,
+    widths: Vec<u16>,
+    state: TableState,
+    column_index: usize,
+    column_offset: usize,
+    rect: Rect,
+    display_format: DisplayFormat,
+}
+
+impl Grid {
+    pub fn new(display_format: DisplayFormat) -> Self {
+        Grid {
+            display_format,
+            ..Grid::default()
+        }
+    }
+    // Cells of bool columns hold "true"/"false" and are drawn with the configured glyphs.
+    pub fn set_data(
+        &mut self,
+        headers: Vec<String>,
+        bools: Vec<bool>,
+        values: Vec<Vec<Option<String>>>,
+    ) {
+        self.widths = get_column_widths(
+            &headers,
+            &values,
+            &self.display_format.null_text,
+            MAX_COLUMN_WIDTH,
+        );
+        if headers.len() != self.headers.len() {
+            self.column_index = 0;
+            self.column_offset = 0;
+        }
+        self.headers = headers;
+        self.bools = bools;
+        self.values = values;
+        if self.values.is_empty() {
+            self.state.select(None);
+        } else if let Some(index) = self.state.selected() {
+            self.state.select(Some(index.min(self.values.len() - 1)));
+        }
+    }
+    pub fn clear(&mut self) {
+        *self = Grid::new(self.display_format.clone());
+    }
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    pub fn selected(&self) -> Option<usize> {
+        self.state.selected()
+    }
+    pub fn select(&mut self, index: Option<usize>) {
+        self.state.select(index);
+    }
+    pub fn column_index(&self) -> usize {
+        self.column_index
+    }
+    pub fn select_column(&mut self, index: usize) {
+        self.column_index = index.min(self.headers.len().saturating_sub(1));
+    }
+    pub fn get_selected_cell(&self) -> Option<(&str, Option<&str>)> {
+        let index = self.state.selected()?;
+        let header = self.headers.get(self.column_index)?;
+        Some((header, self.values[index][self.column_index].as_deref()))
+    }
+    pub fn get_row_values(&self, index: usize) -> Vec<(String, Option<String>)> {
+        self.headers
+            .iter()
+            .cloned()
+            .zip(self.values[index].iter().cloned())
+            .collect()
+    }
+    pub fn to_markdown(&self) -> String {
+        let escape = |value: &str| value.replace('|', "\\|").replace('\n', "<br>");
+        let mut lines = vec![
+            format!(
+                "| {} |",
+                self.headers
+                    .iter()
+                    .map(|h| escape(h))
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            ),
+            format!("|{}", " --- |".repeat(self.headers.len())),
+        ];
+        for row in self.values.iter() {
+            lines.push(format!(
+                "| {} |",
+                row.iter()
+                    .map(|v| v
+                        .as_deref()
+                        .map_or(self.display_format.null_text.clone(), escape))
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            ));
+        }
+        lines.join("\n")
+    }
+    pub fn draw<B>(&mut self, f: &mut Frame<B>, r: Rect, block: Block)
+    where
+        B: Backend,
+    {
+        self.rect = block.inner(r);
+        let (offset, end) = get_column_window(
+            self.column_offset,
+            self.column_index,
+            &self.widths,
+            self.rect.width,
+        );
+        self.column_offset = offset;
+        let columns = &self.widths[offset..end]
+            .iter()
+            .map(|w| Constraint::Length(*w))
+            .collect::<Vec<Constraint>>();
+
+        let table = Table::new(
+            self.values
+                .iter()
+                .map(|row| {
+                    Row::new(
+                        row[offset..end]
+                            .iter()
+                            .enumerate()
+                            .map(|(i, value)| {
+                                let value = match value.as_deref() {
+                                    Some("true") if self.bools.get(offset + i) == Some(&true) => {
+                                        Some(self.display_format.format_bool(true))
+                                    }
+                                    Some("false") if self.bools.get(offset + i) == Some(&true) => {
+                                        Some(self.display_format.format_bool(false))
+                                    }
+                                    value => value,
+                                };
+                                get_value_cell(value, &self.display_format.null_text)
+                            })
+                            .collect::<Vec<Cell>>(),
+                    )
+                })
+                .collect::<Vec<Row>>(),
+        )
+        .header(Row::new(
+            self.headers[offset..end]
+                .iter()
+                .enumerate()
+                .map(|(i, header)| {
+                    let cell = Cell::from(header.as_str());
+                    if offset + i == self.column_index {
+                        cell.style(Style::default().add_modifier(Modifier::UNDERLINED))
+                    } else {
+                        cell
+                    }
+                })
+                .collect::<Vec<Cell>>(),
+        ))
+        .block(block)
+        .widths(&columns[..])
+        .highlight_style(Style::default().fg(Color::Green));
+
+        f.render_stateful_widget(table, r, &mut self.state);
+        f.render_widget(
+            Scrollbar::new(
+                self.values.len(),
+                self.state.offset(),
+                self.rect.height.saturating_sub(1) as usize,
+            ),
+            Rect::new(
+                r.right().saturating_sub(1),
+                self.rect.y + 1,
+                1,
+                self.rect.height.saturating_sub(1),
+            ),
+        );
+    }
+    pub fn handle_event(&mut self, key: &Key) -> bool {
+        match *key {
+            UP_KEY => {
+                if !self.values.is_empty() {
+                    let index = self.state.selected().unwrap_or_default();
+                    self.state.select(Some(index.saturating_sub(1)));
+                }
+            }
+            DOWN_KEY => {
+                if !self.values.is_empty() {
+                    let index = match self.state.selected() {
+                        Some(i) => (i + 1).min(self.values.len() - 1),
+                        None => 0,
+                    };
+                    self.state.select(Some(index));
+                }
+            }
+            LEFT_KEY => {
+                if self.column_index > 0 {
+                    self.column_index -= 1;
+                }
+            }
+            RIGHT_KEY => {
+                if self.column_index + 1 < self.headers.len() {
+                    self.column_index += 1;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+    pub fn handle_click(&mut self, x: u16, y: u16) {
+        if let Some(index) = get_clicked_row(self.rect, 1, self.state.offset(), x, y) {
+            if index < self.values.len() {
+                self.state.select(Some(index));
+            }
+        }
+    }
+}
+
+fn get_column_window(offset: usize, selected: usize, widths: &[u16], width: u16) -> (usize, usize) {
+    if widths.is_empty() {
+        return (0, 0);
+    }
+    let span = |start: usize, end: usize| widths[start..end].iter().map(|w| w + 1).sum::<u16>();
+    let mut start = offset.min(selected);
+    while start < selected && span(start, selected + 1) > width + 1 {
+        start += 1;
+    }
+    let mut end = selected + 1;
+    while end < widths.len() && span(start, end + 1) <= width + 1 {
+        end += 1;
+    }
+    (start, end)
+}
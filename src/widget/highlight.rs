@@ -0,0 +1,299 @@
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::Paragraph,
+    Frame,
+};
+use tui_textarea::TextArea;
+
+pub const SQL_KEYWORDS: &[&str] = &[
+    "ADD",
+    "AFTER",
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "AUTO_INCREMENT",
+    "BEFORE",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMENT",
+    "COMMIT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DATABASE",
+    "DECLARE",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "EACH",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EVENT",
+    "EXCEPT",
+    "EXECUTE",
+    "EXISTS",
+    "EXPLAIN",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "FUNCTION",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IDENTIFIED",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INSTEAD",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LANGUAGE",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "OWNER",
+    "PARTITION",
+    "PRIMARY",
+    "PROCEDURE",
+    "REFERENCES",
+    "RENAME",
+    "REPLACE",
+    "RETURN",
+    "RETURNING",
+    "RETURNS",
+    "REVOKE",
+    "RIGHT",
+    "ROLE",
+    "ROLLBACK",
+    "ROW",
+    "RULE",
+    "SCHEMA",
+    "SELECT",
+    "SEQUENCE",
+    "SET",
+    "SHOW",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "TRIGGER",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USE",
+    "USER",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+#[derive(Clone)]
+pub struct SyntaxTheme {
+    pub text: Style,
+    pub keyword: Style,
+    pub string: Style,
+    pub number: Style,
+    pub comment: Style,
+}
+
+impl SyntaxTheme {
+    pub fn dark() -> Self {
+        SyntaxTheme {
+            text: Style::default(),
+            keyword: Style::default()
+                .fg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD),
+            string: Style::default().fg(Color::LightGreen),
+            number: Style::default().fg(Color::LightMagenta),
+            comment: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        }
+    }
+    pub fn light() -> Self {
+        SyntaxTheme {
+            text: Style::default(),
+            keyword: Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+            string: Style::default().fg(Color::Green),
+            number: Style::default().fg(Color::Magenta),
+            comment: Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        }
+    }
+    pub fn plain() -> Self {
+        SyntaxTheme {
+            text: Style::default(),
+            keyword: Style::default(),
+            string: Style::default(),
+            number: Style::default(),
+            comment: Style::default(),
+        }
+    }
+}
+
+impl Default for SyntaxTheme {
+    fn default() -> Self {
+        SyntaxTheme::dark()
+    }
+}
+
+pub fn highlight_sql<'b>(sql: &str, theme: &SyntaxTheme) -> Vec<Spans<'b>> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut lines: Vec<Vec<Span<'b>>> = vec![Vec::new()];
+    let mut push = |text: String, style: Style| {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Vec::new());
+            }
+            if !part.is_empty() {
+                lines
+                    .last_mut()
+                    .unwrap()
+                    .push(Span::styled(part.to_string(), style));
+            }
+        }
+    };
+    let take_until = |start: usize, end: &str| {
+        let end: Vec<char> = end.chars().collect();
+        let mut i = start;
+        while i < chars.len() {
+            if chars[i..].starts_with(&end) {
+                return i + end.len();
+            }
+            i += 1;
+        }
+        chars.len()
+    };
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let (end, style) = if chars[i..].starts_with(&['-', '-']) {
+            let end = chars[i..]
+                .iter()
+                .position(|c| *c == '\n')
+                .map_or(chars.len(), |p| i + p);
+            (end, theme.comment)
+        } else if chars[i..].starts_with(&['/', '*']) {
+            (take_until(i + 2, "*/"), theme.comment)
+        } else if c == '\'' {
+            (take_until(i + 1, "'"), theme.string)
+        } else if c == '"' || c == '`' {
+            (take_until(i + 1, &c.to_string()), theme.text)
+        } else if c.is_ascii_digit() {
+            let mut j = i + 1;
+            while j < chars.len() && (chars[j].is_ascii_digit() || chars[j] == '.') {
+                j += 1;
+            }
+            (j, theme.number)
+        } else if c.is_alphabetic() || c == '_' {
+            let mut j = i + 1;
+            while j < chars.len()
+                && (chars[j].is_alphanumeric() || chars[j] == '_' || chars[j] == '$')
+            {
+                j += 1;
+            }
+            let word = chars[i..j].iter().collect::<String>().to_uppercase();
+            if SQL_KEYWORDS.contains(&word.as_str()) {
+                (j, theme.keyword)
+            } else {
+                (j, theme.text)
+            }
+        } else {
+            (i + 1, theme.text)
+        };
+        push(chars[i..end].iter().collect(), style);
+        i = end;
+    }
+    lines.into_iter().map(Spans::from).collect()
+}
+
+pub fn draw_sql_textarea<B>(
+    f: &mut Frame<B>,
+    r: Rect,
+    textarea: &TextArea,
+    scroll: &mut (u16, u16),
+    theme: &SyntaxTheme,
+) where
+    B: Backend,
+{
+    if r.width == 0 || r.height == 0 {
+        return;
+    }
+    let (row, col) = textarea.cursor();
+    let (row, col) = (row as u16, col as u16);
+    let (mut top, mut left) = *scroll;
+    if row < top {
+        top = row;
+    } else if row >= top + r.height {
+        top = row + 1 - r.height;
+    }
+    if col < left {
+        left = col;
+    } else if col >= left + r.width {
+        left = col + 1 - r.width;
+    }
+    *scroll = (top, left);
+
+    f.render_widget(
+        Paragraph::new(highlight_sql(&textarea.lines().join("\n"), theme)).scroll((top, left)),
+        r,
+    );
+    let cursor = textarea.lines()[row as usize]
+        .chars()
+        .nth(col as usize)
+        .unwrap_or(' ');
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            cursor.to_string(),
+            Style::default().add_modifier(Modifier::REVERSED),
+        )),
+        Rect::new(r.x + col - left, r.y + row - top, 1, 1),
+    );
+}
@@ -1,5 +1,15 @@
+mod accent;
+mod completion;
+mod data_table;
+mod editor;
 mod form;
 mod form_item;
+mod grid;
+mod highlight;
+mod scrollbar;
 mod select;
 
-pub use {form::*, form_item::*, select::*};
+pub use {
+    accent::*, completion::*, data_table::*, editor::*, form::*, form_item::*, grid::*,
+    highlight::*, scrollbar::*, select::*,
+};
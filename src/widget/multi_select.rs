@@ -1,6 +1,6 @@
 use crate::{app::DialogResult, event::Key};
 use std::cmp::min;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -0,0 +1,44 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+pub struct Scrollbar {
+    len: usize,
+    offset: usize,
+    viewport: usize,
+}
+
+impl Scrollbar {
+    pub fn new(len: usize, offset: usize, viewport: usize) -> Self {
+        Scrollbar {
+            len,
+            offset,
+            viewport,
+        }
+    }
+}
+
+impl Widget for Scrollbar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 || self.len <= self.viewport {
+            return;
+        }
+        let height = area.height as usize;
+        let thumb_len = (height * self.viewport / self.len).clamp(1, height);
+        let max_offset = self.len - self.viewport;
+        let thumb_start = (height - thumb_len) * self.offset.min(max_offset) / max_offset;
+        for i in 0..height {
+            let (symbol, style) = if i >= thumb_start && i < thumb_start + thumb_len {
+                ("█", Style::default().fg(Color::Green))
+            } else {
+                ("│", Style::default().add_modifier(Modifier::DIM))
+            };
+            buf.get_mut(area.right() - 1, area.y + i as u16)
+                .set_symbol(symbol)
+                .set_style(style);
+        }
+    }
+}
@@ -4,14 +4,14 @@ use crate::{
     event::{config::*, Key, KeyCode},
     widget::form_item::FormItemResult,
 };
-use std::cmp::min;
-use tui::{
+use ratatui::{
     backend::Backend,
     layout::Rect,
     style::{Color, Style},
     widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState},
     Frame,
 };
+use std::cmp::min;
 
 pub struct Select {
     title: String,